@@ -1,17 +1,67 @@
-use crate::domain::entities::CachedUser;
+use crate::domain::entities::{CachedUser, Channel, CustomEmoji, Emoji, Role};
+
+/// Shortcode characters typed after `:` before emoji suggestions appear.
+const MIN_EMOJI_QUERY_LEN: usize = 2;
+const MAX_EMOJI_SUGGESTIONS: usize = 25;
+const MAX_CHANNEL_SUGGESTIONS: usize = 25;
+
+/// What the active trigger completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutocompleteKind {
+    /// `@user` and `@role` mentions.
+    #[default]
+    Mention,
+    /// `:shortcode:` emoji.
+    Emoji,
+    /// `#channel` mentions.
+    Channel,
+}
+
+impl AutocompleteKind {
+    #[must_use]
+    pub const fn trigger(self) -> char {
+        match self {
+            Self::Mention => '@',
+            Self::Emoji => ':',
+            Self::Channel => '#',
+        }
+    }
+
+    fn accepts_query(self, query: &str) -> bool {
+        match self {
+            Self::Mention => !query.contains('\n'),
+            Self::Emoji => {
+                query.chars().count() >= MIN_EMOJI_QUERY_LEN
+                    && query
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '+'))
+            }
+            Self::Channel => !query.is_empty() && !query.contains(char::is_whitespace),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AutocompleteSuggestion {
+    User(CachedUser),
+    Role(Role),
+    Emoji(Emoji),
+    Channel(Channel),
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct AutocompleteState {
     pub active: bool,
+    pub kind: AutocompleteKind,
     pub query: String,
     pub trigger_index: usize,
-    pub results: Vec<CachedUser>,
+    pub results: Vec<AutocompleteSuggestion>,
     pub selected_index: usize,
 }
 
 impl AutocompleteState {
     #[must_use]
-    pub fn selected_user(&self) -> Option<&CachedUser> {
+    pub fn selected(&self) -> Option<&AutocompleteSuggestion> {
         self.results.get(self.selected_index)
     }
 }
@@ -69,33 +119,16 @@ impl AutocompleteService {
 
         let slice_up_to_cursor = &text[..safe_cursor_idx];
 
-        if let Some(last_at_index) = slice_up_to_cursor.rfind('@') {
-            let valid_trigger = if last_at_index == 0 {
-                true
-            } else {
-                let char_before = slice_up_to_cursor[..last_at_index]
-                    .chars()
-                    .last()
-                    .unwrap_or(' ');
-                char_before.is_whitespace()
-            };
-
-            if valid_trigger {
-                let query = &slice_up_to_cursor[last_at_index + 1..];
-
-                if query.contains('\n') {
-                    if self.state.active {
-                        self.reset();
-                        return true;
-                    }
-                    return false;
-                }
+        if let Some((kind, trigger_index)) = Self::find_trigger(slice_up_to_cursor) {
+            let query = &slice_up_to_cursor[trigger_index + 1..];
 
+            if kind.accepts_query(query) {
                 let new_query = query.to_string();
-                if !self.state.active || self.state.query != new_query {
+                if !self.state.active || self.state.kind != kind || self.state.query != new_query {
                     self.state.active = true;
+                    self.state.kind = kind;
                     self.state.query = new_query;
-                    self.state.trigger_index = last_at_index;
+                    self.state.trigger_index = trigger_index;
                     return true;
                 }
                 return false;
@@ -109,16 +142,81 @@ impl AutocompleteService {
         false
     }
 
-    pub fn update_results(&mut self, candidates: Vec<CachedUser>) {
+    /// Finds the trigger closest to the cursor that starts a word.
+    fn find_trigger(text: &str) -> Option<(AutocompleteKind, usize)> {
+        [
+            AutocompleteKind::Mention,
+            AutocompleteKind::Emoji,
+            AutocompleteKind::Channel,
+        ]
+        .into_iter()
+        .filter_map(|kind| {
+            let index = text.rfind(kind.trigger())?;
+            let valid_trigger = text[..index].chars().last().is_none_or(char::is_whitespace);
+            valid_trigger.then_some((kind, index))
+        })
+        .max_by_key(|(_, index)| *index)
+    }
+
+    /// Suggests matching users followed by matching mentionable roles.
+    pub fn update_results(&mut self, candidates: Vec<CachedUser>, roles: Vec<Role>) {
         let lower_query = self.state.query.to_lowercase();
-        self.state.results = candidates
+        let users = candidates
             .into_iter()
             .filter(|user| {
                 user.username().to_lowercase().contains(&lower_query)
                     || user.display_name().to_lowercase().contains(&lower_query)
             })
+            .map(AutocompleteSuggestion::User);
+        let roles = roles
+            .into_iter()
+            .filter(|role| role.name.to_lowercase().contains(&lower_query))
+            .map(AutocompleteSuggestion::Role);
+        self.state.results = users.chain(roles).collect();
+
+        self.clamp_selection();
+    }
+
+    /// Suggests the guild's custom emoji and the bundled Unicode shortcodes
+    /// matching the query, prefix matches first.
+    pub fn update_emoji_results(&mut self, custom_emojis: &[CustomEmoji]) {
+        let lower_query = self.state.query.to_lowercase();
+
+        let mut matches: Vec<(bool, Emoji)> = custom_emojis
+            .iter()
+            .filter(|e| e.available)
+            .cloned()
+            .map(Emoji::Custom)
+            .chain(Emoji::unicode_catalog().iter().cloned())
+            .filter_map(|emoji| {
+                let name = emoji.name().to_lowercase();
+                name.contains(&lower_query)
+                    .then(|| (name.starts_with(&lower_query), emoji))
+            })
+            .collect();
+        matches.sort_by_key(|(is_prefix, _)| !is_prefix);
+
+        self.state.results = matches
+            .into_iter()
+            .take(MAX_EMOJI_SUGGESTIONS)
+            .map(|(_, emoji)| AutocompleteSuggestion::Emoji(emoji))
+            .collect();
+
+        self.clamp_selection();
+    }
+
+    /// Shows channels already filtered and ranked by the caller.
+    pub fn update_channel_results(&mut self, channels: Vec<Channel>) {
+        self.state.results = channels
+            .into_iter()
+            .take(MAX_CHANNEL_SUGGESTIONS)
+            .map(AutocompleteSuggestion::Channel)
             .collect();
 
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
         if self.state.selected_index >= self.state.results.len() {
             self.state.selected_index = 0;
         }
@@ -150,3 +248,100 @@ impl Default for AutocompleteService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colon_trigger_needs_two_characters() {
+        let mut service = AutocompleteService::new();
+
+        assert!(!service.process_input("hi :s", 5));
+        assert!(service.process_input("hi :sm", 6));
+
+        let state = service.state();
+        assert_eq!(state.kind, AutocompleteKind::Emoji);
+        assert_eq!(state.query, "sm");
+        assert_eq!(state.trigger_index, 3);
+    }
+
+    #[test]
+    fn test_colon_inside_word_is_not_a_trigger() {
+        let mut service = AutocompleteService::new();
+        assert!(!service.process_input("12:30", 5));
+        assert!(!service.process_input("time: now", 9));
+    }
+
+    #[test]
+    fn test_closest_trigger_wins() {
+        let mut service = AutocompleteService::new();
+        service.process_input("@bob :wave", 10);
+        assert_eq!(service.state().kind, AutocompleteKind::Emoji);
+
+        service.process_input(":wave @bo", 9);
+        assert_eq!(service.state().kind, AutocompleteKind::Mention);
+        assert_eq!(service.state().query, "bo");
+    }
+
+    #[test]
+    fn test_mention_results_include_roles_after_users() {
+        let mut service = AutocompleteService::new();
+        service.process_input("@mo", 3);
+
+        let role = |id: u64, name: &str| Role {
+            id: crate::domain::entities::RoleId(id),
+            name: name.to_string(),
+            color: 0,
+            hoist: false,
+            icon: None,
+            unicode_emoji: None,
+            position: 0,
+            permissions: crate::domain::entities::Permissions::empty(),
+            managed: false,
+            mentionable: true,
+        };
+        let user = CachedUser::new("1", "morgan", "0", None, None, false);
+        service.update_results(vec![user], vec![role(2, "mods"), role(3, "admins")]);
+
+        let results = &service.state().results;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(&results[0], AutocompleteSuggestion::User(u) if u.username() == "morgan"));
+        assert!(matches!(&results[1], AutocompleteSuggestion::Role(r) if r.name == "mods"));
+    }
+
+    #[test]
+    fn test_hash_trigger_opens_channel_suggestions() {
+        let mut service = AutocompleteService::new();
+        assert!(
+            !service.process_input("see #", 5),
+            "a bare # keeps Enter sending"
+        );
+
+        assert!(service.process_input("see #gen", 8));
+        assert_eq!(service.state().kind, AutocompleteKind::Channel);
+        assert_eq!(service.state().query, "gen");
+
+        assert!(service.process_input("# heading", 9));
+        assert!(!service.state().active);
+    }
+
+    #[test]
+    fn test_emoji_results_prefer_prefix_and_custom() {
+        let mut service = AutocompleteService::new();
+        service.process_input(":rock", 5);
+        service.update_emoji_results(&[CustomEmoji::new(1, "rockon")]);
+
+        let names: Vec<_> = service
+            .state()
+            .results
+            .iter()
+            .map(|s| match s {
+                AutocompleteSuggestion::Emoji(e) => e.name().to_string(),
+                _ => panic!("Expected emoji suggestion"),
+            })
+            .collect();
+        assert_eq!(names[0], "rockon");
+        assert!(names.contains(&"rocket".to_string()));
+    }
+}
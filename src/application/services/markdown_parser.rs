@@ -6,6 +6,11 @@ use std::str::CharIndices;
 pub trait MentionResolver: Send + Sync {
     fn resolve(&self, user_id: &str) -> Option<String>;
     fn resolve_channel(&self, channel_id: &str) -> Option<String>;
+
+    /// Name and RGB colour (0 when uncoloured) of a role.
+    fn resolve_role(&self, _role_id: &str) -> Option<(String, u32)> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -36,10 +41,140 @@ pub enum MdInline {
     Spoiler(Vec<MdInline>),
     Code(String),
     Mention(String),
+    Role(String),
     Channel(String),
+    Timestamp {
+        unix: i64,
+        style: TimestampStyle,
+    },
+    /// Custom guild emoji written as `<:name:id>` or `<a:name:id>`.
+    Emoji {
+        name: String,
+        id: String,
+        animated: bool,
+    },
     Url(String),
 }
 
+/// Display style of a `<t:unix:style>` tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampStyle {
+    /// `t`: short time.
+    ShortTime,
+    /// `T`: long time.
+    LongTime,
+    /// `d`: short date.
+    ShortDate,
+    /// `D`: long date.
+    LongDate,
+    /// `f`: short date and time, used when the style is omitted.
+    #[default]
+    ShortDateTime,
+    /// `F`: long date and time with weekday.
+    LongDateTime,
+    /// `R`: relative to now.
+    Relative,
+}
+
+impl TimestampStyle {
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            't' => Some(Self::ShortTime),
+            'T' => Some(Self::LongTime),
+            'd' => Some(Self::ShortDate),
+            'D' => Some(Self::LongDate),
+            'f' => Some(Self::ShortDateTime),
+            'F' => Some(Self::LongDateTime),
+            'R' => Some(Self::Relative),
+            _ => None,
+        }
+    }
+}
+
+/// Whether any block contains a relative timestamp, whose text changes over time.
+#[must_use]
+pub fn contains_relative_timestamp(blocks: &[MdBlock]) -> bool {
+    fn in_inlines(inlines: &[MdInline]) -> bool {
+        inlines.iter().any(|inline| match inline {
+            MdInline::Timestamp { style, .. } => *style == TimestampStyle::Relative,
+            MdInline::Bold(c)
+            | MdInline::Italic(c)
+            | MdInline::Underline(c)
+            | MdInline::Strike(c)
+            | MdInline::Spoiler(c) => in_inlines(c),
+            _ => false,
+        })
+    }
+
+    blocks.iter().any(|block| match block {
+        MdBlock::Header(_, inlines)
+        | MdBlock::List {
+            content: inlines, ..
+        }
+        | MdBlock::Subtext(inlines)
+        | MdBlock::Paragraph(inlines) => in_inlines(inlines),
+        MdBlock::BlockQuote(blocks) => contains_relative_timestamp(blocks),
+        MdBlock::CodeBlock { .. } | MdBlock::Empty => false,
+    })
+}
+
+/// IDs of the custom emoji in render order, skipping those inside hidden spoilers
+/// unless `include_spoilers` is set.
+#[must_use]
+pub fn custom_emoji_ids(blocks: &[MdBlock], include_spoilers: bool) -> Vec<String> {
+    let mut ids = Vec::new();
+    for_each_inline(blocks, include_spoilers, &mut |inline| {
+        if let MdInline::Emoji { id, .. } = inline {
+            ids.push(id.clone());
+        }
+    });
+    ids
+}
+
+/// Targets of the links in render order, including those inside spoilers.
+#[must_use]
+pub fn link_urls(blocks: &[MdBlock]) -> Vec<String> {
+    let mut urls = Vec::new();
+    for_each_inline(blocks, true, &mut |inline| {
+        if let MdInline::Url(url) = inline {
+            urls.push(url.clone());
+        }
+    });
+    urls
+}
+
+/// Visits every inline in render order, descending into styled children.
+fn for_each_inline(blocks: &[MdBlock], include_spoilers: bool, f: &mut impl FnMut(&MdInline)) {
+    fn visit(inlines: &[MdInline], include_spoilers: bool, f: &mut impl FnMut(&MdInline)) {
+        for inline in inlines {
+            f(inline);
+            match inline {
+                MdInline::Spoiler(_) if !include_spoilers => {}
+                MdInline::Bold(c)
+                | MdInline::Italic(c)
+                | MdInline::Underline(c)
+                | MdInline::Strike(c)
+                | MdInline::Spoiler(c) => visit(c, include_spoilers, f),
+                _ => {}
+            }
+        }
+    }
+
+    for block in blocks {
+        match block {
+            MdBlock::Header(_, inlines)
+            | MdBlock::List {
+                content: inlines, ..
+            }
+            | MdBlock::Subtext(inlines)
+            | MdBlock::Paragraph(inlines) => visit(inlines, include_spoilers, f),
+            MdBlock::BlockQuote(blocks) => for_each_inline(blocks, include_spoilers, f),
+            MdBlock::CodeBlock { .. } | MdBlock::Empty => {}
+        }
+    }
+}
+
 #[must_use]
 pub fn parse_markdown(content: &str) -> Vec<MdBlock> {
     Parser::parse(content)
@@ -353,7 +488,61 @@ fn handle_mention(
 ) {
     let remaining = &input[idx..];
 
-    if remaining.starts_with("<@") {
+    if remaining.starts_with("<t:")
+        && let Some(end) = remaining.find('>')
+    {
+        let (unix, style) = match remaining[3..end].split_once(':') {
+            Some((unix, style)) => {
+                let mut style_chars = style.chars();
+                let style = match (style_chars.next(), style_chars.next()) {
+                    (Some(c), None) => TimestampStyle::from_char(c),
+                    _ => None,
+                };
+                (unix, style)
+            }
+            None => (&remaining[3..end], Some(TimestampStyle::default())),
+        };
+
+        if let (Ok(unix), Some(style)) = (unix.parse::<i64>(), style) {
+            if idx > *start {
+                inlines.push(MdInline::Text(input[*start..idx].to_string()));
+            }
+            inlines.push(MdInline::Timestamp { unix, style });
+
+            let end_pos = idx + end;
+            while let Some((curr, _)) = chars.peek() {
+                if *curr <= end_pos {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            *start = end_pos + 1;
+        }
+    } else if remaining.starts_with("<:") || remaining.starts_with("<a:") {
+        handle_custom_emoji(input, idx, start, inlines, chars);
+    } else if remaining.starts_with("<@&")
+        && let Some(end) = remaining.find('>')
+    {
+        let id_content = &remaining[3..end];
+
+        if id_content.chars().all(char::is_numeric) && !id_content.is_empty() {
+            if idx > *start {
+                inlines.push(MdInline::Text(input[*start..idx].to_string()));
+            }
+            inlines.push(MdInline::Role(id_content.to_string()));
+
+            let end_pos = idx + end;
+            while let Some((curr, _)) = chars.peek() {
+                if *curr <= end_pos {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            *start = end_pos + 1;
+        }
+    } else if remaining.starts_with("<@") {
         if let Some(end) = remaining.find('>') {
             if idx > *start {
                 inlines.push(MdInline::Text(input[*start..idx].to_string()));
@@ -401,6 +590,47 @@ fn handle_mention(
     }
 }
 
+fn handle_custom_emoji(
+    input: &str,
+    idx: usize,
+    start: &mut usize,
+    inlines: &mut Vec<MdInline>,
+    chars: &mut Peekable<CharIndices>,
+) {
+    let remaining = &input[idx..];
+    let Some(end) = remaining.find('>') else {
+        return;
+    };
+    let animated = remaining.starts_with("<a:");
+    let body = &remaining[if animated { 3 } else { 2 }..end];
+
+    if let Some((name, id)) = body.split_once(':')
+        && !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !id.is_empty()
+        && id.chars().all(|c| c.is_ascii_digit())
+    {
+        if idx > *start {
+            inlines.push(MdInline::Text(input[*start..idx].to_string()));
+        }
+        inlines.push(MdInline::Emoji {
+            name: name.to_string(),
+            id: id.to_string(),
+            animated,
+        });
+
+        let end_pos = idx + end;
+        while let Some((curr, _)) = chars.peek() {
+            if *curr <= end_pos {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        *start = end_pos + 1;
+    }
+}
+
 fn handle_escape(
     input: &str,
     idx: usize,
@@ -580,6 +810,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_role_mention() {
+        let content = "Ping <@&987> now";
+        let blocks = parse_markdown(content);
+
+        if let MdBlock::Paragraph(inlines) = &blocks[0] {
+            assert_eq!(inlines.len(), 3);
+            if let MdInline::Role(id) = &inlines[1] {
+                assert_eq!(id, "987");
+            } else {
+                panic!("Expected Role mention");
+            }
+        } else {
+            panic!("Expected Paragraph");
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_styles() {
+        let blocks = parse_markdown("<t:1700000000:R> <t:1700000000> <t:1700000000:x>");
+
+        let MdBlock::Paragraph(inlines) = &blocks[0] else {
+            panic!("Expected Paragraph");
+        };
+        assert!(matches!(
+            inlines[0],
+            MdInline::Timestamp {
+                unix: 1_700_000_000,
+                style: TimestampStyle::Relative
+            }
+        ));
+        assert!(matches!(
+            inlines[2],
+            MdInline::Timestamp {
+                style: TimestampStyle::ShortDateTime,
+                ..
+            }
+        ));
+        assert!(matches!(&inlines[3], MdInline::Text(t) if t == " <t:1700000000:x>"));
+        assert!(contains_relative_timestamp(&blocks));
+    }
+
+    #[test]
+    fn test_parse_custom_emoji() {
+        let blocks = parse_markdown("hi <:party_blob:123> <a:wave:456> <:bad:x1>");
+
+        let MdBlock::Paragraph(inlines) = &blocks[0] else {
+            panic!("Expected Paragraph");
+        };
+        assert!(matches!(
+            &inlines[1],
+            MdInline::Emoji { name, id, animated: false } if name == "party_blob" && id == "123"
+        ));
+        assert!(matches!(
+            &inlines[3],
+            MdInline::Emoji { name, id, animated: true } if name == "wave" && id == "456"
+        ));
+        assert!(matches!(&inlines[4], MdInline::Text(t) if t == " <:bad:x1>"));
+
+        let blocks = parse_markdown("> <:a:1> ||<:b:2>|| **<:c:3>**");
+        assert_eq!(custom_emoji_ids(&blocks, false), ["1", "3"]);
+        assert_eq!(custom_emoji_ids(&blocks, true), ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_link_urls_in_render_order() {
+        let blocks = parse_markdown("see https://a.com and **https://b.com**\n> https://c.com");
+        assert_eq!(
+            link_urls(&blocks),
+            ["https://a.com", "https://b.com", "https://c.com"]
+        );
+    }
+
     #[test]
     fn test_parse_discord_channel_url_with_query_params() {
         let content = "Check https://discord.com/channels/123/456?foo=bar";
@@ -3,5 +3,6 @@ pub mod identity_resolver;
 pub mod markdown_parser;
 pub mod message_content_service;
 pub mod notification_manager;
+pub mod notification_rules;
 pub mod notification_service;
 pub mod url_extractor;
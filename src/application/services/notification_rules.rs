@@ -0,0 +1,298 @@
+//! User-defined notification rules from `[[notifications.rules]]`.
+
+use std::sync::Arc;
+
+use regex::{Regex, RegexBuilder};
+use tracing::warn;
+
+use crate::domain::entities::{GuildId, Message};
+use crate::infrastructure::config::{NotificationRuleAction, NotificationRuleConfig};
+
+/// Where a message was posted, as far as rules are concerned.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageScope {
+    pub guild_id: Option<GuildId>,
+    pub guild_name: Option<String>,
+    pub channel_name: Option<String>,
+}
+
+/// Combined actions of every rule matching a message.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleOutcome {
+    pub desktop: bool,
+    pub internal: bool,
+    pub highlight: bool,
+    pub bell: bool,
+    pub suppress: bool,
+}
+
+impl RuleOutcome {
+    fn add(&mut self, action: NotificationRuleAction) {
+        match action {
+            NotificationRuleAction::Desktop => self.desktop = true,
+            NotificationRuleAction::Internal => self.internal = true,
+            NotificationRuleAction::Highlight => self.highlight = true,
+            NotificationRuleAction::Bell => self.bell = true,
+            NotificationRuleAction::Suppress => self.suppress = true,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct NotificationRule {
+    keywords: Option<Regex>,
+    regex: Option<Regex>,
+    author: Option<String>,
+    guild: Option<String>,
+    channel: Option<String>,
+    actions: Vec<NotificationRuleAction>,
+}
+
+impl NotificationRule {
+    fn compile(config: &NotificationRuleConfig) -> Result<Self, regex::Error> {
+        let keywords: Vec<String> = config
+            .keywords
+            .iter()
+            .map(|keyword| keyword.trim())
+            .filter(|keyword| !keyword.is_empty())
+            .map(regex::escape)
+            .collect();
+        let keywords = if keywords.is_empty() {
+            None
+        } else {
+            // Keywords must stand on their own, so "rust" does not match "trust".
+            let pattern = format!(r"(?:^|\W)(?:{})(?:$|\W)", keywords.join("|"));
+            Some(RegexBuilder::new(&pattern).case_insensitive(true).build()?)
+        };
+        let regex = config.regex.as_deref().map(Regex::new).transpose()?;
+        let name = |value: &Option<String>, prefix: Option<char>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .map(|value| {
+                    prefix
+                        .and_then(|prefix| value.strip_prefix(prefix))
+                        .unwrap_or(value)
+                        .to_lowercase()
+                })
+                .filter(|value| !value.is_empty())
+        };
+
+        Ok(Self {
+            keywords,
+            regex,
+            author: name(&config.author, Some('@')),
+            guild: name(&config.guild, None),
+            channel: name(&config.channel, Some('#')),
+            actions: config.actions.clone(),
+        })
+    }
+
+    fn matches(&self, message: &Message, scope: &MessageScope) -> bool {
+        let content = message.content();
+        if self
+            .keywords
+            .as_ref()
+            .is_some_and(|re| !re.is_match(content))
+            || self.regex.as_ref().is_some_and(|re| !re.is_match(content))
+        {
+            return false;
+        }
+
+        let author = message.author();
+        if let Some(pattern) = &self.author
+            && !matches_name(
+                pattern,
+                author.id(),
+                [Some(author.username()), author.global_name.as_deref()],
+            )
+        {
+            return false;
+        }
+
+        if let Some(pattern) = &self.guild {
+            let guild_id = scope.guild_id.or_else(|| message.guild_id());
+            let Some(guild_id) = guild_id else {
+                return false;
+            };
+            if !matches_name(
+                pattern,
+                &guild_id.to_string(),
+                [scope.guild_name.as_deref(), None],
+            ) {
+                return false;
+            }
+        }
+
+        self.channel.as_ref().is_none_or(|pattern| {
+            matches_name(
+                pattern,
+                &message.channel_id().to_string(),
+                [scope.channel_name.as_deref(), None],
+            )
+        })
+    }
+}
+
+fn matches_name(pattern: &str, id: &str, names: [Option<&str>; 2]) -> bool {
+    pattern == id
+        || names
+            .into_iter()
+            .flatten()
+            .any(|name| name.to_lowercase() == pattern)
+}
+
+/// Compiled notification rules; cheap to clone and share with the UI.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationRules {
+    rules: Arc<[NotificationRule]>,
+}
+
+impl NotificationRules {
+    /// Compiles the configured rules, skipping any with an invalid regex.
+    #[must_use]
+    pub fn new(configs: &[NotificationRuleConfig]) -> Self {
+        Self::compile(configs).0
+    }
+
+    /// Compiles the configured rules, also returning a message for each skipped rule.
+    #[must_use]
+    pub fn compile(configs: &[NotificationRuleConfig]) -> (Self, Vec<String>) {
+        let mut rules = Vec::new();
+        let mut errors = Vec::new();
+        for (index, config) in configs.iter().enumerate() {
+            match NotificationRule::compile(config) {
+                Ok(rule) => rules.push(rule),
+                Err(error) => {
+                    warn!(rule = index, error = %error, "Skipping invalid notification rule");
+                    errors.push(format!("Rule {} was skipped: {error}", index + 1));
+                }
+            }
+        }
+        (
+            Self {
+                rules: rules.into(),
+            },
+            errors,
+        )
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether both share the same compiled rules.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.rules, &other.rules)
+    }
+
+    /// Actions of every rule matching the message.
+    #[must_use]
+    pub fn evaluate(&self, message: &Message, scope: &MessageScope) -> RuleOutcome {
+        let mut outcome = RuleOutcome::default();
+        for rule in self
+            .rules
+            .iter()
+            .filter(|rule| rule.matches(message, scope))
+        {
+            for action in &rule.actions {
+                outcome.add(*action);
+            }
+        }
+        outcome
+    }
+
+    #[must_use]
+    pub fn highlights(&self, message: &Message, scope: &MessageScope) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.actions.contains(&NotificationRuleAction::Highlight)
+                && rule.matches(message, scope)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(author: &str, content: &str) -> Message {
+        Message::test(1, 7, content)
+            .with_author("42", author)
+            .with_guild_id(Some(GuildId(3)))
+    }
+
+    fn scope() -> MessageScope {
+        MessageScope {
+            guild_id: Some(GuildId(3)),
+            guild_name: Some("Infra".to_string()),
+            channel_name: Some("alerts".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_keywords_match_whole_words() {
+        let rules = NotificationRules::new(&[NotificationRuleConfig {
+            keywords: vec!["Deploy".to_string()],
+            guild: Some("infra".to_string()),
+            channel: Some("#alerts".to_string()),
+            actions: vec![
+                NotificationRuleAction::Desktop,
+                NotificationRuleAction::Highlight,
+            ],
+            ..NotificationRuleConfig::default()
+        }]);
+
+        let outcome = rules.evaluate(&message("alice", "deploy failed!"), &scope());
+        assert!(outcome.desktop && outcome.highlight);
+        assert!(!outcome.internal && !outcome.suppress);
+        assert!(rules.highlights(&message("alice", "who will DEPLOY?"), &scope()));
+        assert!(!rules.highlights(&message("alice", "redeployed"), &scope()));
+
+        let other_guild = MessageScope {
+            guild_name: Some("Games".to_string()),
+            guild_id: Some(GuildId(4)),
+            ..scope()
+        };
+        assert!(!rules.highlights(&message("alice", "deploy"), &other_guild));
+    }
+
+    #[test]
+    fn test_rules_combine_and_skip_invalid_regex() {
+        let (rules, errors) = NotificationRules::compile(&[
+            NotificationRuleConfig {
+                regex: Some("(unclosed".to_string()),
+                actions: vec![NotificationRuleAction::Bell],
+                ..NotificationRuleConfig::default()
+            },
+            NotificationRuleConfig {
+                regex: Some(r"^ERR-\d+".to_string()),
+                actions: vec![NotificationRuleAction::Internal],
+                ..NotificationRuleConfig::default()
+            },
+            NotificationRuleConfig {
+                author: Some("@Noisy-Bot".to_string()),
+                actions: vec![NotificationRuleAction::Suppress],
+                ..NotificationRuleConfig::default()
+            },
+        ]);
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("Rule 1 was skipped"));
+        let outcome = rules.evaluate(&message("noisy-bot", "ERR-12 disk full"), &scope());
+        assert_eq!(
+            outcome,
+            RuleOutcome {
+                internal: true,
+                suppress: true,
+                ..RuleOutcome::default()
+            }
+        );
+        assert_eq!(
+            rules.evaluate(&message("alice", "all good"), &scope()),
+            RuleOutcome::default()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{GuildId, MessageId};
+use super::{ForumTag, GuildId, MessageId, Permissions};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -254,6 +254,12 @@ pub struct Channel {
     thread_metadata: Option<ThreadMetadata>,
     #[serde(default)]
     last_pin_timestamp: Option<String>,
+    /// Whether the current user has joined this thread.
+    #[serde(default)]
+    thread_member: bool,
+    /// Tags a forum channel offers for its posts.
+    #[serde(default)]
+    available_tags: Vec<ForumTag>,
 }
 
 impl Channel {
@@ -281,6 +287,8 @@ impl Channel {
             permission_overwrites: Vec::new(),
             thread_metadata: None,
             last_pin_timestamp: None,
+            thread_member: false,
+            available_tags: Vec::new(),
         }
     }
 
@@ -386,6 +394,18 @@ impl Channel {
         self
     }
 
+    #[must_use]
+    pub const fn with_thread_member(mut self, member: bool) -> Self {
+        self.thread_member = member;
+        self
+    }
+
+    #[must_use]
+    pub fn with_available_tags(mut self, tags: Vec<ForumTag>) -> Self {
+        self.available_tags = tags;
+        self
+    }
+
     #[must_use]
     pub const fn id(&self) -> ChannelId {
         self.id
@@ -502,10 +522,94 @@ impl Channel {
         self.thread_metadata.as_ref()
     }
 
+    /// Whether this is a thread that has been archived.
+    #[must_use]
+    pub fn is_archived(&self) -> bool {
+        self.thread_metadata.as_ref().is_some_and(|m| m.archived)
+    }
+
+    #[must_use]
+    pub const fn is_thread_member(&self) -> bool {
+        self.thread_member
+    }
+
+    pub const fn set_thread_member(&mut self, member: bool) {
+        self.thread_member = member;
+    }
+
+    #[must_use]
+    pub fn available_tags(&self) -> &[ForumTag] {
+        &self.available_tags
+    }
+
     #[must_use]
     pub fn display_name(&self) -> String {
         format!("{}{}", self.kind.prefix(), self.name)
     }
+
+    /// Id of the member list Discord syncs for this channel.
+    ///
+    /// Channels with the same `VIEW_CHANNEL` overwrites share one list: it is
+    /// `everyone` without such overwrites, else a murmur3 hash of them.
+    #[must_use]
+    pub fn member_list_id(&self) -> String {
+        let grants_view = |bits: &str| {
+            bits.parse::<u64>()
+                .is_ok_and(|bits| bits & Permissions::VIEW_CHANNEL.bits() != 0)
+        };
+        let mut overwrites: Vec<String> = self
+            .permission_overwrites
+            .iter()
+            .filter_map(|overwrite| {
+                if grants_view(&overwrite.allow) {
+                    Some(format!("allow:{}", overwrite.id))
+                } else if grants_view(&overwrite.deny) {
+                    Some(format!("deny:{}", overwrite.id))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if overwrites.is_empty() {
+            return "everyone".to_string();
+        }
+        overwrites.sort();
+        murmur3_32(overwrites.join(",").as_bytes(), 0).to_string()
+    }
+}
+
+/// 32-bit `MurmurHash3` (x86), as used for member list ids.
+#[allow(clippy::cast_possible_truncation)]
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    let scramble = |k: u32| k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+
+    let mut hash = seed;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        hash ^= scramble(k);
+        hash = hash
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe654_6b64);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .rev()
+            .fold(0_u32, |k, &byte| (k << 8) | u32::from(byte));
+        hash ^= scramble(k);
+    }
+
+    hash ^= data.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^ (hash >> 16)
 }
 
 #[cfg(test)]
@@ -537,6 +641,31 @@ mod tests {
         assert_eq!(channel.display_name(), "󰕾 Voice");
     }
 
+    #[test]
+    fn test_member_list_id_hashes_view_overwrites() {
+        assert_eq!(murmur3_32(b"hello", 0), 0x248b_fa47);
+
+        let overwrite = |id: &str, allow: Permissions, deny: Permissions| PermissionOverwrite {
+            id: id.to_string(),
+            overwrite_type: OverwriteType::Role,
+            allow: allow.bits().to_string(),
+            deny: deny.bits().to_string(),
+        };
+        let channel =
+            Channel::new(1_u64, "general", ChannelKind::Text).with_permission_overwrites(vec![
+                overwrite("5", Permissions::SEND_MESSAGES, Permissions::empty()),
+            ]);
+        assert_eq!(channel.member_list_id(), "everyone");
+
+        let staff =
+            Channel::new(2_u64, "staff", ChannelKind::Text).with_permission_overwrites(vec![
+                overwrite("9", Permissions::VIEW_CHANNEL, Permissions::empty()),
+                overwrite("1", Permissions::empty(), Permissions::VIEW_CHANNEL),
+            ]);
+        let expected = murmur3_32(b"allow:9,deny:1", 0).to_string();
+        assert_eq!(staff.member_list_id(), expected);
+    }
+
     #[test]
     fn test_channel_kind_is_text_based() {
         assert!(ChannelKind::Text.is_text_based());
@@ -0,0 +1,168 @@
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::ReactionEmoji;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EmojiId(#[serde(with = "crate::domain::serde_utils::string_to_u64")] pub u64);
+
+impl EmojiId {
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for EmojiId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for EmojiId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+/// A guild's custom emoji.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CustomEmoji {
+    pub id: EmojiId,
+    pub name: String,
+    #[serde(default)]
+    pub animated: bool,
+    #[serde(default = "default_available")]
+    pub available: bool,
+}
+
+const fn default_available() -> bool {
+    true
+}
+
+impl CustomEmoji {
+    #[must_use]
+    pub fn new(id: impl Into<EmojiId>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            animated: false,
+            available: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_available(mut self, available: bool) -> Self {
+        self.available = available;
+        self
+    }
+
+    /// Message markup for the emoji: `<:name:id>`, or `<a:name:id>` when animated.
+    #[must_use]
+    pub fn markup(&self) -> String {
+        let prefix = if self.animated { "a" } else { "" };
+        format!("<{prefix}:{}:{}>", self.name, self.id)
+    }
+}
+
+/// An emoji that can be inserted into a message or used as a reaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Emoji {
+    Unicode {
+        shortcode: &'static str,
+        value: &'static str,
+    },
+    Custom(CustomEmoji),
+}
+
+static UNICODE_EMOJIS: LazyLock<Vec<Emoji>> = LazyLock::new(|| {
+    emojis::iter()
+        .filter_map(|emoji| {
+            Some(Emoji::Unicode {
+                shortcode: emoji.shortcode()?,
+                value: emoji.as_str(),
+            })
+        })
+        .collect()
+});
+
+impl Emoji {
+    /// Every Unicode emoji that has a shortcode, in Unicode ordering.
+    #[must_use]
+    pub fn unicode_catalog() -> &'static [Self] {
+        &UNICODE_EMOJIS
+    }
+
+    #[must_use]
+    pub fn from_shortcode(shortcode: &str) -> Option<Self> {
+        let emoji = emojis::get_by_shortcode(shortcode)?;
+        Some(Self::Unicode {
+            shortcode: emoji.shortcode()?,
+            value: emoji.as_str(),
+        })
+    }
+
+    /// Shortcode without the surrounding colons.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Unicode { shortcode, .. } => shortcode,
+            Self::Custom(emoji) => &emoji.name,
+        }
+    }
+
+    /// Text inserted into a message for this emoji.
+    #[must_use]
+    pub fn markup(&self) -> String {
+        match self {
+            Self::Unicode { value, .. } => (*value).to_string(),
+            Self::Custom(emoji) => emoji.markup(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_reaction(&self) -> ReactionEmoji {
+        match self {
+            Self::Unicode { value, .. } => ReactionEmoji::unicode(*value),
+            Self::Custom(emoji) => ReactionEmoji::custom(emoji.id.to_string(), &emoji.name),
+        }
+    }
+}
+
+impl From<CustomEmoji> for Emoji {
+    fn from(emoji: CustomEmoji) -> Self {
+        Self::Custom(emoji)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_emoji_markup() {
+        let emoji = CustomEmoji::new(42, "blob");
+        assert_eq!(emoji.markup(), "<:blob:42>");
+        assert_eq!(emoji.with_animated(true).markup(), "<a:blob:42>");
+    }
+
+    #[test]
+    fn test_unicode_emoji_lookup() {
+        let emoji = Emoji::from_shortcode("thumbsup").unwrap();
+        assert_eq!(emoji.markup(), "👍");
+        assert_eq!(emoji.to_reaction(), ReactionEmoji::unicode("👍"));
+        assert!(
+            Emoji::unicode_catalog()
+                .iter()
+                .any(|e| e.name() == "rocket")
+        );
+    }
+}
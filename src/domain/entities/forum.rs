@@ -2,6 +2,74 @@ use serde::{Deserialize, Serialize};
 
 use super::{ChannelId, GuildId, Message};
 
+/// A tag a forum channel offers for its posts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ForumTag {
+    #[serde(with = "crate::domain::serde_utils::string_to_u64")]
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+    /// Only members who can manage threads may apply it.
+    #[serde(default)]
+    pub moderated: bool,
+}
+
+impl ForumTag {
+    #[must_use]
+    pub fn new(id: u64, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            emoji_name: None,
+            moderated: false,
+        }
+    }
+
+    /// The tag name, prefixed with its unicode emoji if it has one.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match &self.emoji_name {
+            Some(emoji) => format!("{emoji} {}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Order of posts in a forum listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ForumSortOrder {
+    #[default]
+    LatestActivity,
+    CreationDate,
+}
+
+impl ForumSortOrder {
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::LatestActivity => Self::CreationDate,
+            Self::CreationDate => Self::LatestActivity,
+        }
+    }
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::LatestActivity => "Latest activity",
+            Self::CreationDate => "Creation date",
+        }
+    }
+}
+
+/// Which forum posts are listed, and in what order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ForumFilter {
+    pub sort: ForumSortOrder,
+    /// Only list posts carrying this tag.
+    pub tag_id: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ForumThread {
     pub id: ChannelId,
@@ -0,0 +1,63 @@
+//! Entries of a guild channel's member list.
+
+use super::{Activity, PresenceStatus};
+
+/// A section of the member list: a hoisted role id, `online` or `offline`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberListGroup {
+    pub id: String,
+    pub count: u32,
+}
+
+/// A member as shown in the member list, with its presence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberListEntry {
+    pub user_id: String,
+    pub username: String,
+    pub global_name: Option<String>,
+    pub nick: Option<String>,
+    pub bot: bool,
+    pub roles: Vec<String>,
+    pub status: PresenceStatus,
+    pub activities: Vec<Activity>,
+}
+
+impl MemberListEntry {
+    /// Guild nickname, then global display name, then username.
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.nick
+            .as_deref()
+            .or(self.global_name.as_deref())
+            .unwrap_or(&self.username)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberListItem {
+    Group(MemberListGroup),
+    Member(MemberListEntry),
+}
+
+/// An edit to the flat item list (group headers followed by their members).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberListOp {
+    Sync {
+        range: (u32, u32),
+        items: Vec<MemberListItem>,
+    },
+    Insert {
+        index: u32,
+        item: MemberListItem,
+    },
+    Update {
+        index: u32,
+        item: MemberListItem,
+    },
+    Delete {
+        index: u32,
+    },
+    Invalidate {
+        range: (u32, u32),
+    },
+}
@@ -3,7 +3,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
-use super::{ChannelId, GuildId, User};
+use super::{ChannelId, GuildId, RoleId, User};
 
 /// Regex for matching Discord channel mentions: `<#channel_id>`
 pub static CHANNEL_MENTION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<#(\d+)>").unwrap());
@@ -16,6 +16,43 @@ pub static CHANNEL_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"https?://(?:ptb\.|canary\.)?discord(?:app)?\.com/channels/\d+/(\d+)").unwrap()
 });
 
+/// Regex for matching a whole Discord channel or message link, including DM (`@me`) links.
+static DISCORD_LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^https?://(?:ptb\.|canary\.)?discord(?:app)?\.com/channels/(\d+|@me)/(\d+)(?:/(\d+))?/?(?:[?#]\S*)?$",
+    )
+    .unwrap()
+});
+
+/// Target of a `discord.com/channels/...` link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscordLink {
+    /// `None` for direct message links (`@me`).
+    pub guild_id: Option<GuildId>,
+    pub channel_id: ChannelId,
+    pub message_id: Option<MessageId>,
+}
+
+impl DiscordLink {
+    /// Parses a channel or message link; surrounding whitespace is ignored.
+    #[must_use]
+    pub fn parse(url: &str) -> Option<Self> {
+        let captures = DISCORD_LINK_RE.captures(url.trim())?;
+        let guild_id = match &captures[1] {
+            "@me" => None,
+            id => Some(GuildId(id.parse().ok()?)),
+        };
+        Some(Self {
+            guild_id,
+            channel_id: ChannelId(captures[2].parse().ok()?),
+            message_id: captures
+                .get(3)
+                .and_then(|id| id.as_str().parse().ok())
+                .map(MessageId),
+        })
+    }
+}
+
 /// Unique identifier for a Discord message.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -374,6 +411,8 @@ pub struct Message {
     embeds: Vec<Embed>,
     pinned: bool,
     mentions: Vec<User>,
+    mention_roles: Vec<RoleId>,
+    mention_everyone: bool,
     reactions: Vec<Reaction>,
     flags: MessageFlags,
     #[allow(clippy::struct_field_names)]
@@ -428,6 +467,17 @@ impl Message {
         &self.mentions
     }
 
+    #[must_use]
+    pub fn mention_roles(&self) -> &[RoleId] {
+        &self.mention_roles
+    }
+
+    /// Whether the message pings `@everyone` or `@here`.
+    #[must_use]
+    pub const fn mentions_everyone(&self) -> bool {
+        self.mention_everyone
+    }
+
     #[must_use]
     pub fn reactions(&self) -> &[Reaction] {
         &self.reactions
@@ -438,6 +488,16 @@ impl Message {
         self.flags
     }
 
+    /// The thread started from this message; it shares the message's id.
+    #[must_use]
+    pub const fn thread_id(&self) -> Option<ChannelId> {
+        if self.flags.contains(MessageFlags::HAS_THREAD) {
+            Some(ChannelId(self.id.0))
+        } else {
+            None
+        }
+    }
+
     #[must_use]
     pub const fn message_reference(&self) -> Option<&MessageReference> {
         self.message_reference.as_ref()
@@ -453,6 +513,11 @@ impl Message {
         self.timestamp.format("%H:%M").to_string()
     }
 
+    #[must_use]
+    pub const fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
     #[must_use]
     pub const fn is_edited(&self) -> bool {
         self.edited_timestamp.is_some()
@@ -499,6 +564,55 @@ pub struct ReactionEmoji {
     pub name: Option<String>,
 }
 
+impl ReactionEmoji {
+    #[must_use]
+    pub fn unicode(emoji: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: Some(emoji.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn custom(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: Some(id.into()),
+            name: Some(name.into()),
+        }
+    }
+
+    /// Text shown for the emoji: the character itself, or `:name:` for custom emoji.
+    #[must_use]
+    pub fn display(&self) -> String {
+        let name = self.name.as_deref().unwrap_or("?");
+        if self.id.is_some() {
+            format!(":{name}:")
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Identifier used by the reaction endpoints (`name:id` for custom emoji).
+    #[must_use]
+    pub fn api_name(&self) -> String {
+        let name = self.name.as_deref().unwrap_or_default();
+        match &self.id {
+            Some(id) => format!("{name}:{id}"),
+            None => name.to_string(),
+        }
+    }
+
+    /// Custom emoji are compared by id since their names can change.
+    #[must_use]
+    pub fn is_same(&self, other: &Self) -> bool {
+        match (&self.id, &other.id) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => self.name == other.name,
+            _ => false,
+        }
+    }
+}
+
 impl MessageReference {
     #[must_use]
     pub const fn new(
@@ -537,6 +651,8 @@ impl Message {
             embeds: Vec::new(),
             pinned: false,
             mentions: Vec::new(),
+            mention_roles: Vec::new(),
+            mention_everyone: false,
             reactions: Vec::new(),
             flags: MessageFlags::empty(),
             message_reference: None,
@@ -586,6 +702,18 @@ impl Message {
         self
     }
 
+    #[must_use]
+    pub fn with_mention_roles(mut self, mention_roles: Vec<RoleId>) -> Self {
+        self.mention_roles = mention_roles;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_mention_everyone(mut self, mention_everyone: bool) -> Self {
+        self.mention_everyone = mention_everyone;
+        self
+    }
+
     #[must_use]
     pub fn with_reactions(mut self, reactions: Vec<Reaction>) -> Self {
         self.reactions = reactions;
@@ -610,6 +738,50 @@ impl Message {
         self
     }
 
+    pub const fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    /// Applies a reaction added by a user. Our own reactions are only counted
+    /// once, so an optimistic update followed by the gateway echo is a no-op.
+    pub fn add_reaction(&mut self, emoji: ReactionEmoji, me: bool) {
+        if let Some(reaction) = self.reactions.iter_mut().find(|r| r.emoji.is_same(&emoji)) {
+            if me && reaction.me {
+                return;
+            }
+            reaction.count += 1;
+            reaction.me |= me;
+        } else {
+            self.reactions.push(Reaction {
+                count: 1,
+                me,
+                emoji,
+            });
+        }
+    }
+
+    /// Removes a user's reaction, dropping the reaction entirely when its count reaches zero.
+    pub fn remove_reaction(&mut self, emoji: &ReactionEmoji, me: bool) {
+        let Some(pos) = self.reactions.iter().position(|r| r.emoji.is_same(emoji)) else {
+            return;
+        };
+        let reaction = &mut self.reactions[pos];
+        if me {
+            if !reaction.me {
+                return;
+            }
+            reaction.me = false;
+        }
+        reaction.count = reaction.count.saturating_sub(1);
+        if reaction.count == 0 {
+            self.reactions.remove(pos);
+        }
+    }
+
+    pub fn clear_reactions(&mut self) {
+        self.reactions.clear();
+    }
+
     /// Checks if the message can be edited by the given user.
     ///
     /// Currently, a user can only edit their own messages.
@@ -618,3 +790,99 @@ impl Message {
         self.author.id == user.id_str()
     }
 }
+
+#[cfg(test)]
+impl Message {
+    /// Plain message by `alice` for tests; the `with_*` builders cover the rest.
+    #[must_use]
+    pub fn test(id: u64, channel_id: u64, content: &str) -> Self {
+        Self::new(
+            MessageId(id),
+            ChannelId(channel_id),
+            MessageAuthor {
+                id: "2".to_string(),
+                username: "alice".to_string(),
+                discriminator: "0".to_string(),
+                avatar: None,
+                bot: false,
+                global_name: None,
+                color: None,
+            },
+            content.to_string(),
+            Local::now(),
+            MessageKind::Default,
+        )
+    }
+
+    #[must_use]
+    pub fn with_author(mut self, id: &str, username: &str) -> Self {
+        self.author.id = id.to_string();
+        self.author.username = username.to_string();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_message() -> Message {
+        Message::test(1, 100, "Hello")
+    }
+
+    #[test]
+    fn test_parse_discord_links() {
+        assert_eq!(
+            DiscordLink::parse("https://discord.com/channels/1/2/3"),
+            Some(DiscordLink {
+                guild_id: Some(GuildId(1)),
+                channel_id: ChannelId(2),
+                message_id: Some(MessageId(3)),
+            })
+        );
+        assert_eq!(
+            DiscordLink::parse(" https://canary.discord.com/channels/@me/2?x=y "),
+            Some(DiscordLink {
+                guild_id: None,
+                channel_id: ChannelId(2),
+                message_id: None,
+            })
+        );
+        assert!(DiscordLink::parse("https://discord.com/channels/1").is_none());
+        assert!(DiscordLink::parse("https://example.com/channels/1/2").is_none());
+    }
+
+    #[test]
+    fn test_own_reaction_is_counted_once() {
+        let mut message = create_test_message();
+        let emoji = ReactionEmoji::unicode("👍");
+
+        message.add_reaction(emoji.clone(), true);
+        message.add_reaction(emoji.clone(), true);
+        message.add_reaction(emoji, false);
+
+        assert_eq!(message.reactions.len(), 1);
+        assert_eq!(message.reactions[0].count, 2);
+        assert!(message.reactions[0].me);
+    }
+
+    #[test]
+    fn test_remove_reaction_drops_empty_reaction() {
+        let mut message = create_test_message();
+        let emoji = ReactionEmoji::custom("42", "blob");
+
+        message.add_reaction(emoji.clone(), false);
+        message.remove_reaction(&emoji, true);
+        assert_eq!(message.reactions[0].count, 1);
+
+        message.remove_reaction(&ReactionEmoji::custom("42", "renamed"), false);
+        assert!(message.reactions.is_empty());
+    }
+
+    #[test]
+    fn test_reaction_emoji_api_name() {
+        assert_eq!(ReactionEmoji::unicode("🔥").api_name(), "🔥");
+        assert_eq!(ReactionEmoji::custom("42", "blob").api_name(), "blob:42");
+        assert_eq!(ReactionEmoji::custom("42", "blob").display(), ":blob:");
+    }
+}
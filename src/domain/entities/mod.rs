@@ -1,36 +1,52 @@
 //! Domain entity definitions.
 
 mod channel;
+mod emoji;
 mod forum;
 mod guild;
 mod image;
 mod member;
+mod member_list;
 mod message;
+mod notification_settings;
 mod permissions;
+mod presence;
 mod read_state;
 mod relationship;
 mod role;
 mod token;
 mod user;
 mod user_cache;
+mod user_profile;
 
 pub use channel::{
     Channel, ChannelFlags, ChannelId, ChannelKind, OverwriteType, PermissionOverwrite,
     ThreadMetadata, VideoQualityMode,
 };
-pub use forum::ForumThread;
+pub use emoji::{CustomEmoji, Emoji, EmojiId};
+pub use forum::{ForumFilter, ForumSortOrder, ForumTag, ForumThread};
 pub use guild::{Guild, GuildFolder, GuildId, NsfwLevel, PremiumTier, VerificationLevel};
 pub use image::{ImageId, ImageMetadata, ImageSource, ImageStatus, LoadedImage};
 pub use member::Member;
+pub use member_list::{MemberListEntry, MemberListGroup, MemberListItem, MemberListOp};
 pub use message::{
-    Attachment, CHANNEL_MENTION_RE, CHANNEL_URL_RE, Embed, EmbedAuthor, EmbedField, EmbedFooter,
-    EmbedImage, EmbedProvider, EmbedThumbnail, EmbedVideo, Message, MessageAuthor, MessageFlags,
-    MessageId, MessageKind, MessageReference, Reaction, ReactionEmoji, USER_MENTION_RE,
+    Attachment, CHANNEL_MENTION_RE, CHANNEL_URL_RE, DiscordLink, Embed, EmbedAuthor, EmbedField,
+    EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail, EmbedVideo, Message, MessageAuthor,
+    MessageFlags, MessageId, MessageKind, MessageReference, Reaction, ReactionEmoji,
+    USER_MENTION_RE,
+};
+pub use notification_settings::{
+    ChannelNotificationSettings, GuildNotificationSettings, MentionKind, MessageNotificationLevel,
+    MuteState, NotificationSettingsStore, NotificationTarget,
 };
 pub use permissions::Permissions;
+pub use presence::{
+    Activity, ActivityKind, CustomStatus, PresenceStatus, PresenceStore, UserPresence,
+};
 pub use read_state::ReadState;
 pub use relationship::{Relationship, RelationshipState, RelationshipType};
 pub use role::{Role, RoleId};
 pub use token::AuthToken;
 pub use user::{PremiumType, User, UserFlags, UserId};
 pub use user_cache::{CachedUser, UserCache};
+pub use user_profile::{GuildMemberProfile, UserProfile};
@@ -0,0 +1,387 @@
+//! Per-guild and per-channel notification settings.
+//!
+//! Mirrors Discord's `user_guild_settings`: each guild (and the DM "guild")
+//! has a mute state, a message notification level, mention suppression flags
+//! and overrides for individual channels and categories.
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{Channel, ChannelId, GuildId};
+
+/// Which messages trigger a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageNotificationLevel {
+    AllMessages,
+    OnlyMentions,
+    Nothing,
+    /// Inherit from the parent category or guild.
+    #[default]
+    Default,
+}
+
+impl MessageNotificationLevel {
+    pub const ALL: [Self; 4] = [
+        Self::Default,
+        Self::AllMessages,
+        Self::OnlyMentions,
+        Self::Nothing,
+    ];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::AllMessages => "All messages",
+            Self::OnlyMentions => "Only @mentions",
+            Self::Nothing => "Nothing",
+            Self::Default => "Default",
+        }
+    }
+
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        match self {
+            Self::AllMessages => 0,
+            Self::OnlyMentions => 1,
+            Self::Nothing => 2,
+            Self::Default => 3,
+        }
+    }
+}
+
+impl From<u8> for MessageNotificationLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::AllMessages,
+            1 => Self::OnlyMentions,
+            2 => Self::Nothing,
+            _ => Self::Default,
+        }
+    }
+}
+
+/// Mute state of a guild, category or channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MuteState {
+    pub muted: bool,
+    /// When a temporary mute ends; `None` mutes until turned off.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl MuteState {
+    #[must_use]
+    pub const fn muted_until(until: Option<DateTime<Utc>>) -> Self {
+        Self { muted: true, until }
+    }
+
+    /// Whether the mute is on and has not expired yet.
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.muted && self.until.is_none_or(|until| until > now)
+    }
+}
+
+/// Settings for a single channel or category within a guild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChannelNotificationSettings {
+    pub mute: MuteState,
+    pub level: MessageNotificationLevel,
+}
+
+/// Notification settings for one guild; `guild_id` is `None` for direct messages.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GuildNotificationSettings {
+    pub guild_id: Option<GuildId>,
+    pub mute: MuteState,
+    pub level: MessageNotificationLevel,
+    pub suppress_everyone: bool,
+    pub suppress_roles: bool,
+    pub channel_overrides: HashMap<ChannelId, ChannelNotificationSettings>,
+}
+
+impl GuildNotificationSettings {
+    #[must_use]
+    pub fn new(guild_id: Option<GuildId>) -> Self {
+        Self {
+            guild_id,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn channel(&self, channel_id: ChannelId) -> ChannelNotificationSettings {
+        self.channel_overrides
+            .get(&channel_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// How a message mentions us, from most to least specific.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MentionKind {
+    /// A DM, or a message that mentions us by name.
+    Direct,
+    Role,
+    Everyone,
+    None,
+}
+
+/// Where a message was sent: the channel and the channels it sits in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationTarget {
+    pub guild_id: Option<GuildId>,
+    pub channel_id: ChannelId,
+    /// The category of a channel, or the parent channel of a thread.
+    pub parent_id: Option<ChannelId>,
+    /// The category of a thread's parent channel.
+    pub category_id: Option<ChannelId>,
+}
+
+impl NotificationTarget {
+    /// Target for a channel, looking up a thread's parent to find its category.
+    #[must_use]
+    pub fn for_channel<'a>(
+        guild_id: Option<GuildId>,
+        channel: &Channel,
+        get_channel: impl Fn(ChannelId) -> Option<&'a Channel>,
+    ) -> Self {
+        let parent_id = channel.parent_id();
+        let category_id = if channel.kind().is_thread() {
+            parent_id.and_then(get_channel).and_then(Channel::parent_id)
+        } else {
+            None
+        };
+        Self {
+            guild_id,
+            channel_id: channel.id(),
+            parent_id,
+            category_id,
+        }
+    }
+
+    /// The channel, then its parents, nearest first.
+    fn channels(self) -> impl Iterator<Item = ChannelId> {
+        [Some(self.channel_id), self.parent_id, self.category_id]
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Thread-safe store of notification settings, keyed by guild.
+///
+/// Seeded from READY and kept current by `USER_GUILD_SETTINGS_UPDATE` events.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationSettingsStore {
+    guilds: Arc<RwLock<HashMap<Option<GuildId>, GuildNotificationSettings>>>,
+}
+
+impl NotificationSettingsStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn replace_all(&self, settings: Vec<GuildNotificationSettings>) {
+        let mut guilds = self.guilds.write();
+        guilds.clear();
+        for entry in settings {
+            guilds.insert(entry.guild_id, entry);
+        }
+    }
+
+    pub fn upsert(&self, settings: GuildNotificationSettings) {
+        self.guilds.write().insert(settings.guild_id, settings);
+    }
+
+    /// Settings for a guild, or defaults if Discord never sent any.
+    #[must_use]
+    pub fn guild(&self, guild_id: Option<GuildId>) -> GuildNotificationSettings {
+        self.guilds
+            .read()
+            .get(&guild_id)
+            .cloned()
+            .unwrap_or_else(|| GuildNotificationSettings::new(guild_id))
+    }
+
+    #[must_use]
+    pub fn is_guild_muted(&self, guild_id: GuildId, now: DateTime<Utc>) -> bool {
+        self.guilds
+            .read()
+            .get(&Some(guild_id))
+            .is_some_and(|settings| settings.mute.is_active(now))
+    }
+
+    /// Whether the channel itself, one of its parents or its guild is muted.
+    #[must_use]
+    pub fn is_muted(&self, target: NotificationTarget, now: DateTime<Utc>) -> bool {
+        let guilds = self.guilds.read();
+        let Some(settings) = guilds.get(&target.guild_id) else {
+            return false;
+        };
+        settings.mute.is_active(now)
+            || target
+                .channels()
+                .any(|id| settings.channel(id).mute.is_active(now))
+    }
+
+    /// The level in effect for a channel: its own, then its parents', then the guild's.
+    #[must_use]
+    pub fn effective_level(&self, target: NotificationTarget) -> MessageNotificationLevel {
+        let settings = self.guild(target.guild_id);
+        target
+            .channels()
+            .map(|id| settings.channel(id).level)
+            .chain(std::iter::once(settings.level))
+            .find(|level| *level != MessageNotificationLevel::Default)
+            .unwrap_or(MessageNotificationLevel::Default)
+    }
+
+    /// Decides whether a message should notify.
+    ///
+    /// Muted channels only notify for direct mentions. Without an explicit
+    /// level, DMs notify for everything and guild channels only for mentions.
+    #[must_use]
+    pub fn should_notify(
+        &self,
+        target: NotificationTarget,
+        mention: MentionKind,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let settings = self.guild(target.guild_id);
+        let mention = match mention {
+            MentionKind::Everyone if settings.suppress_everyone => MentionKind::None,
+            MentionKind::Role if settings.suppress_roles => MentionKind::None,
+            other => other,
+        };
+
+        if self.is_muted(target, now) {
+            return mention == MentionKind::Direct;
+        }
+
+        match self.effective_level(target) {
+            MessageNotificationLevel::AllMessages => true,
+            MessageNotificationLevel::Nothing => false,
+            MessageNotificationLevel::OnlyMentions => mention != MentionKind::None,
+            MessageNotificationLevel::Default => {
+                target.guild_id.is_none() || mention != MentionKind::None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const GUILD: GuildId = GuildId(1);
+    const CATEGORY: ChannelId = ChannelId(10);
+    const CHANNEL: ChannelId = ChannelId(11);
+
+    fn target() -> NotificationTarget {
+        NotificationTarget {
+            guild_id: Some(GUILD),
+            channel_id: CHANNEL,
+            parent_id: Some(CATEGORY),
+            category_id: None,
+        }
+    }
+
+    #[test]
+    fn test_mute_expires() {
+        let now = Utc::now();
+        assert!(MuteState::muted_until(None).is_active(now));
+        assert!(MuteState::muted_until(Some(now + Duration::hours(1))).is_active(now));
+        assert!(!MuteState::muted_until(Some(now - Duration::hours(1))).is_active(now));
+        assert!(!MuteState::default().is_active(now));
+    }
+
+    #[test]
+    fn test_muted_category_only_notifies_direct_mentions() {
+        let store = NotificationSettingsStore::new();
+        let mut settings = GuildNotificationSettings::new(Some(GUILD));
+        settings.channel_overrides.insert(
+            CATEGORY,
+            ChannelNotificationSettings {
+                mute: MuteState::muted_until(None),
+                level: MessageNotificationLevel::Default,
+            },
+        );
+        store.upsert(settings);
+        let now = Utc::now();
+
+        assert!(store.is_muted(target(), now));
+        assert!(store.should_notify(target(), MentionKind::Direct, now));
+        assert!(!store.should_notify(target(), MentionKind::Role, now));
+    }
+
+    #[test]
+    fn test_levels_and_suppression() {
+        let store = NotificationSettingsStore::new();
+        let now = Utc::now();
+        assert!(!store.should_notify(target(), MentionKind::None, now));
+        assert!(store.should_notify(target(), MentionKind::Everyone, now));
+
+        let mut settings = GuildNotificationSettings::new(Some(GUILD));
+        settings.level = MessageNotificationLevel::OnlyMentions;
+        settings.suppress_everyone = true;
+        settings.channel_overrides.insert(
+            CHANNEL,
+            ChannelNotificationSettings {
+                mute: MuteState::default(),
+                level: MessageNotificationLevel::AllMessages,
+            },
+        );
+        store.upsert(settings);
+
+        assert_eq!(
+            store.effective_level(target()),
+            MessageNotificationLevel::AllMessages
+        );
+        assert!(store.should_notify(target(), MentionKind::None, now));
+
+        let other = NotificationTarget {
+            channel_id: ChannelId(12),
+            parent_id: None,
+            ..target()
+        };
+        assert!(!store.should_notify(other, MentionKind::Everyone, now));
+        assert!(store.should_notify(other, MentionKind::Role, now));
+    }
+
+    #[test]
+    fn test_threads_inherit_from_channel_and_category() {
+        use crate::domain::entities::ChannelKind;
+
+        let category = Channel::new(CATEGORY, "Text", ChannelKind::Category);
+        let parent = Channel::new(CHANNEL, "general", ChannelKind::Text).with_parent(CATEGORY);
+        let thread =
+            Channel::new(ChannelId(20), "thread", ChannelKind::PublicThread).with_parent(CHANNEL);
+        let channels = [category, parent];
+        let target = NotificationTarget::for_channel(Some(GUILD), &thread, |id| {
+            channels.iter().find(|channel| channel.id() == id)
+        });
+        assert_eq!(target.parent_id, Some(CHANNEL));
+        assert_eq!(target.category_id, Some(CATEGORY));
+
+        let store = NotificationSettingsStore::new();
+        let mut settings = GuildNotificationSettings::new(Some(GUILD));
+        settings.channel_overrides.insert(
+            CATEGORY,
+            ChannelNotificationSettings {
+                mute: MuteState::muted_until(None),
+                level: MessageNotificationLevel::Nothing,
+            },
+        );
+        store.upsert(settings);
+
+        assert!(store.is_muted(target, Utc::now()));
+        assert_eq!(
+            store.effective_level(target),
+            MessageNotificationLevel::Nothing
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! User presence entities.
+//!
+//! Tracks the online status and activities of users from Gateway events.
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::UserId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PresenceStatus {
+    Online,
+    Idle,
+    #[serde(rename = "dnd")]
+    DoNotDisturb,
+    Invisible,
+    #[default]
+    Offline,
+}
+
+impl PresenceStatus {
+    #[must_use]
+    pub fn parse(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "online" => Self::Online,
+            "idle" => Self::Idle,
+            "dnd" => Self::DoNotDisturb,
+            "invisible" => Self::Invisible,
+            _ => Self::Offline,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Idle => "idle",
+            Self::DoNotDisturb => "dnd",
+            Self::Invisible => "invisible",
+            Self::Offline => "offline",
+        }
+    }
+
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::Idle => "Idle",
+            Self::DoNotDisturb => "Do Not Disturb",
+            Self::Invisible => "Invisible",
+            Self::Offline => "Offline",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_online(&self) -> bool {
+        !matches!(self, Self::Offline | Self::Invisible)
+    }
+
+    #[must_use]
+    pub const fn display_indicator(&self) -> &'static str {
+        match self {
+            Self::Online => "●",
+            Self::Idle => "◐",
+            Self::DoNotDisturb => "⊘",
+            Self::Invisible | Self::Offline => "○",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    pub name: String,
+    pub kind: ActivityKind,
+    pub details: Option<String>,
+    pub state: Option<String>,
+}
+
+impl Activity {
+    /// Text shown for the activity, e.g. "Playing Rust" or a custom status.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self.kind.prefix() {
+            "" => self.state.clone().unwrap_or_else(|| self.name.clone()),
+            prefix => format!("{prefix} {}", self.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActivityKind {
+    #[default]
+    Playing = 0,
+    Streaming = 1,
+    Listening = 2,
+    Watching = 3,
+    Custom = 4,
+    Competing = 5,
+}
+
+impl ActivityKind {
+    #[must_use]
+    pub const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Streaming,
+            2 => Self::Listening,
+            3 => Self::Watching,
+            4 => Self::Custom,
+            5 => Self::Competing,
+            _ => Self::Playing,
+        }
+    }
+
+    #[must_use]
+    pub const fn prefix(&self) -> &'static str {
+        match self {
+            Self::Playing => "Playing",
+            Self::Streaming => "Streaming",
+            Self::Listening => "Listening to",
+            Self::Watching => "Watching",
+            Self::Custom => "",
+            Self::Competing => "Competing in",
+        }
+    }
+}
+
+/// Text and emoji shown under the current user's name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomStatus {
+    pub text: Option<String>,
+    /// A unicode emoji.
+    pub emoji: Option<String>,
+    /// When Discord clears the status; never if unset.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CustomStatus {
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.as_deref().is_none_or(str::is_empty)
+            && self.emoji.as_deref().is_none_or(str::is_empty)
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Emoji followed by the text, e.g. "🦀 Writing Rust".
+    #[must_use]
+    pub fn label(&self) -> String {
+        [self.emoji.as_deref(), self.text.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The status as the custom activity other clients display.
+    #[must_use]
+    pub fn to_activity(&self) -> Activity {
+        Activity {
+            name: "Custom Status".to_string(),
+            kind: ActivityKind::Custom,
+            details: None,
+            state: Some(self.label()),
+        }
+    }
+}
+
+/// A user's status and activities.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserPresence {
+    pub status: PresenceStatus,
+    pub activities: Vec<Activity>,
+}
+
+impl UserPresence {
+    #[must_use]
+    pub const fn new(status: PresenceStatus, activities: Vec<Activity>) -> Self {
+        Self { status, activities }
+    }
+}
+
+/// Thread-safe store of the last known presence of each user.
+///
+/// Seeded from READY and kept current by `PRESENCE_UPDATE` events.
+/// Users without an entry have never been seen, which renders differently
+/// from a known offline user.
+#[derive(Debug, Clone, Default)]
+pub struct PresenceStore {
+    presences: Arc<RwLock<HashMap<UserId, UserPresence>>>,
+}
+
+impl PresenceStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn get(&self, user_id: UserId) -> Option<UserPresence> {
+        self.presences.read().get(&user_id).cloned()
+    }
+
+    #[must_use]
+    pub fn status(&self, user_id: UserId) -> Option<PresenceStatus> {
+        self.presences
+            .read()
+            .get(&user_id)
+            .map(|presence| presence.status)
+    }
+
+    #[must_use]
+    pub fn status_str(&self, user_id: &str) -> Option<PresenceStatus> {
+        user_id
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| self.status(UserId(id)))
+    }
+
+    #[must_use]
+    pub fn is_online(&self, user_id: UserId) -> bool {
+        self.status(user_id)
+            .is_some_and(|status| status.is_online())
+    }
+
+    pub fn update(&self, user_id: UserId, presence: UserPresence) {
+        self.presences.write().insert(user_id, presence);
+    }
+
+    /// Replaces every known presence, as on a fresh READY.
+    pub fn initialize_from_presences(&self, presences: HashMap<UserId, UserPresence>) {
+        *self.presences.write() = presences;
+    }
+
+    pub fn clear(&self) {
+        self.presences.write().clear();
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.presences.read().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.presences.read().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presence_store_tracks_updates() {
+        let store = PresenceStore::new();
+        assert_eq!(store.status(UserId(1)), None);
+
+        store.initialize_from_presences(HashMap::from([(
+            UserId(1),
+            UserPresence::new(PresenceStatus::Idle, Vec::new()),
+        )]));
+        assert_eq!(store.status_str("1"), Some(PresenceStatus::Idle));
+        assert!(store.is_online(UserId(1)));
+
+        let shared = store.clone();
+        shared.update(
+            UserId(1),
+            UserPresence::new(PresenceStatus::Offline, Vec::new()),
+        );
+        assert_eq!(store.status(UserId(1)), Some(PresenceStatus::Offline));
+        assert!(!store.is_online(UserId(1)));
+        assert_eq!(store.status_str("invalid"), None);
+    }
+
+    #[test]
+    fn test_custom_status_label_and_expiry() {
+        let now = Utc::now();
+        let status = CustomStatus::new("Writing Rust")
+            .with_emoji("🦀")
+            .with_expires_at(now + chrono::Duration::minutes(30));
+        assert_eq!(status.label(), "🦀 Writing Rust");
+        assert_eq!(status.to_activity().label(), "🦀 Writing Rust");
+        assert!(!status.is_expired(now));
+        assert!(status.is_expired(now + chrono::Duration::hours(1)));
+        assert!(CustomStatus::default().is_empty());
+
+        assert_eq!(
+            serde_json::from_str::<PresenceStatus>("\"dnd\"").unwrap(),
+            PresenceStatus::DoNotDisturb
+        );
+    }
+}
@@ -4,10 +4,10 @@
 
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use super::UserId;
+use super::{User, UserId};
 
 /// Discord relationship types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -37,6 +37,16 @@ impl RelationshipType {
     pub const fn is_blocked(self) -> bool {
         matches!(self, Self::Blocked)
     }
+
+    #[must_use]
+    pub const fn is_friend(self) -> bool {
+        matches!(self, Self::Friend)
+    }
+
+    #[must_use]
+    pub const fn is_pending(self) -> bool {
+        matches!(self, Self::PendingIncoming | Self::PendingOutgoing)
+    }
 }
 
 /// A single relationship entry.
@@ -44,6 +54,12 @@ impl RelationshipType {
 pub struct Relationship {
     pub user_id: UserId,
     pub relationship_type: RelationshipType,
+    /// The other user, when Discord sent it along.
+    #[serde(default)]
+    pub user: Option<User>,
+    /// Friend nickname set by the current user.
+    #[serde(default)]
+    pub nickname: Option<String>,
 }
 
 impl Relationship {
@@ -52,37 +68,68 @@ impl Relationship {
         Self {
             user_id,
             relationship_type,
+            user: None,
+            nickname: None,
         }
     }
 
+    #[must_use]
+    pub fn with_user(mut self, user: User) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    #[must_use]
+    pub fn with_nickname(mut self, nickname: Option<String>) -> Self {
+        self.nickname = nickname.filter(|nickname| !nickname.is_empty());
+        self
+    }
+
     #[must_use]
     pub const fn is_blocked(&self) -> bool {
         self.relationship_type.is_blocked()
     }
+
+    /// Nickname, then the user's display name, then the raw ID.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.nickname
+            .clone()
+            .or_else(|| self.user.as_ref().map(User::display_name))
+            .unwrap_or_else(|| self.user_id.to_string())
+    }
+
+    /// Unique username, if the user is known.
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        self.user.as_ref().map(User::username)
+    }
 }
 
-/// Thread-safe state manager for tracking blocked user IDs.
+/// Thread-safe state manager for tracking blocked and friend user IDs.
 ///
 /// Uses a `RwLock<HashSet<UserId>>` for O(1) lookup performance.
 /// This follows the Observer pattern - Gateway events update this state,
-/// and the `MessagePane` queries it during rendering.
+/// and the `MessagePane` queries it during rendering. Full entries are kept
+/// alongside for the friends view.
 #[derive(Debug, Clone, Default)]
 pub struct RelationshipState {
     blocked_users: Arc<RwLock<HashSet<UserId>>>,
+    friends: Arc<RwLock<HashSet<UserId>>>,
+    relationships: Arc<RwLock<HashMap<UserId, Relationship>>>,
 }
 
 impl RelationshipState {
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            blocked_users: Arc::new(RwLock::new(HashSet::new())),
-        }
+        Self::default()
     }
 
     #[must_use]
     pub fn with_blocked_users(blocked: HashSet<UserId>) -> Self {
         Self {
             blocked_users: Arc::new(RwLock::new(blocked)),
+            ..Self::default()
         }
     }
 
@@ -100,6 +147,18 @@ impl RelationshipState {
         }
     }
 
+    #[must_use]
+    pub fn is_friend(&self, user_id: UserId) -> bool {
+        self.friends.read().contains(&user_id)
+    }
+
+    #[must_use]
+    pub fn is_friend_str(&self, user_id: &str) -> bool {
+        user_id
+            .parse::<u64>()
+            .is_ok_and(|id| self.is_friend(UserId(id)))
+    }
+
     pub fn block_user(&self, user_id: UserId) {
         self.blocked_users.write().insert(user_id);
     }
@@ -108,16 +167,74 @@ impl RelationshipState {
         self.blocked_users.write().remove(&user_id);
     }
 
+    /// Stores `relationship`, keeping the known user if the update has none.
+    pub fn upsert_relationship(&self, mut relationship: Relationship) {
+        self.update_relationship(relationship.user_id, relationship.relationship_type);
+
+        let mut relationships = self.relationships.write();
+        if let Some(existing) = relationships.get(&relationship.user_id) {
+            if relationship.user.is_none() {
+                relationship.user.clone_from(&existing.user);
+            }
+            if relationship.nickname.is_none() {
+                relationship.nickname.clone_from(&existing.nickname);
+            }
+        }
+        relationships.insert(relationship.user_id, relationship);
+    }
+
     pub fn update_relationship(&self, user_id: UserId, relationship_type: RelationshipType) {
+        self.relationships
+            .write()
+            .entry(user_id)
+            .or_insert_with(|| Relationship::new(user_id, relationship_type))
+            .relationship_type = relationship_type;
+
         if relationship_type.is_blocked() {
             self.block_user(user_id);
         } else {
             self.unblock_user(user_id);
         }
+
+        if relationship_type.is_friend() {
+            self.friends.write().insert(user_id);
+        } else {
+            self.friends.write().remove(&user_id);
+        }
+    }
+
+    pub fn remove_relationship(&self, user_id: UserId) {
+        self.unblock_user(user_id);
+        self.friends.write().remove(&user_id);
+        self.relationships.write().remove(&user_id);
     }
 
     pub fn clear(&self) {
         self.blocked_users.write().clear();
+        self.friends.write().clear();
+        self.relationships.write().clear();
+    }
+
+    #[must_use]
+    pub fn relationship(&self, user_id: UserId) -> Option<Relationship> {
+        self.relationships.read().get(&user_id).cloned()
+    }
+
+    /// Snapshot of every relationship, sorted by display name.
+    #[must_use]
+    pub fn relationships(&self) -> Vec<Relationship> {
+        let mut relationships: Vec<_> = self.relationships.read().values().cloned().collect();
+        relationships.sort_by_cached_key(|rel| rel.display_name().to_lowercase());
+        relationships
+    }
+
+    #[must_use]
+    pub fn pending_incoming_count(&self) -> usize {
+        self.relationships
+            .read()
+            .values()
+            .filter(|rel| rel.relationship_type == RelationshipType::PendingIncoming)
+            .count()
     }
 
     #[must_use]
@@ -127,11 +244,18 @@ impl RelationshipState {
 
     pub fn initialize_from_relationships(&self, relationships: &[Relationship]) {
         let mut blocked = self.blocked_users.write();
+        let mut friends = self.friends.write();
+        let mut entries = self.relationships.write();
         blocked.clear();
+        friends.clear();
+        entries.clear();
         for rel in relationships {
             if rel.is_blocked() {
                 blocked.insert(rel.user_id);
+            } else if rel.relationship_type.is_friend() {
+                friends.insert(rel.user_id);
             }
+            entries.insert(rel.user_id, rel.clone());
         }
     }
 }
@@ -212,6 +336,33 @@ mod tests {
         assert!(state.is_blocked(UserId(333)));
         assert!(!state.is_blocked(UserId(444)));
         assert_eq!(state.blocked_count(), 2);
+        assert!(state.is_friend(UserId(111)));
+        assert!(!state.is_friend(UserId(444)));
+
+        state.remove_relationship(UserId(111));
+        assert!(!state.is_friend_str("111"));
+        assert_eq!(state.relationships().len(), 3);
+        assert_eq!(state.pending_incoming_count(), 1);
+    }
+
+    #[test]
+    fn test_relationship_state_upsert_keeps_user() {
+        let state = RelationshipState::new();
+        let user = User::new(444u64, "ferris", "0", None, false, None);
+        state.upsert_relationship(
+            Relationship::new(UserId(444), RelationshipType::PendingIncoming)
+                .with_user(user)
+                .with_nickname(Some("Crab".to_string())),
+        );
+
+        state.upsert_relationship(Relationship::new(UserId(444), RelationshipType::Friend));
+
+        let relationship = state.relationship(UserId(444)).expect("relationship");
+        assert_eq!(relationship.relationship_type, RelationshipType::Friend);
+        assert_eq!(relationship.username(), Some("ferris"));
+        assert_eq!(relationship.display_name(), "Crab");
+        assert!(state.is_friend(UserId(444)));
+        assert_eq!(state.pending_incoming_count(), 0);
     }
 
     #[test]
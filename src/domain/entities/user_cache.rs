@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-use super::User;
+use super::{GuildId, User, UserProfile};
 use crate::domain::ports::MentionResolver;
 
 #[derive(Debug, Clone)]
@@ -113,6 +113,7 @@ pub struct UserCache {
 #[derive(Debug, Default)]
 struct UserCacheInner {
     users: HashMap<String, CachedUser>,
+    profiles: HashMap<String, UserProfile>,
 }
 
 impl UserCache {
@@ -159,6 +160,53 @@ impl UserCache {
             .is_some_and(|inner| inner.users.contains_key(user_id))
     }
 
+    /// Snapshot of every cached user.
+    #[must_use]
+    pub fn users(&self) -> Vec<CachedUser> {
+        self.inner
+            .read()
+            .map(|inner| inner.users.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Finds a user by username or global name, ignoring case.
+    #[must_use]
+    pub fn find_by_name(&self, name: &str) -> Option<CachedUser> {
+        self.inner.read().ok().and_then(|inner| {
+            inner
+                .users
+                .values()
+                .find(|user| {
+                    user.username.eq_ignore_ascii_case(name)
+                        || user
+                            .global_name
+                            .as_deref()
+                            .is_some_and(|global| global.eq_ignore_ascii_case(name))
+                })
+                .cloned()
+        })
+    }
+
+    /// Caches a fetched profile, replacing the user's previous one.
+    pub fn insert_profile(&self, profile: UserProfile) {
+        self.insert_from_user(profile.user());
+        if let Ok(mut inner) = self.inner.write() {
+            inner.profiles.insert(profile.user().id_str(), profile);
+        }
+    }
+
+    /// The cached profile of a user, if it was fetched in `guild_id`.
+    #[must_use]
+    pub fn get_profile(&self, user_id: &str, guild_id: Option<GuildId>) -> Option<UserProfile> {
+        self.inner.read().ok().and_then(|inner| {
+            inner
+                .profiles
+                .get(user_id)
+                .filter(|profile| profile.guild_id() == guild_id)
+                .cloned()
+        })
+    }
+
     pub fn update_username(&self, user_id: &str, username: impl Into<String>) {
         if let Ok(mut inner) = self.inner.write()
             && let Some(user) = inner.users.get_mut(user_id)
@@ -170,12 +218,14 @@ impl UserCache {
     pub fn remove(&self, user_id: &str) {
         if let Ok(mut inner) = self.inner.write() {
             inner.users.remove(user_id);
+            inner.profiles.remove(user_id);
         }
     }
 
     pub fn clear(&self) {
         if let Ok(mut inner) = self.inner.write() {
             inner.users.clear();
+            inner.profiles.clear();
         }
     }
 
@@ -224,6 +274,38 @@ mod tests {
         assert_eq!(user.username(), "testuser");
     }
 
+    #[test]
+    fn test_user_cache_find_by_name() {
+        let cache = UserCache::new();
+        cache.insert(CachedUser::new(
+            "123",
+            "alice",
+            "0",
+            None,
+            Some("Alice A".to_string()),
+            false,
+        ));
+
+        assert_eq!(
+            cache.find_by_name("ALICE").map(|u| u.id().to_string()),
+            Some("123".to_string())
+        );
+        assert!(cache.find_by_name("alice a").is_some());
+        assert!(cache.find_by_name("bob").is_none());
+    }
+
+    #[test]
+    fn test_user_cache_profiles_are_per_guild() {
+        let cache = UserCache::new();
+        let user = User::new(123_u64, "alice", "0", None, false, None);
+        cache.insert_profile(UserProfile::new(user, Some(GuildId(1))));
+
+        assert!(cache.contains("123"));
+        assert!(cache.get_profile("123", Some(GuildId(1))).is_some());
+        assert!(cache.get_profile("123", Some(GuildId(2))).is_none());
+        assert!(cache.get_profile("123", None).is_none());
+    }
+
     #[test]
     fn test_user_cache_mention_resolver() {
         let cache = UserCache::new();
@@ -0,0 +1,168 @@
+//! Discord user profile entity.
+
+use chrono::{DateTime, Utc};
+
+use super::{GuildId, RoleId, User};
+use crate::domain::search::snowflake_time;
+
+/// The part of a profile specific to one guild.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuildMemberProfile {
+    pub nick: Option<String>,
+    pub roles: Vec<RoleId>,
+    pub joined_at: Option<DateTime<Utc>>,
+    /// Overrides the user's pronouns in this guild.
+    pub pronouns: Option<String>,
+    /// Overrides the user's bio in this guild.
+    pub bio: Option<String>,
+}
+
+/// A user's profile as shown in the profile popup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserProfile {
+    user: User,
+    /// The guild the profile was fetched in, if any.
+    guild_id: Option<GuildId>,
+    pronouns: Option<String>,
+    bio: Option<String>,
+    premium_since: Option<DateTime<Utc>>,
+    mutual_guilds: Vec<GuildId>,
+    member: Option<GuildMemberProfile>,
+}
+
+impl UserProfile {
+    #[must_use]
+    pub const fn new(user: User, guild_id: Option<GuildId>) -> Self {
+        Self {
+            user,
+            guild_id,
+            pronouns: None,
+            bio: None,
+            premium_since: None,
+            mutual_guilds: Vec::new(),
+            member: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pronouns(mut self, pronouns: impl Into<String>) -> Self {
+        self.pronouns = Some(pronouns.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_bio(mut self, bio: impl Into<String>) -> Self {
+        self.bio = Some(bio.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_premium_since(mut self, since: DateTime<Utc>) -> Self {
+        self.premium_since = Some(since);
+        self
+    }
+
+    #[must_use]
+    pub fn with_mutual_guilds(mut self, guilds: Vec<GuildId>) -> Self {
+        self.mutual_guilds = guilds;
+        self
+    }
+
+    #[must_use]
+    pub fn with_member(mut self, member: GuildMemberProfile) -> Self {
+        self.member = Some(member);
+        self
+    }
+
+    #[must_use]
+    pub const fn user(&self) -> &User {
+        &self.user
+    }
+
+    #[must_use]
+    pub const fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+
+    #[must_use]
+    pub const fn member(&self) -> Option<&GuildMemberProfile> {
+        self.member.as_ref()
+    }
+
+    #[must_use]
+    pub fn mutual_guilds(&self) -> &[GuildId] {
+        &self.mutual_guilds
+    }
+
+    #[must_use]
+    pub const fn premium_since(&self) -> Option<DateTime<Utc>> {
+        self.premium_since
+    }
+
+    /// Nickname in the profile's guild, falling back to the user's display name.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.member
+            .as_ref()
+            .and_then(|member| member.nick.clone())
+            .unwrap_or_else(|| self.user.display_name())
+    }
+
+    /// Pronouns, preferring the ones set for the profile's guild.
+    #[must_use]
+    pub fn pronouns(&self) -> Option<&str> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.pronouns.as_deref())
+            .or(self.pronouns.as_deref())
+    }
+
+    /// Bio, preferring the one set for the profile's guild.
+    #[must_use]
+    pub fn bio(&self) -> Option<&str> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.bio.as_deref())
+            .or(self.bio.as_deref())
+    }
+
+    /// When the account was created.
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        snowflake_time(self.user.id().as_u64())
+    }
+
+    #[must_use]
+    pub fn joined_at(&self) -> Option<DateTime<Utc>> {
+        self.member.as_ref().and_then(|member| member.joined_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guild_profile_overrides_user_profile() {
+        let user = User::new(175_928_847_299_117_063_u64, "alice", "0", None, false, None)
+            .with_global_name("Alice");
+        let profile = UserProfile::new(user, Some(GuildId(1)))
+            .with_pronouns("she/her")
+            .with_bio("hello");
+        assert_eq!(profile.display_name(), "Alice");
+        assert_eq!(profile.pronouns(), Some("she/her"));
+        assert_eq!(
+            profile.created_at().format("%Y-%m-%d").to_string(),
+            "2016-04-30"
+        );
+
+        let profile = profile.with_member(GuildMemberProfile {
+            nick: Some("Al".to_string()),
+            pronouns: Some("they/them".to_string()),
+            ..GuildMemberProfile::default()
+        });
+        assert_eq!(profile.display_name(), "Al");
+        assert_eq!(profile.pronouns(), Some("they/them"));
+        assert_eq!(profile.bio(), Some("hello"));
+    }
+}
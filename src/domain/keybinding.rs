@@ -8,6 +8,7 @@ pub enum Action {
     Logout,
     ToggleHelp,
     ToggleGuildsTree,
+    ToggleMemberList,
     ToggleFileExplorer,
     ToggleHiddenFiles,
 
@@ -45,8 +46,29 @@ pub enum Action {
     YankUrl,
     OpenAttachments,
     JumpToReply,
+    ToggleReaction,
+    NextReaction,
+    AddReaction,
+    TogglePin,
+    OpenPins,
+    OpenThread,
+    StartThread,
+    ToggleThreadMembership,
+    NewForumPost,
+    CycleForumSort,
+    CycleForumTagFilter,
+    OpenProfile,
+    SearchMessages,
+    JumpToPresent,
+    OpenClipboardLink,
+    OpenStatusMenu,
+    NewDirectMessage,
+    RenameGroupDm,
+    NotificationSettings,
+    OpenInbox,
 
     OpenEditor,
+    OpenEmojiPicker,
     ClearInput,
     Cancel,
     Paste,
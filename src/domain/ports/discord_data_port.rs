@@ -3,23 +3,50 @@
 use async_trait::async_trait;
 
 use crate::domain::entities::{
-    AuthToken, Channel, ChannelId, ForumThread, Guild, GuildId, Message, MessageId, ReadState,
+    AuthToken, Channel, ChannelId, ChannelNotificationSettings, CustomStatus, ForumFilter,
+    ForumThread, Guild, GuildId, GuildNotificationSettings, Message, MessageId,
+    MessageNotificationLevel, MuteState, PresenceStatus, ReactionEmoji, ReadState, UserId,
+    UserProfile,
 };
 use crate::domain::errors::AuthError;
+use crate::domain::search::HasFilter;
 
-/// Represents a direct message channel with a recipient.
+/// A participant of a direct message channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectMessageRecipient {
+    /// The user's ID.
+    pub id: String,
+    /// The user's username.
+    pub username: String,
+    /// The user's discriminator.
+    pub discriminator: String,
+    /// The user's global name (display name).
+    pub global_name: Option<String>,
+}
+
+impl DirectMessageRecipient {
+    /// Returns the name to show for this recipient.
+    #[must_use]
+    pub fn name(&self, use_display_name: bool) -> &str {
+        if use_display_name {
+            self.global_name.as_deref().unwrap_or(&self.username)
+        } else {
+            &self.username
+        }
+    }
+}
+
+/// Represents a direct message channel with one or more recipients.
 #[derive(Debug, Clone)]
 pub struct DirectMessageChannel {
     /// The unique channel ID for this DM conversation.
     pub channel_id: String,
-    /// The recipient user's ID.
-    pub recipient_id: String,
-    /// The recipient's username.
-    pub recipient_username: String,
-    /// The recipient's discriminator.
-    pub recipient_discriminator: String,
-    /// The recipient's global name (display name).
-    pub recipient_global_name: Option<String>,
+    /// Every recipient of the channel, excluding ourselves.
+    pub recipients: Vec<DirectMessageRecipient>,
+    /// The custom name of a group DM, if one was set.
+    pub name: Option<String>,
+    /// Whether this is a group DM rather than a 1:1 conversation.
+    pub is_group: bool,
     /// The ID of the last message sent in this channel.
     pub last_message_id: Option<MessageId>,
     /// Whether the channel has unread messages.
@@ -28,6 +55,88 @@ pub struct DirectMessageChannel {
     pub mention_count: u32,
 }
 
+impl DirectMessageChannel {
+    #[must_use]
+    pub fn new(channel_id: impl Into<String>, recipients: Vec<DirectMessageRecipient>) -> Self {
+        Self {
+            channel_id: channel_id.into(),
+            is_group: recipients.len() > 1,
+            recipients,
+            name: None,
+            last_message_id: None,
+            has_unread: false,
+            mention_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.name = (!name.trim().is_empty()).then_some(name);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_group(mut self, is_group: bool) -> Self {
+        self.is_group = is_group;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_last_message_id(mut self, message_id: Option<MessageId>) -> Self {
+        self.last_message_id = message_id;
+        self
+    }
+
+    /// Returns the group name, or every recipient's name joined with commas.
+    #[must_use]
+    pub fn display_name(&self, use_display_name: bool) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        self.recipients
+            .iter()
+            .map(|recipient| recipient.name(use_display_name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The first recipient, the other side of a 1:1 DM.
+    #[must_use]
+    pub fn recipient(&self) -> Option<&DirectMessageRecipient> {
+        self.recipients.first()
+    }
+
+    /// The first recipient's user ID, or an empty string without recipients.
+    #[must_use]
+    pub fn recipient_id(&self) -> &str {
+        self.recipient().map_or("", |recipient| &recipient.id)
+    }
+
+    #[must_use]
+    pub fn recipient_username(&self) -> &str {
+        self.recipient().map_or("", |recipient| &recipient.username)
+    }
+
+    #[must_use]
+    pub fn recipient_discriminator(&self) -> &str {
+        self.recipient()
+            .map_or("", |recipient| &recipient.discriminator)
+    }
+
+    #[must_use]
+    pub fn recipient_global_name(&self) -> Option<&str> {
+        self.recipient()
+            .and_then(|recipient| recipient.global_name.as_deref())
+    }
+
+    /// Whether this is a 1:1 DM with `user_id`.
+    #[must_use]
+    pub fn is_direct_with(&self, user_id: &str) -> bool {
+        !self.is_group && self.recipient_id() == user_id
+    }
+}
+
 /// Options for fetching messages from a channel.
 #[derive(Debug, Clone, Default)]
 pub struct FetchMessagesOptions {
@@ -55,6 +164,13 @@ impl FetchMessagesOptions {
         self.after = Some(message_id);
         self
     }
+
+    /// Fetches a window of messages centred on `message_id`.
+    #[must_use]
+    pub const fn around_message(mut self, message_id: u64) -> Self {
+        self.around = Some(message_id);
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -107,6 +223,194 @@ impl EditMessageRequest {
     }
 }
 
+/// A new thread, started from a message or standalone in a text channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateThreadRequest {
+    pub channel_id: ChannelId,
+    pub message_id: Option<MessageId>,
+    pub name: String,
+    pub auto_archive_duration: Option<u16>,
+}
+
+impl CreateThreadRequest {
+    #[must_use]
+    pub fn new(channel_id: ChannelId, name: impl Into<String>) -> Self {
+        Self {
+            channel_id,
+            message_id: None,
+            name: name.into(),
+            auto_archive_duration: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_message(mut self, message_id: MessageId) -> Self {
+        self.message_id = Some(message_id);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_auto_archive_duration(mut self, minutes: u16) -> Self {
+        self.auto_archive_duration = Some(minutes);
+        self
+    }
+}
+
+/// A new forum post: a thread with its opening message and applied tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateForumPostRequest {
+    pub channel_id: ChannelId,
+    pub title: String,
+    pub content: String,
+    pub applied_tags: Vec<u64>,
+    pub attachments: Vec<std::path::PathBuf>,
+}
+
+impl CreateForumPostRequest {
+    #[must_use]
+    pub fn new(
+        channel_id: ChannelId,
+        title: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            channel_id,
+            title: title.into(),
+            content: content.into(),
+            applied_tags: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_tags(mut self, tag_ids: Vec<u64>) -> Self {
+        self.applied_tags = tag_ids;
+        self
+    }
+
+    #[must_use]
+    pub fn with_attachments(mut self, attachments: Vec<std::path::PathBuf>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+}
+
+/// A change to one of our relationships.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelationshipChange {
+    /// Sends a friend request to a username.
+    SendRequest(String),
+    /// Accepts an incoming friend request.
+    Accept(UserId),
+    /// Removes a friend, declines or cancels a request, or unblocks.
+    Remove(UserId),
+    Block(UserId),
+}
+
+/// A change to the notification settings of a guild, or of one of its channels or categories.
+///
+/// `guild_id` is `None` for direct messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationSettingsUpdate {
+    Guild {
+        guild_id: Option<GuildId>,
+        mute: MuteState,
+        level: MessageNotificationLevel,
+        suppress_everyone: bool,
+        suppress_roles: bool,
+    },
+    Channel {
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+        settings: ChannelNotificationSettings,
+    },
+}
+
+impl NotificationSettingsUpdate {
+    #[must_use]
+    pub const fn guild_id(&self) -> Option<GuildId> {
+        match self {
+            Self::Guild { guild_id, .. } | Self::Channel { guild_id, .. } => *guild_id,
+        }
+    }
+
+    /// Applies the update to locally cached settings of the same guild.
+    pub fn apply(&self, target: &mut GuildNotificationSettings) {
+        match self {
+            Self::Guild {
+                mute,
+                level,
+                suppress_everyone,
+                suppress_roles,
+                ..
+            } => {
+                target.mute = *mute;
+                target.level = *level;
+                target.suppress_everyone = *suppress_everyone;
+                target.suppress_roles = *suppress_roles;
+            }
+            Self::Channel {
+                channel_id,
+                settings,
+                ..
+            } => {
+                target.channel_overrides.insert(*channel_id, *settings);
+            }
+        }
+    }
+}
+
+/// Where a message search runs: a whole guild, or a single (DM) channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScope {
+    Guild(GuildId),
+    Channel(ChannelId),
+}
+
+/// A message search with filters already resolved to ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMessagesRequest {
+    pub scope: SearchScope,
+    pub content: Option<String>,
+    pub author_ids: Vec<u64>,
+    pub mention_ids: Vec<u64>,
+    pub channel_ids: Vec<ChannelId>,
+    pub has: Vec<HasFilter>,
+    pub min_id: Option<u64>,
+    pub max_id: Option<u64>,
+    pub offset: u32,
+}
+
+impl SearchMessagesRequest {
+    #[must_use]
+    pub const fn new(scope: SearchScope) -> Self {
+        Self {
+            scope,
+            content: None,
+            author_ids: Vec::new(),
+            mention_ids: Vec::new(),
+            channel_ids: Vec::new(),
+            has: Vec::new(),
+            min_id: None,
+            max_id: None,
+            offset: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// One page of search hits along with the total number of matches.
+#[derive(Debug, Clone, Default)]
+pub struct MessageSearchPage {
+    pub total_results: u32,
+    pub messages: Vec<Message>,
+}
+
 /// Port for fetching Discord data (guilds, channels, DMs, etc).
 #[async_trait]
 pub trait DiscordDataPort: Send + Sync {
@@ -168,6 +472,54 @@ pub trait DiscordDataPort: Send + Sync {
         message_id: MessageId,
     ) -> Result<(), AuthError>;
 
+    /// Adds our reaction to a message.
+    async fn add_reaction(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
+    ) -> Result<(), AuthError>;
+
+    /// Removes our reaction from a message.
+    async fn remove_reaction(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
+    ) -> Result<(), AuthError>;
+
+    /// Fetches the pinned messages of a channel, newest pin first.
+    async fn fetch_pinned_messages(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Message>, AuthError>;
+
+    /// Pins a message in its channel.
+    async fn pin_message(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), AuthError>;
+
+    /// Unpins a message from its channel.
+    async fn unpin_message(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), AuthError>;
+
+    /// Searches messages in a guild or channel, returning the page at `request.offset`.
+    async fn search_messages(
+        &self,
+        token: &AuthToken,
+        request: &SearchMessagesRequest,
+    ) -> Result<MessageSearchPage, AuthError>;
+
     /// Sends a typing indicator to a channel.
     async fn send_typing_indicator(
         &self,
@@ -183,20 +535,116 @@ pub trait DiscordDataPort: Send + Sync {
         message_id: MessageId,
     ) -> Result<(), AuthError>;
 
-    /// Fetches forum threads for a channel.
+    /// Fetches forum threads for a channel, sorted and filtered by `filter`.
     async fn fetch_forum_threads(
         &self,
         token: &AuthToken,
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
+        filter: ForumFilter,
         offset: u32,
         limit: Option<u8>,
     ) -> Result<Vec<ForumThread>, AuthError>;
 
+    /// Creates a post in a forum channel and returns it.
+    async fn create_forum_post(
+        &self,
+        token: &AuthToken,
+        request: &CreateForumPostRequest,
+    ) -> Result<ForumThread, AuthError>;
+
+    /// Fetches the archived public threads of a channel and the archived private
+    /// threads we have joined, each most recently archived first.
+    async fn fetch_archived_threads(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Channel>, AuthError>;
+
+    /// Creates a thread and returns it.
+    async fn create_thread(
+        &self,
+        token: &AuthToken,
+        request: &CreateThreadRequest,
+    ) -> Result<Channel, AuthError>;
+
+    /// Adds the current user to a thread.
+    async fn join_thread(&self, token: &AuthToken, channel_id: ChannelId) -> Result<(), AuthError>;
+
+    /// Removes the current user from a thread.
+    async fn leave_thread(&self, token: &AuthToken, channel_id: ChannelId)
+    -> Result<(), AuthError>;
+
     /// Fetches a single channel by ID.
     async fn fetch_channel(
         &self,
         token: &AuthToken,
         channel_id: ChannelId,
     ) -> Result<Channel, AuthError>;
+
+    /// Fetches a user's profile, including their member profile in `guild_id` if given.
+    async fn fetch_user_profile(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    ) -> Result<UserProfile, AuthError>;
+
+    /// Saves our own status and custom status to the user settings.
+    async fn update_status(
+        &self,
+        token: &AuthToken,
+        status: PresenceStatus,
+        custom_status: Option<&CustomStatus>,
+    ) -> Result<(), AuthError>;
+
+    /// Sends a friend request to the user with this unique username.
+    async fn send_friend_request(&self, token: &AuthToken, username: &str)
+    -> Result<(), AuthError>;
+
+    /// Accepts an incoming friend request from `user_id`.
+    async fn accept_friend_request(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+    ) -> Result<(), AuthError>;
+
+    /// Removes any relationship with `user_id`: unfriends, declines, cancels or unblocks.
+    async fn remove_relationship(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+    ) -> Result<(), AuthError>;
+
+    /// Blocks `user_id`, replacing any other relationship.
+    async fn block_user(&self, token: &AuthToken, user_id: UserId) -> Result<(), AuthError>;
+
+    /// Fetches recent messages that mention us, our roles or @everyone, newest first.
+    async fn fetch_recent_mentions(
+        &self,
+        token: &AuthToken,
+        limit: u8,
+    ) -> Result<Vec<Message>, AuthError>;
+
+    /// Saves mute and notification settings for a guild or one of its channels.
+    async fn update_notification_settings(
+        &self,
+        token: &AuthToken,
+        update: &NotificationSettingsUpdate,
+    ) -> Result<(), AuthError>;
+
+    /// Opens the DM with `recipients`, creating a group DM when there are several.
+    async fn open_dm_channel(
+        &self,
+        token: &AuthToken,
+        recipients: &[UserId],
+    ) -> Result<DirectMessageChannel, AuthError>;
+
+    /// Renames the group DM `channel_id`.
+    async fn rename_group_dm(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        name: &str,
+    ) -> Result<(), AuthError>;
 }
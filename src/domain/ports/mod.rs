@@ -8,7 +8,9 @@ mod token_storage_port;
 
 pub use auth_port::AuthPort;
 pub use discord_data_port::{
-    DirectMessageChannel, DiscordDataPort, EditMessageRequest, FetchMessagesOptions,
+    CreateForumPostRequest, CreateThreadRequest, DirectMessageChannel, DirectMessageRecipient,
+    DiscordDataPort, EditMessageRequest, FetchMessagesOptions, MessageSearchPage,
+    NotificationSettingsUpdate, RelationshipChange, SearchMessagesRequest, SearchScope,
     SendMessageRequest,
 };
 pub use gateway_port::{GatewayEvent, GatewayPort};
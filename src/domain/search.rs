@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -35,6 +36,9 @@ pub struct SearchResult {
     pub score: i64,
     #[serde(default)]
     pub is_favorite: bool,
+    /// A DM with a friend who is currently online.
+    #[serde(default)]
+    pub is_online_friend: bool,
 }
 
 impl SearchResult {
@@ -48,6 +52,7 @@ impl SearchResult {
             parent_name: None,
             score: 0,
             is_favorite: false,
+            is_online_friend: false,
         }
     }
 
@@ -139,6 +144,171 @@ impl RecentItem {
     }
 }
 
+/// Discord snowflakes count milliseconds from the first second of 2015.
+const DISCORD_EPOCH_MS: i64 = 1_420_070_400_000;
+
+/// Smallest snowflake that can have been created at `time`.
+#[must_use]
+pub fn snowflake_at(time: DateTime<Utc>) -> u64 {
+    let elapsed = (time.timestamp_millis() - DISCORD_EPOCH_MS).max(0);
+    u64::try_from(elapsed).unwrap_or(0) << 22
+}
+
+/// When the object with snowflake `id` was created.
+#[must_use]
+pub fn snowflake_time(id: u64) -> DateTime<Utc> {
+    let elapsed = i64::try_from(id >> 22).unwrap_or(i64::MAX);
+    DateTime::from_timestamp_millis(DISCORD_EPOCH_MS.saturating_add(elapsed)).unwrap_or_default()
+}
+
+/// Kinds of content a `has:` search filter can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HasFilter {
+    Link,
+    Embed,
+    File,
+    Image,
+    Video,
+    Sound,
+    Sticker,
+}
+
+impl HasFilter {
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "link" => Some(Self::Link),
+            "embed" => Some(Self::Embed),
+            "file" => Some(Self::File),
+            "image" => Some(Self::Image),
+            "video" => Some(Self::Video),
+            "sound" => Some(Self::Sound),
+            "sticker" => Some(Self::Sticker),
+            _ => None,
+        }
+    }
+
+    /// The value Discord's search endpoints expect for the `has` parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Link => "link",
+            Self::Embed => "embed",
+            Self::File => "file",
+            Self::Image => "image",
+            Self::Video => "video",
+            Self::Sound => "sound",
+            Self::Sticker => "sticker",
+        }
+    }
+}
+
+/// A message search as typed by the user: free text plus `key:value` filters.
+///
+/// `from:`, `mentions:` and `in:` values are kept as typed; resolving them to ids
+/// needs the caches of whoever runs the search.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageSearchQuery {
+    pub content: String,
+    pub from: Vec<String>,
+    pub mentions: Vec<String>,
+    pub in_channels: Vec<String>,
+    pub has: Vec<HasFilter>,
+    pub before: Option<NaiveDate>,
+    pub after: Option<NaiveDate>,
+}
+
+impl MessageSearchQuery {
+    /// Splits a query into filters and free text.
+    ///
+    /// Values may be quoted to include spaces, e.g. `from:"display name"`.
+    ///
+    /// # Errors
+    /// Returns a message describing the first filter whose value cannot be used.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut query = Self::default();
+        let mut words = Vec::new();
+
+        for token in tokenize(input) {
+            let Some((key, value)) = token.split_once(':') else {
+                words.push(token);
+                continue;
+            };
+            let value = value.to_string();
+            match key.to_lowercase().as_str() {
+                "from" => query.from.push(value),
+                "mentions" => query.mentions.push(value),
+                "in" => query.in_channels.push(value),
+                "has" => query.has.push(
+                    HasFilter::parse(&value)
+                        .ok_or_else(|| format!("Unknown has: filter '{value}'"))?,
+                ),
+                "before" => query.before = Some(parse_date(&value)?),
+                "after" => query.after = Some(parse_date(&value)?),
+                _ => words.push(token),
+            }
+        }
+
+        query.content = words.join(" ");
+        Ok(query)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Lower id bound: messages sent after the `after:` day ended.
+    #[must_use]
+    pub fn min_id(&self) -> Option<u64> {
+        self.after
+            .and_then(|date| date.succ_opt())
+            .and_then(local_midnight)
+            .map(snowflake_at)
+    }
+
+    /// Upper id bound: messages sent before the `before:` day started.
+    #[must_use]
+    pub fn max_id(&self) -> Option<u64> {
+        self.before.and_then(local_midnight).map(snowflake_at)
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{value}', expected YYYY-MM-DD"))
+}
+
+fn local_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    date.and_hms_opt(0, 0, 0)?
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Splits on whitespace, keeping double-quoted runs together without their quotes.
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +336,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_message_search_query() {
+        let query = MessageSearchQuery::parse(
+            r#"release from:alice in:general has:image mentions:<@42> from:"Bob Smith" notes"#,
+        )
+        .unwrap();
+
+        assert_eq!(query.content, "release notes");
+        assert_eq!(query.from, vec!["alice", "Bob Smith"]);
+        assert_eq!(query.in_channels, vec!["general"]);
+        assert_eq!(query.mentions, vec!["<@42>"]);
+        assert_eq!(query.has, vec![HasFilter::Image]);
+        assert!(MessageSearchQuery::parse("has:hologram").is_err());
+        assert!(MessageSearchQuery::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_date_filters_bound_snowflakes() {
+        let query = MessageSearchQuery::parse("before:2024-01-02 after:2024-01-01").unwrap();
+        let (min_id, max_id) = (query.min_id().unwrap(), query.max_id().unwrap());
+
+        assert_eq!(
+            min_id, max_id,
+            "after the 1st ends where before the 2nd starts"
+        );
+        assert!(MessageSearchQuery::parse("before:yesterday").is_err());
+
+        let epoch = DateTime::from_timestamp_millis(DISCORD_EPOCH_MS).unwrap();
+        assert_eq!(snowflake_at(epoch), 0);
+        assert_eq!(
+            snowflake_at(epoch + chrono::Duration::milliseconds(1)),
+            1 << 22
+        );
+        assert_eq!(
+            snowflake_time(1 << 22),
+            epoch + chrono::Duration::milliseconds(1)
+        );
+    }
+
     #[test]
     fn test_parse_thread_prefix() {
         assert_eq!(
@@ -116,6 +116,13 @@ pub mod string_to_u64 {
                     Ok(None)
                 }
 
+                fn visit_unit<E>(self) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    Ok(None)
+                }
+
                 fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
                 where
                     D: Deserializer<'de>,
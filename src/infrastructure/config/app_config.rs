@@ -1,10 +1,12 @@
 //! Application configuration.
 
+use crate::domain::entities::{CustomStatus, Emoji, PresenceStatus};
 use crate::domain::keybinding::Action;
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use tracing::warn;
 
 const APP_NAME: &str = "oxicord";
 const APP_QUALIFIER: &str = "com";
@@ -93,13 +95,17 @@ pub struct AppConfig {
     #[serde(default)]
     pub notifications: NotificationsConfig,
 
-    /// Quick Switcher sort mode (Recents, Mixed).
+    /// Quick Switcher sort mode (Recents, Mixed, Online).
     #[serde(default)]
     pub quick_switcher_order: QuickSwitcherSortMode,
 
     /// Theme configuration.
     #[serde(default)]
     pub theme: ThemeConfig,
+
+    /// Status applied at startup.
+    #[serde(default)]
+    pub status: StatusConfig,
 }
 
 /// UI configuration.
@@ -164,6 +170,10 @@ pub struct NotificationsConfig {
     /// Enable internal TUI notifications.
     #[serde(default = "default_true", alias = "internal_notifications")]
     pub internal: bool,
+
+    /// Keyword and highlight rules (`[[notifications.rules]]`).
+    #[serde(default)]
+    pub rules: Vec<NotificationRuleConfig>,
 }
 
 impl Default for NotificationsConfig {
@@ -171,10 +181,107 @@ impl Default for NotificationsConfig {
         Self {
             desktop: true,
             internal: true,
+            rules: Vec::new(),
         }
     }
 }
 
+/// What a matching notification rule does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationRuleAction {
+    /// Send a desktop notification.
+    Desktop,
+    /// Show an internal TUI notification.
+    Internal,
+    /// Highlight the message in the message pane.
+    Highlight,
+    /// Ring the terminal bell.
+    Bell,
+    /// Drop every notification for the message.
+    Suppress,
+}
+
+/// A single notification rule. Every condition that is set must match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotificationRuleConfig {
+    /// Words or phrases matched case-insensitively; any one of them matches.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+
+    /// Regular expression matched against the message content.
+    #[serde(default)]
+    pub regex: Option<String>,
+
+    /// Author username, display name or ID.
+    #[serde(default)]
+    pub author: Option<String>,
+
+    /// Guild name or ID.
+    #[serde(default)]
+    pub guild: Option<String>,
+
+    /// Channel name or ID.
+    #[serde(default)]
+    pub channel: Option<String>,
+
+    /// Actions to run when the rule matches.
+    #[serde(default)]
+    pub actions: Vec<NotificationRuleAction>,
+}
+
+/// Startup status configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusConfig {
+    /// Status to set on connect (online, idle, dnd, invisible); keeps the current one if unset.
+    #[serde(default)]
+    pub status: Option<PresenceStatus>,
+
+    /// Custom status text.
+    #[serde(default)]
+    pub text: Option<String>,
+
+    /// Custom status emoji, as a unicode emoji or a known shortcode.
+    #[serde(default)]
+    pub emoji: Option<String>,
+}
+
+impl StatusConfig {
+    /// Custom status built from `text` and `emoji`, if either is set.
+    ///
+    /// An unknown shortcode is dropped, as Discord would reject it as an emoji name.
+    #[must_use]
+    pub fn custom_status(&self) -> Option<CustomStatus> {
+        let text = self
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty());
+        let emoji = self
+            .emoji
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .and_then(|emoji| {
+                if !emoji.is_ascii() {
+                    return Some(emoji.to_string());
+                }
+                let known = Emoji::from_shortcode(emoji.trim_matches(':'));
+                if known.is_none() {
+                    warn!(emoji, "Ignoring unknown custom status emoji");
+                }
+                known.map(|emoji| emoji.markup())
+            });
+
+        let custom_status = CustomStatus {
+            text: text.map(String::from),
+            emoji,
+            expires_at: None,
+        };
+        (!custom_status.is_empty()).then_some(custom_status)
+    }
+}
+
 /// Quick Switcher sorting strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -182,6 +289,8 @@ pub enum QuickSwitcherSortMode {
     #[default]
     Recents,
     Mixed,
+    /// Direct messages with online friends first.
+    Online,
 }
 
 impl std::fmt::Display for QuickSwitcherSortMode {
@@ -189,6 +298,7 @@ impl std::fmt::Display for QuickSwitcherSortMode {
         match self {
             Self::Recents => write!(f, "Recents"),
             Self::Mixed => write!(f, "Mixed"),
+            Self::Online => write!(f, "Online"),
         }
     }
 }
@@ -365,6 +475,7 @@ impl Default for AppConfig {
             notifications: NotificationsConfig::default(),
             quick_switcher_order: QuickSwitcherSortMode::default(),
             theme: ThemeConfig::default(),
+            status: StatusConfig::default(),
         }
     }
 }
@@ -408,6 +519,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_status_config() {
+        let toml_content = r#"
+            [status]
+            status = "dnd"
+            text = "Writing Rust"
+            emoji = ":crab:"
+        "#;
+
+        let config: AppConfig = toml::from_str(toml_content).expect("Failed to parse config");
+
+        assert_eq!(config.status.status, Some(PresenceStatus::DoNotDisturb));
+        let custom_status = config.status.custom_status().expect("custom status");
+        assert_eq!(custom_status.label(), "🦀 Writing Rust");
+        assert!(AppConfig::default().status.custom_status().is_none());
+
+        let unknown = StatusConfig {
+            text: Some("Writing Rust".to_string()),
+            emoji: Some(":notreal:".to_string()),
+            ..StatusConfig::default()
+        };
+        let custom_status = unknown.custom_status().expect("custom status");
+        assert_eq!(custom_status.emoji, None);
+        assert_eq!(custom_status.label(), "Writing Rust");
+    }
+
+    #[test]
+    fn test_parse_notification_rules() {
+        let toml_content = r#"
+            [notifications]
+            desktop = false
+
+            [[notifications.rules]]
+            keywords = ["deploy", "incident"]
+            guild = "Infra"
+            actions = ["desktop", "highlight", "bell"]
+
+            [[notifications.rules]]
+            author = "noisy-bot"
+            actions = ["suppress"]
+        "#;
+
+        let config: AppConfig = toml::from_str(toml_content).expect("Failed to parse config");
+
+        let rules = &config.notifications.rules;
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].keywords, ["deploy", "incident"]);
+        assert_eq!(rules[0].guild.as_deref(), Some("Infra"));
+        assert_eq!(
+            rules[0].actions,
+            [
+                NotificationRuleAction::Desktop,
+                NotificationRuleAction::Highlight,
+                NotificationRuleAction::Bell
+            ]
+        );
+        assert_eq!(rules[1].author.as_deref(), Some("noisy-bot"));
+        assert_eq!(rules[1].actions, [NotificationRuleAction::Suppress]);
+        assert!(config.notifications.internal);
+    }
+
     #[test]
     fn test_default_config() {
         let config = AppConfig::default();
@@ -1,4 +1,5 @@
 use super::app_config::LogLevel;
+use crate::domain::entities::DiscordLink;
 use clap::Parser;
 use std::path::PathBuf;
 
@@ -10,6 +11,10 @@ use std::path::PathBuf;
     long_about = None
 )]
 pub struct CliArgs {
+    /// Discord channel or message link to open once logged in.
+    #[arg(value_name = "LINK", value_parser = parse_discord_link)]
+    pub link: Option<DiscordLink>,
+
     /// Configuration file path.
     #[arg(short, long, value_name = "PATH")]
     pub config: Option<PathBuf>,
@@ -74,3 +79,8 @@ pub struct CliArgs {
     #[arg(long)]
     pub header_text_color: Option<String>,
 }
+
+fn parse_discord_link(value: &str) -> Result<DiscordLink, String> {
+    DiscordLink::parse(value)
+        .ok_or_else(|| "expected a https://discord.com/channels/... link".to_string())
+}
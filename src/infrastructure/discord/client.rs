@@ -2,35 +2,55 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local, Utc};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use reqwest::{Client, Method, StatusCode, header};
 use std::sync::Arc;
 use tracing::{debug, warn};
 
 use super::dto::{
-    AttachmentResponse, ChannelResponse, DmChannelResponse, EditMessagePayload, EmbedDto,
-    ErrorResponse, GuildResponse, MessageReferencePayload, MessageResponse, SendMessagePayload,
+    AttachmentResponse, ChannelOverridePatchPayload, ChannelResponse, CreateForumPostPayload,
+    CreateThreadPayload, CustomStatusSettingsPayload, DmChannelResponse, EditMessagePayload,
+    EmbedDto, ErrorResponse, ForumPostMessagePayload, ForumPostResponse, ForumTagDto,
+    FriendRequestPayload, GuildResponse, GuildSettingsPatchPayload, MessageReferencePayload,
+    MessageResponse, MuteConfigPatchPayload, OpenDmChannelPayload, PutRelationshipPayload,
+    ReadStateResponse, ReadStatesResponse, RenameChannelPayload, SearchMessagesResponse,
+    SendMessagePayload, ThreadsResponse, UpdateStatusSettingsPayload, UserProfileResponse,
     UserResponse,
 };
 use super::identity::ClientIdentity;
+use super::rate_limit::{RateLimitHeaders, RateLimiter, RouteKey};
 use super::scraper;
 use crate::domain::entities::{
-    Attachment, AuthToken, Channel, ChannelId, ChannelKind, Embed, EmbedProvider, EmbedThumbnail,
-    ForumThread, Guild, GuildId, Message, MessageAuthor, MessageId, ReadState, User,
+    Attachment, AuthToken, Channel, ChannelId, ChannelKind, CustomStatus, Embed, EmbedProvider,
+    EmbedThumbnail, ForumFilter, ForumSortOrder, ForumThread, Guild, GuildId, GuildMemberProfile,
+    Message, MessageAuthor, MessageId, MuteState, PremiumType, PresenceStatus, ReactionEmoji,
+    ReadState, RelationshipType, RoleId, User, UserFlags, UserId, UserProfile,
 };
 use crate::domain::errors::AuthError;
 use crate::domain::ports::{
-    AuthPort, DirectMessageChannel, DiscordDataPort, EditMessageRequest, FetchMessagesOptions,
+    AuthPort, CreateForumPostRequest, CreateThreadRequest, DirectMessageChannel,
+    DirectMessageRecipient, DiscordDataPort, EditMessageRequest, FetchMessagesOptions,
+    MessageSearchPage, NotificationSettingsUpdate, SearchMessagesRequest, SearchScope,
     SendMessageRequest,
 };
 
 const DISCORD_API_BASE: &str = "https://discord.com/api/v10";
 const MAX_IDLE_CONNECTIONS: usize = 10;
 const DEFAULT_MESSAGE_LIMIT: u8 = 50;
+/// Read state entries of this type track channel acknowledgements (guild
+/// channels, threads and DMs); other types cover guild events and inbox state.
+const READ_STATE_TYPE_CHANNEL: u8 = 0;
+/// How many times a request is retried after a 429 before giving up.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// Longer rate limits are surfaced to the caller instead of being waited out.
+const MAX_RATE_LIMIT_WAIT: std::time::Duration = std::time::Duration::from_secs(10);
+const DEFAULT_RETRY_AFTER_MS: u64 = 5000;
 
 /// Discord API client for authentication and data fetching.
 pub struct DiscordClient {
     client: Client,
     base_url: String,
+    rate_limiter: RateLimiter,
     pub identity: Arc<ClientIdentity>,
 }
 
@@ -71,6 +91,7 @@ impl DiscordClient {
         Ok(Self {
             client,
             base_url: base_url.into(),
+            rate_limiter: RateLimiter::new(),
             identity,
         })
     }
@@ -88,22 +109,93 @@ impl DiscordClient {
             .header("X-Super-Properties", self.identity.get_header_value())
     }
 
+    /// Sends a request once its rate limit bucket allows it.
+    ///
+    /// Requests sharing a rate limit bucket are queued in order. A 429 is
+    /// retried after the server-provided delay when that delay is short;
+    /// otherwise the 429 response is returned for `handle_error_response` to
+    /// report.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let request = request.build()?;
+        let route = RouteKey::new(request.method(), request.url());
+        let queue = self.rate_limiter.queue(&route);
+        let response = {
+            let _guard = queue.lock().await;
+            self.send_queued(&route, request).await
+        };
+        self.rate_limiter.release(queue);
+        response
+    }
+
+    /// Sends a request while holding its bucket's queue, retrying short 429s.
+    async fn send_queued(
+        &self,
+        route: &RouteKey,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let mut retries = 0;
+
+        loop {
+            self.rate_limiter.acquire(route).await;
+
+            let retry_request = request.try_clone();
+            let response = self.client.execute(request).await?;
+            let status = response.status();
+            let headers = RateLimitHeaders::from_headers(response.headers());
+            self.rate_limiter.update(route, status, &headers);
+
+            if status != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+
+            let retry_after = headers.retry_after.unwrap_or_default();
+            match retry_request {
+                Some(next)
+                    if retries < MAX_RATE_LIMIT_RETRIES && retry_after <= MAX_RATE_LIMIT_WAIT =>
+                {
+                    retries += 1;
+                    debug!(
+                        route = route.as_str(),
+                        retries,
+                        retry_after_ms = retry_after.as_millis(),
+                        "Retrying rate limited request"
+                    );
+                    request = next;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
     async fn handle_error_response(
         &self,
         status: StatusCode,
         response: reqwest::Response,
     ) -> AuthError {
-        let error_message = match response.json::<ErrorResponse>().await {
-            Ok(error) => error.message,
-            Err(_) => format!("HTTP {status}"),
+        let header_retry_after = RateLimitHeaders::from_headers(response.headers()).retry_after;
+        let (error_message, body_retry_after) = match response.json::<ErrorResponse>().await {
+            Ok(error) => (error.message, error.retry_after),
+            Err(_) => (format!("HTTP {status}"), None),
         };
 
         match status {
             StatusCode::UNAUTHORIZED => AuthError::rejected("invalid or expired token"),
             StatusCode::FORBIDDEN => AuthError::rejected(format!("access denied: {error_message}")),
-            StatusCode::TOO_MANY_REQUESTS => AuthError::RateLimited {
-                retry_after_ms: 5000,
-            },
+            StatusCode::TOO_MANY_REQUESTS => {
+                let retry_after_ms = header_retry_after
+                    .or_else(|| {
+                        body_retry_after
+                            .filter(|s| s.is_finite() && *s >= 0.0)
+                            .map(std::time::Duration::from_secs_f64)
+                    })
+                    .map_or(DEFAULT_RETRY_AFTER_MS, |d| {
+                        u64::try_from(d.as_millis()).unwrap_or(u64::MAX)
+                    });
+                AuthError::RateLimited { retry_after_ms }
+            }
             StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
                 AuthError::network("Discord API is temporarily unavailable")
             }
@@ -203,6 +295,15 @@ impl DiscordClient {
                     channel = channel.with_last_pin_timestamp(timestamp);
                 }
 
+                if !c.available_tags.is_empty() {
+                    let tags = c
+                        .available_tags
+                        .iter()
+                        .filter_map(ForumTagDto::to_forum_tag)
+                        .collect();
+                    channel = channel.with_available_tags(tags);
+                }
+
                 Some(channel)
             })
             .collect()
@@ -310,6 +411,323 @@ impl DiscordClient {
             .collect()
     }
 
+    fn own_reaction_url(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
+    ) -> String {
+        let emoji = utf8_percent_encode(&emoji.api_name(), NON_ALPHANUMERIC).to_string();
+        format!(
+            "{}/channels/{}/messages/{}/reactions/{emoji}/@me",
+            self.base_url,
+            channel_id.as_u64(),
+            message_id.as_u64()
+        )
+    }
+
+    fn pin_url(&self, channel_id: ChannelId, message_id: MessageId) -> String {
+        format!(
+            "{}/channels/{}/pins/{}",
+            self.base_url,
+            channel_id.as_u64(),
+            message_id.as_u64()
+        )
+    }
+
+    fn create_thread_url(&self, request: &CreateThreadRequest) -> String {
+        match request.message_id {
+            Some(message_id) => format!(
+                "{}/channels/{}/messages/{}/threads",
+                self.base_url,
+                request.channel_id.as_u64(),
+                message_id.as_u64()
+            ),
+            None => format!(
+                "{}/channels/{}/threads",
+                self.base_url,
+                request.channel_id.as_u64()
+            ),
+        }
+    }
+
+    fn forum_threads_url(
+        &self,
+        channel_id: ChannelId,
+        filter: ForumFilter,
+        offset: u32,
+        limit: u8,
+    ) -> String {
+        let sort_by = match filter.sort {
+            ForumSortOrder::LatestActivity => "last_message_time",
+            ForumSortOrder::CreationDate => "creation_time",
+        };
+        let tag = filter
+            .tag_id
+            .map(|tag_id| format!("&tag={tag_id}"))
+            .unwrap_or_default();
+        format!(
+            "{}/channels/{}/threads/search?archived=false&sort_by={sort_by}&sort_order=desc&limit={limit}&tag_setting=match_some&offset={offset}{tag}",
+            self.base_url,
+            channel_id.as_u64(),
+        )
+    }
+
+    /// Attaches `payload` as JSON, or as `payload_json` plus `files[n]` parts
+    /// when there are attachments to upload.
+    async fn with_payload_and_files(
+        request_builder: reqwest::RequestBuilder,
+        payload: &impl serde::Serialize,
+        attachments: &[std::path::PathBuf],
+    ) -> Result<reqwest::RequestBuilder, AuthError> {
+        use reqwest::multipart::{Form, Part};
+
+        if attachments.is_empty() {
+            return Ok(request_builder
+                .header(header::CONTENT_TYPE, "application/json")
+                .json(payload));
+        }
+
+        let mut form = Form::new();
+
+        let json_payload = serde_json::to_string(payload)
+            .map_err(|e| AuthError::unexpected(format!("failed to serialize payload: {e}")))?;
+        form = form.part(
+            "payload_json",
+            Part::text(json_payload)
+                .mime_str("application/json")
+                .map_err(|e| AuthError::unexpected(format!("failed to set mime type: {e}")))?,
+        );
+
+        for (index, path) in attachments.iter().enumerate() {
+            let filename = path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+
+            let content = tokio::fs::read(path).await.map_err(|e| {
+                AuthError::unexpected(format!(
+                    "failed to read attachment {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+            let part = Part::bytes(content).file_name(filename);
+            form = form.part(format!("files[{index}]"), part);
+        }
+
+        Ok(request_builder.multipart(form))
+    }
+
+    fn thread_member_url(&self, channel_id: ChannelId) -> String {
+        format!(
+            "{}/channels/{}/thread-members/@me",
+            self.base_url,
+            channel_id.as_u64()
+        )
+    }
+
+    /// Joins or leaves a thread depending on `method`.
+    async fn set_thread_membership(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        method: Method,
+    ) -> Result<(), AuthError> {
+        let url = self.thread_member_url(channel_id);
+
+        debug!(channel_id = %channel_id, method = %method, "Updating thread membership via Discord API");
+
+        let request_builder = self
+            .build_request(method, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to update thread membership");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    fn relationship_url(&self, user_id: UserId) -> String {
+        format!(
+            "{}/users/@me/relationships/{}",
+            self.base_url,
+            user_id.as_u64()
+        )
+    }
+
+    fn guild_settings_url(&self, guild_id: Option<GuildId>) -> String {
+        let guild = guild_id.map_or_else(|| "@me".to_string(), |id| id.as_u64().to_string());
+        format!("{}/users/@me/guilds/{guild}/settings", self.base_url)
+    }
+
+    fn mute_config_payload(mute: MuteState, now: DateTime<Utc>) -> Option<MuteConfigPatchPayload> {
+        mute.muted.then(|| MuteConfigPatchPayload {
+            end_time: mute.until.map(|until| until.to_rfc3339()),
+            selected_time_window: mute
+                .until
+                .map_or(-1, |until| (until - now).num_seconds().max(0)),
+        })
+    }
+
+    fn guild_settings_payload(
+        update: &NotificationSettingsUpdate,
+        now: DateTime<Utc>,
+    ) -> GuildSettingsPatchPayload {
+        match update {
+            NotificationSettingsUpdate::Guild {
+                mute,
+                level,
+                suppress_everyone,
+                suppress_roles,
+                ..
+            } => GuildSettingsPatchPayload {
+                muted: Some(mute.muted),
+                mute_config: Self::mute_config_payload(*mute, now),
+                message_notifications: Some(level.as_u8()),
+                suppress_everyone: Some(*suppress_everyone),
+                suppress_roles: Some(*suppress_roles),
+                channel_overrides: None,
+            },
+            NotificationSettingsUpdate::Channel {
+                channel_id,
+                settings,
+                ..
+            } => GuildSettingsPatchPayload {
+                channel_overrides: Some(std::collections::HashMap::from([(
+                    channel_id.as_u64().to_string(),
+                    ChannelOverridePatchPayload {
+                        muted: settings.mute.muted,
+                        mute_config: Self::mute_config_payload(settings.mute, now),
+                        message_notifications: settings.level.as_u8(),
+                    },
+                )])),
+                ..GuildSettingsPatchPayload::default()
+            },
+        }
+    }
+
+    async fn put_relationship(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+        payload: PutRelationshipPayload,
+    ) -> Result<(), AuthError> {
+        let url = self.relationship_url(user_id);
+
+        debug!(user_id = %user_id, relationship_type = ?payload.relationship_type, "Updating relationship via Discord API");
+
+        let request_builder = self
+            .build_request(Method::PUT, &url)
+            .header(header::AUTHORIZATION, token.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        self.send_relationship_request(request_builder).await
+    }
+
+    async fn send_relationship_request(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> Result<(), AuthError> {
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to update relationship");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    fn search_url(&self, request: &SearchMessagesRequest) -> String {
+        let mut url = match request.scope {
+            SearchScope::Guild(guild_id) => {
+                format!(
+                    "{}/guilds/{}/messages/search",
+                    self.base_url,
+                    guild_id.as_u64()
+                )
+            }
+            SearchScope::Channel(channel_id) => format!(
+                "{}/channels/{}/messages/search",
+                self.base_url,
+                channel_id.as_u64()
+            ),
+        };
+
+        let mut query_parts = Vec::new();
+        if let Some(content) = request.content.as_deref().filter(|c| !c.is_empty()) {
+            query_parts.push(format!(
+                "content={}",
+                utf8_percent_encode(content, NON_ALPHANUMERIC)
+            ));
+        }
+        query_parts.extend(
+            request
+                .author_ids
+                .iter()
+                .map(|id| format!("author_id={id}")),
+        );
+        query_parts.extend(
+            request
+                .mention_ids
+                .iter()
+                .map(|id| format!("mentions={id}")),
+        );
+        query_parts.extend(
+            request
+                .channel_ids
+                .iter()
+                .map(|id| format!("channel_id={}", id.as_u64())),
+        );
+        query_parts.extend(
+            request
+                .has
+                .iter()
+                .map(|has| format!("has={}", has.as_str())),
+        );
+        if let Some(min_id) = request.min_id {
+            query_parts.push(format!("min_id={min_id}"));
+        }
+        if let Some(max_id) = request.max_id {
+            query_parts.push(format!("max_id={max_id}"));
+        }
+        if request.offset > 0 {
+            query_parts.push(format!("offset={}", request.offset));
+        }
+        query_parts.push("include_nsfw=true".to_string());
+
+        url.push('?');
+        url.push_str(&query_parts.join("&"));
+        url
+    }
+
+    fn parse_read_states(entries: Vec<ReadStateResponse>) -> Vec<ReadState> {
+        entries
+            .into_iter()
+            .filter(|entry| entry.read_state_type == READ_STATE_TYPE_CHANNEL)
+            .map(|entry| {
+                ReadState::new(ChannelId(entry.id), entry.last_message_id.map(MessageId))
+                    .with_mention_count(entry.mention_count)
+            })
+            .collect()
+    }
+
     fn parse_message_response(response: MessageResponse, channel_id: u64) -> Option<Message> {
         let MessageResponse {
             id,
@@ -324,6 +742,8 @@ impl DiscordClient {
             referenced_message,
             pinned,
             mentions,
+            mention_roles,
+            mention_everyone,
             member,
             reactions,
             flags,
@@ -332,10 +752,7 @@ impl DiscordClient {
         } = response;
 
         let id: u64 = id.parse().ok()?;
-        let mut author_color = None;
-        if let Some(m) = member {
-            author_color = m.color;
-        }
+        let author_color = member.and_then(|m| m.color);
 
         let message_author = MessageAuthor {
             id: author.id,
@@ -358,6 +775,7 @@ impl DiscordClient {
             kind.into(),
         )
         .with_pinned(pinned)
+        .with_mention_everyone(mention_everyone)
         .with_guild_id(guild_id.and_then(|g| g.parse::<u64>().ok()).map(GuildId));
 
         if let Some(r) = message_reference {
@@ -400,6 +818,13 @@ impl DiscordClient {
             message = message.with_mentions(Self::parse_mentions(mentions));
         }
 
+        message = message.with_mention_roles(
+            mention_roles
+                .iter()
+                .filter_map(|id| id.parse().ok().map(RoleId))
+                .collect(),
+        );
+
         if !reactions.is_empty() {
             message = message.with_reactions(Self::parse_reactions(reactions));
         }
@@ -427,21 +852,20 @@ impl AuthPort for DiscordClient {
 
         debug!("Validating token against Discord API");
 
-        let response = self
+        let request_builder = self
             .build_request(Method::GET, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to connect to Discord API");
-                if e.is_timeout() {
-                    AuthError::network("request timed out")
-                } else if e.is_connect() {
-                    AuthError::network("failed to connect to Discord")
-                } else {
-                    AuthError::network(e.to_string())
-                }
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to connect to Discord API");
+            if e.is_timeout() {
+                AuthError::network("request timed out")
+            } else if e.is_connect() {
+                AuthError::network("failed to connect to Discord")
+            } else {
+                AuthError::network(e.to_string())
+            }
+        })?;
 
         let status = response.status();
 
@@ -477,19 +901,17 @@ impl AuthPort for DiscordClient {
 
         debug!("Performing Discord API health check");
 
-        let response = self
-            .build_request(Method::GET, &url)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    AuthError::network("request timed out")
-                } else if e.is_connect() {
-                    AuthError::network("failed to connect to Discord")
-                } else {
-                    AuthError::network(e.to_string())
-                }
-            })?;
+        let request_builder = self.build_request(Method::GET, &url);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            if e.is_timeout() {
+                AuthError::network("request timed out")
+            } else if e.is_connect() {
+                AuthError::network("failed to connect to Discord")
+            } else {
+                AuthError::network(e.to_string())
+            }
+        })?;
 
         if response.status().is_success() {
             Ok(())
@@ -509,15 +931,14 @@ impl DiscordDataPort for DiscordClient {
 
         debug!("Fetching user guilds from Discord API");
 
-        let response = self
+        let request_builder = self
             .build_request(Method::GET, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch guilds");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch guilds");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -583,15 +1004,14 @@ impl DiscordDataPort for DiscordClient {
 
         debug!(guild_id = guild_id, "Fetching channels from Discord API");
 
-        let response = self
+        let request_builder = self
             .build_request(Method::GET, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch channels");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch channels");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -629,15 +1049,14 @@ impl DiscordDataPort for DiscordClient {
 
         debug!("Fetching DM channels from Discord API");
 
-        let response = self
+        let request_builder = self
             .build_request(Method::GET, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch DM channels");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch DM channels");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -657,29 +1076,45 @@ impl DiscordDataPort for DiscordClient {
 
         let dm_channels = dm_responses
             .into_iter()
-            .filter_map(|dm| {
-                let _kind = dm.kind;
-                let recipient = dm.recipients.first()?;
-                Some(DirectMessageChannel {
-                    channel_id: dm.id,
-                    recipient_id: recipient.id.clone(),
-                    recipient_username: recipient.username.clone(),
-                    recipient_discriminator: recipient.discriminator.clone(),
-                    recipient_global_name: recipient.global_name.clone(),
-                    last_message_id: dm
-                        .last_message_id
-                        .and_then(|id| id.parse::<u64>().ok().map(Into::into)),
-                    has_unread: false,
-                    mention_count: 0,
-                })
-            })
+            .filter_map(Self::parse_dm_channel)
             .collect();
 
         Ok(dm_channels)
     }
 
-    async fn fetch_read_states(&self, _token: &AuthToken) -> Result<Vec<ReadState>, AuthError> {
-        Ok(Vec::new())
+    async fn fetch_read_states(&self, token: &AuthToken) -> Result<Vec<ReadState>, AuthError> {
+        let url = format!("{}/users/@me/read-states", self.base_url);
+
+        debug!("Fetching read states from Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch read states");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let read_states: ReadStatesResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse read states response");
+            AuthError::unexpected(format!("failed to parse read states: {e}"))
+        })?;
+
+        let read_states = Self::parse_read_states(read_states.into_entries());
+
+        debug!(
+            count = read_states.len(),
+            "Fetched read states successfully"
+        );
+
+        Ok(read_states)
     }
 
     async fn fetch_messages(
@@ -713,15 +1148,14 @@ impl DiscordDataPort for DiscordClient {
             "Fetching messages from Discord API"
         );
 
-        let response = self
+        let request_builder = self
             .build_request(Method::GET, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch messages");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch messages");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -793,50 +1227,15 @@ impl DiscordDataPort for DiscordClient {
             }),
         };
 
-        let mut request_builder = self
-            .build_request(Method::POST, &url)
-            .header(header::AUTHORIZATION, token.as_str());
-
-        if request.attachments.is_empty() {
-            request_builder = request_builder
-                .header(header::CONTENT_TYPE, "application/json")
-                .json(&payload);
-        } else {
-            use reqwest::multipart::{Form, Part};
-            let mut form = Form::new();
-
-            let json_payload = serde_json::to_string(&payload)
-                .map_err(|e| AuthError::unexpected(format!("failed to serialize payload: {e}")))?;
-            form = form.part(
-                "payload_json",
-                Part::text(json_payload)
-                    .mime_str("application/json")
-                    .map_err(|e| AuthError::unexpected(format!("failed to set mime type: {e}")))?,
-            );
-
-            for (index, path) in request.attachments.iter().enumerate() {
-                let filename = path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string();
-
-                let content = tokio::fs::read(path).await.map_err(|e| {
-                    AuthError::unexpected(format!(
-                        "failed to read attachment {}: {}",
-                        path.display(),
-                        e
-                    ))
-                })?;
-
-                let part = Part::bytes(content).file_name(filename);
-                form = form.part(format!("files[{index}]"), part);
-            }
-
-            request_builder = request_builder.multipart(form);
-        }
+        let request_builder = Self::with_payload_and_files(
+            self.build_request(Method::POST, &url)
+                .header(header::AUTHORIZATION, token.as_str()),
+            &payload,
+            &request.attachments,
+        )
+        .await?;
 
-        let response = request_builder.send().await.map_err(|e| {
+        let response = self.send(request_builder).await.map_err(|e| {
             warn!(error = %e, "Failed to send message");
             AuthError::network(e.to_string())
         })?;
@@ -880,17 +1279,16 @@ impl DiscordDataPort for DiscordClient {
             content: request.content,
         };
 
-        let response = self
+        let request_builder = self
             .build_request(Method::PATCH, &url)
             .header(header::AUTHORIZATION, token.as_str())
             .header(header::CONTENT_TYPE, "application/json")
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to edit message");
-                AuthError::network(e.to_string())
-            })?;
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to edit message");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -928,15 +1326,14 @@ impl DiscordDataPort for DiscordClient {
             "Deleting message via Discord API"
         );
 
-        let response = self
+        let request_builder = self
             .build_request(Method::DELETE, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to delete message");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to delete message");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -949,147 +1346,834 @@ impl DiscordDataPort for DiscordClient {
         Ok(())
     }
 
-    async fn send_typing_indicator(
+    async fn add_reaction(
         &self,
         token: &AuthToken,
         channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
     ) -> Result<(), AuthError> {
-        let url = format!("{}/channels/{}/typing", self.base_url, channel_id.as_u64());
+        let url = self.own_reaction_url(channel_id, message_id, emoji);
 
-        debug!(channel_id = %channel_id, "Sending typing indicator");
-
-        let response = self
-            .build_request(Method::POST, &url)
-            .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to send typing indicator");
-                AuthError::network(e.to_string())
+        debug!(
+            channel_id = %channel_id,
+            message_id = %message_id,
+            emoji = %emoji.display(),
+            "Adding reaction via Discord API"
+        );
+
+        let request_builder = self
+            .build_request(Method::PUT, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to add reaction");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn remove_reaction(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
+    ) -> Result<(), AuthError> {
+        let url = self.own_reaction_url(channel_id, message_id, emoji);
+
+        debug!(
+            channel_id = %channel_id,
+            message_id = %message_id,
+            emoji = %emoji.display(),
+            "Removing reaction via Discord API"
+        );
+
+        let request_builder = self
+            .build_request(Method::DELETE, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to remove reaction");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_pinned_messages(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Message>, AuthError> {
+        let url = format!("{}/channels/{}/pins", self.base_url, channel_id.as_u64());
+
+        debug!(channel_id = %channel_id, "Fetching pinned messages from Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch pinned messages");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let message_responses: Vec<MessageResponse> = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse pinned messages response");
+            AuthError::unexpected(format!("failed to parse pinned messages: {e}"))
+        })?;
+
+        debug!(
+            count = message_responses.len(),
+            channel_id = %channel_id,
+            "Fetched pinned messages successfully"
+        );
+
+        Ok(message_responses
+            .into_iter()
+            .filter_map(|m| Self::parse_message_response(m, channel_id.as_u64()))
+            .collect())
+    }
+
+    async fn pin_message(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), AuthError> {
+        let url = self.pin_url(channel_id, message_id);
+
+        debug!(
+            channel_id = %channel_id,
+            message_id = %message_id,
+            "Pinning message via Discord API"
+        );
+
+        let request_builder = self
+            .build_request(Method::PUT, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to pin message");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn unpin_message(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), AuthError> {
+        let url = self.pin_url(channel_id, message_id);
+
+        debug!(
+            channel_id = %channel_id,
+            message_id = %message_id,
+            "Unpinning message via Discord API"
+        );
+
+        let request_builder = self
+            .build_request(Method::DELETE, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to unpin message");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn search_messages(
+        &self,
+        token: &AuthToken,
+        request: &SearchMessagesRequest,
+    ) -> Result<MessageSearchPage, AuthError> {
+        let url = self.search_url(request);
+
+        debug!(scope = ?request.scope, offset = request.offset, "Searching messages via Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to search messages");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        // Discord answers 202 while it is still indexing the guild or channel.
+        if status == StatusCode::ACCEPTED {
+            return Err(AuthError::unexpected(
+                "search index is not ready yet, try again in a moment",
+            ));
+        }
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let search_response: SearchMessagesResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse search response");
+            AuthError::unexpected(format!("failed to parse search results: {e}"))
+        })?;
+
+        let messages = search_response
+            .messages
+            .into_iter()
+            .filter_map(|hit| hit.into_iter().next())
+            .filter_map(|m| {
+                let channel_id = m.channel_id.parse().ok()?;
+                Self::parse_message_response(m, channel_id)
+            })
+            .collect::<Vec<_>>();
+
+        debug!(
+            count = messages.len(),
+            total = search_response.total_results,
+            "Searched messages successfully"
+        );
+
+        Ok(MessageSearchPage {
+            total_results: search_response.total_results,
+            messages,
+        })
+    }
+
+    async fn send_typing_indicator(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<(), AuthError> {
+        let url = format!("{}/channels/{}/typing", self.base_url, channel_id.as_u64());
+
+        debug!(channel_id = %channel_id, "Sending typing indicator");
+
+        let request_builder = self
+            .build_request(Method::POST, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to send typing indicator");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn acknowledge_message(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        message_id: crate::domain::entities::MessageId,
+    ) -> Result<(), AuthError> {
+        let url = format!(
+            "{}/channels/{}/messages/{}/ack",
+            self.base_url,
+            channel_id.as_u64(),
+            message_id.as_u64()
+        );
+
+        debug!(
+            channel_id = %channel_id,
+            message_id = %message_id,
+            "Acknowledging message"
+        );
+
+        let payload = serde_json::json!({ "token": null });
+
+        let request_builder = self
+            .build_request(Method::POST, &url)
+            .header(header::AUTHORIZATION, token.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to acknowledge message");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() && status != StatusCode::NO_CONTENT {
+            warn!(status = %status, "Failed to ACK message in API");
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_forum_threads(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+        _guild_id: Option<GuildId>,
+        filter: ForumFilter,
+        offset: u32,
+        limit: Option<u8>,
+    ) -> Result<Vec<ForumThread>, AuthError> {
+        let effective_limit = limit.unwrap_or(25).min(25);
+
+        let url = self.forum_threads_url(channel_id, filter, offset, effective_limit);
+
+        debug!("Fetching forum threads from URL: {}", url);
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch forum threads");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            let truncated_error: String = error_text.chars().take(200).collect();
+            warn!(
+                "Fetch forum threads failed: Status={} Body={} (truncated)",
+                status, truncated_error
+            );
+            return Err(AuthError::unexpected(format!(
+                "Fetch threads failed: {status} - {truncated_error}"
+            )));
+        }
+
+        let threads_response: super::dto::ThreadsResponse =
+            serde_json::from_str(&response.text().await.unwrap_or_default()).map_err(|e| {
+                warn!(error = %e, "Failed to parse threads response");
+                AuthError::unexpected(format!("failed to parse threads: {e}"))
             })?;
 
-        let status = response.status();
+        let threads = Self::process_threads_response(threads_response, None);
+        Ok(threads)
+    }
+
+    async fn create_forum_post(
+        &self,
+        token: &AuthToken,
+        request: &CreateForumPostRequest,
+    ) -> Result<ForumThread, AuthError> {
+        let url = format!(
+            "{}/channels/{}/threads",
+            self.base_url,
+            request.channel_id.as_u64()
+        );
+
+        debug!(
+            channel_id = %request.channel_id,
+            tag_count = request.applied_tags.len(),
+            attachment_count = request.attachments.len(),
+            "Creating forum post in Discord API"
+        );
+
+        let payload = CreateForumPostPayload {
+            name: request.title.clone(),
+            applied_tags: request
+                .applied_tags
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            message: ForumPostMessagePayload {
+                content: (!request.content.is_empty()).then(|| request.content.clone()),
+            },
+        };
+
+        let request_builder = Self::with_payload_and_files(
+            self.build_request(Method::POST, &url)
+                .header(header::AUTHORIZATION, token.as_str()),
+            &payload,
+            &request.attachments,
+        )
+        .await?;
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to create forum post");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let post: ForumPostResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse forum post response");
+            AuthError::unexpected(format!("failed to parse forum post: {e}"))
+        })?;
+
+        let threads = ThreadsResponse {
+            threads: vec![post.thread],
+            first_messages: post.message.map(|message| vec![message]),
+            members: Vec::new(),
+        };
+
+        Self::process_threads_response(threads, None)
+            .into_iter()
+            .next()
+            .ok_or_else(|| AuthError::unexpected("failed to parse created forum post"))
+    }
+
+    async fn fetch_archived_threads(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Channel>, AuthError> {
+        let public_url = format!(
+            "{}/channels/{}/threads/archived/public?limit=50",
+            self.base_url,
+            channel_id.as_u64()
+        );
+        let private_url = format!(
+            "{}/channels/{}/users/@me/threads/archived/private?limit=50",
+            self.base_url,
+            channel_id.as_u64()
+        );
+
+        debug!(channel_id = %channel_id, "Fetching archived threads from Discord API");
+
+        let mut threads = self.fetch_archived_thread_list(token, &public_url).await?;
+        match self.fetch_archived_thread_list(token, &private_url).await {
+            Ok(private) => threads.extend(
+                private
+                    .into_iter()
+                    .map(|thread| thread.with_thread_member(true)),
+            ),
+            Err(e) => {
+                warn!(channel_id = %channel_id, error = %e, "Failed to fetch private archived threads");
+            }
+        }
+        Ok(threads)
+    }
+
+    async fn create_thread(
+        &self,
+        token: &AuthToken,
+        request: &CreateThreadRequest,
+    ) -> Result<Channel, AuthError> {
+        let url = self.create_thread_url(request);
+
+        debug!(channel_id = %request.channel_id, name = %request.name, "Creating thread in Discord API");
+
+        let payload = CreateThreadPayload {
+            name: request.name.clone(),
+            auto_archive_duration: request.auto_archive_duration,
+            kind: request
+                .message_id
+                .is_none()
+                .then_some(ChannelKind::PublicThread as u8),
+        };
+
+        let request_builder = self
+            .build_request(Method::POST, &url)
+            .header(header::AUTHORIZATION, token.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to create thread");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let channel_response: ChannelResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse thread response");
+            AuthError::unexpected(format!("failed to parse thread: {e}"))
+        })?;
+
+        let guild_id = channel_response
+            .guild_id
+            .as_deref()
+            .and_then(|g: &str| g.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        Self::parse_channels(vec![channel_response], guild_id)
+            .into_iter()
+            .next()
+            .map(|thread| thread.with_thread_member(true))
+            .ok_or_else(|| AuthError::unexpected("failed to parse created thread"))
+    }
+
+    async fn join_thread(&self, token: &AuthToken, channel_id: ChannelId) -> Result<(), AuthError> {
+        self.set_thread_membership(token, channel_id, Method::PUT)
+            .await
+    }
+
+    async fn leave_thread(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<(), AuthError> {
+        self.set_thread_membership(token, channel_id, Method::DELETE)
+            .await
+    }
+
+    async fn fetch_channel(
+        &self,
+        token: &AuthToken,
+        channel_id: ChannelId,
+    ) -> Result<Channel, AuthError> {
+        let url = format!("{}/channels/{}", self.base_url, channel_id.as_u64());
+
+        debug!(channel_id = %channel_id, "Fetching single channel from Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch channel");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let channel_response: ChannelResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse channel response");
+            AuthError::unexpected(format!("failed to parse channel: {e}"))
+        })?;
+
+        let guild_id = channel_response
+            .guild_id
+            .as_deref()
+            .and_then(|g: &str| g.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        let channels = Self::parse_channels(vec![channel_response], guild_id);
+        channels
+            .into_iter()
+            .next()
+            .ok_or_else(|| AuthError::unexpected("failed to parse fetched channel"))
+    }
+
+    async fn fetch_user_profile(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    ) -> Result<UserProfile, AuthError> {
+        let guild = guild_id
+            .map(|guild_id| format!("&guild_id={}", guild_id.as_u64()))
+            .unwrap_or_default();
+        let url = format!(
+            "{}/users/{}/profile?with_mutual_guilds=true{guild}",
+            self.base_url,
+            user_id.as_u64()
+        );
+
+        debug!(user_id = %user_id, "Fetching user profile from Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch user profile");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let profile_response: UserProfileResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse user profile response");
+            AuthError::unexpected(format!("failed to parse user profile: {e}"))
+        })?;
+
+        Ok(Self::parse_user_profile(profile_response, guild_id))
+    }
+
+    async fn update_status(
+        &self,
+        token: &AuthToken,
+        status: PresenceStatus,
+        custom_status: Option<&CustomStatus>,
+    ) -> Result<(), AuthError> {
+        let url = format!("{}/users/@me/settings", self.base_url);
+
+        debug!(status = status.as_str(), "Updating status via Discord API");
+
+        let payload = UpdateStatusSettingsPayload {
+            status: status.as_str().to_string(),
+            custom_status: custom_status.map(|custom| CustomStatusSettingsPayload {
+                text: custom.text.clone(),
+                emoji_name: custom.emoji.clone(),
+                expires_at: custom.expires_at.map(|expires_at| expires_at.to_rfc3339()),
+            }),
+        };
+
+        let request_builder = self
+            .build_request(Method::PATCH, &url)
+            .header(header::AUTHORIZATION, token.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to update status");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        Ok(())
+    }
+
+    async fn send_friend_request(
+        &self,
+        token: &AuthToken,
+        username: &str,
+    ) -> Result<(), AuthError> {
+        let url = format!("{}/users/@me/relationships", self.base_url);
+
+        debug!(username = %username, "Sending friend request via Discord API");
+
+        let payload = FriendRequestPayload {
+            username: username.to_string(),
+            discriminator: None,
+        };
+
+        let request_builder = self
+            .build_request(Method::POST, &url)
+            .header(header::AUTHORIZATION, token.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        self.send_relationship_request(request_builder).await
+    }
+
+    async fn accept_friend_request(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+    ) -> Result<(), AuthError> {
+        self.put_relationship(token, user_id, PutRelationshipPayload::default())
+            .await
+    }
+
+    async fn remove_relationship(
+        &self,
+        token: &AuthToken,
+        user_id: UserId,
+    ) -> Result<(), AuthError> {
+        let url = self.relationship_url(user_id);
 
-        if !status.is_success() && status != StatusCode::NO_CONTENT {
-            return Err(self.handle_error_response(status, response).await);
-        }
+        debug!(user_id = %user_id, "Removing relationship via Discord API");
 
-        Ok(())
+        let request_builder = self
+            .build_request(Method::DELETE, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        self.send_relationship_request(request_builder).await
     }
 
-    async fn acknowledge_message(
+    async fn block_user(&self, token: &AuthToken, user_id: UserId) -> Result<(), AuthError> {
+        let payload = PutRelationshipPayload {
+            relationship_type: Some(RelationshipType::Blocked as u8),
+        };
+        self.put_relationship(token, user_id, payload).await
+    }
+
+    async fn fetch_recent_mentions(
         &self,
         token: &AuthToken,
-        channel_id: ChannelId,
-        message_id: crate::domain::entities::MessageId,
-    ) -> Result<(), AuthError> {
+        limit: u8,
+    ) -> Result<Vec<Message>, AuthError> {
         let url = format!(
-            "{}/channels/{}/messages/{}/ack",
+            "{}/users/@me/mentions?limit={}&roles=true&everyone=true",
             self.base_url,
-            channel_id.as_u64(),
-            message_id.as_u64()
+            limit.clamp(1, 100)
         );
 
+        debug!(limit = limit, "Fetching recent mentions from Discord API");
+
+        let request_builder = self
+            .build_request(Method::GET, &url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch recent mentions");
+            AuthError::network(e.to_string())
+        })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let message_responses: Vec<MessageResponse> = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse recent mentions response");
+            AuthError::unexpected(format!("failed to parse recent mentions: {e}"))
+        })?;
+
         debug!(
-            channel_id = %channel_id,
-            message_id = %message_id,
-            "Acknowledging message"
+            count = message_responses.len(),
+            "Fetched recent mentions successfully"
         );
 
-        let payload = serde_json::json!({ "token": null });
+        Ok(message_responses
+            .into_iter()
+            .filter_map(|m| {
+                let channel_id = m.channel_id.parse().ok()?;
+                Self::parse_message_response(m, channel_id)
+            })
+            .collect())
+    }
 
-        let response = self
-            .build_request(Method::POST, &url)
+    async fn update_notification_settings(
+        &self,
+        token: &AuthToken,
+        update: &NotificationSettingsUpdate,
+    ) -> Result<(), AuthError> {
+        let url = self.guild_settings_url(update.guild_id());
+        let payload = Self::guild_settings_payload(update, Utc::now());
+
+        debug!(update = ?update, "Updating notification settings via Discord API");
+
+        let request_builder = self
+            .build_request(Method::PATCH, &url)
             .header(header::AUTHORIZATION, token.as_str())
             .header(header::CONTENT_TYPE, "application/json")
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to acknowledge message");
-                AuthError::network(e.to_string())
-            })?;
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to update notification settings");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
-        if !status.is_success() && status != StatusCode::NO_CONTENT {
-            warn!(status = %status, "Failed to ACK message in API");
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
         }
 
         Ok(())
     }
 
-    async fn fetch_forum_threads(
+    async fn open_dm_channel(
         &self,
         token: &AuthToken,
-        channel_id: ChannelId,
-        _guild_id: Option<GuildId>,
-        offset: u32,
-        limit: Option<u8>,
-    ) -> Result<Vec<ForumThread>, AuthError> {
-        let effective_limit = limit.unwrap_or(25).min(25);
+        recipients: &[UserId],
+    ) -> Result<DirectMessageChannel, AuthError> {
+        let url = format!("{}/users/@me/channels", self.base_url);
+        let payload = OpenDmChannelPayload {
+            recipients: recipients.iter().map(ToString::to_string).collect(),
+        };
 
-        let url = format!(
-            "{}/channels/{}/threads/search?archived=false&sort_by=last_message_time&sort_order=desc&limit={}&tag_setting=match_some&offset={}",
-            self.base_url,
-            channel_id.as_u64(),
-            effective_limit,
-            offset
+        debug!(
+            count = recipients.len(),
+            "Opening DM channel via Discord API"
         );
 
-        debug!("Fetching forum threads from URL: {}", url);
-
-        let response = self
-            .build_request(Method::GET, &url)
+        let request_builder = self
+            .build_request(Method::POST, &url)
             .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch forum threads");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to open DM channel");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
+
         if !status.is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            let truncated_error: String = error_text.chars().take(200).collect();
-            warn!(
-                "Fetch forum threads failed: Status={} Body={} (truncated)",
-                status, truncated_error
-            );
-            return Err(AuthError::unexpected(format!(
-                "Fetch threads failed: {status} - {truncated_error}"
-            )));
+            return Err(self.handle_error_response(status, response).await);
         }
 
-        let threads_response: super::dto::ThreadsResponse =
-            serde_json::from_str(&response.text().await.unwrap_or_default()).map_err(|e| {
-                warn!(error = %e, "Failed to parse threads response");
-                AuthError::unexpected(format!("failed to parse threads: {e}"))
-            })?;
+        let dm: DmChannelResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse DM channel response");
+            AuthError::unexpected(format!("failed to parse DM channel: {e}"))
+        })?;
 
-        let threads = Self::process_threads_response(threads_response, None);
-        Ok(threads)
+        Self::parse_dm_channel(dm)
+            .ok_or_else(|| AuthError::unexpected("DM channel has no recipients"))
     }
 
-    async fn fetch_channel(
+    async fn rename_group_dm(
         &self,
         token: &AuthToken,
         channel_id: ChannelId,
-    ) -> Result<Channel, AuthError> {
+        name: &str,
+    ) -> Result<(), AuthError> {
         let url = format!("{}/channels/{}", self.base_url, channel_id.as_u64());
+        let payload = RenameChannelPayload {
+            name: name.to_string(),
+        };
 
-        debug!(channel_id = %channel_id, "Fetching single channel from Discord API");
+        debug!(channel_id = %channel_id, "Renaming group DM via Discord API");
 
-        let response = self
-            .build_request(Method::GET, &url)
+        let request_builder = self
+            .build_request(Method::PATCH, &url)
             .header(header::AUTHORIZATION, token.as_str())
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to fetch channel");
-                AuthError::network(e.to_string())
-            })?;
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to rename group DM");
+            AuthError::network(e.to_string())
+        })?;
 
         let status = response.status();
 
@@ -1097,26 +2181,151 @@ impl DiscordDataPort for DiscordClient {
             return Err(self.handle_error_response(status, response).await);
         }
 
-        let channel_response: ChannelResponse = response.json().await.map_err(|e| {
-            warn!(error = %e, "Failed to parse channel response");
-            AuthError::unexpected(format!("failed to parse channel: {e}"))
+        Ok(())
+    }
+}
+
+impl DiscordClient {
+    /// Converts a DM channel response, skipping DMs without any recipient.
+    fn parse_dm_channel(dm: DmChannelResponse) -> Option<DirectMessageChannel> {
+        if dm.recipients.is_empty() {
+            return None;
+        }
+        let is_group = ChannelKind::from(dm.kind) == ChannelKind::GroupDm;
+        let recipients = dm
+            .recipients
+            .into_iter()
+            .map(|recipient| DirectMessageRecipient {
+                id: recipient.id,
+                username: recipient.username,
+                discriminator: recipient.discriminator,
+                global_name: recipient.global_name,
+            })
+            .collect();
+        let mut channel = DirectMessageChannel::new(dm.id, recipients)
+            .with_group(is_group)
+            .with_last_message_id(
+                dm.last_message_id
+                    .and_then(|id| id.parse::<u64>().ok().map(Into::into)),
+            );
+        if let Some(name) = dm.name {
+            channel = channel.with_name(name);
+        }
+        Some(channel)
+    }
+
+    fn parse_user_profile(response: UserProfileResponse, guild_id: Option<GuildId>) -> UserProfile {
+        let non_empty = |text: Option<String>| text.filter(|text| !text.trim().is_empty());
+
+        let dto = response.user;
+        let mut user = User::new(
+            dto.id,
+            dto.username,
+            dto.discriminator,
+            dto.avatar,
+            dto.bot,
+            None,
+        )
+        .with_public_flags(UserFlags::from_bits_truncate(dto.public_flags))
+        .with_premium_type(PremiumType::from(response.premium_type.unwrap_or(0)));
+        if let Some(global_name) = dto.global_name {
+            user = user.with_global_name(global_name);
+        }
+        if let Some(banner) = dto.banner {
+            user = user.with_banner(banner);
+        }
+        if let Some(color) = dto.accent_color {
+            user = user.with_accent_color(color);
+        }
+
+        let mut profile = UserProfile::new(user, guild_id).with_mutual_guilds(
+            response
+                .mutual_guilds
+                .iter()
+                .filter_map(|guild| guild.id.parse().ok().map(GuildId))
+                .collect(),
+        );
+        if let Some(metadata) = response.user_profile {
+            if let Some(pronouns) = non_empty(metadata.pronouns) {
+                profile = profile.with_pronouns(pronouns);
+            }
+            if let Some(bio) = non_empty(metadata.bio) {
+                profile = profile.with_bio(bio);
+            }
+        }
+        if let Some(since) = response
+            .premium_since
+            .and_then(|since| since.parse::<DateTime<Utc>>().ok())
+        {
+            profile = profile.with_premium_since(since);
+        }
+        if let Some(member) = response.guild_member {
+            let metadata = response.guild_member_profile;
+            let (pronouns, bio) = metadata.map_or((None, None), |metadata| {
+                (non_empty(metadata.pronouns), non_empty(metadata.bio))
+            });
+            profile = profile.with_member(GuildMemberProfile {
+                nick: member.nick,
+                roles: member
+                    .roles
+                    .iter()
+                    .filter_map(|id| id.parse().ok().map(RoleId))
+                    .collect(),
+                joined_at: member.joined_at.and_then(|joined| joined.parse().ok()),
+                pronouns,
+                bio,
+            });
+        }
+        profile
+    }
+
+    /// Fetches one archived threads listing, marking the threads we have joined.
+    async fn fetch_archived_thread_list(
+        &self,
+        token: &AuthToken,
+        url: &str,
+    ) -> Result<Vec<Channel>, AuthError> {
+        let request_builder = self
+            .build_request(Method::GET, url)
+            .header(header::AUTHORIZATION, token.as_str());
+
+        let response = self.send(request_builder).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch archived threads");
+            AuthError::network(e.to_string())
         })?;
 
-        let guild_id = channel_response
-            .guild_id
-            .as_deref()
-            .and_then(|g: &str| g.parse::<u64>().ok())
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.handle_error_response(status, response).await);
+        }
+
+        let threads_response: ThreadsResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse archived threads response");
+            AuthError::unexpected(format!("failed to parse archived threads: {e}"))
+        })?;
+
+        let joined: std::collections::HashSet<String> = threads_response
+            .members
+            .into_iter()
+            .filter_map(|member| member.id)
+            .collect();
+        let guild_id = threads_response
+            .threads
+            .first()
+            .and_then(|t| t.guild_id.as_deref())
+            .and_then(|g| g.parse::<u64>().ok())
             .unwrap_or(0);
 
-        let channels = Self::parse_channels(vec![channel_response], guild_id);
-        channels
+        Ok(Self::parse_channels(threads_response.threads, guild_id)
             .into_iter()
-            .next()
-            .ok_or_else(|| AuthError::unexpected("failed to parse fetched channel"))
+            .map(|thread| {
+                let member = joined.contains(&thread.id().to_string());
+                thread.with_thread_member(member)
+            })
+            .collect())
     }
-}
 
-impl DiscordClient {
     fn process_threads_response(
         response: super::dto::ThreadsResponse,
         parent_id: Option<ChannelId>,
@@ -1318,4 +2527,297 @@ mod tests {
         assert_eq!(r2.emoji.id.as_deref(), Some("999"));
         assert_eq!(r2.emoji.name.as_deref(), Some("custom"));
     }
+
+    #[test]
+    fn test_read_states_parsing_keeps_channel_entries() {
+        use super::ReadStatesResponse;
+
+        let json = r#"[
+            {"id": "100", "last_message_id": "200", "mention_count": 2},
+            {"id": "300", "last_message_id": null, "read_state_type": 0},
+            {"id": "400", "last_message_id": "0", "read_state_type": 1, "badge_count": 1}
+        ]"#;
+
+        let response: ReadStatesResponse =
+            serde_json::from_str(json).expect("Should parse read states JSON");
+        let read_states = DiscordClient::parse_read_states(response.into_entries());
+
+        assert_eq!(read_states.len(), 2);
+        assert_eq!(read_states[0].channel_id, ChannelId(100));
+        assert_eq!(read_states[0].last_read_message_id, Some(MessageId(200)));
+        assert_eq!(read_states[0].mention_count, 2);
+        assert_eq!(read_states[1].channel_id, ChannelId(300));
+        assert_eq!(read_states[1].last_read_message_id, None);
+    }
+
+    /// Serves the given raw HTTP responses, one per connection, and counts requests.
+    async fn spawn_stand_in(
+        responses: Vec<&'static str>,
+    ) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Should bind stand-in server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let hits_clone = hits.clone();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                hits_clone.fetch_add(1, Ordering::SeqCst);
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (base_url, hits)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_is_retried_after_reset() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, hits) = spawn_stand_in(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0.05\r\nx-ratelimit-bucket: dms\r\nx-ratelimit-remaining: 0\r\nx-ratelimit-reset-after: 0.05\r\ncontent-type: application/json\r\ncontent-length: 48\r\nconnection: close\r\n\r\n{\"message\": \"rate limited\", \"retry_after\": 0.05}",
+            "HTTP/1.1 200 OK\r\nx-ratelimit-bucket: dms\r\nx-ratelimit-remaining: 4\r\nx-ratelimit-reset-after: 1\r\ncontent-type: application/json\r\ncontent-length: 2\r\nconnection: close\r\n\r\n[]",
+        ])
+        .await;
+
+        let client = DiscordClient::with_base_url(base_url, Arc::new(ClientIdentity::new()))
+            .expect("Should create client");
+        let token = AuthToken::new_unchecked("token");
+
+        let dms = client.fetch_dm_channels(&token).await;
+
+        assert!(dms.is_ok_and(|d| d.is_empty()));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_search_url_encodes_filters() {
+        use crate::domain::search::HasFilter;
+
+        let client =
+            DiscordClient::with_base_url("http://api".to_string(), Arc::new(ClientIdentity::new()))
+                .expect("Should create client");
+        let mut request =
+            SearchMessagesRequest::new(SearchScope::Guild(GuildId(1))).with_offset(25);
+        request.content = Some("hello world".to_string());
+        request.author_ids = vec![2, 3];
+        request.channel_ids = vec![ChannelId(4)];
+        request.has = vec![HasFilter::Image];
+        request.max_id = Some(5);
+
+        assert_eq!(
+            client.search_url(&request),
+            "http://api/guilds/1/messages/search?content=hello%20world&author_id=2&author_id=3&channel_id=4&has=image&max_id=5&offset=25&include_nsfw=true"
+        );
+    }
+
+    #[test]
+    fn test_create_thread_url_depends_on_starter_message() {
+        let client =
+            DiscordClient::with_base_url("http://api".to_string(), Arc::new(ClientIdentity::new()))
+                .expect("Should create client");
+        let request = CreateThreadRequest::new(ChannelId(1), "topic");
+
+        assert_eq!(
+            client.create_thread_url(&request),
+            "http://api/channels/1/threads"
+        );
+        assert_eq!(
+            client.create_thread_url(&request.from_message(MessageId(2))),
+            "http://api/channels/1/messages/2/threads"
+        );
+    }
+
+    #[test]
+    fn test_user_profile_parsing() {
+        let json = r#"{
+            "user": {"id": "2", "username": "alice", "discriminator": "0", "avatar": null, "global_name": "Alice", "accent_color": 16711680, "public_flags": 64},
+            "user_profile": {"bio": "hi there", "pronouns": ""},
+            "guild_member": {"nick": "Al", "roles": ["5", "6"], "joined_at": "2021-01-02T03:04:05.000000+00:00"},
+            "guild_member_profile": {"guild_id": "1", "pronouns": "they/them", "bio": ""},
+            "premium_type": 2,
+            "mutual_guilds": [{"id": "1", "nick": "Al"}, {"id": "3", "nick": null}]
+        }"#;
+        let response: UserProfileResponse = serde_json::from_str(json).unwrap();
+        let profile = DiscordClient::parse_user_profile(response, Some(GuildId(1)));
+
+        assert_eq!(profile.display_name(), "Al");
+        assert_eq!(profile.pronouns(), Some("they/them"));
+        assert_eq!(profile.bio(), Some("hi there"));
+        assert_eq!(profile.mutual_guilds(), &[GuildId(1), GuildId(3)]);
+        assert_eq!(profile.user().accent_color(), Some(0xFF_00_00));
+        assert_eq!(profile.user().premium_type(), PremiumType::Nitro);
+        assert!(
+            profile
+                .user()
+                .public_flags()
+                .contains(UserFlags::HYPESQUAD_BRAVERY)
+        );
+        let member = profile.member().unwrap();
+        assert_eq!(member.roles, vec![RoleId(5), RoleId(6)]);
+        assert_eq!(
+            profile
+                .joined_at()
+                .map(|at| at.format("%Y-%m-%d").to_string()),
+            Some("2021-01-02".to_string())
+        );
+    }
+
+    #[test]
+    fn test_guild_settings_payload_for_channel_mute() {
+        use crate::domain::entities::{ChannelNotificationSettings, MessageNotificationLevel};
+
+        let now = Utc::now();
+        let update = NotificationSettingsUpdate::Channel {
+            guild_id: Some(GuildId(1)),
+            channel_id: ChannelId(10),
+            settings: ChannelNotificationSettings {
+                mute: MuteState::muted_until(Some(now + chrono::Duration::hours(1))),
+                level: MessageNotificationLevel::Nothing,
+            },
+        };
+        let payload =
+            serde_json::to_value(DiscordClient::guild_settings_payload(&update, now)).unwrap();
+
+        let entry = &payload["channel_overrides"]["10"];
+        assert_eq!(entry["muted"], true);
+        assert_eq!(entry["message_notifications"], 2);
+        assert_eq!(entry["mute_config"]["selected_time_window"], 3600);
+        assert!(payload.get("muted").is_none());
+    }
+
+    #[test]
+    fn test_group_dm_parsing_keeps_every_recipient() {
+        let json = r#"{
+            "id": "10",
+            "type": 3,
+            "name": "weekend",
+            "last_message_id": "42",
+            "recipients": [
+                {"id": "2", "username": "alice", "global_name": "Alice"},
+                {"id": "3", "username": "bob", "discriminator": "0"}
+            ]
+        }"#;
+        let response: DmChannelResponse = serde_json::from_str(json).unwrap();
+        let dm = DiscordClient::parse_dm_channel(response).unwrap();
+
+        assert!(dm.is_group);
+        assert_eq!(dm.recipients.len(), 2);
+        assert_eq!(dm.recipient_username(), "alice");
+        assert_eq!(dm.last_message_id, Some(MessageId(42)));
+        assert_eq!(dm.display_name(true), "weekend");
+
+        let unnamed = dm.clone().with_name("");
+        assert_eq!(unnamed.display_name(true), "Alice, bob");
+        assert_eq!(unnamed.display_name(false), "alice, bob");
+    }
+
+    #[test]
+    fn test_forum_threads_url_applies_filter() {
+        let client =
+            DiscordClient::with_base_url("http://api".to_string(), Arc::new(ClientIdentity::new()))
+                .expect("Should create client");
+
+        let url = client.forum_threads_url(ChannelId(1), ForumFilter::default(), 0, 25);
+        assert!(url.contains("sort_by=last_message_time"));
+        assert!(!url.contains("&tag="));
+
+        let filter = ForumFilter {
+            sort: ForumSortOrder::CreationDate,
+            tag_id: Some(42),
+        };
+        let url = client.forum_threads_url(ChannelId(1), filter, 25, 25);
+        assert!(url.contains("sort_by=creation_time"));
+        assert!(url.contains("offset=25"));
+        assert!(url.ends_with("&tag=42"));
+    }
+
+    #[tokio::test]
+    async fn test_archived_threads_mark_joined_threads() {
+        let (base_url, _hits) = spawn_stand_in(vec![
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 209\r\nconnection: close\r\n\r\n{\"threads\": [{\"id\": \"11\", \"type\": 11, \"guild_id\": \"1\", \"parent_id\": \"10\", \"name\": \"a\"}, {\"id\": \"12\", \"type\": 11, \"guild_id\": \"1\", \"parent_id\": \"10\", \"name\": \"b\"}], \"members\": [{\"id\": \"12\"}], \"has_more\": false}",
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 122\r\nconnection: close\r\n\r\n{\"threads\": [{\"id\": \"13\", \"type\": 12, \"guild_id\": \"1\", \"parent_id\": \"10\", \"name\": \"c\"}], \"members\": [], \"has_more\": false}",
+        ])
+        .await;
+
+        let client = DiscordClient::with_base_url(base_url, Arc::new(ClientIdentity::new()))
+            .expect("Should create client");
+        let token = AuthToken::new_unchecked("token");
+
+        let threads = client
+            .fetch_archived_threads(&token, ChannelId(10))
+            .await
+            .expect("Should parse archived threads");
+
+        assert_eq!(threads.len(), 3);
+        assert_eq!(threads[0].guild_id(), Some(GuildId(1)));
+        assert_eq!(threads[0].parent_id(), Some(ChannelId(10)));
+        assert!(!threads[0].is_thread_member());
+        assert!(threads[1].is_thread_member());
+        assert_eq!(threads[2].kind(), ChannelKind::PrivateThread);
+        assert!(
+            threads[2].is_thread_member(),
+            "listed private threads are joined"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_messages_takes_first_message_of_each_hit() {
+        let (base_url, _hits) = spawn_stand_in(vec![
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 224\r\nconnection: close\r\n\r\n{\"total_results\": 7, \"messages\": [[{\"id\": \"10\", \"channel_id\": \"20\", \"author\": {\"id\": \"1\", \"username\": \"alice\", \"discriminator\": \"0\"}, \"content\": \"found\", \"timestamp\": \"2024-01-01T00:00:00+00:00\", \"edited_timestamp\": null}]]}",
+        ])
+        .await;
+
+        let client = DiscordClient::with_base_url(base_url, Arc::new(ClientIdentity::new()))
+            .expect("Should create client");
+        let token = AuthToken::new_unchecked("token");
+        let request = SearchMessagesRequest::new(SearchScope::Channel(ChannelId(20)));
+
+        let page = client
+            .search_messages(&token, &request)
+            .await
+            .expect("Should parse search results");
+
+        assert_eq!(page.total_results, 7);
+        assert_eq!(page.messages.len(), 1);
+        assert_eq!(page.messages[0].channel_id(), ChannelId(20));
+        assert_eq!(page.messages[0].content(), "found");
+    }
+
+    #[tokio::test]
+    async fn test_long_rate_limit_reports_server_retry_after() {
+        let (base_url, _hits) = spawn_stand_in(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 42\r\ncontent-type: application/json\r\ncontent-length: 46\r\nconnection: close\r\n\r\n{\"message\": \"rate limited\", \"retry_after\": 42}",
+        ])
+        .await;
+
+        let client = DiscordClient::with_base_url(base_url, Arc::new(ClientIdentity::new()))
+            .expect("Should create client");
+        let token = AuthToken::new_unchecked("token");
+
+        let result = client.fetch_read_states(&token).await;
+
+        assert!(matches!(
+            result,
+            Err(AuthError::RateLimited {
+                retry_after_ms: 42_000
+            })
+        ));
+    }
 }
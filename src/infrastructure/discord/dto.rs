@@ -2,6 +2,8 @@
 
 use serde::Deserialize;
 
+use crate::domain::entities::ForumTag;
+
 #[derive(Debug, Deserialize)]
 pub struct UserResponse {
     pub id: String,
@@ -12,11 +14,61 @@ pub struct UserResponse {
     pub bot: bool,
     #[serde(default)]
     pub global_name: Option<String>,
+    #[serde(default)]
+    pub banner: Option<String>,
+    #[serde(default)]
+    pub accent_color: Option<u32>,
+    #[serde(default)]
+    pub public_flags: u64,
+}
+
+/// Response of `GET /users/{id}/profile`.
+#[derive(Debug, Deserialize)]
+pub struct UserProfileResponse {
+    pub user: UserResponse,
+    #[serde(default)]
+    pub user_profile: Option<ProfileMetadataResponse>,
+    #[serde(default)]
+    pub guild_member: Option<ProfileMemberResponse>,
+    #[serde(default)]
+    pub guild_member_profile: Option<ProfileMetadataResponse>,
+    #[serde(default)]
+    pub premium_since: Option<String>,
+    #[serde(default)]
+    pub premium_type: Option<u8>,
+    #[serde(default)]
+    pub mutual_guilds: Vec<MutualGuildResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileMetadataResponse {
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub pronouns: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileMemberResponse {
+    #[serde(default)]
+    pub nick: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub joined_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutualGuildResponse {
+    pub id: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ErrorResponse {
     pub message: String,
+    /// Seconds until a rate limited request may be retried (429 responses only).
+    #[serde(default)]
+    pub retry_after: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -93,6 +145,31 @@ pub struct ChannelResponse {
     pub default_auto_archive_duration: Option<u16>,
     #[serde(default)]
     pub last_pin_timestamp: Option<String>,
+    #[serde(default)]
+    pub available_tags: Vec<ForumTagDto>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForumTagDto {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+    #[serde(default)]
+    pub moderated: bool,
+}
+
+impl ForumTagDto {
+    /// Converts to the domain tag, treating an empty `emoji_name` as no emoji.
+    #[must_use]
+    pub fn to_forum_tag(&self) -> Option<ForumTag> {
+        Some(ForumTag {
+            id: self.id.parse().ok()?,
+            name: self.name.clone(),
+            emoji_name: self.emoji_name.clone().filter(|e| !e.is_empty()),
+            moderated: self.moderated,
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -137,6 +214,9 @@ pub struct DmChannelResponse {
     #[serde(default)]
     pub recipients: Vec<DmRecipient>,
     pub last_message_id: Option<String>,
+    /// Custom name of a group DM.
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -264,12 +344,27 @@ pub struct MessageResponse {
     #[serde(default)]
     pub mentions: Vec<MentionUserResponse>,
     #[serde(default)]
+    pub mention_roles: Vec<String>,
+    #[serde(default)]
+    pub mention_everyone: bool,
+    #[serde(default)]
     pub reactions: Vec<ReactionDto>,
     pub member: Option<MemberResponse>,
     #[serde(default)]
     pub flags: Option<u64>,
 }
 
+/// Response of the guild and channel message search endpoints.
+///
+/// Each hit is a list holding the matching message (older API versions also
+/// returned surrounding context messages there).
+#[derive(Debug, Deserialize)]
+pub struct SearchMessagesResponse {
+    pub total_results: u32,
+    #[serde(default)]
+    pub messages: Vec<Vec<MessageResponse>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct MemberResponse {
     pub color: Option<u32>,
@@ -292,6 +387,57 @@ pub struct ThreadsResponse {
     pub threads: Vec<ChannelResponse>,
     #[serde(default)]
     pub first_messages: Option<Vec<MessageResponse>>,
+    /// The current user's memberships among `threads`.
+    #[serde(default)]
+    pub members: Vec<ThreadMemberResponse>,
+}
+
+/// A newly created forum post: the thread plus its opening message.
+#[derive(Debug, Deserialize)]
+pub struct ForumPostResponse {
+    #[serde(flatten)]
+    pub thread: ChannelResponse,
+    #[serde(default)]
+    pub message: Option<MessageResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadMemberResponse {
+    /// Thread id; omitted by some endpoints.
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReadStateResponse {
+    #[serde(with = "crate::domain::serde_utils::string_to_u64")]
+    pub id: u64,
+    #[serde(default, with = "crate::domain::serde_utils::string_to_u64::option")]
+    pub last_message_id: Option<u64>,
+    #[serde(default)]
+    pub mention_count: u32,
+    #[serde(default)]
+    pub read_state_type: u8,
+}
+
+/// The read states endpoint has returned both a bare array and a versioned
+/// object wrapping the entries, so accept either shape.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ReadStatesResponse {
+    Entries(Vec<ReadStateResponse>),
+    Versioned {
+        #[serde(default)]
+        entries: Vec<ReadStateResponse>,
+    },
+}
+
+impl ReadStatesResponse {
+    #[must_use]
+    pub fn into_entries(self) -> Vec<ReadStateResponse> {
+        match self {
+            Self::Entries(entries) | Self::Versioned { entries } => entries,
+        }
+    }
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -312,6 +458,98 @@ pub struct EditMessagePayload {
     pub content: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct FriendRequestPayload {
+    pub username: String,
+    /// Always `null` for migrated usernames.
+    pub discriminator: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct PutRelationshipPayload {
+    /// Relationship type; omitted to accept a request.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub relationship_type: Option<u8>,
+}
+
+/// Partial update of `/users/@me/guilds/{guild_id}/settings`.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct GuildSettingsPatchPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mute_config: Option<MuteConfigPatchPayload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_notifications: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_everyone: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_roles: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_overrides: Option<std::collections::HashMap<String, ChannelOverridePatchPayload>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ChannelOverridePatchPayload {
+    pub muted: bool,
+    pub mute_config: Option<MuteConfigPatchPayload>,
+    pub message_notifications: u8,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct MuteConfigPatchPayload {
+    pub end_time: Option<String>,
+    /// Mute length in seconds, `-1` until turned off.
+    pub selected_time_window: i64,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OpenDmChannelPayload {
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RenameChannelPayload {
+    pub name: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct UpdateStatusSettingsPayload {
+    pub status: String,
+    /// Serialized as `null` to clear the custom status.
+    pub custom_status: Option<CustomStatusSettingsPayload>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CustomStatusSettingsPayload {
+    pub text: Option<String>,
+    pub emoji_name: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CreateThreadPayload {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<u16>,
+    /// Channel type; only sent for threads that do not start from a message.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<u8>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CreateForumPostPayload {
+    pub name: String,
+    pub applied_tags: Vec<String>,
+    pub message: ForumPostMessagePayload,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ForumPostMessagePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,4 +619,52 @@ mod tests {
         assert_eq!(decoded.bitrate, Some(64000));
         assert_eq!(decoded.permission_overwrites[0].allow, "4503599648342032");
     }
+
+    #[test]
+    fn test_read_states_decoding_both_shapes() {
+        let bare = r#"[
+            {"id": "111", "last_message_id": "222", "mention_count": 3},
+            {"id": "333", "last_message_id": 444, "read_state_type": 0}
+        ]"#;
+        let entries = serde_json::from_str::<ReadStatesResponse>(bare)
+            .expect("Should decode bare read states array")
+            .into_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].last_message_id, Some(222));
+        assert_eq!(entries[0].mention_count, 3);
+        assert_eq!(entries[1].last_message_id, Some(444));
+        assert_eq!(entries[1].mention_count, 0);
+
+        let versioned = r#"{
+            "version": 12,
+            "partial": false,
+            "entries": [
+                {"id": "555", "last_message_id": null, "read_state_type": 1}
+            ]
+        }"#;
+        let entries = serde_json::from_str::<ReadStatesResponse>(versioned)
+            .expect("Should decode versioned read states object")
+            .into_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].last_message_id, None);
+        assert_eq!(entries[0].read_state_type, 1);
+    }
+
+    #[test]
+    fn test_forum_tag_conversion() {
+        let json = r#"[
+            {"id": "1", "name": "Bug", "emoji_name": "", "moderated": true},
+            {"id": "2", "name": "Idea", "emoji_name": "💡"},
+            {"id": "not a snowflake", "name": "Broken"}
+        ]"#;
+        let tags: Vec<ForumTag> = serde_json::from_str::<Vec<ForumTagDto>>(json)
+            .expect("Should decode forum tags")
+            .iter()
+            .filter_map(ForumTagDto::to_forum_tag)
+            .collect();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].emoji_name, None);
+        assert!(tags[0].moderated);
+        assert_eq!(tags[1].label(), "💡 Idea");
+    }
 }
@@ -361,6 +361,27 @@ async fn process_gateway_command(command: GatewayCommand, payload_tx: &mpsc::Sen
                 let _ = payload_tx.send(json).await;
             }
         }
+        GatewayCommand::RequestMemberListRanges {
+            guild_id,
+            channel_id,
+            ranges,
+        } => {
+            let payload = GatewayPayload::lazy_request_ranges(&guild_id, &channel_id, &ranges);
+            if let Ok(json) = serde_json::to_string(&payload) {
+                debug!(guild_id = %guild_id, channel_id = %channel_id, ranges = ?ranges, "Requesting member list ranges (LazyRequest)");
+                let _ = payload_tx.send(json).await;
+            }
+        }
+        GatewayCommand::UpdatePresence {
+            status,
+            custom_status,
+        } => {
+            let payload = GatewayPayload::presence_update(status, custom_status.as_ref());
+            if let Ok(json) = serde_json::to_string(&payload) {
+                debug!(status = status.as_str(), "Sending presence update");
+                let _ = payload_tx.send(json).await;
+            }
+        }
     }
 }
 
@@ -417,8 +438,7 @@ fn rand_jitter(max: u64) -> u64 {
 
     let nanos = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
-        .map(|d| u64::from(d.subsec_nanos()))
-        .unwrap_or(0);
+        .map_or(0, |d| u64::from(d.subsec_nanos()));
 
     nanos % max
 }
@@ -4,19 +4,26 @@ use flate2::{Decompress, FlushDecompress, Status};
 use super::constants::ZLIB_SUFFIX;
 use super::error::{GatewayError, GatewayResult};
 use super::events::{
-    Activity, ActivityKind, DispatchEvent, PresenceStatus, ReactionEmoji, UnavailableGuild,
+    Activity, ActivityKind, DispatchEvent, MemberListEntry, MemberListGroup, MemberListItem,
+    MemberListOp, PresenceStatus, ReactionEmoji, UnavailableGuild,
 };
 use super::payloads::{
-    ActivityPayload, ChannelPayload, GatewayMessage, GuildCreatePayload, GuildDeletePayload,
-    HelloPayload, MessageDeleteBulkPayload, MessageDeletePayload, MessagePayload,
-    PresenceUpdatePayload, ReactionPayload, ReactionRemoveAllPayload, ReadyPayload,
-    TypingStartPayload, UserUpdatePayload,
+    ActivityPayload, ChannelPayload, ChannelPinsUpdatePayload, CustomStatusPayload, EmojiPayload,
+    GatewayMessage, GuildCreatePayload, GuildDeletePayload, GuildEmojisUpdatePayload,
+    GuildMemberListUpdatePayload, HelloPayload, MemberListGroupPayload, MemberListItemPayload,
+    MemberListOpPayload, MessageDeleteBulkPayload, MessageDeletePayload, MessagePayload,
+    MuteConfigPayload, PresenceUpdatePayload, ReactionPayload, ReactionRemoveAllPayload,
+    ReadyPayload, RelationshipPayload, ThreadListSyncPayload, TypingStartPayload,
+    UserGuildSettingsPayload, UserUpdatePayload,
 };
 
 use crate::domain::entities::{
-    Attachment, ChannelId, GuildId, Member, Message, MessageAuthor, MessageId, MessageKind,
-    MessageReference, Permissions, Role, RoleId, User,
+    Attachment, ChannelId, ChannelNotificationSettings, CustomEmoji, CustomStatus, ForumTag,
+    GuildId, GuildNotificationSettings, Member, Message, MessageAuthor, MessageId, MessageKind,
+    MessageNotificationLevel, MessageReference, MuteState, Permissions, Relationship,
+    RelationshipType, Role, RoleId, User, UserPresence,
 };
+use crate::infrastructure::discord::dto::ForumTagDto;
 
 const INITIAL_BUFFER_SIZE: usize = 32 * 1024;
 const MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
@@ -168,14 +175,22 @@ impl EventParser {
             "MESSAGE_REACTION_REMOVE_ALL" => Self::parse_reaction_remove_all(data),
             "TYPING_START" => Self::parse_typing_start(data),
             "PRESENCE_UPDATE" => Self::parse_presence_update(data),
+            "GUILD_MEMBER_LIST_UPDATE" => Self::parse_guild_member_list_update(data),
             "CHANNEL_CREATE" => Self::parse_channel_create(data),
             "CHANNEL_UPDATE" => Self::parse_channel_update(data),
             "CHANNEL_DELETE" => Self::parse_channel_delete(data),
+            "CHANNEL_PINS_UPDATE" => Self::parse_channel_pins_update(data),
+            "THREAD_CREATE" => Self::parse_thread_create(data),
+            "THREAD_UPDATE" => Self::parse_thread_update(data),
+            "THREAD_DELETE" => Self::parse_thread_delete(data),
+            "THREAD_LIST_SYNC" => Self::parse_thread_list_sync(data),
             "GUILD_CREATE" => Self::parse_guild_create(data),
             "GUILD_UPDATE" => Self::parse_guild_update(data),
             "GUILD_DELETE" => Self::parse_guild_delete(data),
+            "GUILD_EMOJIS_UPDATE" => Self::parse_guild_emojis_update(data),
             "USER_UPDATE" => Self::parse_user_update(data),
             "USER_SETTINGS_UPDATE" => Self::parse_user_settings_update(data),
+            "USER_GUILD_SETTINGS_UPDATE" => Self::parse_user_guild_settings_update(data),
             "VOICE_STATE_UPDATE" => Self::parse_voice_state_update(data),
             "VOICE_SERVER_UPDATE" => Self::parse_voice_server_update(data),
             "RELATIONSHIP_ADD" | "RELATIONSHIP_UPDATE" => Self::parse_relationship_add(data),
@@ -197,6 +212,7 @@ impl EventParser {
         let mut initial_guild_channels = std::collections::HashMap::new();
         let mut initial_guild_roles = std::collections::HashMap::new();
         let mut initial_guild_members = std::collections::HashMap::new();
+        let mut initial_guild_emojis = std::collections::HashMap::new();
 
         for g in &ready.guilds {
             if let Ok(guild_id) = g.id.parse::<u64>()
@@ -241,36 +257,27 @@ impl EventParser {
                                 deny: o.deny.clone(),
                             })
                             .collect();
-                        channel = channel.with_permission_overwrites(overwrites);
+                        channel = channel
+                            .with_permission_overwrites(overwrites)
+                            .with_available_tags(Self::convert_forum_tags(
+                                &channel_payload.available_tags,
+                            ));
+
+                        if let Some(flags) = channel_payload.flags {
+                            channel = channel.with_flags(
+                                crate::domain::entities::ChannelFlags::from_bits_truncate(flags),
+                            );
+                        }
 
                         channels.push(channel);
                     }
                 }
 
-                for thread_payload in &g.threads {
-                    if let Ok(id) = thread_payload.id.parse::<u64>() {
-                        let kind = crate::domain::entities::ChannelKind::from(thread_payload.kind);
-                        let name = thread_payload.name.clone().unwrap_or_default();
-
-                        let mut thread =
-                            crate::domain::entities::Channel::new(ChannelId(id), name, kind)
-                                .with_guild(guild_id);
-
-                        if let Some(parent_id) = &thread_payload.parent_id
-                            && let Ok(pid) = parent_id.parse::<u64>()
-                        {
-                            thread = thread.with_parent(pid);
-                        }
-
-                        if let Some(last_message_id) = &thread_payload.last_message_id
-                            && let Ok(lmid) = last_message_id.parse::<u64>()
-                        {
-                            thread = thread.with_last_message_id(Some(lmid.into()));
-                        }
-
-                        channels.push(thread);
-                    }
-                }
+                channels.extend(
+                    g.threads
+                        .iter()
+                        .filter_map(|thread| Self::convert_thread(thread, Some(guild_id))),
+                );
 
                 initial_guild_channels.insert(GuildId(guild_id), channels);
 
@@ -328,6 +335,7 @@ impl EventParser {
                     })
                     .collect();
                 initial_guild_members.insert(GuildId(guild_id), members);
+                initial_guild_emojis.insert(GuildId(guild_id), Self::convert_emojis(&g.emojis));
             }
         }
 
@@ -359,10 +367,15 @@ impl EventParser {
             })
             .collect();
 
-        let guild_folders = ready
-            .user_settings
-            .map(|s| s.guild_folders)
-            .unwrap_or_default()
+        let user_settings = ready.user_settings.unwrap_or_default();
+        let status = user_settings.status.as_deref().map(PresenceStatus::parse);
+        let custom_status = user_settings
+            .custom_status
+            .map(Self::convert_custom_status)
+            .filter(|custom| !custom.is_empty());
+
+        let guild_folders = user_settings
+            .guild_folders
             .into_iter()
             .map(|f| crate::domain::entities::GuildFolder {
                 id: f.id.and_then(|s| {
@@ -387,16 +400,45 @@ impl EventParser {
         let relationships = ready
             .relationships
             .into_iter()
-            .filter_map(|rel| {
-                rel.id.parse::<u64>().ok().map(|id| {
-                    crate::domain::entities::Relationship::new(
-                        crate::domain::entities::UserId(id),
-                        crate::domain::entities::RelationshipType::from(rel.relationship_type),
-                    )
-                })
-            })
+            .filter_map(Self::convert_relationship)
             .collect();
 
+        let presences = ready
+            .merged_presences
+            .map(|merged| {
+                merged
+                    .friends
+                    .into_iter()
+                    .chain(merged.guilds.into_iter().flatten())
+                    .filter_map(|presence| {
+                        let user_id = presence.user_id.parse::<u64>().ok()?;
+                        Some((
+                            crate::domain::entities::UserId(user_id),
+                            UserPresence::new(
+                                PresenceStatus::parse(&presence.status),
+                                presence
+                                    .activities
+                                    .into_iter()
+                                    .map(Self::convert_activity)
+                                    .collect(),
+                            ),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let notification_settings = ready
+            .user_guild_settings
+            .map(|settings| {
+                settings
+                    .into_entries()
+                    .into_iter()
+                    .map(Self::convert_guild_settings)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Ok(DispatchEvent::Ready {
             session_id: ready.session_id,
             resume_gateway_url: ready.resume_gateway_url,
@@ -405,9 +447,14 @@ impl EventParser {
             initial_guild_channels,
             initial_guild_roles,
             initial_guild_members,
+            initial_guild_emojis,
             read_states,
             guild_folders,
             relationships,
+            presences,
+            status,
+            custom_status,
+            notification_settings,
         })
     }
 
@@ -642,6 +689,107 @@ impl EventParser {
         })
     }
 
+    fn parse_guild_member_list_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: GuildMemberListUpdatePayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse GuildMemberListUpdate: {e}"))
+        })?;
+
+        let guild_id = payload
+            .guild_id
+            .parse::<u64>()
+            .map_err(|_| GatewayError::protocol("Invalid guild ID"))?;
+
+        // Later ops address rows by index, so an item we cannot read must fail the whole
+        // update rather than shift every following row.
+        let ops = payload
+            .ops
+            .into_iter()
+            .map(|op| {
+                Ok(match op {
+                    MemberListOpPayload::Sync { range, items } => MemberListOp::Sync {
+                        range: (range[0], range[1]),
+                        items: items
+                            .into_iter()
+                            .map(Self::convert_member_list_item)
+                            .collect::<GatewayResult<_>>()?,
+                    },
+                    MemberListOpPayload::Insert { index, item } => MemberListOp::Insert {
+                        index,
+                        item: Self::convert_member_list_item(item)?,
+                    },
+                    MemberListOpPayload::Update { index, item } => MemberListOp::Update {
+                        index,
+                        item: Self::convert_member_list_item(item)?,
+                    },
+                    MemberListOpPayload::Delete { index } => MemberListOp::Delete { index },
+                    MemberListOpPayload::Invalidate { range } => MemberListOp::Invalidate {
+                        range: (range[0], range[1]),
+                    },
+                })
+            })
+            .collect::<GatewayResult<_>>()?;
+
+        Ok(DispatchEvent::GuildMemberListUpdate {
+            guild_id: GuildId(guild_id),
+            list_id: payload.id,
+            member_count: payload.member_count,
+            online_count: payload.online_count,
+            groups: payload
+                .groups
+                .into_iter()
+                .map(Self::convert_member_list_group)
+                .collect(),
+            ops,
+        })
+    }
+
+    fn convert_forum_tags(tags: &[ForumTagDto]) -> Vec<ForumTag> {
+        tags.iter().filter_map(ForumTagDto::to_forum_tag).collect()
+    }
+
+    fn convert_member_list_group(payload: MemberListGroupPayload) -> MemberListGroup {
+        MemberListGroup {
+            id: payload.id,
+            count: payload.count,
+        }
+    }
+
+    fn convert_member_list_item(payload: MemberListItemPayload) -> GatewayResult<MemberListItem> {
+        if let Some(group) = payload.group {
+            return Ok(MemberListItem::Group(Self::convert_member_list_group(
+                group,
+            )));
+        }
+
+        let member = payload
+            .member
+            .ok_or_else(|| GatewayError::protocol("Member list item without group or member"))?;
+        let (status, activities) = member.presence.map_or_else(
+            || (PresenceStatus::Offline, Vec::new()),
+            |presence| {
+                (
+                    PresenceStatus::parse(&presence.status),
+                    presence
+                        .activities
+                        .into_iter()
+                        .map(Self::convert_activity)
+                        .collect(),
+                )
+            },
+        );
+
+        Ok(MemberListItem::Member(MemberListEntry {
+            user_id: member.user.id,
+            username: member.user.username,
+            global_name: member.user.global_name,
+            nick: member.nick,
+            bot: member.user.bot,
+            roles: member.roles,
+            status,
+            activities,
+        }))
+    }
+
     fn parse_channel_create(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
         let payload: ChannelPayload = serde_json::from_value(data).map_err(|e| {
             GatewayError::serialization(format!("Failed to parse ChannelCreate: {e}"))
@@ -709,6 +857,149 @@ impl EventParser {
         })
     }
 
+    fn parse_channel_pins_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: ChannelPinsUpdatePayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse ChannelPinsUpdate: {e}"))
+        })?;
+
+        Ok(DispatchEvent::ChannelPinsUpdate {
+            channel_id: ChannelId(payload.channel_id),
+            guild_id: payload.guild_id.map(GuildId),
+            last_pin_timestamp: payload.last_pin_timestamp,
+        })
+    }
+
+    /// Builds a thread channel; `guild_id` fills in for payloads that omit it.
+    fn convert_thread(
+        payload: &ChannelPayload,
+        guild_id: Option<u64>,
+    ) -> Option<crate::domain::entities::Channel> {
+        let id = payload.id.parse::<u64>().ok()?;
+        let kind = crate::domain::entities::ChannelKind::from(payload.kind);
+        let name = payload.name.clone().unwrap_or_default();
+
+        let mut thread = crate::domain::entities::Channel::new(id, name, kind)
+            .with_thread_member(payload.member.is_some());
+
+        if let Some(guild_id) = payload
+            .guild_id
+            .as_deref()
+            .and_then(|id| id.parse::<u64>().ok())
+            .or(guild_id)
+        {
+            thread = thread.with_guild(guild_id);
+        }
+
+        if let Some(parent_id) = &payload.parent_id
+            && let Ok(pid) = parent_id.parse::<u64>()
+        {
+            thread = thread.with_parent(pid);
+        }
+
+        if let Some(last_message_id) = &payload.last_message_id
+            && let Ok(lmid) = last_message_id.parse::<u64>()
+        {
+            thread = thread.with_last_message_id(Some(lmid.into()));
+        }
+
+        if let Some(metadata) = &payload.thread_metadata {
+            thread = thread.with_thread_metadata(crate::domain::entities::ThreadMetadata {
+                archived: metadata.archived,
+                auto_archive_duration: metadata.auto_archive_duration,
+                archive_timestamp: metadata.archive_timestamp.clone(),
+                locked: metadata.locked,
+                invitable: metadata.invitable,
+                create_timestamp: metadata.create_timestamp.clone(),
+            });
+        }
+
+        Some(thread)
+    }
+
+    fn parse_thread_create(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: ChannelPayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse ThreadCreate: {e}"))
+        })?;
+
+        let thread = Self::convert_thread(&payload, None)
+            .ok_or_else(|| GatewayError::protocol("Invalid thread ID"))?;
+
+        Ok(DispatchEvent::ThreadCreate { thread })
+    }
+
+    fn parse_thread_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: ChannelPayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse ThreadUpdate: {e}"))
+        })?;
+
+        let thread = Self::convert_thread(&payload, None)
+            .ok_or_else(|| GatewayError::protocol("Invalid thread ID"))?;
+
+        Ok(DispatchEvent::ThreadUpdate { thread })
+    }
+
+    fn parse_thread_delete(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: ChannelPayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse ThreadDelete: {e}"))
+        })?;
+
+        let channel_id = payload
+            .id
+            .parse::<u64>()
+            .map_err(|_| GatewayError::protocol("Invalid thread ID"))?;
+
+        Ok(DispatchEvent::ThreadDelete {
+            channel_id: ChannelId(channel_id),
+            guild_id: payload
+                .guild_id
+                .and_then(|id| id.parse::<u64>().ok())
+                .map(GuildId),
+            parent_id: payload
+                .parent_id
+                .and_then(|id| id.parse::<u64>().ok())
+                .map(ChannelId),
+        })
+    }
+
+    fn parse_thread_list_sync(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: ThreadListSyncPayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse ThreadListSync: {e}"))
+        })?;
+
+        let guild_id = payload
+            .guild_id
+            .parse::<u64>()
+            .map_err(|_| GatewayError::protocol("Invalid guild ID"))?;
+
+        let joined: std::collections::HashSet<&str> = payload
+            .members
+            .iter()
+            .filter_map(|member| member.id.as_deref())
+            .collect();
+
+        let threads = payload
+            .threads
+            .iter()
+            .filter_map(|thread| Self::convert_thread(thread, Some(guild_id)))
+            .map(|thread| {
+                let member =
+                    thread.is_thread_member() || joined.contains(thread.id().to_string().as_str());
+                thread.with_thread_member(member)
+            })
+            .collect();
+
+        Ok(DispatchEvent::ThreadListSync {
+            guild_id: GuildId(guild_id),
+            channel_ids: payload
+                .channel_ids
+                .iter()
+                .filter_map(|id| id.parse::<u64>().ok())
+                .map(ChannelId)
+                .collect(),
+            threads,
+        })
+    }
+
     #[allow(clippy::too_many_lines)]
     fn parse_guild_create(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
         let payload: GuildCreatePayload = serde_json::from_value(data).map_err(|e| {
@@ -760,38 +1051,27 @@ impl EventParser {
                     deny: o.deny.clone(),
                 })
                 .collect();
-            channel = channel.with_permission_overwrites(overwrites);
+            channel = channel
+                .with_permission_overwrites(overwrites)
+                .with_available_tags(Self::convert_forum_tags(&channel_payload.available_tags));
+
+            if let Some(flags) = channel_payload.flags {
+                channel = channel.with_flags(
+                    crate::domain::entities::ChannelFlags::from_bits_truncate(flags),
+                );
+            }
 
             channels.push(channel);
         }
 
-        let mut threads = Vec::new();
-        for thread_payload in payload.threads {
-            let id = thread_payload
-                .id
-                .parse::<u64>()
-                .map_err(|_| GatewayError::protocol("Invalid thread ID"))?;
-
-            let kind = crate::domain::entities::ChannelKind::from(thread_payload.kind);
-            let name = thread_payload.name.unwrap_or_default();
-
-            let mut thread =
-                crate::domain::entities::Channel::new(id, name, kind).with_guild(guild_id);
-
-            if let Some(parent_id) = thread_payload.parent_id
-                && let Ok(pid) = parent_id.parse::<u64>()
-            {
-                thread = thread.with_parent(pid);
-            }
-
-            if let Some(last_message_id) = thread_payload.last_message_id
-                && let Ok(lmid) = last_message_id.parse::<u64>()
-            {
-                thread = thread.with_last_message_id(Some(lmid.into()));
-            }
-
-            threads.push(thread);
-        }
+        let threads = payload
+            .threads
+            .iter()
+            .map(|thread| {
+                Self::convert_thread(thread, Some(guild_id))
+                    .ok_or_else(|| GatewayError::protocol("Invalid thread ID"))
+            })
+            .collect::<GatewayResult<Vec<_>>>()?;
 
         let roles = payload
             .roles
@@ -839,6 +1119,8 @@ impl EventParser {
             })
             .collect();
 
+        let emojis = Self::convert_emojis(&payload.emojis);
+
         Ok(DispatchEvent::GuildCreate {
             guild_id: GuildId(guild_id),
             name: payload.name,
@@ -847,6 +1129,7 @@ impl EventParser {
             threads,
             roles,
             members,
+            emojis,
         })
     }
 
@@ -882,6 +1165,22 @@ impl EventParser {
         })
     }
 
+    fn parse_guild_emojis_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: GuildEmojisUpdatePayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse GuildEmojisUpdate: {e}"))
+        })?;
+
+        let guild_id = payload
+            .guild_id
+            .parse::<u64>()
+            .map_err(|_| GatewayError::protocol("Invalid guild ID"))?;
+
+        Ok(DispatchEvent::GuildEmojisUpdate {
+            guild_id: GuildId(guild_id),
+            emojis: Self::convert_emojis(&payload.emojis),
+        })
+    }
+
     fn parse_user_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
         let payload: UserUpdatePayload = serde_json::from_value(data)
             .map_err(|e| GatewayError::serialization(format!("Failed to parse UserUpdate: {e}")))?;
@@ -926,6 +1225,16 @@ impl EventParser {
         Ok(DispatchEvent::UserSettingsUpdate { guild_folders })
     }
 
+    fn parse_user_guild_settings_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
+        let payload: UserGuildSettingsPayload = serde_json::from_value(data).map_err(|e| {
+            GatewayError::serialization(format!("Failed to parse UserGuildSettingsUpdate: {e}"))
+        })?;
+
+        Ok(DispatchEvent::UserGuildSettingsUpdate {
+            settings: Self::convert_guild_settings(payload),
+        })
+    }
+
     fn parse_voice_state_update(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
         let payload: super::payloads::VoiceStateUpdatePayload = serde_json::from_value(data)
             .map_err(|e| {
@@ -981,19 +1290,35 @@ impl EventParser {
                 GatewayError::serialization(format!("Failed to parse RelationshipAdd: {e}"))
             })?;
 
-        let user_id = payload
-            .id
-            .parse::<u64>()
-            .map(crate::domain::entities::UserId)
-            .map_err(|_| GatewayError::protocol("Invalid user ID in relationship"))?;
+        let relationship = Self::convert_relationship(payload)
+            .ok_or_else(|| GatewayError::protocol("Invalid user ID in relationship"))?;
 
-        let relationship_type =
-            crate::domain::entities::RelationshipType::from(payload.relationship_type);
+        Ok(DispatchEvent::RelationshipAdd { relationship })
+    }
 
-        Ok(DispatchEvent::RelationshipAdd {
-            user_id,
-            relationship_type,
-        })
+    fn convert_relationship(payload: RelationshipPayload) -> Option<Relationship> {
+        let id = payload.id.parse::<u64>().ok()?;
+        let mut relationship = Relationship::new(
+            crate::domain::entities::UserId(id),
+            RelationshipType::from(payload.relationship_type),
+        )
+        .with_nickname(payload.nickname);
+
+        if let Some(user) = payload.user {
+            let mut domain_user = User::new(
+                user.id,
+                user.username,
+                user.discriminator,
+                user.avatar,
+                user.bot,
+                None,
+            );
+            if let Some(global_name) = user.global_name {
+                domain_user = domain_user.with_global_name(global_name);
+            }
+            relationship = relationship.with_user(domain_user);
+        }
+        Some(relationship)
     }
 
     fn parse_relationship_remove(data: serde_json::Value) -> GatewayResult<DispatchEvent> {
@@ -1045,7 +1370,14 @@ impl EventParser {
             timestamp.with_timezone(&Local),
             MessageKind::from(payload.kind),
         )
-        .with_pinned(payload.pinned);
+        .with_pinned(payload.pinned)
+        .with_guild_id(
+            payload
+                .guild_id
+                .as_deref()
+                .and_then(|id| id.parse::<u64>().ok())
+                .map(GuildId),
+        );
 
         if !payload.attachments.is_empty() {
             let attachments: Vec<Attachment> = payload
@@ -1103,9 +1435,90 @@ impl EventParser {
             message = message.with_mentions(mentions);
         }
 
+        if !payload.mention_roles.is_empty() {
+            let mention_roles = payload
+                .mention_roles
+                .iter()
+                .filter_map(|id| id.parse::<u64>().ok().map(RoleId))
+                .collect();
+            message = message.with_mention_roles(mention_roles);
+        }
+
+        message = message.with_mention_everyone(payload.mention_everyone);
+
         Ok(message)
     }
 
+    /// Converts guild emoji payloads, skipping entries without a valid id or name.
+    fn convert_emojis(payloads: &[EmojiPayload]) -> Vec<CustomEmoji> {
+        payloads
+            .iter()
+            .filter_map(|e| {
+                let id = e.id.as_ref()?.parse::<u64>().ok()?;
+                let name = e.name.clone()?;
+                Some(
+                    CustomEmoji::new(id, name)
+                        .with_animated(e.animated)
+                        .with_available(e.available.unwrap_or(true)),
+                )
+            })
+            .collect()
+    }
+
+    fn convert_custom_status(payload: CustomStatusPayload) -> CustomStatus {
+        CustomStatus {
+            text: payload.text.filter(|text| !text.is_empty()),
+            emoji: payload.emoji_name,
+            expires_at: payload
+                .expires_at
+                .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+                .map(|at| at.with_timezone(&Utc)),
+        }
+    }
+
+    fn convert_mute(muted: bool, config: Option<MuteConfigPayload>) -> MuteState {
+        MuteState {
+            muted,
+            until: config
+                .and_then(|config| config.end_time)
+                .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+                .map(|at| at.with_timezone(&Utc)),
+        }
+    }
+
+    fn convert_guild_settings(payload: UserGuildSettingsPayload) -> GuildNotificationSettings {
+        let channel_overrides = payload
+            .channel_overrides
+            .into_iter()
+            .filter_map(|entry| {
+                let channel_id = entry.channel_id.parse::<u64>().ok()?;
+                Some((
+                    ChannelId(channel_id),
+                    ChannelNotificationSettings {
+                        mute: Self::convert_mute(entry.muted, entry.mute_config),
+                        level: entry
+                            .message_notifications
+                            .map_or_else(Default::default, MessageNotificationLevel::from),
+                    },
+                ))
+            })
+            .collect();
+
+        GuildNotificationSettings {
+            guild_id: payload
+                .guild_id
+                .and_then(|id| id.parse::<u64>().ok())
+                .map(GuildId),
+            mute: Self::convert_mute(payload.muted, payload.mute_config),
+            level: payload
+                .message_notifications
+                .map_or_else(Default::default, MessageNotificationLevel::from),
+            suppress_everyone: payload.suppress_everyone,
+            suppress_roles: payload.suppress_roles,
+            channel_overrides,
+        }
+    }
+
     fn convert_activity(payload: ActivityPayload) -> Activity {
         Activity {
             name: payload.name,
@@ -1261,6 +1674,239 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_channel_pins_update() {
+        let data = serde_json::json!({
+            "channel_id": "123456789",
+            "guild_id": "987654321",
+            "last_pin_timestamp": "2024-01-01T00:00:00+00:00"
+        });
+        let result = EventParser::parse_dispatch("CHANNEL_PINS_UPDATE", Some(data)).unwrap();
+        match result {
+            DispatchEvent::ChannelPinsUpdate {
+                channel_id,
+                guild_id,
+                last_pin_timestamp,
+            } => {
+                assert_eq!(channel_id, ChannelId(123_456_789));
+                assert_eq!(guild_id, Some(GuildId(987_654_321)));
+                assert_eq!(
+                    last_pin_timestamp.as_deref(),
+                    Some("2024-01-01T00:00:00+00:00")
+                );
+            }
+            _ => panic!("Expected ChannelPinsUpdate event"),
+        }
+
+        let unpinned = serde_json::json!({
+            "channel_id": "123456789",
+            "last_pin_timestamp": null
+        });
+        let result = EventParser::parse_dispatch("CHANNEL_PINS_UPDATE", Some(unpinned)).unwrap();
+        assert!(matches!(
+            result,
+            DispatchEvent::ChannelPinsUpdate {
+                guild_id: None,
+                last_pin_timestamp: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_relationship_add_with_user() {
+        let data = serde_json::json!({
+            "id": "444",
+            "type": 3,
+            "nickname": null,
+            "user": {
+                "id": "444",
+                "username": "ferris",
+                "global_name": "Ferris",
+                "discriminator": "0",
+                "avatar": null
+            }
+        });
+        let result = EventParser::parse_dispatch("RELATIONSHIP_ADD", Some(data)).unwrap();
+        let DispatchEvent::RelationshipAdd { relationship } = result else {
+            panic!("Expected RelationshipAdd event");
+        };
+        assert_eq!(relationship.user_id, crate::domain::entities::UserId(444));
+        assert_eq!(
+            relationship.relationship_type,
+            RelationshipType::PendingIncoming
+        );
+        assert_eq!(relationship.username(), Some("ferris"));
+        assert_eq!(relationship.display_name(), "Ferris");
+    }
+
+    #[test]
+    fn test_parse_user_guild_settings_update() {
+        let data = serde_json::json!({
+            "guild_id": "1",
+            "muted": true,
+            "mute_config": {"end_time": "2030-01-02T03:04:05+00:00", "selected_time_window": 3600},
+            "message_notifications": 1,
+            "suppress_everyone": true,
+            "suppress_roles": false,
+            "channel_overrides": [
+                {"channel_id": "10", "muted": false, "mute_config": null, "message_notifications": 0},
+                {"channel_id": "11", "muted": true, "mute_config": null, "message_notifications": 3}
+            ]
+        });
+        let result = EventParser::parse_dispatch("USER_GUILD_SETTINGS_UPDATE", Some(data)).unwrap();
+        let DispatchEvent::UserGuildSettingsUpdate { settings } = result else {
+            panic!("Expected UserGuildSettingsUpdate event");
+        };
+        assert_eq!(settings.guild_id, Some(GuildId(1)));
+        assert!(settings.mute.muted);
+        assert_eq!(
+            settings.mute.until.map(|at| at.format("%Y").to_string()),
+            Some("2030".to_string())
+        );
+        assert_eq!(settings.level, MessageNotificationLevel::OnlyMentions);
+        assert!(settings.suppress_everyone);
+        assert_eq!(
+            settings.channel(ChannelId(10)).level,
+            MessageNotificationLevel::AllMessages
+        );
+        let muted_channel = settings.channel(ChannelId(11));
+        assert!(muted_channel.mute.muted && muted_channel.mute.until.is_none());
+        assert_eq!(muted_channel.level, MessageNotificationLevel::Default);
+    }
+
+    #[test]
+    fn test_parse_guild_member_list_update() {
+        let data = serde_json::json!({
+            "guild_id": "1",
+            "id": "everyone",
+            "member_count": 3,
+            "online_count": 2,
+            "groups": [{ "id": "50", "count": 1 }, { "id": "online", "count": 1 }],
+            "ops": [
+                {
+                    "op": "SYNC",
+                    "range": [0, 99],
+                    "items": [
+                        { "group": { "id": "50", "count": 1 } },
+                        { "member": {
+                            "user": { "id": "7", "username": "alice", "global_name": "Alice" },
+                            "roles": ["50"],
+                            "nick": null,
+                            "presence": {
+                                "status": "dnd",
+                                "activities": [{ "name": "Rust", "type": 0 }]
+                            }
+                        } }
+                    ]
+                },
+                { "op": "DELETE", "index": 3 },
+                { "op": "INVALIDATE", "range": [100, 199] }
+            ]
+        });
+
+        match EventParser::parse_dispatch("GUILD_MEMBER_LIST_UPDATE", Some(data)).unwrap() {
+            DispatchEvent::GuildMemberListUpdate {
+                guild_id,
+                list_id,
+                online_count,
+                groups,
+                ops,
+                ..
+            } => {
+                assert_eq!(guild_id, GuildId(1));
+                assert_eq!(list_id, "everyone");
+                assert_eq!(online_count, 2);
+                assert_eq!(groups.len(), 2);
+                assert_eq!(ops.len(), 3);
+                let MemberListOp::Sync { range, items } = &ops[0] else {
+                    panic!("Expected a SYNC op");
+                };
+                assert_eq!(*range, (0, 99));
+                let MemberListItem::Member(member) = &items[1] else {
+                    panic!("Expected a member item");
+                };
+                assert_eq!(member.display_name(), "Alice");
+                assert_eq!(member.status, PresenceStatus::DoNotDisturb);
+                assert_eq!(member.activities[0].name, "Rust");
+                assert_eq!(ops[1], MemberListOp::Delete { index: 3 });
+            }
+            _ => panic!("Expected GuildMemberListUpdate event"),
+        }
+
+        let unreadable = serde_json::json!({
+            "guild_id": "1",
+            "id": "everyone",
+            "member_count": 3,
+            "online_count": 2,
+            "groups": [],
+            "ops": [{ "op": "INSERT", "index": 1, "item": {} }]
+        });
+        assert!(EventParser::parse_dispatch("GUILD_MEMBER_LIST_UPDATE", Some(unreadable)).is_err());
+    }
+
+    #[test]
+    fn test_parse_thread_events() {
+        let data = serde_json::json!({
+            "id": "20",
+            "type": 11,
+            "guild_id": "1",
+            "parent_id": "10",
+            "name": "topic",
+            "thread_metadata": {
+                "archived": true,
+                "auto_archive_duration": 1440,
+                "archive_timestamp": "2024-01-01T00:00:00+00:00",
+                "locked": false
+            },
+            "member": { "user_id": "5" }
+        });
+        match EventParser::parse_dispatch("THREAD_UPDATE", Some(data)).unwrap() {
+            DispatchEvent::ThreadUpdate { thread } => {
+                assert_eq!(thread.guild_id(), Some(GuildId(1)));
+                assert_eq!(thread.parent_id(), Some(ChannelId(10)));
+                assert!(thread.is_archived());
+                assert!(thread.is_thread_member());
+            }
+            _ => panic!("Expected ThreadUpdate event"),
+        }
+
+        let data = serde_json::json!({
+            "guild_id": "1",
+            "channel_ids": ["10"],
+            "threads": [
+                { "id": "20", "type": 11, "parent_id": "10", "name": "a" },
+                { "id": "21", "type": 11, "parent_id": "10", "name": "b" }
+            ],
+            "members": [{ "id": "21", "user_id": "5" }]
+        });
+        match EventParser::parse_dispatch("THREAD_LIST_SYNC", Some(data)).unwrap() {
+            DispatchEvent::ThreadListSync {
+                guild_id,
+                channel_ids,
+                threads,
+            } => {
+                assert_eq!(guild_id, GuildId(1));
+                assert_eq!(channel_ids, vec![ChannelId(10)]);
+                assert_eq!(threads[0].guild_id(), Some(GuildId(1)));
+                assert!(!threads[0].is_thread_member());
+                assert!(threads[1].is_thread_member());
+            }
+            _ => panic!("Expected ThreadListSync event"),
+        }
+
+        let data =
+            serde_json::json!({ "id": "20", "type": 11, "guild_id": "1", "parent_id": "10" });
+        assert!(matches!(
+            EventParser::parse_dispatch("THREAD_DELETE", Some(data)).unwrap(),
+            DispatchEvent::ThreadDelete {
+                channel_id: ChannelId(20),
+                guild_id: Some(GuildId(1)),
+                parent_id: Some(ChannelId(10)),
+            }
+        ));
+    }
+
     #[test]
     fn test_parse_ready_with_integer_zero_fields() {
         let data = serde_json::json!({
@@ -1319,4 +1965,78 @@ mod tests {
             panic!("Expected Ready event");
         }
     }
+
+    #[test]
+    fn test_parse_ready_merged_presences() {
+        let data = serde_json::json!({
+            "v": 9,
+            "session_id": "session",
+            "user": { "id": "1", "username": "me", "discriminator": "0" },
+            "user_settings": {
+                "status": "idle",
+                "custom_status": {
+                    "text": "Writing Rust",
+                    "emoji_name": "🦀",
+                    "expires_at": "2030-01-01T00:00:00.000Z"
+                }
+            },
+            "merged_presences": {
+                "friends": [
+                    { "user_id": "10", "status": "online", "activities": [] }
+                ],
+                "guilds": [[
+                    {
+                        "user_id": "20",
+                        "status": "dnd",
+                        "activities": [{ "name": "Rust", "type": 0 }]
+                    }
+                ]]
+            }
+        });
+
+        let Ok(DispatchEvent::Ready {
+            presences,
+            status,
+            custom_status,
+            ..
+        }) = EventParser::parse_ready(data)
+        else {
+            panic!("Expected Ready event");
+        };
+        assert_eq!(status, Some(PresenceStatus::Idle));
+        let custom_status = custom_status.unwrap();
+        assert_eq!(custom_status.label(), "🦀 Writing Rust");
+        assert!(custom_status.expires_at.is_some());
+        assert_eq!(presences.len(), 2);
+        assert_eq!(
+            presences[&crate::domain::entities::UserId(10)].status,
+            PresenceStatus::Online
+        );
+        let presence = &presences[&crate::domain::entities::UserId(20)];
+        assert_eq!(presence.status, PresenceStatus::DoNotDisturb);
+        assert_eq!(presence.activities[0].label(), "Playing Rust");
+    }
+
+    #[test]
+    fn test_parse_guild_create_emojis() {
+        let data = serde_json::json!({
+            "id": "42",
+            "name": "Guild",
+            "emojis": [
+                { "id": "100", "name": "blob", "animated": false },
+                { "id": "101", "name": "party", "animated": true, "available": false },
+                { "id": null, "name": "broken" }
+            ]
+        });
+        let result = EventParser::parse_dispatch("GUILD_CREATE", Some(data)).unwrap();
+        match result {
+            DispatchEvent::GuildCreate { emojis, .. } => {
+                assert_eq!(emojis.len(), 2);
+                assert_eq!(emojis[0].markup(), "<:blob:100>");
+                assert!(emojis[1].animated);
+                assert!(!emojis[1].available);
+            }
+            _ => panic!("Expected GuildCreate event"),
+        }
+    }
 }
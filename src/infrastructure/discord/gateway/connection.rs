@@ -291,12 +291,10 @@ impl GatewayConnectionHandler {
 
         let opcode = GatewayOpcode::from_u8(message.op);
         match opcode {
-            Some(GatewayOpcode::Dispatch) => {
-                if message.t.as_deref() == Some("READY") {
-                    self.handle_ready_event(message)?;
-                    self.state.transition_to_connected();
-                    return Ok(());
-                }
+            Some(GatewayOpcode::Dispatch) if message.t.as_deref() == Some("READY") => {
+                self.handle_ready_event(message)?;
+                self.state.transition_to_connected();
+                return Ok(());
             }
             Some(GatewayOpcode::InvalidSession) => {
                 let resumable = message.d.and_then(|d| d.as_bool()).unwrap_or(false);
@@ -319,14 +317,12 @@ impl GatewayConnectionHandler {
 
         let opcode = GatewayOpcode::from_u8(message.op);
         match opcode {
-            Some(GatewayOpcode::Dispatch) => {
-                if message.t.as_deref() == Some("RESUMED") {
-                    info!("Session resumed successfully");
-                    self.state.transition_to_connected();
+            Some(GatewayOpcode::Dispatch) if message.t.as_deref() == Some("RESUMED") => {
+                info!("Session resumed successfully");
+                self.state.transition_to_connected();
 
-                    let _ = self.event_tx.send(GatewayEventKind::Resumed);
-                    return Ok(());
-                }
+                let _ = self.event_tx.send(GatewayEventKind::Resumed);
+                return Ok(());
             }
             Some(GatewayOpcode::InvalidSession) => {
                 let resumable = message.d.and_then(|d| d.as_bool()).unwrap_or(false);
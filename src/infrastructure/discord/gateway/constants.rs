@@ -7,7 +7,7 @@ pub const HEARTBEAT_JITTER_PERCENT: f64 = 0.05;
 pub const HEARTBEAT_TIMEOUT_MULTIPLIER: f64 = 1.5;
 
 pub const RECONNECT_DELAY_BASE: Duration = Duration::from_secs(1);
-pub const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+pub const RECONNECT_DELAY_MAX: Duration = Duration::from_mins(1);
 pub const RECONNECT_JITTER_MAX: Duration = Duration::from_millis(500);
 pub const MAX_RECONNECT_ATTEMPTS: u32 = 10;
 
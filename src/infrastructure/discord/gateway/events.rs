@@ -3,8 +3,13 @@ use std::time::Instant;
 use chrono::{DateTime, Utc};
 
 use crate::domain::entities::{
-    Channel, ChannelId, GuildFolder, GuildId, Member, Message, MessageId, ReadState, Relationship,
-    RelationshipType, Role, UserId,
+    Channel, ChannelId, CustomEmoji, CustomStatus, GuildFolder, GuildId, GuildNotificationSettings,
+    Member, Message, MessageId, ReadState, Relationship, Role, UserId, UserPresence,
+};
+
+pub use crate::domain::entities::{
+    Activity, ActivityKind, MemberListEntry, MemberListGroup, MemberListItem, MemberListOp,
+    PresenceStatus,
 };
 
 /// Commands that can be sent to the gateway.
@@ -16,6 +21,18 @@ pub enum GatewayCommand {
         guild_id: String,
         channel_id: String,
     },
+    /// Subscribe to member list ranges of a guild channel.
+    /// Ranges are inclusive item indices, at most three per request.
+    RequestMemberListRanges {
+        guild_id: String,
+        channel_id: String,
+        ranges: Vec<(u32, u32)>,
+    },
+    /// Set our own status and custom status for this session.
+    UpdatePresence {
+        status: PresenceStatus,
+        custom_status: Option<CustomStatus>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -77,9 +94,15 @@ pub enum DispatchEvent {
         initial_guild_channels: std::collections::HashMap<GuildId, Vec<Channel>>,
         initial_guild_roles: std::collections::HashMap<GuildId, Vec<Role>>,
         initial_guild_members: std::collections::HashMap<GuildId, Vec<Member>>,
+        initial_guild_emojis: std::collections::HashMap<GuildId, Vec<CustomEmoji>>,
         read_states: Vec<ReadState>,
         guild_folders: Vec<GuildFolder>,
         relationships: Vec<Relationship>,
+        /// Our own status as saved in user settings.
+        status: Option<PresenceStatus>,
+        custom_status: Option<CustomStatus>,
+        presences: std::collections::HashMap<UserId, UserPresence>,
+        notification_settings: Vec<GuildNotificationSettings>,
     },
 
     MessageCreate {
@@ -134,6 +157,17 @@ pub enum DispatchEvent {
         activities: Vec<Activity>,
     },
 
+    /// Changes to the member list of a channel, as subscribed through op 14.
+    GuildMemberListUpdate {
+        guild_id: GuildId,
+        /// Lists are shared by channels with the same member visibility.
+        list_id: String,
+        member_count: u32,
+        online_count: u32,
+        groups: Vec<MemberListGroup>,
+        ops: Vec<MemberListOp>,
+    },
+
     ChannelCreate {
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
@@ -150,6 +184,29 @@ pub enum DispatchEvent {
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
     },
+    ChannelPinsUpdate {
+        channel_id: ChannelId,
+        guild_id: Option<GuildId>,
+        last_pin_timestamp: Option<String>,
+    },
+
+    ThreadCreate {
+        thread: Channel,
+    },
+    ThreadUpdate {
+        thread: Channel,
+    },
+    ThreadDelete {
+        channel_id: ChannelId,
+        guild_id: Option<GuildId>,
+        parent_id: Option<ChannelId>,
+    },
+    /// Active threads of `channel_ids` (or of the whole guild when empty).
+    ThreadListSync {
+        guild_id: GuildId,
+        channel_ids: Vec<ChannelId>,
+        threads: Vec<Channel>,
+    },
 
     GuildCreate {
         guild_id: GuildId,
@@ -159,6 +216,7 @@ pub enum DispatchEvent {
         threads: Vec<Channel>,
         roles: Vec<Role>,
         members: Vec<Member>,
+        emojis: Vec<CustomEmoji>,
     },
     GuildUpdate {
         guild_id: GuildId,
@@ -168,6 +226,10 @@ pub enum DispatchEvent {
         guild_id: GuildId,
         unavailable: bool,
     },
+    GuildEmojisUpdate {
+        guild_id: GuildId,
+        emojis: Vec<CustomEmoji>,
+    },
 
     UserUpdate {
         user_id: String,
@@ -180,6 +242,10 @@ pub enum DispatchEvent {
         guild_folders: Vec<GuildFolder>,
     },
 
+    UserGuildSettingsUpdate {
+        settings: GuildNotificationSettings,
+    },
+
     VoiceStateUpdate {
         guild_id: Option<GuildId>,
         channel_id: Option<ChannelId>,
@@ -199,8 +265,7 @@ pub enum DispatchEvent {
     },
 
     RelationshipAdd {
-        user_id: UserId,
-        relationship_type: RelationshipType,
+        relationship: Relationship,
     },
     RelationshipRemove {
         user_id: UserId,
@@ -225,14 +290,22 @@ impl DispatchEvent {
             Self::MessageReactionRemoveAll { .. } => "MESSAGE_REACTION_REMOVE_ALL",
             Self::TypingStart { .. } => "TYPING_START",
             Self::PresenceUpdate { .. } => "PRESENCE_UPDATE",
+            Self::GuildMemberListUpdate { .. } => "GUILD_MEMBER_LIST_UPDATE",
             Self::ChannelCreate { .. } => "CHANNEL_CREATE",
             Self::ChannelUpdate { .. } => "CHANNEL_UPDATE",
             Self::ChannelDelete { .. } => "CHANNEL_DELETE",
+            Self::ChannelPinsUpdate { .. } => "CHANNEL_PINS_UPDATE",
+            Self::ThreadCreate { .. } => "THREAD_CREATE",
+            Self::ThreadUpdate { .. } => "THREAD_UPDATE",
+            Self::ThreadDelete { .. } => "THREAD_DELETE",
+            Self::ThreadListSync { .. } => "THREAD_LIST_SYNC",
             Self::GuildCreate { .. } => "GUILD_CREATE",
             Self::GuildUpdate { .. } => "GUILD_UPDATE",
             Self::GuildDelete { .. } => "GUILD_DELETE",
+            Self::GuildEmojisUpdate { .. } => "GUILD_EMOJIS_UPDATE",
             Self::UserUpdate { .. } => "USER_UPDATE",
             Self::UserSettingsUpdate { .. } => "USER_SETTINGS_UPDATE",
+            Self::UserGuildSettingsUpdate { .. } => "USER_GUILD_SETTINGS_UPDATE",
             Self::VoiceStateUpdate { .. } => "VOICE_STATE_UPDATE",
             Self::VoiceServerUpdate { .. } => "VOICE_SERVER_UPDATE",
             Self::RelationshipAdd { .. } => "RELATIONSHIP_ADD",
@@ -256,10 +329,13 @@ impl DispatchEvent {
             | Self::ChannelCreate { channel_id, .. }
             | Self::ChannelUpdate { channel_id, .. }
             | Self::ChannelDelete { channel_id, .. }
+            | Self::ChannelPinsUpdate { channel_id, .. }
+            | Self::ThreadDelete { channel_id, .. }
             | Self::VoiceStateUpdate {
                 channel_id: Some(channel_id),
                 ..
             } => Some(*channel_id),
+            Self::ThreadCreate { thread } | Self::ThreadUpdate { thread } => Some(thread.id()),
             _ => None,
         }
     }
@@ -277,10 +353,16 @@ impl DispatchEvent {
             | Self::ChannelCreate { guild_id, .. }
             | Self::ChannelUpdate { guild_id, .. }
             | Self::ChannelDelete { guild_id, .. }
+            | Self::ChannelPinsUpdate { guild_id, .. }
+            | Self::ThreadDelete { guild_id, .. }
             | Self::VoiceStateUpdate { guild_id, .. } => *guild_id,
+            Self::ThreadCreate { thread } | Self::ThreadUpdate { thread } => thread.guild_id(),
             Self::GuildCreate { guild_id, .. }
             | Self::GuildUpdate { guild_id, .. }
             | Self::GuildDelete { guild_id, .. }
+            | Self::GuildEmojisUpdate { guild_id, .. }
+            | Self::ThreadListSync { guild_id, .. }
+            | Self::GuildMemberListUpdate { guild_id, .. }
             | Self::VoiceServerUpdate { guild_id, .. } => Some(*guild_id),
             _ => None,
         }
@@ -307,96 +389,11 @@ impl ReactionEmoji {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum PresenceStatus {
-    Online,
-    Idle,
-    DoNotDisturb,
-    Invisible,
-    #[default]
-    Offline,
-}
-
-impl PresenceStatus {
-    #[must_use]
-    pub fn parse(s: &str) -> Self {
-        match s.to_lowercase().as_str() {
-            "online" => Self::Online,
-            "idle" => Self::Idle,
-            "dnd" => Self::DoNotDisturb,
-            "invisible" => Self::Invisible,
-            _ => Self::Offline,
-        }
-    }
-
-    #[must_use]
-    pub const fn as_str(&self) -> &'static str {
-        match self {
-            Self::Online => "online",
-            Self::Idle => "idle",
-            Self::DoNotDisturb => "dnd",
-            Self::Invisible => "invisible",
-            Self::Offline => "offline",
-        }
-    }
-
-    #[must_use]
-    pub const fn is_online(&self) -> bool {
-        !matches!(self, Self::Offline | Self::Invisible)
-    }
-
-    #[must_use]
-    pub const fn display_indicator(&self) -> &'static str {
-        match self {
-            Self::Online => "●",
-            Self::Idle => "◐",
-            Self::DoNotDisturb => "⊘",
-            Self::Invisible | Self::Offline => "○",
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Activity {
-    pub name: String,
-    pub kind: ActivityKind,
-    pub details: Option<String>,
-    pub state: Option<String>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum ActivityKind {
-    #[default]
-    Playing = 0,
-    Streaming = 1,
-    Listening = 2,
-    Watching = 3,
-    Custom = 4,
-    Competing = 5,
-}
-
-impl ActivityKind {
-    #[must_use]
-    pub const fn from_u8(value: u8) -> Self {
-        match value {
-            1 => Self::Streaming,
-            2 => Self::Listening,
-            3 => Self::Watching,
-            4 => Self::Custom,
-            5 => Self::Competing,
-            _ => Self::Playing,
-        }
-    }
-
-    #[must_use]
-    pub const fn prefix(&self) -> &'static str {
-        match self {
-            Self::Playing => "Playing",
-            Self::Streaming => "Streaming",
-            Self::Listening => "Listening to",
-            Self::Watching => "Watching",
-            Self::Custom => "",
-            Self::Competing => "Competing in",
+impl From<ReactionEmoji> for crate::domain::entities::ReactionEmoji {
+    fn from(emoji: ReactionEmoji) -> Self {
+        Self {
+            id: emoji.id,
+            name: emoji.name,
         }
     }
 }
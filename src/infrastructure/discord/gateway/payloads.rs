@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::constants::LARGE_THRESHOLD;
+use super::constants::{GatewayOpcode, LARGE_THRESHOLD};
+use crate::domain::entities::{CustomStatus, PresenceStatus};
 use crate::infrastructure::discord::identity::SuperProperties;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -90,15 +91,23 @@ impl GatewayPayload {
     /// This is required for user accounts to receive `TYPING_START` events.
     #[must_use]
     pub fn lazy_request(guild_id: &str, channel_id: &str) -> Self {
+        Self::lazy_request_ranges(guild_id, channel_id, &[(0, 99)])
+    }
+
+    /// Creates a `LazyRequest` (Opcode 14) payload subscribing to the given
+    /// member list ranges of a guild channel.
+    #[must_use]
+    pub fn lazy_request_ranges(guild_id: &str, channel_id: &str, ranges: &[(u32, u32)]) -> Self {
         use serde_json::json;
 
+        let ranges: Vec<[u32; 2]> = ranges.iter().map(|&(start, end)| [start, end]).collect();
         let data = json!({
             "guild_id": guild_id,
             "typing": true,
             "activities": true,
             "threads": true,
             "channels": {
-                (channel_id): [[0, 99]]
+                (channel_id): ranges
             }
         });
 
@@ -109,6 +118,41 @@ impl GatewayPayload {
             t: None,
         }
     }
+
+    /// Creates a Presence Update (Opcode 3) payload setting our own status
+    /// and, if given, a custom status activity.
+    #[must_use]
+    pub fn presence_update(status: PresenceStatus, custom_status: Option<&CustomStatus>) -> Self {
+        use serde_json::json;
+
+        let activities: Vec<Value> = custom_status
+            .filter(|custom| !custom.is_empty() && !custom.is_expired(chrono::Utc::now()))
+            .map(|custom| {
+                let mut activity = json!({
+                    "type": 4,
+                    "name": "Custom Status",
+                    "state": custom.text,
+                });
+                if let Some(emoji) = &custom.emoji {
+                    activity["emoji"] = json!({ "name": emoji });
+                }
+                activity
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            op: GatewayOpcode::PresenceUpdate.as_u8(),
+            d: json!({
+                "status": status.as_str(),
+                "since": 0,
+                "activities": activities,
+                "afk": false,
+            }),
+            s: None,
+            t: None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -216,12 +260,101 @@ pub struct ReadyPayload {
     /// User relationships (friends, blocked, pending).
     #[serde(default)]
     pub relationships: Vec<RelationshipPayload>,
+    /// Presences of friends and guild members known at connect time.
+    #[serde(default)]
+    pub merged_presences: Option<MergedPresencesPayload>,
+    /// Mute and notification settings per guild.
+    #[serde(default)]
+    pub user_guild_settings: Option<UserGuildSettingsListPayload>,
+}
+
+/// `user_guild_settings` in READY: a versioned wrapper, or a bare list on older gateways.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UserGuildSettingsListPayload {
+    Versioned {
+        #[serde(default)]
+        entries: Vec<UserGuildSettingsPayload>,
+    },
+    List(Vec<UserGuildSettingsPayload>),
+}
+
+impl UserGuildSettingsListPayload {
+    #[must_use]
+    pub fn into_entries(self) -> Vec<UserGuildSettingsPayload> {
+        match self {
+            Self::Versioned { entries } | Self::List(entries) => entries,
+        }
+    }
+}
+
+/// Notification settings of one guild; `guild_id` is null for direct messages.
+#[derive(Debug, Deserialize)]
+pub struct UserGuildSettingsPayload {
+    #[serde(default)]
+    pub guild_id: Option<String>,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub mute_config: Option<MuteConfigPayload>,
+    #[serde(default)]
+    pub message_notifications: Option<u8>,
+    #[serde(default)]
+    pub suppress_everyone: bool,
+    #[serde(default)]
+    pub suppress_roles: bool,
+    #[serde(default)]
+    pub channel_overrides: Vec<ChannelOverridePayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChannelOverridePayload {
+    pub channel_id: String,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub mute_config: Option<MuteConfigPayload>,
+    #[serde(default)]
+    pub message_notifications: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MuteConfigPayload {
+    pub end_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct MergedPresencesPayload {
+    #[serde(default)]
+    pub friends: Vec<MergedPresencePayload>,
+    /// One list per guild, in the order of `guilds`.
+    #[serde(default)]
+    pub guilds: Vec<Vec<MergedPresencePayload>>,
 }
 
 #[derive(Debug, Deserialize)]
+pub struct MergedPresencePayload {
+    pub user_id: String,
+    pub status: String,
+    #[serde(default)]
+    pub activities: Vec<ActivityPayload>,
+}
+
+#[derive(Debug, Deserialize, Default)]
 pub struct UserSettingsPayload {
     #[serde(default)]
     pub guild_folders: Vec<GuildFolderPayload>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub custom_status: Option<CustomStatusPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomStatusPayload {
+    pub text: Option<String>,
+    pub emoji_name: Option<String>,
+    pub expires_at: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -263,6 +396,8 @@ pub struct ReadyGuild {
     pub roles: Vec<RolePayload>,
     #[serde(default)]
     pub members: Vec<GuildMemberPayload>,
+    #[serde(default)]
+    pub emojis: Vec<EmojiPayload>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -283,6 +418,12 @@ pub struct MessagePayload {
     pub pinned: bool,
     #[serde(default)]
     pub mentions: Vec<MentionUserPayload>,
+    #[serde(default)]
+    pub mention_roles: Vec<String>,
+    #[serde(default)]
+    pub mention_everyone: bool,
+    #[serde(default)]
+    pub guild_id: Option<String>,
     #[serde(rename = "member")]
     pub _member: Option<MemberPayload>,
 }
@@ -359,6 +500,15 @@ pub struct TypingStartPayload {
     pub member: Option<TypingMemberPayload>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ChannelPinsUpdatePayload {
+    #[serde(with = "crate::domain::serde_utils::string_to_u64")]
+    pub channel_id: u64,
+    #[serde(default, with = "crate::domain::serde_utils::string_to_u64::option")]
+    pub guild_id: Option<u64>,
+    pub last_pin_timestamp: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TypingMemberPayload {
     pub user: Option<TypingUserPayload>,
@@ -397,6 +547,77 @@ pub struct ActivityPayload {
     pub state: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct GuildMemberListUpdatePayload {
+    pub guild_id: String,
+    pub id: String,
+    #[serde(default)]
+    pub member_count: u32,
+    #[serde(default)]
+    pub online_count: u32,
+    #[serde(default)]
+    pub groups: Vec<MemberListGroupPayload>,
+    #[serde(default)]
+    pub ops: Vec<MemberListOpPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberListGroupPayload {
+    pub id: String,
+    #[serde(default)]
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "UPPERCASE")]
+pub enum MemberListOpPayload {
+    Sync {
+        range: [u32; 2],
+        #[serde(default)]
+        items: Vec<MemberListItemPayload>,
+    },
+    Insert {
+        index: u32,
+        item: MemberListItemPayload,
+    },
+    Update {
+        index: u32,
+        item: MemberListItemPayload,
+    },
+    Delete {
+        index: u32,
+    },
+    Invalidate {
+        range: [u32; 2],
+    },
+}
+
+/// Either a group header or a member; Discord sends exactly one of the two.
+#[derive(Debug, Deserialize)]
+pub struct MemberListItemPayload {
+    pub group: Option<MemberListGroupPayload>,
+    pub member: Option<MemberListMemberPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberListMemberPayload {
+    pub user: AuthorPayload,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub nick: Option<String>,
+    #[serde(default)]
+    pub presence: Option<MemberListPresencePayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberListPresencePayload {
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub activities: Vec<ActivityPayload>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ReactionPayload {
     pub user_id: String,
@@ -412,6 +633,8 @@ pub struct EmojiPayload {
     pub name: Option<String>,
     #[serde(default)]
     pub animated: bool,
+    #[serde(default)]
+    pub available: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -441,6 +664,49 @@ pub struct ChannelPayload {
     pub last_message_id: Option<String>,
     #[serde(default)]
     pub permission_overwrites: Vec<PermissionOverwritePayload>,
+    #[serde(default)]
+    pub thread_metadata: Option<ThreadMetadataPayload>,
+    /// Thread member object, present on threads the current user has joined.
+    #[serde(default)]
+    pub member: Option<Value>,
+    #[serde(default)]
+    pub flags: Option<u64>,
+    /// Tags offered by forum channels.
+    #[serde(default)]
+    pub available_tags: Vec<crate::infrastructure::discord::dto::ForumTagDto>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadMetadataPayload {
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub auto_archive_duration: u16,
+    #[serde(default)]
+    pub archive_timestamp: String,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub invitable: Option<bool>,
+    #[serde(default)]
+    pub create_timestamp: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadListSyncPayload {
+    pub guild_id: String,
+    /// Parent channels being synced; absent when the whole guild is.
+    #[serde(default)]
+    pub channel_ids: Vec<String>,
+    #[serde(default)]
+    pub threads: Vec<ChannelPayload>,
+    #[serde(default)]
+    pub members: Vec<ThreadMemberPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadMemberPayload {
+    pub id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -466,6 +732,8 @@ pub struct GuildCreatePayload {
     pub roles: Vec<RolePayload>,
     #[serde(default)]
     pub members: Vec<GuildMemberPayload>,
+    #[serde(default)]
+    pub emojis: Vec<EmojiPayload>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -518,6 +786,13 @@ pub struct GuildDeletePayload {
     pub unavailable: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct GuildEmojisUpdatePayload {
+    pub guild_id: String,
+    #[serde(default)]
+    pub emojis: Vec<EmojiPayload>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UserUpdatePayload {
     pub id: String,
@@ -561,6 +836,12 @@ pub struct RelationshipPayload {
     /// Relationship type (1 = friend, 2 = blocked, 3 = pending incoming, 4 = pending outgoing).
     #[serde(rename = "type", default)]
     pub relationship_type: u8,
+    /// The other user.
+    #[serde(default)]
+    pub user: Option<AuthorPayload>,
+    /// Friend nickname set by the current user.
+    #[serde(default)]
+    pub nickname: Option<String>,
 }
 
 /// Relationship remove payload.
@@ -682,6 +963,25 @@ mod tests {
         assert!(obj.contains_key("intents"));
     }
 
+    #[test]
+    fn test_presence_update_payload() {
+        let custom = CustomStatus::new("Writing Rust").with_emoji("🦀");
+        let payload = GatewayPayload::presence_update(PresenceStatus::DoNotDisturb, Some(&custom));
+        assert_eq!(payload.op, 3);
+        assert_eq!(payload.d["status"], "dnd");
+        assert_eq!(payload.d["activities"][0]["type"], 4);
+        assert_eq!(payload.d["activities"][0]["state"], "Writing Rust");
+        assert_eq!(payload.d["activities"][0]["emoji"]["name"], "🦀");
+
+        let payload = GatewayPayload::presence_update(PresenceStatus::Idle, None);
+        assert_eq!(payload.d["activities"], Value::Array(Vec::new()));
+
+        let expired = CustomStatus::new("Lunch")
+            .with_expires_at(chrono::Utc::now() - chrono::Duration::minutes(1));
+        let payload = GatewayPayload::presence_update(PresenceStatus::Online, Some(&expired));
+        assert_eq!(payload.d["activities"], Value::Array(Vec::new()));
+    }
+
     #[test]
     fn test_resume_payload() {
         let payload = GatewayPayload::resume("token", "session123", 100);
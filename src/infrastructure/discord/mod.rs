@@ -4,6 +4,7 @@ mod client;
 mod dto;
 pub mod gateway;
 pub mod identity;
+mod rate_limit;
 pub mod scraper;
 
 pub use client::DiscordClient;
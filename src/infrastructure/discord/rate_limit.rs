@@ -0,0 +1,423 @@
+//! Per-bucket REST rate limiting.
+//!
+//! Discord groups routes into buckets identified by the `X-RateLimit-Bucket`
+//! header. A bucket is further split by the route's major parameter (channel,
+//! guild or webhook id), so two channels never share a budget even when their
+//! routes map to the same bucket hash. Until a route's bucket is known, the
+//! route is treated as a bucket of its own.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url};
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+const HEADER_BUCKET: &str = "x-ratelimit-bucket";
+const HEADER_REMAINING: &str = "x-ratelimit-remaining";
+const HEADER_RESET_AFTER: &str = "x-ratelimit-reset-after";
+const HEADER_GLOBAL: &str = "x-ratelimit-global";
+const HEADER_SCOPE: &str = "x-ratelimit-scope";
+const HEADER_RETRY_AFTER: &str = "retry-after";
+
+/// Path segments whose following id is a major parameter.
+const MAJOR_PARAMETERS: [&str; 3] = ["channels", "guilds", "webhooks"];
+
+/// Identifies a route for bucketing purposes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteKey {
+    route: String,
+    major: String,
+}
+
+impl RouteKey {
+    /// Builds a route key from a request, keeping major parameters and
+    /// replacing every other id (and reaction emoji) with a placeholder.
+    #[must_use]
+    pub fn new(method: &Method, url: &Url) -> Self {
+        let mut route = String::from(method.as_str());
+        let mut major = String::new();
+        let mut previous: Option<&str> = None;
+
+        for segment in url.path().split('/').filter(|s| !s.is_empty()) {
+            let is_major = previous.is_some_and(|p| MAJOR_PARAMETERS.contains(&p));
+            let is_id = segment.bytes().all(|b| b.is_ascii_digit());
+
+            route.push('/');
+            if is_id && is_major && major.is_empty() {
+                major = segment.to_string();
+                route.push_str(segment);
+            } else if is_id {
+                route.push_str(":id");
+            } else if previous == Some("reactions") {
+                route.push_str(":emoji");
+            } else {
+                route.push_str(segment);
+            }
+
+            previous = Some(segment);
+        }
+
+        Self { route, major }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.route
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BucketState {
+    remaining: u32,
+    reset_at: Instant,
+}
+
+/// Rate limit information extracted from a response.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimitHeaders {
+    pub bucket: Option<String>,
+    pub remaining: Option<u32>,
+    pub reset_after: Option<Duration>,
+    pub retry_after: Option<Duration>,
+    pub global: bool,
+}
+
+impl RateLimitHeaders {
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let seconds = |name: &str| {
+            get(name)
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|s| s.is_finite() && *s >= 0.0)
+                .map(Duration::from_secs_f64)
+        };
+
+        Self {
+            bucket: get(HEADER_BUCKET).map(ToString::to_string),
+            remaining: get(HEADER_REMAINING).and_then(|v| v.parse().ok()),
+            reset_after: seconds(HEADER_RESET_AFTER),
+            retry_after: seconds(HEADER_RETRY_AFTER),
+            global: get(HEADER_GLOBAL).is_some_and(|v| v.eq_ignore_ascii_case("true"))
+                || get(HEADER_SCOPE) == Some("global"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RateLimitState {
+    /// Bucket hash learned for each route.
+    route_buckets: HashMap<String, String>,
+    /// Bucket state keyed by `hash:major` (or the route itself until the hash is known).
+    buckets: HashMap<String, BucketState>,
+    /// Per-bucket queues, keyed like `buckets`.
+    queues: HashMap<String, Arc<tokio::sync::Mutex<()>>>,
+    global_reset_at: Option<Instant>,
+}
+
+impl RateLimitState {
+    fn bucket_key(&self, route: &RouteKey) -> String {
+        match self.route_buckets.get(route.as_str()) {
+            Some(hash) => format!("{hash}:{}", route.major),
+            None => route.route.clone(),
+        }
+    }
+}
+
+/// Queue of one rate limit bucket; holding its lock keeps requests sharing the
+/// bucket in order.
+#[derive(Debug)]
+pub struct BucketQueue {
+    key: String,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl BucketQueue {
+    pub async fn lock(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.lock.lock().await
+    }
+}
+
+/// Tracks Discord rate limit buckets and delays requests that would exceed them.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    state: Mutex<RateLimitState>,
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_state<T>(&self, f: impl FnOnce(&mut RateLimitState) -> T) -> T {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut state)
+    }
+
+    /// Returns the queue of the bucket a route belongs to. Callers hold its
+    /// lock for the whole acquire/send/update cycle so requests sharing a
+    /// bucket run one at a time.
+    #[must_use]
+    pub fn queue(&self, route: &RouteKey) -> BucketQueue {
+        self.with_state(|state| {
+            let key = state.bucket_key(route);
+            let lock = state.queues.entry(key.clone()).or_default().clone();
+            BucketQueue { key, lock }
+        })
+    }
+
+    /// Hands a queue back, forgetting it once no request holds or waits on it.
+    pub fn release(&self, queue: BucketQueue) {
+        let BucketQueue { key, lock } = queue;
+        drop(lock);
+        self.with_state(|state| {
+            if state
+                .queues
+                .get(&key)
+                .is_some_and(|queue| Arc::strong_count(queue) == 1)
+            {
+                state.queues.remove(&key);
+            }
+        });
+    }
+
+    /// Waits until both the global limit and the route's bucket allow another
+    /// request, then reserves a slot in the bucket.
+    pub async fn acquire(&self, route: &RouteKey) {
+        while let Some(delay) = self.reserve(route, Instant::now()) {
+            debug!(
+                route = route.as_str(),
+                delay_ms = delay.as_millis(),
+                "Waiting for rate limit reset"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Reserves a slot if one is available, otherwise returns how long to wait.
+    fn reserve(&self, route: &RouteKey, now: Instant) -> Option<Duration> {
+        self.with_state(|state| {
+            if let Some(reset_at) = state.global_reset_at {
+                if reset_at > now {
+                    return Some(reset_at - now);
+                }
+                state.global_reset_at = None;
+            }
+
+            let key = state.bucket_key(route);
+            let bucket = state.buckets.get_mut(&key)?;
+
+            if bucket.reset_at <= now {
+                state.buckets.remove(&key);
+                return None;
+            }
+            if bucket.remaining == 0 {
+                return Some(bucket.reset_at - now);
+            }
+
+            bucket.remaining -= 1;
+            None
+        })
+    }
+
+    /// Records the rate limit headers of a response for the given route.
+    pub fn update(&self, route: &RouteKey, status: StatusCode, headers: &RateLimitHeaders) {
+        let now = Instant::now();
+
+        self.with_state(|state| {
+            if status == StatusCode::TOO_MANY_REQUESTS && headers.global {
+                let retry_after = headers.retry_after.unwrap_or_default();
+                warn!(
+                    retry_after_ms = retry_after.as_millis(),
+                    "Hit global rate limit"
+                );
+                state.global_reset_at = Some(now + retry_after);
+                return;
+            }
+
+            if let Some(hash) = &headers.bucket {
+                state
+                    .route_buckets
+                    .insert(route.as_str().to_string(), hash.clone());
+            }
+
+            let key = state.bucket_key(route);
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = headers
+                    .retry_after
+                    .or(headers.reset_after)
+                    .unwrap_or_default();
+                warn!(
+                    route = route.as_str(),
+                    retry_after_ms = retry_after.as_millis(),
+                    "Hit route rate limit"
+                );
+                state.buckets.insert(
+                    key,
+                    BucketState {
+                        remaining: 0,
+                        reset_at: now + retry_after,
+                    },
+                );
+            } else if let (Some(remaining), Some(reset_after)) =
+                (headers.remaining, headers.reset_after)
+            {
+                state.buckets.insert(
+                    key,
+                    BucketState {
+                        remaining,
+                        reset_at: now + reset_after,
+                    },
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn route(method: &Method, path: &str) -> RouteKey {
+        let url = Url::parse(&format!("https://discord.com/api/v10{path}")).unwrap();
+        RouteKey::new(method, &url)
+    }
+
+    #[test]
+    fn test_route_key_keeps_major_parameter() {
+        let a = route(&Method::GET, "/channels/111/messages?limit=50");
+        let b = route(&Method::GET, "/channels/111/messages?before=5");
+        let c = route(&Method::GET, "/channels/222/messages");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.as_str(), "GET/api/v10/channels/111/messages");
+    }
+
+    #[test]
+    fn test_route_key_masks_minor_ids_and_emoji() {
+        let key = route(
+            &Method::PUT,
+            "/channels/111/messages/999/reactions/%F0%9F%91%8D/@me",
+        );
+        assert_eq!(
+            key.as_str(),
+            "PUT/api/v10/channels/111/messages/:id/reactions/:emoji/@me"
+        );
+    }
+
+    #[test]
+    fn test_headers_parsing() {
+        let mut headers = HeaderMap::new();
+        headers.insert(HEADER_BUCKET, HeaderValue::from_static("abcd"));
+        headers.insert(HEADER_REMAINING, HeaderValue::from_static("0"));
+        headers.insert(HEADER_RESET_AFTER, HeaderValue::from_static("1.5"));
+        headers.insert(HEADER_SCOPE, HeaderValue::from_static("global"));
+
+        let parsed = RateLimitHeaders::from_headers(&headers);
+        assert_eq!(parsed.bucket.as_deref(), Some("abcd"));
+        assert_eq!(parsed.remaining, Some(0));
+        assert_eq!(parsed.reset_after, Some(Duration::from_millis(1500)));
+        assert!(parsed.global);
+    }
+
+    #[test]
+    fn test_queue_is_released_when_unused() {
+        let limiter = RateLimiter::new();
+        let route = route(&Method::GET, "/channels/111/messages");
+
+        let first = limiter.queue(&route);
+        let waiting = limiter.queue(&route);
+        assert!(Arc::ptr_eq(&first.lock, &waiting.lock));
+
+        limiter.release(first);
+        assert_eq!(limiter.with_state(|state| state.queues.len()), 1);
+
+        limiter.release(waiting);
+        assert!(limiter.with_state(|state| state.queues.is_empty()));
+    }
+
+    #[test]
+    fn test_routes_sharing_a_bucket_share_a_queue() {
+        let limiter = RateLimiter::new();
+        let get = route(&Method::GET, "/channels/111/messages");
+        let post = route(&Method::POST, "/channels/111/messages");
+        let other_channel = route(&Method::POST, "/channels/222/messages");
+        assert!(!Arc::ptr_eq(
+            &limiter.queue(&get).lock,
+            &limiter.queue(&post).lock
+        ));
+
+        let headers = RateLimitHeaders {
+            bucket: Some("shared".to_string()),
+            ..RateLimitHeaders::default()
+        };
+        for key in [&get, &post, &other_channel] {
+            limiter.update(key, StatusCode::OK, &headers);
+        }
+
+        let get_queue = limiter.queue(&get);
+        assert!(Arc::ptr_eq(&get_queue.lock, &limiter.queue(&post).lock));
+        assert!(!Arc::ptr_eq(
+            &get_queue.lock,
+            &limiter.queue(&other_channel).lock
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_bucket_delays_routes_sharing_it() {
+        let limiter = RateLimiter::new();
+        let first = route(&Method::GET, "/channels/111/messages");
+        let second = route(&Method::POST, "/channels/111/messages");
+        let other_channel = route(&Method::GET, "/channels/222/messages");
+
+        let headers = RateLimitHeaders {
+            bucket: Some("shared".to_string()),
+            remaining: Some(0),
+            reset_after: Some(Duration::from_secs(2)),
+            ..RateLimitHeaders::default()
+        };
+        limiter.update(&first, StatusCode::OK, &headers);
+        limiter.update(&second, StatusCode::OK, &headers);
+
+        let now = Instant::now();
+        assert!(limiter.reserve(&first, now).is_some());
+        assert!(limiter.reserve(&second, now).is_some());
+        assert!(limiter.reserve(&other_channel, now).is_none());
+        assert!(
+            limiter
+                .reserve(&first, now + Duration::from_secs(3))
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_global_limit_blocks_every_route() {
+        let limiter = RateLimiter::new();
+        let key = route(&Method::GET, "/users/@me/channels");
+        let headers = RateLimitHeaders {
+            retry_after: Some(Duration::from_secs(1)),
+            global: true,
+            ..RateLimitHeaders::default()
+        };
+        limiter.update(&key, StatusCode::TOO_MANY_REQUESTS, &headers);
+
+        let now = Instant::now();
+        assert!(
+            limiter
+                .reserve(&route(&Method::GET, "/guilds/1/channels"), now)
+                .is_some()
+        );
+        assert!(
+            limiter
+                .reserve(&key, now + Duration::from_secs(2))
+                .is_none()
+        );
+    }
+}
@@ -22,6 +22,12 @@ pub fn optimize_cdn_url(url: &str, width: u32, height: u32) -> String {
         return url.to_string();
     }
 
+    // Emoji are requested at their final size already; resizing them to
+    // attachment dimensions would only waste bandwidth.
+    if url.contains("/emojis/") {
+        return url.to_string();
+    }
+
     let (base_url, existing_params) = if let Some(idx) = url.find('?') {
         (&url[..idx], Some(&url[idx + 1..]))
     } else {
@@ -126,6 +132,12 @@ pub fn guild_splash_url(guild_id: &str, splash_hash: Option<&str>) -> Option<Str
     splash_hash.map(|hash| format!("https://cdn.discordapp.com/splashes/{guild_id}/{hash}.png"))
 }
 
+/// Generates a custom emoji image URL sized for a single terminal cell row.
+#[must_use]
+pub fn emoji_url(emoji_id: &str) -> String {
+    format!("https://cdn.discordapp.com/emojis/{emoji_id}.png?size=32")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,4 +258,16 @@ mod tests {
             Some("https://cdn.discordapp.com/splashes/123/abc.png".to_string())
         );
     }
+
+    #[test]
+    fn test_emoji_url() {
+        assert_eq!(
+            emoji_url("123"),
+            "https://cdn.discordapp.com/emojis/123.png?size=32"
+        );
+        assert_eq!(
+            optimize_cdn_url_default(&emoji_url("123")),
+            emoji_url("123")
+        );
+    }
 }
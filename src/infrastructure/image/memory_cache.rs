@@ -144,7 +144,7 @@ impl ImageCachePort for MemoryImageCache {
 
     fn len(&self) -> usize {
         let cache = self.cache.try_read();
-        cache.map(|c| c.len()).unwrap_or(0)
+        cache.map_or(0, |c| c.len())
     }
 
     async fn clear(&self) {
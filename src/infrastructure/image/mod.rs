@@ -12,7 +12,8 @@ pub mod loader;
 pub mod memory_cache;
 
 pub use discord_cdn::{
-    extract_attachment_id, is_discord_cdn_url, optimize_cdn_url, optimize_cdn_url_default,
+    emoji_url, extract_attachment_id, is_discord_cdn_url, optimize_cdn_url,
+    optimize_cdn_url_default,
 };
 pub use disk_cache::DiskImageCache;
 pub use loader::{ImageLoadedEvent, ImageLoader, ImageLoaderConfig};
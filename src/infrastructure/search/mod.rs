@@ -155,7 +155,7 @@ impl ChannelSearchProvider {
             results.push(result);
         }
 
-        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
         results.truncate(10);
 
         results
@@ -192,15 +192,9 @@ impl DmSearchProvider {
         let query_lower = query.to_lowercase();
 
         for dm in &self.dms {
-            let name = if self.use_display_name {
-                dm.recipient_global_name
-                    .as_ref()
-                    .unwrap_or(&dm.recipient_username)
-            } else {
-                &dm.recipient_username
-            };
+            let name = dm.display_name(self.use_display_name);
 
-            if let Some(mut score) = self.searcher.score(name, query) {
+            if let Some(mut score) = self.searcher.score(&name, query) {
                 if name.eq_ignore_ascii_case(query) {
                     score += 100;
                 } else if name.to_lowercase().starts_with(&query_lower) {
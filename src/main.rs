@@ -53,8 +53,11 @@ fn detect_light_mode() -> bool {
     }
 }
 
-fn create_app() -> Result<(App, Option<(String, TokenSource)>)> {
-    let args = CliArgs::parse();
+type ExternalToken = Option<(String, TokenSource)>;
+
+fn create_app() -> Result<(App, ExternalToken, bool)> {
+    let mut args = CliArgs::parse();
+    let open_link = args.link.take();
 
     let storage = StorageManager::new()?;
 
@@ -62,7 +65,7 @@ fn create_app() -> Result<(App, Option<(String, TokenSource)>)> {
 
     config.merge_with_args(args);
 
-    let external_token: Option<(String, TokenSource)> = std::env::var("OXICORD_TOKEN")
+    let external_token: ExternalToken = std::env::var("OXICORD_TOKEN")
         .ok()
         .map(|token| (token, TokenSource::Environment));
 
@@ -94,11 +97,16 @@ fn create_app() -> Result<(App, Option<(String, TokenSource)>)> {
         internal_notifications: config.notifications.internal,
         enable_animations: config.ui.enable_animations,
         editor: config.editor.clone(),
+        mouse: config.mouse,
         keybindings: config.keybindings.clone(),
         notification_duration: config.ui.notification_duration,
         theme,
         hide_blocked_completely: config.ui.hide_blocked_completely,
         quick_switcher_order: config.quick_switcher_order,
+        open_link,
+        status: config.status.status,
+        custom_status: config.status.custom_status(),
+        notification_rules: config.notifications.rules.clone(),
     };
 
     let app = App::new(
@@ -109,21 +117,27 @@ fn create_app() -> Result<(App, Option<(String, TokenSource)>)> {
         identity,
     );
 
-    Ok((app, external_token))
+    Ok((app, external_token, config.mouse))
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     color_eyre::install()?;
 
-    let (app, external_token) = create_app()?;
+    let (app, external_token, mouse) = create_app()?;
 
     let mut terminal = ratatui::init();
 
     let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableBracketedPaste);
+    if mouse {
+        let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableMouseCapture);
+    }
 
     let result = app.run(&mut terminal, external_token).await;
 
+    if mouse {
+        let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture);
+    }
     let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableBracketedPaste);
     ratatui::restore();
 
@@ -103,6 +103,11 @@ impl Default for CommandRegistry {
             KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL),
             true,
         );
+        register(
+            Action::ToggleMemberList,
+            KeyEvent::new(KeyCode::Char('m'), KeyModifiers::ALT),
+            true,
+        );
         register(
             Action::ToggleFileExplorer,
             KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
@@ -218,6 +223,11 @@ impl Default for CommandRegistry {
             KeyEvent::new(KeyCode::End, KeyModifiers::NONE),
             true,
         );
+        register(
+            Action::JumpToPresent,
+            KeyEvent::new(KeyCode::End, KeyModifiers::SHIFT),
+            true,
+        );
         register(
             Action::Cancel,
             KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
@@ -273,6 +283,112 @@ impl Default for CommandRegistry {
             true,
         );
 
+        register(
+            Action::ToggleReaction,
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::ToggleReaction,
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::SHIFT),
+            false,
+        );
+        register(
+            Action::NextReaction,
+            KeyEvent::new(KeyCode::Char('>'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::NextReaction,
+            KeyEvent::new(KeyCode::Char('>'), KeyModifiers::SHIFT),
+            false,
+        );
+        register(
+            Action::AddReaction,
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::TogglePin,
+            KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT),
+            true,
+        );
+        register(
+            Action::OpenPins,
+            KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL),
+            true,
+        );
+        register(
+            Action::OpenThread,
+            KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::StartThread,
+            KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            true,
+        );
+        register(
+            Action::ToggleThreadMembership,
+            KeyEvent::new(KeyCode::Char('t'), KeyModifiers::ALT),
+            true,
+        );
+        register(
+            Action::NewForumPost,
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::CycleForumSort,
+            KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT),
+            true,
+        );
+        register(
+            Action::CycleForumTagFilter,
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::OpenProfile,
+            KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE),
+            true,
+        );
+        register(
+            Action::SearchMessages,
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL),
+            true,
+        );
+        register(
+            Action::OpenClipboardLink,
+            KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL),
+            true,
+        );
+        register(
+            Action::OpenStatusMenu,
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT),
+            true,
+        );
+        register(
+            Action::NewDirectMessage,
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::ALT),
+            true,
+        );
+        register(
+            Action::RenameGroupDm,
+            KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT),
+            true,
+        );
+        register(
+            Action::NotificationSettings,
+            KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT),
+            true,
+        );
+        register(
+            Action::OpenInbox,
+            KeyEvent::new(KeyCode::Char('i'), KeyModifiers::ALT),
+            true,
+        );
+
         register(
             Action::SendMessage,
             KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
@@ -288,6 +404,11 @@ impl Default for CommandRegistry {
             KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL),
             true,
         );
+        register(
+            Action::OpenEmojiPicker,
+            KeyEvent::new(KeyCode::Char('e'), KeyModifiers::ALT),
+            true,
+        );
         register(
             Action::ClearInput,
             KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
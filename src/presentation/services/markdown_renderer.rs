@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, TimeZone};
 use percent_encoding::percent_decode_str;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
@@ -5,10 +6,22 @@ use std::sync::Arc;
 use url::Url;
 
 use super::syntax_highlighting::{SyntaxHighlighter, SyntectHighlighter};
-use crate::application::services::markdown_parser::{MdBlock, MdInline, MentionResolver};
+use crate::application::services::markdown_parser::{
+    MdBlock, MdInline, MentionResolver, TimestampStyle,
+};
+use crate::presentation::ui::utils::u32_to_color;
+
+const DEFAULT_TIME_FORMAT: &str = "%H:%M";
+
+/// Two-cell stand-in emitted for custom emoji when their images are drawn on top.
+///
+/// Non-breaking spaces keep the slot in one piece when the text is wrapped.
+pub const EMOJI_PLACEHOLDER: &str = "\u{a0}\u{a0}";
 
 pub struct MarkdownRenderer {
     highlighter: Arc<dyn SyntaxHighlighter>,
+    time_format: String,
+    accent: Color,
 }
 
 impl MarkdownRenderer {
@@ -16,12 +29,32 @@ impl MarkdownRenderer {
     pub fn new() -> Self {
         Self {
             highlighter: Arc::new(SyntectHighlighter::new()),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            accent: Color::Yellow,
         }
     }
 
     #[must_use]
     pub fn with_highlighter(highlighter: Arc<dyn SyntaxHighlighter>) -> Self {
-        Self { highlighter }
+        Self {
+            highlighter,
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            accent: Color::Yellow,
+        }
+    }
+
+    /// Sets the `chrono` format used for the time part of `<t:...>` tags.
+    #[must_use]
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = format.into();
+        self
+    }
+
+    /// Sets the color used for custom emoji shown as `:name:`.
+    #[must_use]
+    pub const fn with_accent_color(mut self, accent: Color) -> Self {
+        self.accent = accent;
+        self
     }
 
     #[must_use]
@@ -32,7 +65,38 @@ impl MarkdownRenderer {
         show_spoilers: bool,
         parent_style: Style,
     ) -> Text<'static> {
-        let mut renderer = InternalRenderer::new(resolver, &self.highlighter, show_spoilers);
+        self.render_with(blocks, resolver, show_spoilers, false, parent_style)
+    }
+
+    /// Like [`Self::render`], but emits an [`EMOJI_PLACEHOLDER`] for every visible
+    /// custom emoji so the caller can draw the emoji image over it.
+    #[must_use]
+    pub fn render_with_emoji_images(
+        &self,
+        blocks: Vec<MdBlock>,
+        resolver: Option<&dyn MentionResolver>,
+        show_spoilers: bool,
+        parent_style: Style,
+    ) -> Text<'static> {
+        self.render_with(blocks, resolver, show_spoilers, true, parent_style)
+    }
+
+    fn render_with(
+        &self,
+        blocks: Vec<MdBlock>,
+        resolver: Option<&dyn MentionResolver>,
+        show_spoilers: bool,
+        emoji_images: bool,
+        parent_style: Style,
+    ) -> Text<'static> {
+        let mut renderer = InternalRenderer {
+            resolver,
+            highlighter: &self.highlighter,
+            time_format: &self.time_format,
+            accent: self.accent,
+            show_spoilers,
+            emoji_images,
+        };
         renderer.render(blocks, parent_style)
     }
 
@@ -58,22 +122,13 @@ impl Default for MarkdownRenderer {
 struct InternalRenderer<'a> {
     resolver: Option<&'a dyn MentionResolver>,
     highlighter: &'a Arc<dyn SyntaxHighlighter>,
+    time_format: &'a str,
+    accent: Color,
     show_spoilers: bool,
+    emoji_images: bool,
 }
 
-impl<'a> InternalRenderer<'a> {
-    fn new(
-        resolver: Option<&'a dyn MentionResolver>,
-        highlighter: &'a Arc<dyn SyntaxHighlighter>,
-        show_spoilers: bool,
-    ) -> Self {
-        Self {
-            resolver,
-            highlighter,
-            show_spoilers,
-        }
-    }
-
+impl InternalRenderer<'_> {
     fn render(&mut self, blocks: Vec<MdBlock>, parent_style: Style) -> Text<'static> {
         let mut lines = Vec::new();
         for block in blocks {
@@ -179,6 +234,21 @@ impl<'a> InternalRenderer<'a> {
         }
     }
 
+    fn render_role(&self, id: &str, style: Style) -> Span<'static> {
+        let (name, color) = self.resolver.and_then(|r| r.resolve_role(id)).map_or_else(
+            || (format!("<@&{id}>"), Color::Blue),
+            |(name, color)| {
+                let color = if color == 0 {
+                    Color::Blue
+                } else {
+                    u32_to_color(color)
+                };
+                (format!("@{name}"), color)
+            },
+        );
+        Span::styled(name, style.fg(color).add_modifier(Modifier::BOLD))
+    }
+
     fn render_inlines(&self, inlines: Vec<MdInline>, style: Style) -> Vec<Span<'static>> {
         let mut spans = Vec::new();
 
@@ -209,7 +279,11 @@ impl<'a> InternalRenderer<'a> {
                         spans.extend(self.render_inlines(children, revealed_style));
                     } else {
                         let hidden_style = Style::default().bg(Color::DarkGray).fg(Color::DarkGray);
-                        spans.extend(self.render_inlines(children, hidden_style));
+                        let hidden = InternalRenderer {
+                            emoji_images: false,
+                            ..*self
+                        };
+                        spans.extend(hidden.render_inlines(children, hidden_style));
                     }
                 }
                 MdInline::Code(code) => {
@@ -225,6 +299,19 @@ impl<'a> InternalRenderer<'a> {
                         style.fg(Color::Blue).add_modifier(Modifier::BOLD),
                     ));
                 }
+                MdInline::Role(id) => spans.push(self.render_role(&id, style)),
+                MdInline::Emoji { name, .. } => {
+                    if self.emoji_images {
+                        spans.push(Span::styled(EMOJI_PLACEHOLDER, style));
+                    } else {
+                        spans.push(Span::styled(format!(":{name}:"), style.fg(self.accent)));
+                    }
+                }
+                MdInline::Timestamp { unix, style: ts } => {
+                    let text = format_timestamp(unix, ts, self.time_format, Local::now())
+                        .unwrap_or_else(|| format!("<t:{unix}>"));
+                    spans.push(Span::styled(text, style.fg(Color::Cyan)));
+                }
                 MdInline::Channel(id) => {
                     let name = self
                         .resolver
@@ -236,30 +323,7 @@ impl<'a> InternalRenderer<'a> {
                     ));
                 }
                 MdInline::Url(url_str) => {
-                    let display_text = if let Ok(parsed) = Url::parse(&url_str) {
-                        let scheme = parsed.scheme();
-                        let host = parsed.host_str().unwrap_or("");
-                        let path = percent_decode_str(parsed.path()).decode_utf8_lossy();
-                        let query = if let Some(q) = parsed.query() {
-                            format!("?{}", percent_decode_str(q).decode_utf8_lossy())
-                        } else {
-                            String::new()
-                        };
-                        let fragment = if let Some(f) = parsed.fragment() {
-                            format!("#{}", percent_decode_str(f).decode_utf8_lossy())
-                        } else {
-                            String::new()
-                        };
-
-                        format!("{scheme}://{host}{path}{query}{fragment}")
-                    } else {
-                        url_str.clone()
-                    };
-
-                    spans.push(Span::styled(
-                        display_text,
-                        style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED),
-                    ));
+                    spans.push(Span::styled(display_url(&url_str), link_style(style)));
                 }
             }
         }
@@ -267,6 +331,88 @@ impl<'a> InternalRenderer<'a> {
     }
 }
 
+/// Style of link text drawn on top of `style`.
+#[must_use]
+pub fn link_style(style: Style) -> Style {
+    style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED)
+}
+
+/// Whether a span was drawn in [`link_style`].
+#[must_use]
+pub fn is_link_style(style: Style) -> bool {
+    style.fg == Some(Color::Blue) && style.add_modifier.contains(Modifier::UNDERLINED)
+}
+
+/// Text shown for a link: the URL with percent-escapes decoded.
+#[must_use]
+pub fn display_url(url: &str) -> String {
+    let Ok(parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+    let scheme = parsed.scheme();
+    let host = parsed.host_str().unwrap_or("");
+    let path = percent_decode_str(parsed.path()).decode_utf8_lossy();
+    let query = if let Some(q) = parsed.query() {
+        format!("?{}", percent_decode_str(q).decode_utf8_lossy())
+    } else {
+        String::new()
+    };
+    let fragment = if let Some(f) = parsed.fragment() {
+        format!("#{}", percent_decode_str(f).decode_utf8_lossy())
+    } else {
+        String::new()
+    };
+
+    format!("{scheme}://{host}{path}{query}{fragment}")
+}
+
+/// Formats a `<t:unix:style>` tag in local time, or `None` when out of range.
+fn format_timestamp(
+    unix: i64,
+    style: TimestampStyle,
+    time_format: &str,
+    now: DateTime<Local>,
+) -> Option<String> {
+    let time = Local.timestamp_opt(unix, 0).single()?;
+    let text = match style {
+        TimestampStyle::ShortTime => time.format(time_format).to_string(),
+        TimestampStyle::LongTime => time.format("%H:%M:%S").to_string(),
+        TimestampStyle::ShortDate => time.format("%d/%m/%Y").to_string(),
+        TimestampStyle::LongDate => time.format("%B %-d, %Y").to_string(),
+        TimestampStyle::ShortDateTime => {
+            format!("{} {}", time.format("%B %-d, %Y"), time.format(time_format))
+        }
+        TimestampStyle::LongDateTime => {
+            format!(
+                "{} {}",
+                time.format("%A, %B %-d, %Y"),
+                time.format(time_format)
+            )
+        }
+        TimestampStyle::Relative => format_relative(time - now),
+    };
+    Some(text)
+}
+
+fn format_relative(delta: chrono::TimeDelta) -> String {
+    let seconds = delta.num_seconds().unsigned_abs();
+    let (amount, unit) = match seconds {
+        0..60 => (seconds, "second"),
+        60..3_600 => (seconds / 60, "minute"),
+        3_600..86_400 => (seconds / 3_600, "hour"),
+        86_400..2_592_000 => (seconds / 86_400, "day"),
+        2_592_000..31_536_000 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+
+    if delta.num_seconds() > 0 {
+        format!("in {amount} {unit}{plural}")
+    } else {
+        format!("{amount} {unit}{plural} ago")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,7 +423,7 @@ mod tests {
         let content = "||Secret||";
         let blocks = parse_markdown(content);
         let renderer = MarkdownRenderer::new();
-        let text = renderer.render(blocks, None, false);
+        let text = renderer.render(blocks, None, false, Style::default());
 
         let line = &text.lines[0];
         let span = &line.spans[0];
@@ -291,7 +437,7 @@ mod tests {
         let content = "||Secret||";
         let blocks = parse_markdown(content);
         let renderer = MarkdownRenderer::new();
-        let text = renderer.render(blocks, None, true);
+        let text = renderer.render(blocks, None, true, Style::default());
 
         let line = &text.lines[0];
         let span = &line.spans[0];
@@ -300,12 +446,80 @@ mod tests {
         assert_ne!(span.style.fg, Some(Color::Rgb(50, 50, 50)));
     }
 
+    struct RoleResolver;
+
+    impl MentionResolver for RoleResolver {
+        fn resolve(&self, _user_id: &str) -> Option<String> {
+            None
+        }
+
+        fn resolve_channel(&self, _channel_id: &str) -> Option<String> {
+            None
+        }
+
+        fn resolve_role(&self, role_id: &str) -> Option<(String, u32)> {
+            (role_id == "7").then(|| ("mods".to_string(), 0x00FF_0000))
+        }
+    }
+
+    #[test]
+    fn test_render_role_mention_in_role_color() {
+        let renderer = MarkdownRenderer::new();
+        let text = renderer.render(
+            parse_markdown("<@&7> <@&8>"),
+            Some(&RoleResolver),
+            false,
+            Style::default(),
+        );
+
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans[0].content, "@mods");
+        assert_eq!(spans[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(spans[2].content, "<@&8>");
+    }
+
+    #[test]
+    fn test_render_custom_emoji() {
+        let renderer = MarkdownRenderer::new().with_accent_color(Color::Magenta);
+        let text =
+            renderer.render_markdown("<:blob:1> ||<:blob:2>||", None, false, Style::default());
+        assert_eq!(text.lines[0].spans[0].content, ":blob:");
+        assert_eq!(text.lines[0].spans[0].style.fg, Some(Color::Magenta));
+
+        let blocks = parse_markdown("<:blob:1> ||<:blob:2>||");
+        let text = renderer.render_with_emoji_images(blocks, None, false, Style::default());
+        assert_eq!(text.lines[0].spans[0].content, EMOJI_PLACEHOLDER);
+        assert_eq!(text.lines[0].spans[2].content, ":blob:");
+    }
+
+    #[test]
+    fn test_format_relative_timestamp() {
+        let now = Local.timestamp_opt(1_700_000_000, 0).unwrap();
+        let format = |unix| format_timestamp(unix, TimestampStyle::Relative, "%H:%M", now);
+
+        assert_eq!(format(1_700_000_000 - 3 * 3_600).unwrap(), "3 hours ago");
+        assert_eq!(format(1_700_000_000 + 60).unwrap(), "in 1 minute");
+        assert_eq!(format(1_700_000_000 - 2 * 86_400).unwrap(), "2 days ago");
+    }
+
+    #[test]
+    fn test_short_time_uses_time_format() {
+        let now = Local::now();
+        let time = Local.timestamp_opt(1_700_000_000, 0).unwrap();
+
+        assert_eq!(
+            format_timestamp(1_700_000_000, TimestampStyle::ShortTime, "%H.%M", now).unwrap(),
+            time.format("%H.%M").to_string()
+        );
+        assert!(format_timestamp(i64::MAX, TimestampStyle::ShortDate, "%H:%M", now).is_none());
+    }
+
     #[test]
     fn test_render_decoded_url() {
         let content = "https://example.com/%E6%B5%8B%E8%AF%95";
         let blocks = parse_markdown(content);
         let renderer = MarkdownRenderer::new();
-        let text = renderer.render(blocks, None, false);
+        let text = renderer.render(blocks, None, false, Style::default());
 
         let line = &text.lines[0];
         // The parser might produce [Url] or [Text, Url], but since the whole string is the URL:
@@ -6,7 +6,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use crossterm::event::{Event, EventStream, KeyCode, KeyEvent};
+use crossterm::event::{
+    Event, EventStream, KeyCode, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind,
+};
 use futures_util::StreamExt;
 use ratatui::{DefaultTerminal, Frame};
 use tokio::sync::mpsc;
@@ -16,16 +18,22 @@ use zeroize::Zeroize;
 
 use crate::application::dto::{LoginRequest, TokenSource};
 use crate::application::services::notification_manager::NotificationManager;
+use crate::application::services::notification_rules::{NotificationRules, RuleOutcome};
 use crate::application::services::notification_service::NotificationService;
 use crate::application::use_cases::{LoginUseCase, ResolveTokenUseCase};
 use crate::domain::ConnectionStatus;
 use crate::domain::entities::{
-    AuthToken, Channel, ChannelId, GuildFolder, GuildId, MessageId, RelationshipState, UserCache,
+    AuthToken, Channel, ChannelId, CustomStatus, DiscordLink, ForumFilter, GuildFolder, GuildId,
+    MemberListItem, MemberListOp, MentionKind, MessageId, NotificationSettingsStore,
+    NotificationTarget, PresenceStatus, PresenceStore, ReactionEmoji, RelationshipState, UserCache,
+    UserId, UserPresence,
 };
 use crate::domain::errors::AuthError;
 use crate::domain::ports::{
-    AuthPort, DiscordDataPort, EditMessageRequest, SendMessageRequest, TokenStoragePort,
+    AuthPort, DiscordDataPort, EditMessageRequest, RelationshipChange, SendMessageRequest,
+    TokenStoragePort,
 };
+use crate::infrastructure::config::NotificationRuleConfig;
 use crate::infrastructure::config::app_config::QuickSwitcherSortMode;
 use crate::infrastructure::discord::{
     DispatchEvent, GatewayClient, GatewayClientConfig, GatewayCommand, GatewayEventKind,
@@ -75,10 +83,18 @@ pub struct AppConfig {
     pub notification_duration: u64,
     pub enable_animations: bool,
     pub editor: Option<String>,
+    /// Whether mouse capture is on, so it can be paused while an external editor runs.
+    pub mouse: bool,
     pub keybindings: HashMap<String, KeyAction>,
     pub theme: Theme,
     pub hide_blocked_completely: bool,
     pub quick_switcher_order: QuickSwitcherSortMode,
+    /// Link passed on the command line, opened once the chat screen is shown.
+    pub open_link: Option<DiscordLink>,
+    /// Status applied when the gateway becomes ready.
+    pub status: Option<PresenceStatus>,
+    pub custom_status: Option<CustomStatus>,
+    pub notification_rules: Vec<NotificationRuleConfig>,
 }
 
 #[allow(clippy::struct_excessive_bools)]
@@ -107,6 +123,7 @@ pub struct App {
     pending_channels: std::collections::HashMap<GuildId, Vec<Channel>>,
     pending_roles: std::collections::HashMap<GuildId, Vec<crate::domain::entities::Role>>,
     pending_members: std::collections::HashMap<GuildId, Vec<crate::domain::entities::Member>>,
+    pending_emojis: std::collections::HashMap<GuildId, Vec<crate::domain::entities::CustomEmoji>>,
     gateway_ready: bool,
     connection_status: ConnectionStatus,
     should_render: bool,
@@ -125,6 +142,7 @@ pub struct App {
     internal_notifications: bool,
     enable_animations: bool,
     editor: Option<String>,
+    mouse: bool,
     command_registry: CommandRegistry,
     theme: Theme,
     identity: Arc<ClientIdentity>,
@@ -142,8 +160,19 @@ pub struct App {
     notification_service: NotificationService,
     last_desktop_notification: Option<Instant>,
     relationship_state: RelationshipState,
+    presence_store: PresenceStore,
+    notification_settings: NotificationSettingsStore,
+    notification_rules: NotificationRules,
     hide_blocked_completely: bool,
     pub quick_switcher_order: QuickSwitcherSortMode,
+    /// Command-line link waiting for the chat screen.
+    pending_link: Option<DiscordLink>,
+    own_status: PresenceStatus,
+    own_custom_status: Option<CustomStatus>,
+    /// Status chosen in the config or the status menu, sent on READY over the server value.
+    configured_status: Option<PresenceStatus>,
+    /// Custom status chosen in the config or the status menu, sent on READY over the server value.
+    configured_custom_status: Option<CustomStatus>,
 }
 
 impl App {
@@ -163,7 +192,11 @@ impl App {
         let resolve_token_use_case = ResolveTokenUseCase::new(storage_port);
         let (action_tx, action_rx) = mpsc::unbounded_channel();
         let (command_tx, command_rx) = mpsc::unbounded_channel();
-        let markdown_service = Arc::new(MarkdownRenderer::new());
+        let markdown_service = Arc::new(
+            MarkdownRenderer::new()
+                .with_timestamp_format(config.timestamp_format.clone())
+                .with_accent_color(config.theme.accent),
+        );
         let notification_port = Arc::new(DesktopNotificationService::new(
             config.desktop_notifications,
         ));
@@ -218,6 +251,14 @@ impl App {
             }
         });
 
+        let mut notification_manager =
+            NotificationManager::new(Duration::from_secs(config.notification_duration));
+        let (notification_rules, rule_errors) =
+            NotificationRules::compile(&config.notification_rules);
+        for error in rule_errors {
+            notification_manager.warn("Invalid notification rule", error);
+        }
+
         Self {
             state: AppState::Login,
             screen: CurrentScreen::Login(Box::new(LoginScreen::new().with_theme(config.theme))),
@@ -243,6 +284,7 @@ impl App {
             pending_channels: std::collections::HashMap::new(),
             pending_roles: std::collections::HashMap::new(),
             pending_members: std::collections::HashMap::new(),
+            pending_emojis: std::collections::HashMap::new(),
             gateway_ready: false,
             connection_status: ConnectionStatus::Disconnected,
             should_render: true,
@@ -258,20 +300,27 @@ impl App {
             internal_notifications: config.internal_notifications,
             enable_animations: config.enable_animations,
             editor: config.editor,
+            mouse: config.mouse,
             command_registry,
             theme: config.theme,
             identity,
             state_store,
             state_save_tx,
             clipboard_service: ClipboardService::new(),
-            notification_manager: NotificationManager::new(Duration::from_secs(
-                config.notification_duration,
-            )),
+            notification_manager,
             notification_service,
             last_desktop_notification: None,
             relationship_state: RelationshipState::new(),
+            presence_store: PresenceStore::new(),
+            notification_settings: NotificationSettingsStore::new(),
+            notification_rules,
             hide_blocked_completely: config.hide_blocked_completely,
             quick_switcher_order: config.quick_switcher_order,
+            pending_link: config.open_link,
+            own_status: config.status.unwrap_or(PresenceStatus::Online),
+            own_custom_status: config.custom_status.clone(),
+            configured_status: config.status,
+            configured_custom_status: config.custom_status,
         }
     }
 
@@ -356,6 +405,9 @@ impl App {
                                  self.state = AppState::Chat;
                                  self.screen = CurrentScreen::Chat(self.pending_chat_state.take().unwrap());
                                  self.should_render = true;
+                                 if let Some(link) = self.pending_link.take() {
+                                     self.open_discord_link(link);
+                                 }
                              } else {
                                  warn!("Token verification failed after splash");
                                  self.transition_to_login();
@@ -382,13 +434,7 @@ impl App {
                 }
 
                 Some(Ok(event)) = terminal_event => {
-                    let is_release_event = matches!(
-                        event,
-                        crossterm::event::Event::Key(key)
-                        if key.kind == crossterm::event::KeyEventKind::Release
-                    );
-
-                    if !is_release_event {
+                    if !is_ignored_event(&event) {
                         let result = self.handle_terminal_event(&event);
                         match result {
                             EventResult::Exit => {
@@ -410,6 +456,7 @@ impl App {
 
                 _ = typing_cleanup_interval.tick() => {
                     self.cleanup_typing_indicators();
+                    self.expire_custom_status();
                     self.should_render = true;
                 }
             }
@@ -425,11 +472,21 @@ impl App {
     fn handle_terminal_event(&mut self, event: &Event) -> EventResult {
         match event {
             Event::Key(key) => self.handle_key(*key),
+            Event::Mouse(mouse) => self.handle_mouse(*mouse),
             Event::Paste(text) => self.handle_paste(text),
             _ => EventResult::Continue,
         }
     }
 
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> EventResult {
+        let result = match &mut self.screen {
+            CurrentScreen::Chat(state) => state.handle_mouse(mouse),
+            CurrentScreen::Login(_) | CurrentScreen::Splash(_) => return EventResult::Continue,
+        };
+
+        self.process_chat_key_result(result)
+    }
+
     fn handle_paste(&mut self, text: &str) -> EventResult {
         let clean_text = text.trim_end_matches(['\n', '\r']);
 
@@ -635,17 +692,23 @@ impl App {
                     self.subscribe_to_channel(guild_id, channel_id);
                 }
                 self.load_channel_messages(channel_id);
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.should_fetch_archived_threads(channel_id)
+                {
+                    self.load_archived_threads(channel_id);
+                }
             }
             ChatKeyResult::LoadForumThreads {
                 channel_id,
                 guild_id,
+                filter,
                 offset,
             } => {
                 self.save_state(guild_id, Some(channel_id));
                 if let Some(guild_id) = guild_id {
                     self.subscribe_to_channel(guild_id, channel_id);
                 }
-                self.load_forum_threads(channel_id, guild_id, offset);
+                self.load_forum_threads(channel_id, guild_id, filter, offset);
             }
             ChatKeyResult::LoadDmMessages {
                 channel_id,
@@ -682,6 +745,14 @@ impl App {
                 debug!(message_id = %message_id, "Delete message requested");
                 self.handle_delete_message(message_id);
             }
+            ChatKeyResult::AddReaction { message_id, emoji } => {
+                debug!(message_id = %message_id, emoji = %emoji.display(), "Add reaction requested");
+                self.handle_reaction(message_id, emoji, true);
+            }
+            ChatKeyResult::RemoveReaction { message_id, emoji } => {
+                debug!(message_id = %message_id, emoji = %emoji.display(), "Remove reaction requested");
+                self.handle_reaction(message_id, emoji, false);
+            }
             ChatKeyResult::OpenAttachments(message_id) => {
                 debug!(message_id = %message_id, "Open attachments requested");
                 if let CurrentScreen::Chat(state) = &self.screen
@@ -731,11 +802,93 @@ impl App {
                     }
                 });
             }
+            ChatKeyResult::LoadPinnedMessages(channel_id) => {
+                self.load_pinned_messages(channel_id);
+            }
+            ChatKeyResult::LoadUserProfile { user_id, guild_id } => {
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::FetchUserProfile {
+                        token: token.clone(),
+                        user_id,
+                        guild_id,
+                    });
+                }
+            }
+            ChatKeyResult::SetMessagePinned { message_id, pinned } => {
+                debug!(message_id = %message_id, pinned, "Pin state change requested");
+                self.handle_set_message_pinned(message_id, pinned);
+            }
+            ChatKeyResult::SearchMessages(request) => {
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::SearchMessages {
+                        token: token.clone(),
+                        request,
+                    });
+                }
+            }
+            ChatKeyResult::CreateForumPost(request) => {
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::CreateForumPost {
+                        token: token.clone(),
+                        request,
+                    });
+                }
+            }
+            ChatKeyResult::CreateThread(request) => {
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::CreateThread {
+                        token: token.clone(),
+                        request,
+                    });
+                }
+            }
+            ChatKeyResult::SetThreadMembership { channel_id, join } => {
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::SetThreadMembership {
+                        token: token.clone(),
+                        channel_id,
+                        join,
+                    });
+                }
+            }
+            ChatKeyResult::LoadArchivedThreads(channel_id) => {
+                self.load_archived_threads(channel_id);
+            }
+            ChatKeyResult::LoadMessagesAround {
+                channel_id,
+                message_id,
+            } => {
+                debug!(channel_id = %channel_id, message_id = %message_id, "Loading messages around target");
+                self.load_messages_around(channel_id, message_id);
+            }
             ChatKeyResult::JumpToMessage(message_id) => {
                 debug!(message_id = %message_id, "Jump to message requested");
-                if let CurrentScreen::Chat(state) = &mut self.screen {
-                    state.jump_to_message(message_id);
+                let mut unloaded_in = None;
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && !state.jump_to_message(message_id)
+                {
+                    unloaded_in = state.message_pane_data().channel_id();
                 }
+                if let Some(channel_id) = unloaded_in {
+                    self.load_messages_around(channel_id, message_id);
+                }
+            }
+            ChatKeyResult::LoadNewerMessages {
+                channel_id,
+                after_message_id,
+            } => {
+                debug!(channel_id = %channel_id, after = %after_message_id, "Loading newer messages");
+                if let Some(ref token) = self.current_token {
+                    let _ = self.command_tx.send(BackendCommand::LoadNewerMessages {
+                        channel_id,
+                        after_message_id,
+                        token: token.clone(),
+                    });
+                }
+            }
+            ChatKeyResult::JumpToPresent(channel_id) => {
+                debug!(channel_id = %channel_id, "Jump to present requested");
+                self.load_channel_messages(channel_id);
             }
             ChatKeyResult::SendMessage {
                 content,
@@ -808,6 +961,18 @@ impl App {
                     }
                 });
             }
+            ChatKeyResult::OpenClipboardLink => {
+                let clipboard = self.clipboard_service.clone();
+                let tx = self.action_tx.clone();
+
+                tokio::spawn(async move {
+                    let text = tokio::task::spawn_blocking(move || clipboard.get_text())
+                        .await
+                        .unwrap_or_default();
+                    let link = text.as_deref().and_then(DiscordLink::parse);
+                    let _ = tx.send(Action::ClipboardLinkLoaded(link));
+                });
+            }
             ChatKeyResult::ToggleHelp | ChatKeyResult::Consumed | ChatKeyResult::Ignored => {}
             ChatKeyResult::ToggleDisplayName => {
                 self.use_display_name = !self.use_display_name;
@@ -837,19 +1002,7 @@ impl App {
                 debug!(channel_id = %channel_id, "Jump to channel requested");
                 self.save_state(None, Some(channel_id));
                 self.load_channel_messages(channel_id);
-
-                if let CurrentScreen::Chat(state) = &mut self.screen {
-                    let name = state.get_channel(channel_id).map_or_else(
-                        || format!("{channel_id}"),
-                        crate::domain::entities::Channel::display_name,
-                    );
-
-                    state.message_pane_data_mut().set_channel(channel_id, name);
-                    state.message_pane_parts_mut().1.on_channel_change();
-                    state.message_input_parts_mut().set_has_channel(true);
-                    state.message_input_parts_mut().clear();
-                    state.focus_messages_list();
-                }
+                self.show_channel_in_pane(channel_id);
             }
             ChatKeyResult::RequestChannelFetch(ids) => {
                 if let Some(ref token) = self.current_token {
@@ -861,6 +1014,99 @@ impl App {
                     }
                 }
             }
+            ChatKeyResult::RequestMemberListRanges {
+                guild_id,
+                channel_id,
+                ranges,
+            } => {
+                if let Some(ref gateway_client) = self.gateway_client {
+                    gateway_client.send_command(GatewayCommand::RequestMemberListRanges {
+                        guild_id: guild_id.as_u64().to_string(),
+                        channel_id: channel_id.as_u64().to_string(),
+                        ranges,
+                    });
+                }
+            }
+            ChatKeyResult::ModifyRelationship(change) => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self
+                        .command_tx
+                        .send(BackendCommand::ModifyRelationship { token, change });
+                }
+            }
+            ChatKeyResult::OpenDirectMessage { recipients, name } => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self.command_tx.send(BackendCommand::OpenDmChannel {
+                        token,
+                        recipients,
+                        name,
+                    });
+                }
+            }
+            ChatKeyResult::RenameGroupDm { channel_id, name } => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self.command_tx.send(BackendCommand::RenameGroupDm {
+                        token,
+                        channel_id,
+                        name,
+                    });
+                }
+            }
+            ChatKeyResult::LoadInbox { preview_channels } => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self.command_tx.send(BackendCommand::LoadRecentMentions {
+                        token: token.clone(),
+                    });
+                    if !preview_channels.is_empty() {
+                        let _ = self.command_tx.send(BackendCommand::LoadChannelPreviews {
+                            token,
+                            channel_ids: preview_channels,
+                        });
+                    }
+                }
+            }
+            ChatKeyResult::AcknowledgeChannel {
+                channel_id,
+                message_id,
+            } => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self.command_tx.send(BackendCommand::AcknowledgeMessage {
+                        channel_id,
+                        message_id,
+                        token,
+                    });
+                }
+            }
+            ChatKeyResult::UpdateNotificationSettings { update, previous } => {
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self
+                        .command_tx
+                        .send(BackendCommand::UpdateNotificationSettings {
+                            token,
+                            update,
+                            previous,
+                        });
+                }
+            }
+            ChatKeyResult::UpdateStatus {
+                status,
+                custom_status,
+            } => {
+                self.own_status = status;
+                self.own_custom_status = custom_status;
+                self.configured_status = Some(status);
+                self.configured_custom_status
+                    .clone_from(&self.own_custom_status);
+                self.send_own_presence();
+                self.sync_own_status();
+                if let Some(token) = self.current_token.clone() {
+                    let _ = self.command_tx.send(BackendCommand::UpdateStatus {
+                        token,
+                        status: self.own_status,
+                        custom_status: self.own_custom_status.clone(),
+                    });
+                }
+            }
             ChatKeyResult::ShowNotification(message) => {
                 self.show_notification(message);
             }
@@ -1113,19 +1359,75 @@ impl App {
                 self.handle_typing_start(channel_id, user_id, username);
             }
             DispatchEvent::PresenceUpdate {
-                user_id, status, ..
+                user_id,
+                status,
+                activities,
+                ..
             } => {
                 debug!(user_id = %user_id, status = ?status, "Presence updated");
+                if let Ok(id) = user_id.parse::<u64>() {
+                    self.presence_store
+                        .update(UserId(id), UserPresence::new(status, activities));
+                    self.should_render = true;
+                    if let CurrentScreen::Chat(ref mut state) = self.screen {
+                        state.on_presence_update(&user_id);
+                    }
+                }
+            }
+            DispatchEvent::GuildMemberListUpdate {
+                guild_id,
+                list_id,
+                member_count,
+                online_count,
+                groups,
+                ops,
+            } => {
+                self.store_member_list_presences(&ops);
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.on_member_list_update(
+                        guild_id,
+                        &list_id,
+                        (member_count, online_count),
+                        &groups,
+                        ops,
+                    );
+                }
             }
             DispatchEvent::MessageReactionAdd {
-                message_id, emoji, ..
+                user_id,
+                channel_id,
+                message_id,
+                emoji,
+                ..
             } => {
                 debug!(message_id = %message_id, emoji = %emoji.display(), "Reaction added");
+                let me = self.current_user_id.as_deref() == Some(user_id.as_str());
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.add_reaction(channel_id, message_id, emoji.into(), me);
+                }
             }
             DispatchEvent::MessageReactionRemove {
-                message_id, emoji, ..
+                user_id,
+                channel_id,
+                message_id,
+                emoji,
+                ..
             } => {
                 debug!(message_id = %message_id, emoji = %emoji.display(), "Reaction removed");
+                let me = self.current_user_id.as_deref() == Some(user_id.as_str());
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.remove_reaction(channel_id, message_id, &emoji.into(), me);
+                }
+            }
+            DispatchEvent::MessageReactionRemoveAll {
+                channel_id,
+                message_id,
+                ..
+            } => {
+                debug!(message_id = %message_id, "All reactions removed");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.clear_reactions(channel_id, message_id);
+                }
             }
             DispatchEvent::ChannelCreate {
                 channel_id, name, ..
@@ -1151,23 +1453,25 @@ impl App {
                 mut threads,
                 roles,
                 members,
-            } => {
-                if !unavailable {
-                    info!(guild_id = %guild_id, name = %name, channel_count = channels.len(), thread_count = threads.len(), "Guild available");
-                    let mut all_channels = channels;
-                    all_channels.append(&mut threads);
+                emojis,
+            } if !unavailable => {
+                info!(guild_id = %guild_id, name = %name, channel_count = channels.len(), thread_count = threads.len(), "Guild available");
+                let mut all_channels = channels;
+                all_channels.append(&mut threads);
 
-                    if let CurrentScreen::Chat(ref mut state) = self.screen {
-                        state.set_guild_data(guild_id, roles, members);
-                        state.set_channels(guild_id, all_channels);
-                    } else if let Some(ref mut state) = self.pending_chat_state {
-                        state.set_guild_data(guild_id, roles, members);
-                        state.set_channels(guild_id, all_channels);
-                    } else {
-                        self.pending_roles.insert(guild_id, roles);
-                        self.pending_members.insert(guild_id, members);
-                        self.pending_channels.insert(guild_id, all_channels);
-                    }
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_guild_data(guild_id, roles, members);
+                    state.set_guild_emojis(guild_id, emojis);
+                    state.set_channels(guild_id, all_channels);
+                } else if let Some(ref mut state) = self.pending_chat_state {
+                    state.set_guild_data(guild_id, roles, members);
+                    state.set_guild_emojis(guild_id, emojis);
+                    state.set_channels(guild_id, all_channels);
+                } else {
+                    self.pending_roles.insert(guild_id, roles);
+                    self.pending_members.insert(guild_id, members);
+                    self.pending_emojis.insert(guild_id, emojis);
+                    self.pending_channels.insert(guild_id, all_channels);
                 }
             }
             DispatchEvent::GuildUpdate { guild_id, name } => {
@@ -1188,10 +1492,21 @@ impl App {
                     } else {
                         self.pending_roles.remove(&guild_id);
                         self.pending_members.remove(&guild_id);
+                        self.pending_emojis.remove(&guild_id);
                         self.pending_channels.remove(&guild_id);
                     }
                 }
             }
+            DispatchEvent::GuildEmojisUpdate { guild_id, emojis } => {
+                debug!(guild_id = %guild_id, count = emojis.len(), "Guild emojis updated");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_guild_emojis(guild_id, emojis);
+                } else if let Some(ref mut state) = self.pending_chat_state {
+                    state.set_guild_emojis(guild_id, emojis);
+                } else {
+                    self.pending_emojis.insert(guild_id, emojis);
+                }
+            }
             DispatchEvent::UserUpdate {
                 user_id, username, ..
             } => {
@@ -1204,14 +1519,26 @@ impl App {
                 mut initial_guild_channels,
                 mut initial_guild_roles,
                 mut initial_guild_members,
+                mut initial_guild_emojis,
                 read_states,
                 guild_folders,
                 relationships,
+                presences,
+                status,
+                custom_status,
+                notification_settings,
                 ..
             } => {
                 info!(user_id = %user_id, guild_count = guilds.len(), read_states_count = read_states.len(), relationship_count = relationships.len(), "Gateway ready");
                 self.gateway_ready = true;
 
+                debug!(
+                    presence_count = presences.len(),
+                    "Initialized presence store"
+                );
+                self.presence_store.initialize_from_presences(presences);
+                self.apply_ready_status(status, custom_status);
+
                 self.relationship_state
                     .initialize_from_relationships(&relationships);
                 debug!(
@@ -1219,6 +1546,9 @@ impl App {
                     "Initialized relationship state"
                 );
 
+                self.notification_settings
+                    .replace_all(notification_settings);
+
                 let read_states_map: std::collections::HashMap<_, _> = read_states
                     .iter()
                     .map(|rs| (rs.channel_id, rs.clone()))
@@ -1230,7 +1560,9 @@ impl App {
                     for (guild_id, channels) in initial_guild_channels.drain() {
                         let roles = initial_guild_roles.remove(&guild_id).unwrap_or_default();
                         let members = initial_guild_members.remove(&guild_id).unwrap_or_default();
+                        let emojis = initial_guild_emojis.remove(&guild_id).unwrap_or_default();
                         state.set_guild_data(guild_id, roles, members);
+                        state.set_guild_emojis(guild_id, emojis);
                         state.set_channels(guild_id, channels);
                     }
                 } else {
@@ -1241,7 +1573,9 @@ impl App {
                             let roles = initial_guild_roles.remove(&guild_id).unwrap_or_default();
                             let members =
                                 initial_guild_members.remove(&guild_id).unwrap_or_default();
+                            let emojis = initial_guild_emojis.remove(&guild_id).unwrap_or_default();
                             state.set_guild_data(guild_id, roles, members);
+                            state.set_guild_emojis(guild_id, emojis);
                             state.set_channels(guild_id, channels);
                         }
                     } else {
@@ -1249,9 +1583,11 @@ impl App {
                             let roles = initial_guild_roles.remove(&guild_id).unwrap_or_default();
                             let members =
                                 initial_guild_members.remove(&guild_id).unwrap_or_default();
+                            let emojis = initial_guild_emojis.remove(&guild_id).unwrap_or_default();
 
                             self.pending_roles.insert(guild_id, roles);
                             self.pending_members.insert(guild_id, members);
+                            self.pending_emojis.insert(guild_id, emojis);
                             self.pending_channels.insert(guild_id, channels);
                         }
                     }
@@ -1274,28 +1610,110 @@ impl App {
                     state.set_guild_folders(guild_folders);
                 }
             }
-            DispatchEvent::RelationshipAdd {
-                user_id,
-                relationship_type,
-            } => {
-                self.relationship_state
-                    .update_relationship(user_id, relationship_type);
+            DispatchEvent::UserGuildSettingsUpdate { settings } => {
+                debug!(guild_id = ?settings.guild_id, "Notification settings updated");
+                self.notification_settings.upsert(settings);
+                self.should_render = true;
+            }
+            DispatchEvent::RelationshipAdd { relationship } => {
+                self.relationship_state.upsert_relationship(relationship);
                 self.should_render = true;
                 if let CurrentScreen::Chat(ref mut state) = self.screen {
                     state.mark_messages_dirty();
                 }
             }
             DispatchEvent::RelationshipRemove { user_id } => {
-                self.relationship_state.unblock_user(user_id);
+                self.relationship_state.remove_relationship(user_id);
                 self.should_render = true;
                 if let CurrentScreen::Chat(ref mut state) = self.screen {
                     state.mark_messages_dirty();
                 }
             }
+            DispatchEvent::ThreadCreate { thread } | DispatchEvent::ThreadUpdate { thread } => {
+                debug!(thread_id = %thread.id(), name = %thread.name(), "Thread created/updated");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.upsert_threads(vec![thread]);
+                } else if let Some(ref mut state) = self.pending_chat_state {
+                    state.upsert_threads(vec![thread]);
+                }
+            }
+            DispatchEvent::ThreadDelete { channel_id, .. } => {
+                info!(channel_id = %channel_id, "Thread deleted");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.remove_channel(channel_id);
+                } else if let Some(ref mut state) = self.pending_chat_state {
+                    state.remove_channel(channel_id);
+                }
+            }
+            DispatchEvent::ThreadListSync {
+                guild_id,
+                channel_ids,
+                threads,
+            } => {
+                debug!(guild_id = %guild_id, thread_count = threads.len(), "Thread list synced");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.sync_threads(guild_id, &channel_ids, threads);
+                } else if let Some(ref mut state) = self.pending_chat_state {
+                    state.sync_threads(guild_id, &channel_ids, threads);
+                }
+            }
+            DispatchEvent::ChannelPinsUpdate { channel_id, .. } => {
+                debug!(channel_id = %channel_id, "Channel pins updated");
+                if let CurrentScreen::Chat(ref state) = self.screen
+                    && state.pins_channel_id() == Some(channel_id)
+                {
+                    self.load_pinned_messages(channel_id);
+                }
+            }
             _ => {}
         }
     }
 
+    fn chat_state(&self) -> Option<&ChatScreenState> {
+        match self.screen {
+            CurrentScreen::Chat(ref state) => Some(&**state),
+            _ => self.pending_chat_state.as_deref(),
+        }
+    }
+
+    /// Applies the mute and notification settings of the message's channel.
+    fn should_notify(
+        &self,
+        message: &crate::domain::entities::Message,
+        mention: MentionKind,
+    ) -> bool {
+        let state = self.chat_state();
+        let channel = state.and_then(|state| state.get_channel(message.channel_id()));
+        let guild_id = message
+            .guild_id()
+            .or_else(|| channel.and_then(Channel::guild_id));
+        let target = match (state, channel) {
+            (Some(state), Some(channel)) => {
+                NotificationTarget::for_channel(guild_id, channel, |id| state.get_channel(id))
+            }
+            _ => NotificationTarget {
+                guild_id,
+                channel_id: message.channel_id(),
+                parent_id: None,
+                category_id: None,
+            },
+        };
+        self.notification_settings
+            .should_notify(target, mention, chrono::Utc::now())
+    }
+
+    /// Runs the configured notification rules against the message.
+    fn evaluate_rules(&self, message: &crate::domain::entities::Message) -> RuleOutcome {
+        if self.notification_rules.is_empty() {
+            return RuleOutcome::default();
+        }
+        let scope = self
+            .chat_state()
+            .map(|state| state.message_scope(message.channel_id()))
+            .unwrap_or_default();
+        self.notification_rules.evaluate(message, &scope)
+    }
+
     #[allow(clippy::too_many_lines)]
     fn handle_message_create(&mut self, message: crate::domain::entities::Message) {
         let channel_id = message.channel_id();
@@ -1304,16 +1722,27 @@ impl App {
 
         self.cache_users_from_message(&message);
 
-        let mut is_mentioned = false;
+        let is_own = Some(&user_id) == self.current_user_id.as_ref();
+        let mut mention = MentionKind::None;
         if let Some(current_user_id) = &self.current_user_id
             && message
                 .mentions()
                 .iter()
                 .any(|u| u.id().to_string() == *current_user_id)
         {
-            is_mentioned = true;
+            mention = MentionKind::Direct;
         }
 
+        if mention == MentionKind::None
+            && !message.mention_roles().is_empty()
+            && self
+                .chat_state()
+                .is_some_and(|state| state.mentions_own_role(&message))
+        {
+            mention = MentionKind::Role;
+        }
+
+        let mentions_us = !is_own && (mention != MentionKind::None || message.mentions_everyone());
         let mut is_dm = false;
         if let CurrentScreen::Chat(ref state) = self.screen
             && state
@@ -1325,8 +1754,13 @@ impl App {
             is_dm = true;
         }
 
-        if is_dm && Some(&user_id) != self.current_user_id.as_ref() {
-            is_mentioned = true;
+        if is_dm && !is_own {
+            mention = MentionKind::Direct;
+        }
+
+        let is_mentioned = !is_own && matches!(mention, MentionKind::Direct | MentionKind::Role);
+        if mention == MentionKind::None && message.mentions_everyone() {
+            mention = MentionKind::Everyone;
         }
 
         if is_mentioned {
@@ -1337,13 +1771,31 @@ impl App {
             }
         }
 
+        if mentions_us {
+            if let CurrentScreen::Chat(ref mut state) = self.screen {
+                state.record_mention(&message);
+            } else if let Some(ref mut state) = self.pending_chat_state {
+                state.record_mention(&message);
+            }
+        }
+
         let is_focused = if let CurrentScreen::Chat(ref state) = self.screen {
             state.message_pane_data().channel_id() == Some(channel_id)
         } else {
             false
         };
 
-        if is_mentioned && !is_focused {
+        let rules = self.evaluate_rules(&message);
+        let notify = !is_own && !is_focused && !rules.suppress;
+        let by_settings = notify && self.should_notify(&message, mention);
+        let internal = notify && (rules.internal || (by_settings && self.internal_notifications));
+        let desktop = notify && (rules.desktop || by_settings);
+
+        if notify && rules.bell {
+            ring_terminal_bell();
+        }
+
+        if internal || desktop {
             let content = message.content();
             let mut body = if content.is_empty() {
                 if !message.attachments().is_empty() {
@@ -1377,7 +1829,7 @@ impl App {
                 body.push_str("...");
             }
 
-            if self.internal_notifications {
+            if internal {
                 let title = if self.use_display_name {
                     message.author().raw_display_name()
                 } else {
@@ -1393,7 +1845,7 @@ impl App {
                 None => true,
             };
 
-            if should_notify {
+            if desktop && should_notify {
                 let title = format!("Oxicord - @{}", message.author().username());
                 self.notification_service.send(&title, &body);
                 self.last_desktop_notification = Some(now);
@@ -1442,6 +1894,97 @@ impl App {
         }
     }
 
+    /// Member list items carry presences, including users outside READY.
+    fn store_member_list_presences(&self, ops: &[MemberListOp]) {
+        let items = ops.iter().flat_map(|op| match op {
+            MemberListOp::Sync { items, .. } => items.as_slice(),
+            MemberListOp::Insert { item, .. } | MemberListOp::Update { item, .. } => {
+                std::slice::from_ref(item)
+            }
+            MemberListOp::Delete { .. } | MemberListOp::Invalidate { .. } => &[],
+        });
+
+        for item in items {
+            if let MemberListItem::Member(member) = item
+                && let Ok(id) = member.user_id.parse::<u64>()
+            {
+                self.presence_store.update(
+                    UserId(id),
+                    UserPresence::new(member.status, member.activities.clone()),
+                );
+            }
+        }
+    }
+
+    fn apply_ready_status(
+        &mut self,
+        status: Option<PresenceStatus>,
+        custom_status: Option<CustomStatus>,
+    ) {
+        if let Some(status) = self.configured_status.or(status) {
+            self.own_status = status;
+        }
+        self.own_custom_status = self.configured_custom_status.clone().or(custom_status);
+        if self.configured_status.is_some() || self.configured_custom_status.is_some() {
+            self.send_own_presence();
+        }
+        self.sync_own_status();
+    }
+
+    /// Forgets our custom status once its expiry has passed, returning whether it did.
+    fn clear_expired_custom_status(&mut self) -> bool {
+        let expired = self
+            .own_custom_status
+            .as_ref()
+            .is_some_and(|custom| custom.is_expired(chrono::Utc::now()));
+        if expired {
+            self.own_custom_status = None;
+        }
+        expired
+    }
+
+    /// Takes our custom status off the header and the presence store once it expires.
+    fn expire_custom_status(&mut self) {
+        if self.clear_expired_custom_status() {
+            self.sync_own_status();
+        }
+    }
+
+    fn send_own_presence(&mut self) {
+        self.clear_expired_custom_status();
+        if let Some(ref gateway_client) = self.gateway_client {
+            gateway_client.send_command(GatewayCommand::UpdatePresence {
+                status: self.own_status,
+                custom_status: self.own_custom_status.clone(),
+            });
+        }
+    }
+
+    /// Pushes our own status to the chat screen and the presence store.
+    fn sync_own_status(&mut self) {
+        self.clear_expired_custom_status();
+        if let Some(id) = self
+            .current_user_id
+            .as_deref()
+            .and_then(|id| id.parse::<u64>().ok())
+        {
+            let activities = self
+                .own_custom_status
+                .as_ref()
+                .map(|custom| vec![custom.to_activity()])
+                .unwrap_or_default();
+            self.presence_store
+                .update(UserId(id), UserPresence::new(self.own_status, activities));
+        }
+
+        if let CurrentScreen::Chat(ref mut state) = self.screen {
+            state.set_own_status(self.own_status, self.own_custom_status.clone());
+        } else if let Some(ref mut state) = self.pending_chat_state {
+            state.set_own_status(self.own_status, self.own_custom_status.clone());
+        }
+        self.should_render = true;
+    }
+
     fn handle_typing_start(
         &mut self,
         channel_id: ChannelId,
@@ -1543,6 +2086,7 @@ impl App {
         &mut self,
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
+        filter: ForumFilter,
         offset: u32,
     ) {
         self.typing_manager.clear_channel(channel_id);
@@ -1552,6 +2096,7 @@ impl App {
                 channel_id,
                 guild_id,
                 token: token.clone(),
+                filter,
                 offset,
             });
         }
@@ -1618,13 +2163,28 @@ impl App {
                 };
                 let name = format!("{}{}", icon, channel.name());
 
+                let mut result = None;
                 if let CurrentScreen::Chat(state) = &mut self.screen {
                     state.message_pane_data_mut().register_channel(id, name);
                     state.message_pane_data_mut().force_refresh_layout();
+                    if let Some(link) = state.take_pending_channel_link(channel.id()) {
+                        if channel.kind().is_thread() {
+                            state.upsert_threads(vec![channel]);
+                        }
+                        result = Some(state.open_fetched_link(link));
+                    }
+                }
+                if let Some(result) = result {
+                    self.process_chat_key_result(result);
                 }
             }
             Action::ChannelLoadError { channel_id, error } => {
                 warn!(channel_id = %channel_id, error = %error, "Failed to load channel info");
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.take_pending_channel_link(channel_id).is_some()
+                {
+                    self.show_notification(format!("Could not open link: {error}"));
+                }
             }
             Action::DataLoaded {
                 user,
@@ -1653,6 +2213,7 @@ impl App {
                     self.enable_animations,
                     self.command_registry.clone(),
                     self.relationship_state.clone(),
+                    self.presence_store.clone(),
                     self.hide_blocked_completely,
                     sort_mode,
                     recents,
@@ -1660,15 +2221,18 @@ impl App {
                 );
 
                 chat_state.set_connection_status(self.connection_status);
+                chat_state.set_own_status(self.own_status, self.own_custom_status.clone());
+                chat_state.set_notification_settings(self.notification_settings.clone());
+                chat_state.set_notification_rules(self.notification_rules.clone());
 
-                for dm in &dms {
+                for recipient in dms.iter().flat_map(|dm| &dm.recipients) {
                     self.user_cache
                         .insert(crate::domain::entities::CachedUser::new(
-                            &dm.recipient_id,
-                            &dm.recipient_username,
-                            &dm.recipient_discriminator,
+                            &recipient.id,
+                            &recipient.username,
+                            &recipient.discriminator,
                             None,
-                            dm.recipient_global_name.clone(),
+                            recipient.global_name.clone(),
                             false,
                         ));
                 }
@@ -1681,6 +2245,10 @@ impl App {
                 }
                 chat_state.set_group_guilds(self.group_guilds);
 
+                for (guild_id, emojis) in self.pending_emojis.drain() {
+                    chat_state.set_guild_emojis(guild_id, emojis);
+                }
+
                 for (guild_id, channels) in self.pending_channels.drain() {
                     chat_state.set_channels(guild_id, channels);
                 }
@@ -1740,24 +2308,42 @@ impl App {
             }
             Action::GuildChannelsLoaded { guild_id, channels } => {
                 debug!(guild_id = %guild_id, count = channels.len(), "Loaded channels for guild");
+                let mut pending_link = None;
                 if let CurrentScreen::Chat(state) = &mut self.screen {
                     state.set_channels(guild_id, channels);
+                    pending_link = state.take_pending_link(guild_id);
                 } else if let Some(ref mut state) = self.pending_chat_state {
                     state.set_channels(guild_id, channels);
                 }
+                if let Some(link) = pending_link {
+                    self.open_discord_link(link);
+                }
             }
             Action::GuildChannelsLoadError { guild_id, error } => {
                 warn!(guild_id = %guild_id, error = %error, "Failed to load channels for guild");
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.take_pending_link(guild_id).is_some()
+                {
+                    self.show_notification(
+                        "Could not open link: failed to load channels".to_string(),
+                    );
+                }
             }
             Action::ChannelMessagesLoaded {
                 channel_id,
                 messages,
             } => {
+                let mut unloaded_jump = None;
                 if let CurrentScreen::Chat(state) = &mut self.screen
                     && state.message_pane_data().channel_id() == Some(channel_id)
                 {
                     state.set_messages(messages);
                     state.set_typing_indicator(None);
+                    if let Some(message_id) = state.take_pending_jump(channel_id)
+                        && !state.jump_to_message(message_id)
+                    {
+                        unloaded_jump = Some(message_id);
+                    }
 
                     if let Some(last_msg) = state
                         .message_pane_data()
@@ -1776,6 +2362,9 @@ impl App {
                         state.mark_channel_read(channel_id, message_id);
                     }
                 }
+                if let Some(message_id) = unloaded_jump {
+                    self.load_messages_around(channel_id, message_id);
+                }
             }
             Action::ChannelMessagesLoadError { channel_id, error } => {
                 warn!(channel_id = %channel_id, error = %error, "Failed to load messages for channel");
@@ -1841,6 +2430,228 @@ impl App {
                     state.set_message_error(format!("Failed to delete: {error}"));
                 }
             }
+            Action::ReactionError {
+                channel_id,
+                message_id,
+                emoji,
+                added,
+                error,
+            } => {
+                error!(error = %error, "Failed to update reaction");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    if added {
+                        state.remove_reaction(channel_id, message_id, &emoji, true);
+                    } else {
+                        state.add_reaction(channel_id, message_id, emoji, true);
+                    }
+                }
+                self.show_notification(format!("Failed to update reaction: {error}"));
+            }
+            Action::PinnedMessagesLoaded {
+                channel_id,
+                messages,
+            } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_pinned_messages(channel_id, messages);
+                }
+            }
+            Action::PinnedMessagesLoadError { channel_id, error } => {
+                error!(channel_id = %channel_id, error = %error, "Failed to load pinned messages");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_pinned_messages_error(channel_id, error);
+                }
+            }
+            Action::UserProfileLoaded(profile) => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.on_user_profile_loaded(profile);
+                }
+            }
+            Action::UserProfileLoadError { user_id, error } => {
+                error!(user_id = %user_id, error = %error, "Failed to load user profile");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_profile_error(user_id, error);
+                }
+            }
+            Action::MessagePinUpdated {
+                channel_id,
+                message_id,
+                pinned,
+            } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_message_pinned(channel_id, message_id, pinned);
+                }
+            }
+            Action::MessagePinError {
+                channel_id,
+                message_id,
+                pinned,
+                error,
+            } => {
+                error!(error = %error, "Failed to update message pin state");
+                if !pinned && let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.restore_unpinned(channel_id, message_id);
+                }
+                self.show_notification(format!("Failed to update pin: {error}"));
+            }
+            Action::RelationshipUpdated(change) => {
+                if let RelationshipChange::SendRequest(username) = change {
+                    self.show_notification(format!("Friend request sent to {username}"));
+                }
+            }
+            Action::RelationshipError(error) => {
+                error!(error = %error, "Failed to update relationship");
+                self.show_notification(format!("Friend action failed: {error}"));
+            }
+            Action::DmChannelOpened(dm) => {
+                for recipient in &dm.recipients {
+                    self.user_cache
+                        .insert(crate::domain::entities::CachedUser::new(
+                            &recipient.id,
+                            &recipient.username,
+                            &recipient.discriminator,
+                            None,
+                            recipient.global_name.clone(),
+                            false,
+                        ));
+                }
+                if let CurrentScreen::Chat(ref mut state) = self.screen
+                    && let Some(result) = state.open_dm_channel(dm)
+                {
+                    self.process_chat_key_result(result);
+                }
+            }
+            Action::GroupDmRenamed { channel_id, name } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.rename_dm_channel(channel_id, &name);
+                }
+                if name.is_empty() {
+                    self.show_notification("Group name cleared".to_string());
+                } else {
+                    self.show_notification(format!("Group renamed to {name}"));
+                }
+            }
+            Action::DirectMessageError(error) => {
+                error!(error = %error, "Failed to open or rename DM");
+                self.show_notification(format!("Direct message action failed: {error}"));
+            }
+            Action::RecentMentionsLoaded(messages) => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_recent_mentions(messages);
+                }
+            }
+            Action::RecentMentionsLoadError(error) => {
+                error!(error = %error, "Failed to load recent mentions");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_recent_mentions_error(error);
+                }
+            }
+            Action::ChannelPreviewsLoaded {
+                channel_ids,
+                messages,
+            } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_inbox_previews(&channel_ids, &messages);
+                }
+            }
+            Action::NotificationSettingsError { previous, error } => {
+                error!(error = %error, "Failed to save notification settings");
+                self.notification_settings.upsert(previous);
+                self.show_notification(format!("Failed to save notification settings: {error}"));
+            }
+            Action::StatusUpdateError(error) => {
+                error!(error = %error, "Failed to save status");
+                self.show_notification(format!("Failed to save status: {error}"));
+            }
+            Action::ArchivedThreadsLoaded(threads) => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.upsert_threads(threads);
+                }
+            }
+            Action::ThreadCreated(thread) => {
+                let thread_id = thread.id();
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.upsert_threads(vec![thread]);
+                    if let Some(result) = state.open_message_thread(thread_id) {
+                        self.process_chat_key_result(result);
+                    }
+                }
+            }
+            Action::ThreadMembershipChanged { channel_id, joined } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_thread_member(channel_id, joined);
+                }
+                self.show_notification(if joined {
+                    "Joined thread".to_string()
+                } else {
+                    "Left thread".to_string()
+                });
+            }
+            Action::ThreadError(error) => {
+                error!(error = %error, "Thread request failed");
+                self.show_notification(error);
+            }
+            Action::ForumPostCreated(post) => {
+                self.show_notification(format!("Posted \"{}\"", post.name));
+                if let CurrentScreen::Chat(ref mut state) = self.screen
+                    && let Some(result) = state.on_forum_post_created(&post)
+                {
+                    self.process_chat_key_result(result);
+                }
+            }
+            Action::ForumPostError(error) => {
+                error!(error = %error, "Forum post failed");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_forum_post_error(error);
+                }
+            }
+            Action::MessageSearchResults { request, page } => {
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_search_results(&request, page);
+                }
+            }
+            Action::MessageSearchError { request, error } => {
+                warn!(error = %error, "Message search failed");
+                if let CurrentScreen::Chat(ref mut state) = self.screen {
+                    state.set_search_error(&request, error);
+                }
+            }
+            Action::MessagesAroundLoaded {
+                channel_id,
+                message_id,
+                messages,
+            } => {
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.message_pane_data().channel_id() == Some(channel_id)
+                {
+                    state.set_detached_messages(messages);
+                    state.set_typing_indicator(None);
+                    state.jump_to_message(message_id);
+                }
+            }
+            Action::NewerMessagesLoaded {
+                channel_id,
+                messages,
+                reached_present,
+            } => {
+                for message in &messages {
+                    self.cache_users_from_message(message);
+                }
+
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.message_pane_data().channel_id() == Some(channel_id)
+                {
+                    state.append_messages(messages, reached_present);
+                }
+            }
+            Action::NewerMessagesLoadError { channel_id, error } => {
+                error!(channel_id = %channel_id, error = %error, "Failed to load newer messages");
+                if let CurrentScreen::Chat(state) = &mut self.screen
+                    && state.message_pane_data().channel_id() == Some(channel_id)
+                {
+                    state.message_pane_data_mut().set_loading_newer(false);
+                }
+                self.show_notification(format!("Failed to load newer messages: {error}"));
+            }
             Action::TypingIndicatorSent(_) => {}
             Action::ImageLoaderReady(loader) => {
                 self.image_loader = Some(loader);
@@ -1854,6 +2665,10 @@ impl App {
             Action::PasteTextLoaded(text) => {
                 let _ = self.handle_terminal_event(&Event::Paste(text));
             }
+            Action::ClipboardLinkLoaded(Some(link)) => self.open_discord_link(link),
+            Action::ClipboardLinkLoaded(None) => {
+                self.show_notification("Clipboard does not contain a Discord link".to_string());
+            }
             Action::ShowNotification(message) => {
                 self.show_notification(message);
             }
@@ -1953,6 +2768,110 @@ impl App {
         }
     }
 
+    /// Points the message pane at `channel_id` before its messages arrive.
+    fn show_channel_in_pane(&mut self, channel_id: ChannelId) {
+        if let CurrentScreen::Chat(state) = &mut self.screen {
+            let name = state.get_channel(channel_id).map_or_else(
+                || format!("{channel_id}"),
+                crate::domain::entities::Channel::display_name,
+            );
+
+            state.message_pane_data_mut().set_channel(channel_id, name);
+            state.message_pane_parts_mut().1.on_channel_change();
+            state.message_input_parts_mut().set_has_channel(true);
+            state.message_input_parts_mut().clear();
+            state.focus_messages_list();
+        }
+    }
+
+    fn open_discord_link(&mut self, link: DiscordLink) {
+        debug!(?link, "Opening Discord link");
+        if let CurrentScreen::Chat(state) = &mut self.screen {
+            let result = state.open_discord_link(link);
+            self.process_chat_key_result(result);
+        }
+    }
+
+    fn load_messages_around(&mut self, channel_id: ChannelId, message_id: MessageId) {
+        let mut selected = None;
+        if let CurrentScreen::Chat(state) = &mut self.screen
+            && state.message_pane_data().channel_id() != Some(channel_id)
+        {
+            selected = Some(state.select_channel_for_jump(channel_id));
+        }
+        if let Some(guild_id) = selected {
+            self.save_state(guild_id, Some(channel_id));
+            if let Some(guild_id) = guild_id {
+                self.subscribe_to_channel(guild_id, channel_id);
+            }
+        }
+
+        if let Some(ref token) = self.current_token {
+            let _ = self.command_tx.send(BackendCommand::LoadMessagesAround {
+                channel_id,
+                message_id,
+                token: token.clone(),
+            });
+        }
+    }
+
+    fn load_pinned_messages(&mut self, channel_id: ChannelId) {
+        if let Some(ref token) = self.current_token {
+            let _ = self.command_tx.send(BackendCommand::LoadPinnedMessages {
+                channel_id,
+                token: token.clone(),
+            });
+        }
+    }
+
+    fn load_archived_threads(&mut self, channel_id: ChannelId) {
+        if let Some(ref token) = self.current_token {
+            let _ = self.command_tx.send(BackendCommand::LoadArchivedThreads {
+                channel_id,
+                token: token.clone(),
+            });
+        }
+    }
+
+    fn handle_set_message_pinned(&mut self, message_id: MessageId, pinned: bool) {
+        if let Some(ref token) = self.current_token
+            && let CurrentScreen::Chat(state) = &self.screen
+            && let Some(channel_id) = state.message_pane_data().channel_id()
+        {
+            let _ = self.command_tx.send(BackendCommand::SetMessagePinned {
+                token: token.clone(),
+                channel_id,
+                message_id,
+                pinned,
+            });
+        }
+    }
+
+    fn handle_reaction(&mut self, message_id: MessageId, emoji: ReactionEmoji, add: bool) {
+        if let Some(ref token) = self.current_token
+            && let CurrentScreen::Chat(state) = &self.screen
+            && let Some(channel_id) = state.message_pane_data().channel_id()
+        {
+            let token = token.clone();
+            let command = if add {
+                BackendCommand::AddReaction {
+                    token,
+                    channel_id,
+                    message_id,
+                    emoji,
+                }
+            } else {
+                BackendCommand::RemoveReaction {
+                    token,
+                    channel_id,
+                    message_id,
+                    emoji,
+                }
+            };
+            let _ = self.command_tx.send(command);
+        }
+    }
+
     fn handle_send_message(
         &mut self,
         content: String,
@@ -2010,7 +2929,8 @@ impl App {
         target_message_id: Option<MessageId>,
     ) -> color_eyre::Result<()> {
         let editor = self.resolve_editor();
-        let result = Self::run_external_editor(editor, initial_content.to_string()).await;
+        let result =
+            Self::run_external_editor(editor, initial_content.to_string(), self.mouse).await;
 
         terminal.clear()?;
         self.apply_editor_result(result, initial_content, target_message_id);
@@ -2111,6 +3031,7 @@ impl App {
     async fn run_external_editor(
         editor: String,
         initial_content: String,
+        mouse: bool,
     ) -> color_eyre::Result<Option<String>> {
         use std::io::Write;
 
@@ -2145,13 +3066,29 @@ impl App {
                 std::io::Error::new(std::io::ErrorKind::InvalidInput, "Empty editor command")
             })?;
 
-            crossterm::terminal::disable_raw_mode()?;
+            if mouse {
+                crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture)?;
+            }
+            let restore_mouse = || {
+                if mouse {
+                    let _ = crossterm::execute!(
+                        std::io::stdout(),
+                        crossterm::event::EnableMouseCapture
+                    );
+                }
+            };
+
+            if let Err(e) = crossterm::terminal::disable_raw_mode() {
+                restore_mouse();
+                return Err(e.into());
+            }
             if let Err(e) = crossterm::execute!(
                 std::io::stdout(),
                 crossterm::terminal::LeaveAlternateScreen,
                 crossterm::cursor::Show
             ) {
                 let _ = crossterm::terminal::enable_raw_mode();
+                restore_mouse();
                 return Err(e.into());
             }
 
@@ -2167,6 +3104,7 @@ impl App {
                 crossterm::terminal::EnterAlternateScreen,
                 crossterm::cursor::Hide
             );
+            restore_mouse();
 
             match status {
                 Ok(exit_status) if exit_status.success() => {
@@ -2229,6 +3167,24 @@ impl App {
     }
 }
 
+/// Key releases and bare mouse movement change nothing and need no redraw.
+fn is_ignored_event(event: &Event) -> bool {
+    match event {
+        Event::Key(key) => key.kind == KeyEventKind::Release,
+        Event::Mouse(mouse) => mouse.kind == MouseEventKind::Moved,
+        _ => false,
+    }
+}
+
+/// Rings the terminal bell; the BEL byte does not disturb the alternate screen.
+fn ring_terminal_bell() {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2251,6 +3207,26 @@ mod tests {
             Ok(())
         }
 
+        async fn add_reaction(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+            _message_id: MessageId,
+            _emoji: &ReactionEmoji,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn remove_reaction(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+            _message_id: MessageId,
+            _emoji: &ReactionEmoji,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
         async fn fetch_guilds(&self, _token: &AuthToken) -> Result<Vec<Guild>, AuthError> {
             Ok(vec![Guild::new(1_u64, "Test Guild")])
         }
@@ -2312,6 +3288,48 @@ mod tests {
             Err(AuthError::unexpected("mock not implemented"))
         }
 
+        async fn fetch_pinned_messages(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+        ) -> Result<Vec<crate::domain::entities::Message>, AuthError> {
+            Ok(vec![])
+        }
+
+        async fn fetch_recent_mentions(
+            &self,
+            _token: &AuthToken,
+            _limit: u8,
+        ) -> Result<Vec<crate::domain::entities::Message>, AuthError> {
+            Ok(vec![])
+        }
+
+        async fn pin_message(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+            _message_id: MessageId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn unpin_message(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+            _message_id: MessageId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn search_messages(
+            &self,
+            _token: &AuthToken,
+            _request: &crate::domain::ports::SearchMessagesRequest,
+        ) -> Result<crate::domain::ports::MessageSearchPage, AuthError> {
+            Ok(crate::domain::ports::MessageSearchPage::default())
+        }
+
         async fn send_typing_indicator(
             &self,
             _token: &AuthToken,
@@ -2334,12 +3352,57 @@ mod tests {
             _token: &AuthToken,
             _channel_id: ChannelId,
             _guild_id: Option<GuildId>,
+            _filter: crate::domain::entities::ForumFilter,
             _offset: u32,
             _limit: Option<u8>,
         ) -> Result<Vec<crate::domain::entities::ForumThread>, AuthError> {
             Ok(vec![])
         }
 
+        async fn create_forum_post(
+            &self,
+            _token: &AuthToken,
+            _request: &crate::domain::ports::CreateForumPostRequest,
+        ) -> Result<crate::domain::entities::ForumThread, AuthError> {
+            Err(AuthError::unexpected("not supported"))
+        }
+
+        async fn fetch_archived_threads(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+        ) -> Result<Vec<crate::domain::entities::Channel>, AuthError> {
+            Ok(vec![])
+        }
+
+        async fn create_thread(
+            &self,
+            _token: &AuthToken,
+            request: &crate::domain::ports::CreateThreadRequest,
+        ) -> Result<crate::domain::entities::Channel, AuthError> {
+            Ok(crate::domain::entities::Channel::new(
+                124,
+                request.name.clone(),
+                crate::domain::entities::ChannelKind::PublicThread,
+            ))
+        }
+
+        async fn join_thread(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn leave_thread(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
         async fn fetch_channel(
             &self,
             _token: &AuthToken,
@@ -2351,16 +3414,86 @@ mod tests {
                 crate::domain::entities::ChannelKind::Text,
             ))
         }
+
+        async fn fetch_user_profile(
+            &self,
+            _token: &AuthToken,
+            _user_id: crate::domain::entities::UserId,
+            _guild_id: Option<GuildId>,
+        ) -> Result<crate::domain::entities::UserProfile, AuthError> {
+            Err(AuthError::unexpected("not implemented in mock"))
+        }
+
+        async fn update_status(
+            &self,
+            _token: &AuthToken,
+            _status: crate::domain::entities::PresenceStatus,
+            _custom_status: Option<&crate::domain::entities::CustomStatus>,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn send_friend_request(
+            &self,
+            _token: &AuthToken,
+            _username: &str,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn accept_friend_request(
+            &self,
+            _token: &AuthToken,
+            _user_id: crate::domain::entities::UserId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn remove_relationship(
+            &self,
+            _token: &AuthToken,
+            _user_id: crate::domain::entities::UserId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn block_user(
+            &self,
+            _token: &AuthToken,
+            _user_id: crate::domain::entities::UserId,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn update_notification_settings(
+            &self,
+            _token: &AuthToken,
+            _update: &crate::domain::ports::NotificationSettingsUpdate,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
+
+        async fn open_dm_channel(
+            &self,
+            _token: &AuthToken,
+            _recipients: &[crate::domain::entities::UserId],
+        ) -> Result<DirectMessageChannel, AuthError> {
+            Err(AuthError::unexpected("not implemented"))
+        }
+
+        async fn rename_group_dm(
+            &self,
+            _token: &AuthToken,
+            _channel_id: ChannelId,
+            _name: &str,
+        ) -> Result<(), AuthError> {
+            Ok(())
+        }
     }
 
-    #[tokio::test]
-    async fn test_app_creation() {
-        let auth = Arc::new(MockAuthPort::new(true));
-        let data = Arc::new(MockDiscordData);
-        let storage = Arc::new(MockTokenStorage::new());
+    fn test_config() -> AppConfig {
         let theme = Theme::new("Orange", None, None, None, None, false);
-        let identity = Arc::new(ClientIdentity::new());
-        let config = AppConfig {
+        AppConfig {
             disable_user_colors: false,
             group_guilds: false,
             desktop_notifications: false,
@@ -2371,14 +3504,148 @@ mod tests {
             internal_notifications: true,
             enable_animations: true,
             editor: None,
+            mouse: false,
             keybindings: std::collections::HashMap::new(),
             notification_duration: 5,
             theme,
             hide_blocked_completely: false,
             quick_switcher_order: QuickSwitcherSortMode::default(),
-        };
-        let app = App::new(auth, data, storage, config, identity);
+            open_link: None,
+            status: None,
+            custom_status: None,
+            notification_rules: Vec::new(),
+        }
+    }
+
+    fn test_app() -> App {
+        let auth = Arc::new(MockAuthPort::new(true));
+        let data = Arc::new(MockDiscordData);
+        let storage = Arc::new(MockTokenStorage::new());
+        let identity = Arc::new(ClientIdentity::new());
+        App::new(auth, data, storage, test_config(), identity)
+    }
+
+    #[tokio::test]
+    async fn test_app_creation() {
+        let app = test_app();
 
         assert_eq!(app.state, AppState::Login);
     }
+
+    #[tokio::test]
+    async fn test_ready_status_fills_what_the_config_leaves_unset() {
+        let custom = |text: &str| CustomStatus {
+            text: Some(text.to_string()),
+            emoji: None,
+            expires_at: None,
+        };
+
+        let mut app = App::new(
+            Arc::new(MockAuthPort::new(true)),
+            Arc::new(MockDiscordData),
+            Arc::new(MockTokenStorage::new()),
+            AppConfig {
+                status: Some(PresenceStatus::DoNotDisturb),
+                ..test_config()
+            },
+            Arc::new(ClientIdentity::new()),
+        );
+        app.apply_ready_status(Some(PresenceStatus::Idle), Some(custom("saved")));
+        assert_eq!(app.own_status, PresenceStatus::DoNotDisturb);
+        assert_eq!(app.own_custom_status, Some(custom("saved")));
+
+        let mut app = App::new(
+            Arc::new(MockAuthPort::new(true)),
+            Arc::new(MockDiscordData),
+            Arc::new(MockTokenStorage::new()),
+            AppConfig {
+                custom_status: Some(custom("configured")),
+                ..test_config()
+            },
+            Arc::new(ClientIdentity::new()),
+        );
+        app.apply_ready_status(Some(PresenceStatus::Idle), Some(custom("saved")));
+        assert_eq!(app.own_status, PresenceStatus::Idle);
+        assert_eq!(app.own_custom_status, Some(custom("configured")));
+    }
+
+    #[tokio::test]
+    async fn test_custom_status_is_cleared_once_expired() {
+        let mut app = test_app();
+        let status = |minutes: i64| CustomStatus {
+            text: Some("Lunch".to_string()),
+            emoji: None,
+            expires_at: Some(chrono::Utc::now() + chrono::Duration::minutes(minutes)),
+        };
+
+        app.own_custom_status = Some(status(30));
+        app.expire_custom_status();
+        assert!(app.own_custom_status.is_some());
+
+        app.own_custom_status = Some(status(-1));
+        app.expire_custom_status();
+        assert_eq!(app.own_custom_status, None);
+    }
+
+    #[tokio::test]
+    async fn test_own_message_mentioning_own_role_is_not_a_mention() {
+        use crate::domain::entities::{Member, Message, RoleId, User};
+
+        let mut app = test_app();
+        let user = User::new("123", "me", "0", None, false, None);
+        app.current_user_id = Some(user.id_str());
+        let mut state = ChatScreenState::new(
+            user.clone(),
+            Arc::new(MarkdownRenderer::new()),
+            UserCache::new(),
+            false,
+            true,
+            true,
+            "%H:%M".to_string(),
+            Theme::new("Orange", None, None, None, None, false),
+            true,
+            CommandRegistry::default(),
+            RelationshipState::new(),
+            PresenceStore::new(),
+            false,
+            QuickSwitcherSortMode::default(),
+            vec![],
+            vec![],
+        );
+        let guild_id = GuildId(1);
+        let member = Member {
+            user: Some(user),
+            roles: vec![RoleId(5)],
+            nick: None,
+            avatar: None,
+            joined_at: String::new(),
+            premium_since: None,
+            deaf: false,
+            mute: false,
+            pending: false,
+            permissions: None,
+            communication_disabled_until: None,
+        };
+        state.set_guild_data(guild_id, vec![], vec![member]);
+        app.screen = CurrentScreen::Chat(Box::new(state));
+
+        let message = |id: u64, author: &str| {
+            Message::test(id, 10, "<@&5>")
+                .with_author(author, author)
+                .with_guild_id(Some(guild_id))
+                .with_mention_roles(vec![RoleId(5)])
+        };
+
+        app.handle_message_create(message(1, "123"));
+        assert_eq!(
+            app.chat_state().map(|s| s.mention_count(ChannelId(10))),
+            Some(0)
+        );
+
+        app.handle_message_create(message(2, "999"));
+        assert_eq!(
+            app.chat_state().map(|s| s.mention_count(ChannelId(10))),
+            Some(1)
+        );
+    }
 }
@@ -1,14 +1,22 @@
+use futures_util::future::join_all;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use crate::domain::entities::{AuthToken, ChannelId, GuildId, Message, MessageId};
+use crate::domain::entities::{
+    AuthToken, ChannelId, CustomStatus, GuildId, GuildNotificationSettings, Message, MessageId,
+    PresenceStatus, ReactionEmoji, UserId, UserProfile,
+};
 use crate::domain::ports::{
-    DirectMessageChannel, DiscordDataPort, EditMessageRequest, FetchMessagesOptions,
-    SendMessageRequest,
+    CreateForumPostRequest, CreateThreadRequest, DirectMessageChannel, DiscordDataPort,
+    EditMessageRequest, FetchMessagesOptions, MessageSearchPage, NotificationSettingsUpdate,
+    RelationshipChange, SearchMessagesRequest, SendMessageRequest,
 };
 use crate::infrastructure::image::ImageLoader;
 
+/// How many recent mentions the inbox asks Discord for.
+const RECENT_MENTIONS_LIMIT: u8 = 25;
+
 #[derive(Debug)]
 pub enum Action {
     HistoryLoaded(Vec<Message>),
@@ -42,6 +50,23 @@ pub enum Action {
         channel_id: ChannelId,
         error: String,
     },
+    /// A window of messages centred on `message_id`, fetched to jump to it.
+    MessagesAroundLoaded {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        messages: Vec<Message>,
+    },
+    /// Messages after the end of a detached history window, oldest first.
+    NewerMessagesLoaded {
+        channel_id: ChannelId,
+        messages: Vec<Message>,
+        /// Fewer messages than requested came back, so the window now reaches the present.
+        reached_present: bool,
+    },
+    NewerMessagesLoadError {
+        channel_id: ChannelId,
+        error: String,
+    },
     ForumThreadsLoaded {
         channel_id: ChannelId,
         threads: Vec<crate::domain::entities::ForumThread>,
@@ -62,6 +87,80 @@ pub enum Action {
     MessageEditError(String),
     MessageDeleted(MessageId),
     MessageDeleteError(String),
+    /// Adding or removing our reaction failed; the optimistic update should be reverted.
+    ReactionError {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+        added: bool,
+        error: String,
+    },
+    PinnedMessagesLoaded {
+        channel_id: ChannelId,
+        messages: Vec<Message>,
+    },
+    PinnedMessagesLoadError {
+        channel_id: ChannelId,
+        error: String,
+    },
+    /// A message was pinned or unpinned.
+    MessagePinUpdated {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        pinned: bool,
+    },
+    /// Pinning or unpinning a message failed.
+    MessagePinError {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        pinned: bool,
+        error: String,
+    },
+    RecentMentionsLoaded(Vec<Message>),
+    RecentMentionsLoadError(String),
+    /// Latest message of each requested channel previewed in the inbox.
+    ChannelPreviewsLoaded {
+        channel_ids: Vec<ChannelId>,
+        messages: Vec<Message>,
+    },
+    ArchivedThreadsLoaded(Vec<crate::domain::entities::Channel>),
+    ThreadCreated(crate::domain::entities::Channel),
+    ThreadMembershipChanged {
+        channel_id: ChannelId,
+        joined: bool,
+    },
+    /// Loading, creating, joining or leaving a thread failed.
+    ThreadError(String),
+    ForumPostCreated(crate::domain::entities::ForumThread),
+    ForumPostError(String),
+    UserProfileLoaded(UserProfile),
+    UserProfileLoadError {
+        user_id: UserId,
+        error: String,
+    },
+    MessageSearchResults {
+        request: SearchMessagesRequest,
+        page: MessageSearchPage,
+    },
+    MessageSearchError {
+        request: SearchMessagesRequest,
+        error: String,
+    },
+    /// Saving our own status to the user settings failed.
+    StatusUpdateError(String),
+    /// Saving notification settings failed; `previous` undoes the optimistic update.
+    NotificationSettingsError {
+        previous: GuildNotificationSettings,
+        error: String,
+    },
+    RelationshipUpdated(RelationshipChange),
+    RelationshipError(String),
+    DmChannelOpened(DirectMessageChannel),
+    GroupDmRenamed {
+        channel_id: ChannelId,
+        name: String,
+    },
+    DirectMessageError(String),
     TypingIndicatorSent(ChannelId),
     LoginSuccess {
         user: crate::domain::entities::User,
@@ -73,6 +172,8 @@ pub enum Action {
     ImageLoaderReady(Arc<ImageLoader>),
     PasteImageLoaded(std::path::PathBuf),
     PasteTextLoaded(String),
+    /// Clipboard contents parsed as a Discord link, if they were one.
+    ClipboardLinkLoaded(Option<crate::domain::entities::DiscordLink>),
     ShowNotification(String),
     SearchResults(Vec<crate::domain::search::SearchResult>),
 }
@@ -90,6 +191,7 @@ pub enum BackendCommand {
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
         token: AuthToken,
+        filter: crate::domain::entities::ForumFilter,
         offset: u32,
     },
     FetchChannel {
@@ -101,6 +203,11 @@ pub enum BackendCommand {
         before_message_id: MessageId,
         token: AuthToken,
     },
+    LoadNewerMessages {
+        channel_id: ChannelId,
+        after_message_id: MessageId,
+        token: AuthToken,
+    },
     SendMessage {
         token: AuthToken,
         request: SendMessageRequest,
@@ -114,6 +221,92 @@ pub enum BackendCommand {
         channel_id: ChannelId,
         message_id: MessageId,
     },
+    AddReaction {
+        token: AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+    },
+    RemoveReaction {
+        token: AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+    },
+    LoadPinnedMessages {
+        channel_id: ChannelId,
+        token: AuthToken,
+    },
+    LoadRecentMentions {
+        token: AuthToken,
+    },
+    /// Fetches the latest message of each channel, for unread previews.
+    LoadChannelPreviews {
+        token: AuthToken,
+        channel_ids: Vec<ChannelId>,
+    },
+    SetMessagePinned {
+        token: AuthToken,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        pinned: bool,
+    },
+    SearchMessages {
+        token: AuthToken,
+        request: SearchMessagesRequest,
+    },
+    LoadArchivedThreads {
+        channel_id: ChannelId,
+        token: AuthToken,
+    },
+    CreateThread {
+        token: AuthToken,
+        request: CreateThreadRequest,
+    },
+    SetThreadMembership {
+        token: AuthToken,
+        channel_id: ChannelId,
+        join: bool,
+    },
+    CreateForumPost {
+        token: AuthToken,
+        request: CreateForumPostRequest,
+    },
+    FetchUserProfile {
+        token: AuthToken,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    },
+    UpdateStatus {
+        token: AuthToken,
+        status: PresenceStatus,
+        custom_status: Option<CustomStatus>,
+    },
+    ModifyRelationship {
+        token: AuthToken,
+        change: RelationshipChange,
+    },
+    UpdateNotificationSettings {
+        token: AuthToken,
+        update: NotificationSettingsUpdate,
+        previous: GuildNotificationSettings,
+    },
+    /// Opens (or creates) the DM with `recipients`, naming it when it is a group DM.
+    OpenDmChannel {
+        token: AuthToken,
+        recipients: Vec<UserId>,
+        name: Option<String>,
+    },
+    RenameGroupDm {
+        token: AuthToken,
+        channel_id: ChannelId,
+        name: String,
+    },
+    LoadMessagesAround {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        token: AuthToken,
+    },
     SendTypingIndicator {
         channel_id: ChannelId,
         token: AuthToken,
@@ -212,11 +405,12 @@ impl Backend {
                 channel_id,
                 guild_id,
                 token,
+                filter,
                 offset,
             } => {
                 match self
                     .discord_data
-                    .fetch_forum_threads(&token, channel_id, guild_id, offset, Some(50))
+                    .fetch_forum_threads(&token, channel_id, guild_id, filter, offset, Some(50))
                     .await
                 {
                     Ok(mut threads) => {
@@ -278,6 +472,36 @@ impl Backend {
                     }
                 }
             }
+            BackendCommand::LoadNewerMessages {
+                channel_id,
+                after_message_id,
+                token,
+            } => {
+                let options = FetchMessagesOptions::default()
+                    .with_limit(50)
+                    .after_message(after_message_id.as_u64());
+                match self
+                    .discord_data
+                    .fetch_messages(&token, channel_id.as_u64(), options)
+                    .await
+                {
+                    Ok(messages) => {
+                        debug!(count = messages.len(), "Loaded newer messages");
+                        let _ = self.action_tx.send(Action::NewerMessagesLoaded {
+                            channel_id,
+                            reached_present: messages.len() < 50,
+                            messages,
+                        });
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to load newer messages");
+                        let _ = self.action_tx.send(Action::NewerMessagesLoadError {
+                            channel_id,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
             BackendCommand::SendMessage { token, request } => {
                 match self.discord_data.send_message(&token, request).await {
                     Ok(message) => {
@@ -324,6 +548,421 @@ impl Backend {
                     }
                 }
             }
+            BackendCommand::AddReaction {
+                token,
+                channel_id,
+                message_id,
+                emoji,
+            } => {
+                if let Err(e) = self
+                    .discord_data
+                    .add_reaction(&token, channel_id, message_id, &emoji)
+                    .await
+                {
+                    error!(error = %e, "Failed to add reaction");
+                    let _ = self.action_tx.send(Action::ReactionError {
+                        channel_id,
+                        message_id,
+                        emoji,
+                        added: true,
+                        error: e.to_string(),
+                    });
+                }
+            }
+            BackendCommand::RemoveReaction {
+                token,
+                channel_id,
+                message_id,
+                emoji,
+            } => {
+                if let Err(e) = self
+                    .discord_data
+                    .remove_reaction(&token, channel_id, message_id, &emoji)
+                    .await
+                {
+                    error!(error = %e, "Failed to remove reaction");
+                    let _ = self.action_tx.send(Action::ReactionError {
+                        channel_id,
+                        message_id,
+                        emoji,
+                        added: false,
+                        error: e.to_string(),
+                    });
+                }
+            }
+            BackendCommand::LoadPinnedMessages { channel_id, token } => {
+                match self
+                    .discord_data
+                    .fetch_pinned_messages(&token, channel_id)
+                    .await
+                {
+                    Ok(messages) => {
+                        debug!(channel_id = %channel_id, count = messages.len(), "Loaded pinned messages");
+                        let _ = self.action_tx.send(Action::PinnedMessagesLoaded {
+                            channel_id,
+                            messages,
+                        });
+                    }
+                    Err(e) => {
+                        warn!(channel_id = %channel_id, error = %e, "Failed to load pinned messages");
+                        let _ = self.action_tx.send(Action::PinnedMessagesLoadError {
+                            channel_id,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
+            BackendCommand::LoadRecentMentions { token } => {
+                match self
+                    .discord_data
+                    .fetch_recent_mentions(&token, RECENT_MENTIONS_LIMIT)
+                    .await
+                {
+                    Ok(messages) => {
+                        debug!(count = messages.len(), "Loaded recent mentions");
+                        let _ = self.action_tx.send(Action::RecentMentionsLoaded(messages));
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to load recent mentions");
+                        let _ = self
+                            .action_tx
+                            .send(Action::RecentMentionsLoadError(e.to_string()));
+                    }
+                }
+            }
+            BackendCommand::LoadChannelPreviews { token, channel_ids } => {
+                // One request per channel; run them off the command loop so
+                // other commands are not held up behind the whole batch.
+                let discord_data = Arc::clone(&self.discord_data);
+                let action_tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    let fetches = channel_ids.iter().copied().map(|channel_id| {
+                        let discord_data = Arc::clone(&discord_data);
+                        let token = token.clone();
+                        async move {
+                            let options = FetchMessagesOptions::default().with_limit(1);
+                            match discord_data
+                                .fetch_messages(&token, channel_id.as_u64(), options)
+                                .await
+                            {
+                                Ok(messages) => messages.into_iter().next(),
+                                Err(e) => {
+                                    warn!(channel_id = %channel_id, error = %e, "Failed to load channel preview");
+                                    None
+                                }
+                            }
+                        }
+                    });
+                    let messages = join_all(fetches).await.into_iter().flatten().collect();
+                    let _ = action_tx.send(Action::ChannelPreviewsLoaded {
+                        channel_ids,
+                        messages,
+                    });
+                });
+            }
+            BackendCommand::SetMessagePinned {
+                token,
+                channel_id,
+                message_id,
+                pinned,
+            } => {
+                let result = if pinned {
+                    self.discord_data
+                        .pin_message(&token, channel_id, message_id)
+                        .await
+                } else {
+                    self.discord_data
+                        .unpin_message(&token, channel_id, message_id)
+                        .await
+                };
+                match result {
+                    Ok(()) => {
+                        info!(message_id = %message_id, pinned, "Message pin state updated");
+                        let _ = self.action_tx.send(Action::MessagePinUpdated {
+                            channel_id,
+                            message_id,
+                            pinned,
+                        });
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to update message pin state");
+                        let _ = self.action_tx.send(Action::MessagePinError {
+                            channel_id,
+                            message_id,
+                            pinned,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
+            BackendCommand::SearchMessages { token, request } => {
+                match self.discord_data.search_messages(&token, &request).await {
+                    Ok(page) => {
+                        debug!(
+                            total = page.total_results,
+                            offset = request.offset,
+                            "Loaded message search results"
+                        );
+                        let _ = self
+                            .action_tx
+                            .send(Action::MessageSearchResults { request, page });
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to search messages");
+                        let _ = self.action_tx.send(Action::MessageSearchError {
+                            request,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
+            BackendCommand::LoadMessagesAround {
+                channel_id,
+                message_id,
+                token,
+            } => {
+                let options = FetchMessagesOptions::default()
+                    .with_limit(50)
+                    .around_message(message_id.as_u64());
+                match self
+                    .discord_data
+                    .fetch_messages(&token, channel_id.as_u64(), options)
+                    .await
+                {
+                    Ok(messages) => {
+                        debug!(channel_id = %channel_id, message_id = %message_id, count = messages.len(), "Loaded messages around target");
+                        let _ = self.action_tx.send(Action::MessagesAroundLoaded {
+                            channel_id,
+                            message_id,
+                            messages,
+                        });
+                    }
+                    Err(e) => {
+                        warn!(channel_id = %channel_id, error = %e, "Failed to load messages around target");
+                        let _ = self.action_tx.send(Action::ChannelMessagesLoadError {
+                            channel_id,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
+            BackendCommand::LoadArchivedThreads { channel_id, token } => {
+                match self
+                    .discord_data
+                    .fetch_archived_threads(&token, channel_id)
+                    .await
+                {
+                    Ok(threads) => {
+                        debug!(channel_id = %channel_id, count = threads.len(), "Loaded archived threads");
+                        let _ = self.action_tx.send(Action::ArchivedThreadsLoaded(threads));
+                    }
+                    Err(e) => {
+                        warn!(channel_id = %channel_id, error = %e, "Failed to load archived threads");
+                        let _ = self.action_tx.send(Action::ThreadError(format!(
+                            "Failed to load archived threads: {e}"
+                        )));
+                    }
+                }
+            }
+            BackendCommand::CreateThread { token, request } => {
+                match self.discord_data.create_thread(&token, &request).await {
+                    Ok(thread) => {
+                        info!(thread_id = %thread.id(), "Thread created");
+                        let _ = self.action_tx.send(Action::ThreadCreated(thread));
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to create thread");
+                        let _ = self
+                            .action_tx
+                            .send(Action::ThreadError(format!("Failed to create thread: {e}")));
+                    }
+                }
+            }
+            BackendCommand::CreateForumPost { token, request } => {
+                match self.discord_data.create_forum_post(&token, &request).await {
+                    Ok(post) => {
+                        info!(thread_id = %post.id, "Forum post created");
+                        let _ = self.action_tx.send(Action::ForumPostCreated(post));
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to create forum post");
+                        let _ = self.action_tx.send(Action::ForumPostError(format!(
+                            "Failed to create post: {e}"
+                        )));
+                    }
+                }
+            }
+            BackendCommand::FetchUserProfile {
+                token,
+                user_id,
+                guild_id,
+            } => {
+                match self
+                    .discord_data
+                    .fetch_user_profile(&token, user_id, guild_id)
+                    .await
+                {
+                    Ok(profile) => {
+                        debug!(user_id = %user_id, "Loaded user profile");
+                        let _ = self.action_tx.send(Action::UserProfileLoaded(profile));
+                    }
+                    Err(e) => {
+                        warn!(user_id = %user_id, error = %e, "Failed to load user profile");
+                        let _ = self.action_tx.send(Action::UserProfileLoadError {
+                            user_id,
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
+            BackendCommand::UpdateStatus {
+                token,
+                status,
+                custom_status,
+            } => {
+                if let Err(e) = self
+                    .discord_data
+                    .update_status(&token, status, custom_status.as_ref())
+                    .await
+                {
+                    warn!(error = %e, "Failed to save status");
+                    let _ = self
+                        .action_tx
+                        .send(Action::StatusUpdateError(e.to_string()));
+                }
+            }
+            BackendCommand::ModifyRelationship { token, change } => {
+                let result = match &change {
+                    RelationshipChange::SendRequest(username) => {
+                        self.discord_data
+                            .send_friend_request(&token, username)
+                            .await
+                    }
+                    RelationshipChange::Accept(user_id) => {
+                        self.discord_data
+                            .accept_friend_request(&token, *user_id)
+                            .await
+                    }
+                    RelationshipChange::Remove(user_id) => {
+                        self.discord_data
+                            .remove_relationship(&token, *user_id)
+                            .await
+                    }
+                    RelationshipChange::Block(user_id) => {
+                        self.discord_data.block_user(&token, *user_id).await
+                    }
+                };
+                match result {
+                    Ok(()) => {
+                        debug!(change = ?change, "Updated relationship");
+                        let _ = self.action_tx.send(Action::RelationshipUpdated(change));
+                    }
+                    Err(e) => {
+                        warn!(change = ?change, error = %e, "Failed to update relationship");
+                        let _ = self
+                            .action_tx
+                            .send(Action::RelationshipError(e.to_string()));
+                    }
+                }
+            }
+            BackendCommand::UpdateNotificationSettings {
+                token,
+                update,
+                previous,
+            } => {
+                if let Err(e) = self
+                    .discord_data
+                    .update_notification_settings(&token, &update)
+                    .await
+                {
+                    warn!(update = ?update, error = %e, "Failed to save notification settings");
+                    let _ = self.action_tx.send(Action::NotificationSettingsError {
+                        previous,
+                        error: e.to_string(),
+                    });
+                }
+            }
+            BackendCommand::OpenDmChannel {
+                token,
+                recipients,
+                name,
+            } => match self.discord_data.open_dm_channel(&token, &recipients).await {
+                Ok(mut channel) => {
+                    debug!(channel_id = %channel.channel_id, "Opened DM channel");
+                    if let Some(name) = name.filter(|_| channel.is_group)
+                        && let Ok(channel_id) = channel.channel_id.parse::<u64>()
+                    {
+                        match self
+                            .discord_data
+                            .rename_group_dm(&token, ChannelId(channel_id), &name)
+                            .await
+                        {
+                            Ok(()) => channel = channel.with_name(name),
+                            Err(e) => {
+                                warn!(error = %e, "Failed to name group DM");
+                                let _ = self.action_tx.send(Action::DirectMessageError(format!(
+                                    "the group was created but could not be named: {e}"
+                                )));
+                            }
+                        }
+                    }
+                    let _ = self.action_tx.send(Action::DmChannelOpened(channel));
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to open DM channel");
+                    let _ = self
+                        .action_tx
+                        .send(Action::DirectMessageError(e.to_string()));
+                }
+            },
+            BackendCommand::RenameGroupDm {
+                token,
+                channel_id,
+                name,
+            } => match self
+                .discord_data
+                .rename_group_dm(&token, channel_id, &name)
+                .await
+            {
+                Ok(()) => {
+                    debug!(channel_id = %channel_id, "Renamed group DM");
+                    let _ = self
+                        .action_tx
+                        .send(Action::GroupDmRenamed { channel_id, name });
+                }
+                Err(e) => {
+                    warn!(channel_id = %channel_id, error = %e, "Failed to rename group DM");
+                    let _ = self
+                        .action_tx
+                        .send(Action::DirectMessageError(e.to_string()));
+                }
+            },
+            BackendCommand::SetThreadMembership {
+                token,
+                channel_id,
+                join,
+            } => {
+                let result = if join {
+                    self.discord_data.join_thread(&token, channel_id).await
+                } else {
+                    self.discord_data.leave_thread(&token, channel_id).await
+                };
+                match result {
+                    Ok(()) => {
+                        info!(channel_id = %channel_id, join, "Thread membership updated");
+                        let _ = self.action_tx.send(Action::ThreadMembershipChanged {
+                            channel_id,
+                            joined: join,
+                        });
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to update thread membership");
+                        let _ = self.action_tx.send(Action::ThreadError(format!(
+                            "Failed to update thread membership: {e}"
+                        )));
+                    }
+                }
+            }
             BackendCommand::SendTypingIndicator { channel_id, token } => {
                 if let Err(e) = self
                     .discord_data
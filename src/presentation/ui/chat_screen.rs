@@ -1,43 +1,79 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::sync::Arc;
 use std::time::Duration;
 use tachyonfx::{Effect, Interpolation, fx};
 
-use crate::application::services::autocomplete_service::AutocompleteService;
+use crate::application::services::autocomplete_service::{
+    AutocompleteKind, AutocompleteService, AutocompleteSuggestion,
+};
 use crate::application::services::identity_resolver::IdentityResolver;
 use crate::application::services::message_content_service::{
     MessageContentAction, MessageContentService,
 };
+use crate::application::services::notification_rules::{MessageScope, NotificationRules};
 use crate::domain::ConnectionStatus;
 use crate::domain::entities::{
-    CHANNEL_MENTION_RE, CHANNEL_URL_RE, CachedUser, Channel, ChannelId, ChannelKind, Guild,
-    GuildFolder, GuildId, Member, Message, MessageId, Permissions, RelationshipState, Role, User,
-    UserCache,
+    CHANNEL_MENTION_RE, CHANNEL_URL_RE, CachedUser, Channel, ChannelFlags, ChannelId, ChannelKind,
+    CustomEmoji, CustomStatus, DiscordLink, Emoji, ForumFilter, ForumThread, Guild, GuildFolder,
+    GuildId, GuildNotificationSettings, Member, MemberListGroup, MemberListItem, MemberListOp,
+    Message, MessageId, NotificationSettingsStore, Permissions, PresenceStatus, PresenceStore,
+    ReactionEmoji, RelationshipState, Role, User, UserCache, UserId, UserProfile,
 };
 use crate::domain::keybinding::{Action, Keybind};
-use crate::domain::ports::DirectMessageChannel;
-use crate::domain::search::{SearchKind, SearchPrefix, SearchResult, parse_search_query};
+use crate::domain::ports::{
+    CreateForumPostRequest, CreateThreadRequest, DirectMessageChannel, MessageSearchPage,
+    NotificationSettingsUpdate, RelationshipChange, SearchMessagesRequest, SearchScope,
+};
+use crate::domain::search::{
+    MessageSearchQuery, SearchKind, SearchPrefix, SearchResult, parse_search_query,
+};
 use crate::domain::services::permission_calculator::PermissionCalculator;
 use crate::infrastructure::config::app_config::QuickSwitcherSortMode;
-use crate::infrastructure::search::{ChannelSearchProvider, DmSearchProvider, GuildSearchProvider};
+use crate::infrastructure::search::{
+    ChannelSearchProvider, DmSearchProvider, FuzzySearcher, GuildSearchProvider,
+};
 use crate::presentation::commands::{CommandRegistry, HasCommands};
 use crate::presentation::services::markdown_renderer::MarkdownRenderer;
 
 use crate::presentation::theme::Theme;
+use crate::presentation::ui::emoji_picker::{
+    EmojiPicker, EmojiPickerAction, EmojiPickerTarget, EmojiPickerWidget,
+};
+use crate::presentation::ui::forum_composer::{
+    ForumComposer, ForumComposerAction, ForumComposerWidget,
+};
+use crate::presentation::ui::friends_view::{FriendsView, FriendsViewAction, FriendsViewWidget};
+use crate::presentation::ui::inbox::{
+    Inbox, InboxAction, InboxMention, InboxWidget, UnreadChannel,
+};
+use crate::presentation::ui::new_dm_picker::{
+    DmCandidate, DmCandidateSource, NewDmPicker, NewDmPickerAction, NewDmPickerWidget,
+};
+use crate::presentation::ui::notification_menu::{
+    NotificationMenu, NotificationMenuAction, NotificationMenuTarget, NotificationMenuWidget,
+};
+use crate::presentation::ui::pins_overlay::{PinsOverlay, PinsOverlayAction, PinsOverlayWidget};
+use crate::presentation::ui::profile_popup::{
+    ProfilePopup, ProfilePopupAction, ProfilePopupWidget,
+};
 use crate::presentation::ui::quick_switcher::{
     QuickSwitcher, QuickSwitcherAction, QuickSwitcherWidget,
 };
+use crate::presentation::ui::search_pane::{
+    SearchPane, SearchPaneAction, SearchPaneFocus, SearchPaneWidget,
+};
+use crate::presentation::ui::status_menu::{StatusMenu, StatusMenuAction, StatusMenuWidget};
 use crate::presentation::ui::utils::sanitize_channel_name;
 use crate::presentation::widgets::{
     ConfirmationModal, FileExplorerAction, FileExplorerComponent, FocusContext, FooterBar,
-    ForumState, GuildsTree, GuildsTreeAction, GuildsTreeData, GuildsTreeState, HeaderBar,
-    ImageManager, MentionPopup, MessageInput, MessageInputAction, MessageInputMode,
-    MessageInputState, MessagePane, MessagePaneAction, MessagePaneData, MessagePaneState,
-    MessagePaneStyle, TreeNodeId, ViewMode,
+    GuildsTree, GuildsTreeAction, GuildsTreeData, GuildsTreeState, HeaderBar, ImageManager,
+    MemberList, MemberListAction, MemberListState, MemberListStyle, MentionPopup, MessageInput,
+    MessageInputAction, MessageInputMode, MessageInputState, MessagePane, MessagePaneAction,
+    MessagePaneData, MessagePaneState, MessagePaneStyle, TreeNodeId, ViewMode,
 };
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Layout, Position, Rect},
     widgets::{StatefulWidget, Widget},
 };
 
@@ -45,48 +81,50 @@ use crate::{NAME, VERSION};
 
 const GUILDS_TREE_WIDTH_PERCENT: u16 = 25;
 const GUILDS_TREE_MIN_WIDTH: u16 = 20;
+const MEMBER_LIST_WIDTH: u16 = 28;
+/// How often `<t:...:R>` timestamps are re-rendered.
+const RELATIVE_TIMESTAMP_REFRESH: Duration = Duration::from_secs(30);
+/// How many unread channel previews one inbox refresh fetches.
+const MAX_INBOX_PREVIEWS: usize = 10;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChatFocus {
     GuildsTree,
     MessagesList,
     MessageInput,
+    MemberList,
     ConfirmationModal,
 }
 
 impl ChatFocus {
-    const fn next(self, guilds_visible: bool) -> Self {
+    /// Panes in focus cycle order, skipping the sidebars that are hidden.
+    fn cycle(guilds_visible: bool, members_visible: bool) -> Vec<Self> {
+        let mut order = vec![Self::MessagesList, Self::MessageInput];
+        if members_visible {
+            order.push(Self::MemberList);
+        }
         if guilds_visible {
-            match self {
-                Self::MessagesList => Self::MessageInput,
-                Self::MessageInput => Self::GuildsTree,
-                Self::GuildsTree | Self::ConfirmationModal => Self::MessagesList,
-            }
-        } else {
-            match self {
-                Self::MessagesList => Self::MessageInput,
-                Self::MessageInput | Self::GuildsTree | Self::ConfirmationModal => {
-                    Self::MessagesList
-                }
-            }
+            order.push(Self::GuildsTree);
         }
+        order
     }
 
-    const fn previous(self, guilds_visible: bool) -> Self {
-        if guilds_visible {
-            match self {
-                Self::GuildsTree => Self::MessageInput,
-                Self::MessagesList => Self::GuildsTree,
-                Self::MessageInput | Self::ConfirmationModal => Self::MessagesList,
-            }
-        } else {
-            match self {
-                Self::MessagesList => Self::MessageInput,
-                Self::MessageInput | Self::GuildsTree | Self::ConfirmationModal => {
-                    Self::MessagesList
-                }
-            }
-        }
+    fn next(self, guilds_visible: bool, members_visible: bool) -> Self {
+        let order = Self::cycle(guilds_visible, members_visible);
+        order
+            .iter()
+            .position(|&focus| focus == self)
+            .map_or(Self::MessagesList, |index| order[(index + 1) % order.len()])
+    }
+
+    fn previous(self, guilds_visible: bool, members_visible: bool) -> Self {
+        let order = Self::cycle(guilds_visible, members_visible);
+        order
+            .iter()
+            .position(|&focus| focus == self)
+            .map_or(Self::MessagesList, |index| {
+                order[(index + order.len() - 1) % order.len()]
+            })
     }
 
     #[must_use]
@@ -95,6 +133,7 @@ impl ChatFocus {
             Self::GuildsTree => FocusContext::GuildsTree,
             Self::MessagesList => FocusContext::MessagesList,
             Self::MessageInput => FocusContext::MessageInput,
+            Self::MemberList => FocusContext::MemberList,
             Self::ConfirmationModal => FocusContext::ConfirmationModal,
         }
     }
@@ -117,6 +156,7 @@ pub enum ChatKeyResult {
     LoadForumThreads {
         channel_id: ChannelId,
         guild_id: Option<GuildId>,
+        filter: ForumFilter,
         offset: u32,
     },
     LoadDmMessages {
@@ -135,11 +175,48 @@ pub enum ChatKeyResult {
         channel_id: ChannelId,
         before_message_id: MessageId,
     },
+    /// Pages a detached history window forward.
+    LoadNewerMessages {
+        channel_id: ChannelId,
+        after_message_id: MessageId,
+    },
+    /// Leaves detached history and reloads the latest messages.
+    JumpToPresent(ChannelId),
     EditMessage(crate::domain::entities::MessageId),
     DeleteMessage(crate::domain::entities::MessageId),
+    AddReaction {
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+    },
+    RemoveReaction {
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+    },
     OpenAttachments(crate::domain::entities::MessageId),
     OpenLink(String),
+    LoadPinnedMessages(ChannelId),
+    LoadUserProfile {
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    },
+    SetMessagePinned {
+        message_id: MessageId,
+        pinned: bool,
+    },
     JumpToMessage(crate::domain::entities::MessageId),
+    SearchMessages(SearchMessagesRequest),
+    CreateThread(CreateThreadRequest),
+    CreateForumPost(CreateForumPostRequest),
+    SetThreadMembership {
+        channel_id: ChannelId,
+        join: bool,
+    },
+    LoadArchivedThreads(ChannelId),
+    /// Opens `channel_id` on a window of messages fetched around `message_id`.
+    LoadMessagesAround {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
     SendMessage {
         content: String,
         reply_to: Option<MessageId>,
@@ -151,10 +228,47 @@ pub enum ChatKeyResult {
         message_id: Option<crate::domain::entities::MessageId>,
     },
     Paste,
+    /// Reads a Discord link from the clipboard and navigates to it.
+    OpenClipboardLink,
     ToggleHelp,
     ToggleDisplayName,
     JumpToChannel(ChannelId),
     RequestChannelFetch(Vec<ChannelId>),
+    ModifyRelationship(RelationshipChange),
+    /// Opens or creates the DM with these users, a group DM when there are several.
+    OpenDirectMessage {
+        recipients: Vec<UserId>,
+        name: Option<String>,
+    },
+    RenameGroupDm {
+        channel_id: ChannelId,
+        name: String,
+    },
+    UpdateNotificationSettings {
+        update: NotificationSettingsUpdate,
+        /// Settings before the update, restored if saving it fails.
+        previous: GuildNotificationSettings,
+    },
+    /// Fetches recent mentions and the latest message of these unread channels.
+    LoadInbox {
+        preview_channels: Vec<ChannelId>,
+    },
+    /// Marks `channel_id` read up to `message_id`.
+    AcknowledgeChannel {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    /// Sets our own status and custom status.
+    UpdateStatus {
+        status: PresenceStatus,
+        custom_status: Option<CustomStatus>,
+    },
+    /// Subscribes the member list of a guild channel to the given index ranges.
+    RequestMemberListRanges {
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        ranges: Vec<(u32, u32)>,
+    },
     ShowNotification(String),
     SaveState,
 }
@@ -189,6 +303,11 @@ impl StatefulWidget for ChatScreen {
         render_content_area(state, content_area, buf);
         render_footer_bar(state, footer_area, buf);
 
+        if state.show_forum_composer {
+            let widget = ForumComposerWidget::new(&state.forum_composer, &state.theme);
+            widget.render(area, buf);
+        }
+
         if state.show_file_explorer {
             render_explorer_popup(state, area, buf);
         }
@@ -198,6 +317,58 @@ impl StatefulWidget for ChatScreen {
             widget.render(area, buf);
         }
 
+        if state.show_emoji_picker {
+            let widget = EmojiPickerWidget::new(&state.emoji_picker, &state.theme);
+            widget.render(area, buf);
+        }
+
+        if state.show_pins {
+            let widget = PinsOverlayWidget::new(&state.pins_overlay, &state.theme);
+            widget.render(area, buf);
+        }
+
+        if state.show_friends {
+            let widget = FriendsViewWidget::new(
+                &state.friends_view,
+                &state.relationship_state,
+                &state.presences,
+                &state.theme,
+            );
+            widget.render(area, buf);
+        }
+
+        if state.show_profile {
+            let roles = state
+                .profile_popup
+                .guild_id()
+                .and_then(|guild_id| state.guild_roles.get(&guild_id));
+            let widget = ProfilePopupWidget::new(&state.profile_popup, &state.theme)
+                .with_roles(roles)
+                .with_guilds(state.guilds_tree_data.guilds());
+            widget.render(area, buf);
+        }
+
+        if state.show_status_menu {
+            let widget = StatusMenuWidget::new(&state.status_menu, &state.theme);
+            widget.render(area, buf);
+        }
+
+        if state.show_new_dm_picker {
+            let widget = NewDmPickerWidget::new(&state.new_dm_picker, &state.theme);
+            widget.render(area, buf);
+        }
+
+        if state.show_notification_menu {
+            let widget = NotificationMenuWidget::new(&state.notification_menu, &state.theme);
+            widget.render(area, buf);
+        }
+
+        if state.show_inbox {
+            let widget = InboxWidget::new(&state.inbox, &state.theme)
+                .with_timestamp_format(&state.timestamp_format);
+            widget.render(area, buf);
+        }
+
         if state.focus == ChatFocus::ConfirmationModal {
             let modal = ConfirmationModal::new(
                 "Delete Message",
@@ -223,6 +394,39 @@ impl StatefulWidget for ChatScreen {
     }
 }
 
+/// Human-readable name of a key binding, such as `Ctrl+k` or `Shift+End`.
+fn key_label(key: &KeyEvent) -> String {
+    use std::fmt::Write;
+    let mut s = String::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        s.push_str("Ctrl+");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        s.push_str("Alt+");
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_uppercase() => {}
+            _ => s.push_str("Shift+"),
+        }
+    }
+
+    match key.code {
+        KeyCode::Char(c) => s.push(c),
+        KeyCode::Enter => s.push_str("Enter"),
+        KeyCode::Tab | KeyCode::BackTab => s.push_str("Tab"),
+        KeyCode::Esc => s.push_str("Esc"),
+        KeyCode::Backspace => s.push_str("Backspace"),
+        KeyCode::Up => s.push_str("Up"),
+        KeyCode::Down => s.push_str("Down"),
+        KeyCode::Left => s.push_str("Left"),
+        KeyCode::Right => s.push_str("Right"),
+        KeyCode::F(n) => write!(s, "F{n}").unwrap(),
+        code => write!(s, "{code:?}").unwrap(),
+    }
+    s
+}
+
 #[allow(clippy::too_many_lines)]
 fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
     use crate::domain::keybinding::Action;
@@ -242,7 +446,7 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
         .add_modifier(ratatui::style::Modifier::BOLD);
 
     let width = 90;
-    let height = 38;
+    let height = 64;
     let x = (area.width.saturating_sub(width)) / 2;
     let y = (area.height.saturating_sub(height)) / 2;
     let popup_area = Rect::new(x, y, width.min(area.width), height.min(area.height));
@@ -260,7 +464,7 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
 
     let v_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(23), Constraint::Min(0)])
+        .constraints([Constraint::Length(45), Constraint::Min(0)])
         .split(inner_area);
 
     let top_chunks = Layout::default()
@@ -283,7 +487,17 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
             (Action::FocusMessages, "Focus Messages"),
             (Action::FocusInput, "Focus Input"),
             (Action::ToggleGuildsTree, "Toggle Guilds Tree"),
+            (Action::ToggleMemberList, "Toggle Member List"),
             (Action::ToggleQuickSwitcher, "Quick Switcher"),
+            (Action::OpenPins, "Pinned Messages"),
+            (Action::SearchMessages, "Search Messages"),
+            (Action::JumpToPresent, "Jump to Present"),
+            (Action::OpenClipboardLink, "Open Link from Clipboard"),
+            (Action::OpenStatusMenu, "Set Status"),
+            (Action::NewDirectMessage, "New Direct Message"),
+            (Action::RenameGroupDm, "Rename Group DM"),
+            (Action::NotificationSettings, "Notification Settings"),
+            (Action::OpenInbox, "Inbox"),
         ],
     )];
 
@@ -311,6 +525,17 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
             (Action::YankId, "Copy Message ID"),
             (Action::OpenAttachments, "Open Image"),
             (Action::JumpToReply, "Jump to Reply"),
+            (Action::ToggleReaction, "Toggle Reaction"),
+            (Action::NextReaction, "Next Reaction"),
+            (Action::AddReaction, "Add Reaction"),
+            (Action::TogglePin, "Pin / Unpin"),
+            (Action::OpenThread, "Open Thread"),
+            (Action::StartThread, "Start Thread"),
+            (Action::ToggleThreadMembership, "Join / Leave Thread"),
+            (Action::NewForumPost, "New Forum Post"),
+            (Action::CycleForumSort, "Forum Sort Order"),
+            (Action::CycleForumTagFilter, "Forum Tag Filter"),
+            (Action::OpenProfile, "User Profile"),
             (Action::ToggleDisplayName, "Toggle Display Name"),
         ],
     )];
@@ -323,6 +548,7 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
             (Action::NewLine, "New Line"),
             (Action::Paste, "Paste (Text/Image)"),
             (Action::OpenEditor, "Open External Editor"),
+            (Action::OpenEmojiPicker, "Emoji Picker"),
             (Action::ClearInput, "Clear Input"),
             (Action::Cancel, "Cancel Reply / Exit"),
         ],
@@ -331,44 +557,7 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
     let format_key = |action: Action| -> String {
         state.registry.get(action).map_or_else(
             || "N/A".to_string(),
-            |keys| {
-                keys.iter()
-                    .map(|k| {
-                        use std::fmt::Write;
-                        let mut s = String::new();
-                        if k.modifiers
-                            .contains(crossterm::event::KeyModifiers::CONTROL)
-                        {
-                            s.push_str("Ctrl+");
-                        }
-                        if k.modifiers.contains(crossterm::event::KeyModifiers::ALT) {
-                            s.push_str("Alt+");
-                        }
-                        if k.modifiers.contains(crossterm::event::KeyModifiers::SHIFT) {
-                            match k.code {
-                                KeyCode::Char(c) if c.is_ascii_uppercase() => {}
-                                _ => s.push_str("Shift+"),
-                            }
-                        }
-
-                        match k.code {
-                            KeyCode::Char(c) => s.push(c),
-                            KeyCode::Enter => s.push_str("Enter"),
-                            KeyCode::Tab | KeyCode::BackTab => s.push_str("Tab"),
-                            KeyCode::Esc => s.push_str("Esc"),
-                            KeyCode::Backspace => s.push_str("Backspace"),
-                            KeyCode::Up => s.push_str("Up"),
-                            KeyCode::Down => s.push_str("Down"),
-                            KeyCode::Left => s.push_str("Left"),
-                            KeyCode::Right => s.push_str("Right"),
-                            KeyCode::F(n) => write!(s, "F{n}").unwrap(),
-                            _ => write!(s, "{k:?}").unwrap(),
-                        }
-                        s
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            },
+            |keys| keys.iter().map(key_label).collect::<Vec<_>>().join(", "),
         )
     };
 
@@ -435,18 +624,8 @@ fn render_help_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
 }
 
 fn render_explorer_popup(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
+    let (_, content_area) = state.split_content_area(area);
     if let Some(explorer) = &mut state.file_explorer {
-        let content_area = if state.guilds_tree_visible {
-            let chunks = Layout::horizontal([
-                Constraint::Percentage(GUILDS_TREE_WIDTH_PERCENT),
-                Constraint::Min(0),
-            ])
-            .split(area);
-            chunks[1]
-        } else {
-            area
-        };
-
         let base_area = if content_area.width < GUILDS_TREE_MIN_WIDTH {
             area
         } else {
@@ -471,7 +650,8 @@ fn render_header_bar(state: &ChatScreenState, area: Rect, buf: &mut Buffer) {
     let style = HeaderBarStyle::from_theme(&state.theme);
     let header = HeaderBar::new(NAME, VERSION)
         .style(style)
-        .connection_status(state.connection_status());
+        .connection_status(state.connection_status())
+        .presence(state.own_status, state.own_custom_status.as_ref());
     Widget::render(header, area, buf);
 }
 
@@ -502,26 +682,34 @@ fn render_footer_bar(state: &ChatScreenState, area: Rect, buf: &mut Buffer) {
 }
 
 fn render_content_area(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
-    if state.guilds_tree_visible {
-        let content_layout = Layout::horizontal([
-            Constraint::Percentage(GUILDS_TREE_WIDTH_PERCENT),
-            Constraint::Min(0),
-        ]);
-        let [guilds_area, messages_area] = content_layout.areas(area);
-
-        let guilds_area = if guilds_area.width < GUILDS_TREE_MIN_WIDTH {
-            Rect {
-                width: GUILDS_TREE_MIN_WIDTH,
-                ..guilds_area
-            }
-        } else {
-            guilds_area
-        };
+    let (guilds_area, messages_area) = state.split_content_area(area);
+    state.layout.content = area;
+    state.layout.guilds_tree = guilds_area;
 
+    if let Some(guilds_area) = guilds_area {
         render_guilds_tree(state, guilds_area, buf);
+    }
+
+    let messages_area = if state.is_member_list_shown() {
+        let [messages_area, members_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(MEMBER_LIST_WIDTH)])
+                .areas(messages_area);
+        state.layout.member_list = Some(members_area);
+        render_member_list(state, members_area, buf);
+        messages_area
+    } else {
+        state.layout.member_list = None;
+        messages_area
+    };
+
+    if state.show_search {
+        let [messages_area, search_area] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(messages_area);
         render_messages_area(state, messages_area, buf);
+        SearchPaneWidget::new(&state.search_pane, &state.theme).render(search_area, buf);
     } else {
-        render_messages_area(state, area, buf);
+        render_messages_area(state, messages_area, buf);
     }
 }
 
@@ -537,6 +725,16 @@ fn render_guilds_tree(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer)
     StatefulWidget::render(tree, area, buf, tree_state);
 }
 
+fn render_member_list(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
+    let style = MemberListStyle::from_theme(&state.theme);
+    let roles = state
+        .member_list_state
+        .guild_id()
+        .and_then(|guild_id| state.guild_roles.get(&guild_id));
+    let list = MemberList::new(style).roles(roles);
+    StatefulWidget::render(list, area, buf, &mut state.member_list_state);
+}
+
 fn render_message_pane(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
     use crate::presentation::widgets::MessagePaneStyle;
 
@@ -545,7 +743,16 @@ fn render_message_pane(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer
     let image_preview = state.image_preview;
     let timestamp_format = state.timestamp_format.clone();
     let relationship_state = state.relationship_state.clone();
+    let presences = state.presences.clone();
     let hide_blocked_completely = state.hide_blocked_completely;
+    let scope = state
+        .message_pane_data
+        .channel_id()
+        .map(|channel_id| state.message_scope(channel_id))
+        .unwrap_or_default();
+    state
+        .message_pane_data
+        .set_highlight_rules(&state.notification_rules, scope);
 
     let inner_width = area.width.saturating_sub(2);
     let style = MessagePaneStyle::from_theme(&state.theme);
@@ -557,6 +764,10 @@ fn render_message_pane(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer
         image_preview,
     );
     let current_user_id = state.user().id().to_string();
+    let jump_to_present_key = state
+        .registry
+        .get_first(Action::JumpToPresent)
+        .map(|key| key_label(&key));
     let (data, pane_state) = state.message_pane_parts_mut();
 
     let pane = MessagePane::new(data, &service)
@@ -566,7 +777,9 @@ fn render_message_pane(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer
         .with_timestamp_format(&timestamp_format)
         .with_current_user_id(current_user_id)
         .with_relationship_state(&relationship_state)
-        .with_hide_blocked_completely(hide_blocked_completely);
+        .with_presences(&presences)
+        .with_hide_blocked_completely(hide_blocked_completely)
+        .with_jump_to_present_key(jump_to_present_key.as_deref());
     StatefulWidget::render(pane, area, buf, pane_state);
 }
 
@@ -579,9 +792,59 @@ fn render_message_input(state: &mut ChatScreenState, area: Rect, buf: &mut Buffe
         .render(state.message_input_parts_mut(), area, buf);
 }
 
+fn render_thread_parent(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
+    use crate::presentation::widgets::MessagePaneStyle;
+
+    let style = MessagePaneStyle::from_theme(&state.theme);
+    let current_user_id = state.user().id().to_string();
+    let scope = state
+        .thread_parent
+        .as_ref()
+        .and_then(|parent| parent.data.channel_id())
+        .map(|channel_id| state.message_scope(channel_id))
+        .unwrap_or_default();
+    let Some(parent) = state.thread_parent.as_mut() else {
+        return;
+    };
+
+    parent
+        .data
+        .set_highlight_rules(&state.notification_rules, scope);
+    parent.data.update_layout(
+        area.width.saturating_sub(2),
+        &state.markdown_service,
+        style.content_style,
+        parent.state.show_spoilers,
+        state.image_preview,
+    );
+
+    let pane = MessagePane::new(&mut parent.data, &state.markdown_service)
+        .style(style)
+        .with_disable_user_colors(state.disable_user_colors)
+        .with_image_preview(state.image_preview)
+        .with_timestamp_format(&state.timestamp_format)
+        .with_current_user_id(current_user_id)
+        .with_relationship_state(&state.relationship_state)
+        .with_presences(&state.presences)
+        .with_hide_blocked_completely(state.hide_blocked_completely);
+    StatefulWidget::render(pane, area, buf, &mut parent.state);
+}
+
 fn render_messages_area(state: &mut ChatScreenState, area: Rect, buf: &mut Buffer) {
+    let area = if state.thread_parent.is_some() {
+        let [parent_area, thread_area] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(area);
+        render_thread_parent(state, parent_area, buf);
+        thread_area
+    } else {
+        area
+    };
+
     let layout = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]);
     let [messages_area, input_area] = layout.areas(area);
+    state.layout.message_pane = messages_area;
+    state.layout.message_input = input_area;
 
     render_message_pane(state, messages_area, buf);
     render_message_input(state, input_area, buf);
@@ -605,10 +868,28 @@ fn render_messages_area(state: &mut ChatScreenState, area: Rect, buf: &mut Buffe
     }
 }
 
+/// The channel a thread was opened from, kept as it was when the thread opened.
+struct ThreadParent {
+    channel: Option<Channel>,
+    data: MessagePaneData,
+    state: MessagePaneState,
+}
+
+/// Screen areas of the chat widgets in the last frame, used to route mouse events.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChatLayout {
+    content: Rect,
+    guilds_tree: Option<Rect>,
+    message_pane: Rect,
+    message_input: Rect,
+    member_list: Option<Rect>,
+}
+
 #[derive(Debug, Clone)]
 pub struct DmChannelInfo {
     channel_id: ChannelId,
     recipient_name: String,
+    is_group: bool,
 }
 
 impl DmChannelInfo {
@@ -617,9 +898,21 @@ impl DmChannelInfo {
         Self {
             channel_id,
             recipient_name,
+            is_group: false,
         }
     }
 
+    #[must_use]
+    pub const fn with_group(mut self, is_group: bool) -> Self {
+        self.is_group = is_group;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_group(&self) -> bool {
+        self.is_group
+    }
+
     #[must_use]
     pub const fn channel_id(&self) -> ChannelId {
         self.channel_id
@@ -636,12 +929,15 @@ pub struct ChatScreenState {
     user: User,
     focus: ChatFocus,
     guilds_tree_visible: bool,
+    member_list_state: MemberListState,
+    member_list_visible: bool,
     guilds_tree_state: GuildsTreeState,
     guilds_tree_data: GuildsTreeData,
     message_pane_state: MessagePaneState,
     message_pane_data: MessagePaneData,
     message_input_state: MessageInputState<'static>,
     autocomplete_service: AutocompleteService,
+    channel_searcher: FuzzySearcher,
     user_cache: UserCache,
     selected_guild: Option<GuildId>,
     selected_channel: Option<Channel>,
@@ -656,6 +952,8 @@ pub struct ChatScreenState {
     entrance_effect: Effect,
     pending_duration: Duration,
     has_entered: bool,
+    /// Time since relative timestamps in the message pane were last re-rendered.
+    relative_timestamp_age: Duration,
     /// Image manager for rendering image attachments.
     image_manager: ImageManager,
     disable_user_colors: bool,
@@ -667,7 +965,46 @@ pub struct ChatScreenState {
     pending_deletion_id: Option<MessageId>,
     quick_switcher: QuickSwitcher,
     show_quick_switcher: bool,
+    emoji_picker: EmojiPicker,
+    show_emoji_picker: bool,
+    pins_overlay: PinsOverlay,
+    show_pins: bool,
+    profile_popup: ProfilePopup,
+    show_profile: bool,
+    status_menu: StatusMenu,
+    show_status_menu: bool,
+    friends_view: FriendsView,
+    show_friends: bool,
+    new_dm_picker: NewDmPicker,
+    show_new_dm_picker: bool,
+    notification_menu: NotificationMenu,
+    show_notification_menu: bool,
+    inbox: Inbox,
+    show_inbox: bool,
+    notification_settings: NotificationSettingsStore,
+    notification_rules: NotificationRules,
+    own_status: PresenceStatus,
+    own_custom_status: Option<CustomStatus>,
+    search_pane: SearchPane,
+    show_search: bool,
+    forum_composer: ForumComposer,
+    show_forum_composer: bool,
+    /// Link waiting for its guild's channels to load.
+    pending_link: Option<DiscordLink>,
+    /// Link waiting for its channel, such as an archived thread, to be fetched.
+    pending_channel_link: Option<DiscordLink>,
+    /// Message to select once the channel's messages arrive.
+    pending_jump: Option<(ChannelId, MessageId)>,
+    /// Channel shown read-only beside the thread opened from one of its messages.
+    thread_parent: Option<ThreadParent>,
+    /// Channels whose archived threads were already requested.
+    fetched_archived_threads: std::collections::HashSet<ChannelId>,
+    /// Sidebar width in columns once its border has been dragged.
+    guilds_tree_width: Option<u16>,
+    resizing_guilds_tree: bool,
+    layout: ChatLayout,
     relationship_state: RelationshipState,
+    presences: PresenceStore,
     hide_blocked_completely: bool,
     last_scroll_state: Option<(usize, u16)>,
     pub recents: Vec<crate::domain::search::RecentItem>,
@@ -677,6 +1014,7 @@ pub struct ChatScreenState {
     guild_roles: std::collections::HashMap<GuildId, std::collections::HashMap<crate::domain::entities::RoleId, Role>>,
     guild_members: std::collections::HashMap<GuildId, Member>,
     raw_channels: std::collections::HashMap<GuildId, Vec<Channel>>,
+    guild_emojis: std::collections::HashMap<GuildId, Vec<CustomEmoji>>,
 }
 
 impl ChatScreenState {
@@ -692,6 +1030,7 @@ impl ChatScreenState {
     #[must_use]
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::fn_params_excessive_bools)]
+    #[allow(clippy::too_many_lines)]
     pub fn new(
         user: User,
         markdown_service: Arc<MarkdownRenderer>,
@@ -704,6 +1043,7 @@ impl ChatScreenState {
         enable_animations: bool,
         registry: CommandRegistry,
         relationship_state: RelationshipState,
+        presences: PresenceStore,
         hide_blocked_completely: bool,
         quick_switcher_order: QuickSwitcherSortMode,
         recents: Vec<crate::domain::search::RecentItem>,
@@ -752,28 +1092,76 @@ impl ChatScreenState {
             pending_deletion_id: None,
             quick_switcher: QuickSwitcher::new(quick_switcher_order),
             show_quick_switcher: false,
+            emoji_picker: EmojiPicker::new(),
+            show_emoji_picker: false,
+            pins_overlay: PinsOverlay::new(),
+            show_pins: false,
+            profile_popup: ProfilePopup::new(),
+            show_profile: false,
+            status_menu: StatusMenu::new(),
+            show_status_menu: false,
+            friends_view: FriendsView::new(),
+            show_friends: false,
+            new_dm_picker: NewDmPicker::new(),
+            show_new_dm_picker: false,
+            notification_menu: NotificationMenu::new(),
+            show_notification_menu: false,
+            inbox: Inbox::new(),
+            show_inbox: false,
+            notification_settings: NotificationSettingsStore::new(),
+            notification_rules: NotificationRules::default(),
+            own_status: PresenceStatus::Online,
+            own_custom_status: None,
+            search_pane: SearchPane::new(),
+            show_search: false,
+            forum_composer: ForumComposer::new(),
+            show_forum_composer: false,
+            pending_link: None,
+            pending_channel_link: None,
+            pending_jump: None,
+            thread_parent: None,
+            fetched_archived_threads: std::collections::HashSet::new(),
+            guilds_tree_width: None,
+            resizing_guilds_tree: false,
+            layout: ChatLayout::default(),
             relationship_state,
+            presences,
             hide_blocked_completely,
             last_scroll_state: None,
             recents: valid_recents.clone(),
             favorites: favorites.clone(),
             guilds_tree_visible: true,
+            member_list_state: MemberListState::new(),
+            member_list_visible: false,
             autocomplete_service:
                 crate::application::services::autocomplete_service::AutocompleteService::new(),
+            channel_searcher: FuzzySearcher::new(),
             connection_status: crate::domain::ConnectionStatus::Disconnected,
             file_explorer: Some(crate::presentation::widgets::FileExplorerComponent::new()),
             show_file_explorer: false,
             entrance_effect,
             pending_duration: std::time::Duration::ZERO,
+            relative_timestamp_age: std::time::Duration::ZERO,
             has_entered: !enable_animations,
             image_manager: crate::presentation::widgets::ImageManager::new(),
             guild_roles: std::collections::HashMap::new(),
             guild_members: std::collections::HashMap::new(),
             raw_channels: std::collections::HashMap::new(),
+            guild_emojis: std::collections::HashMap::new(),
         };
 
+        state
+            .guilds_tree_data
+            .set_presences(state.presences.clone());
+        state
+            .guilds_tree_data
+            .set_relationships(state.relationship_state.clone());
         state.quick_switcher.set_recents(valid_recents);
         state.quick_switcher.set_favorites(favorites);
+        let inline_emoji_images = state.image_manager.supports_inline_images();
+        state
+            .message_pane_data
+            .set_inline_emoji_images(inline_emoji_images);
         state
     }
 
@@ -864,6 +1252,12 @@ impl ChatScreenState {
         if !self.has_entered {
             self.pending_duration = self.pending_duration.saturating_add(duration);
         }
+
+        self.relative_timestamp_age = self.relative_timestamp_age.saturating_add(duration);
+        if self.relative_timestamp_age >= RELATIVE_TIMESTAMP_REFRESH {
+            self.relative_timestamp_age = Duration::ZERO;
+            self.message_pane_data.refresh_relative_timestamps();
+        }
     }
 
     #[must_use]
@@ -928,6 +1322,7 @@ impl ChatScreenState {
         self.raw_channels.remove(&guild_id);
         self.guild_roles.remove(&guild_id);
         self.guild_members.remove(&guild_id);
+        self.guild_emojis.remove(&guild_id);
 
         let guild_id_str = guild_id.to_string();
         self.recents.retain(|r| {
@@ -944,6 +1339,9 @@ impl ChatScreenState {
     }
 
     pub fn remove_channel(&mut self, channel_id: ChannelId) {
+        for channels in self.raw_channels.values_mut() {
+            channels.retain(|c| c.id() != channel_id);
+        }
         self.guilds_tree_data.remove_channel(channel_id);
         self.forum_states.remove(&channel_id);
 
@@ -962,7 +1360,11 @@ impl ChatScreenState {
         roles: Vec<Role>,
         mut members: Vec<Member>,
     ) {
-        let roles_map = roles.into_iter().map(|r| (r.id, r)).collect();
+        let roles_map: std::collections::HashMap<_, _> =
+            roles.into_iter().map(|r| (r.id, r)).collect();
+        if self.selected_guild == Some(guild_id) {
+            self.message_pane_data.set_roles(roles_map.values());
+        }
         self.guild_roles.insert(guild_id, roles_map);
 
         // Find the member corresponding to self.user.id
@@ -974,11 +1376,215 @@ impl ChatScreenState {
         }
     }
 
+    pub fn set_guild_emojis(&mut self, guild_id: GuildId, emojis: Vec<CustomEmoji>) {
+        self.guild_emojis.insert(guild_id, emojis);
+    }
+
     pub fn set_channels(&mut self, guild_id: GuildId, channels: Vec<Channel>) {
         self.raw_channels.insert(guild_id, channels);
-        let Some(channels_ref) = self.raw_channels.get(&guild_id) else {
+        self.refresh_guild_channels(guild_id);
+    }
+
+    fn refresh_guild_channels(&mut self, guild_id: GuildId) {
+        let visible_channels = self.visible_channels(guild_id);
+
+        self.guilds_tree_data
+            .set_channels(guild_id, visible_channels);
+        self.recalculate_all_unread();
+    }
+
+    /// Adds or replaces threads of guilds whose channels are loaded.
+    pub fn upsert_threads(&mut self, threads: Vec<Channel>) {
+        for guild_id in self.merge_threads(threads) {
+            self.refresh_guild_channels(guild_id);
+        }
+    }
+
+    /// Replaces the active threads of `channel_ids`, or of the whole guild when
+    /// the list is empty. Archived threads are kept.
+    pub fn sync_threads(
+        &mut self,
+        guild_id: GuildId,
+        channel_ids: &[ChannelId],
+        threads: Vec<Channel>,
+    ) {
+        let Some(channels) = self.raw_channels.get_mut(&guild_id) else {
             return;
         };
+        channels.retain(|c| {
+            let synced = channel_ids.is_empty()
+                || c.parent_id()
+                    .is_some_and(|parent_id| channel_ids.contains(&parent_id));
+            !(c.kind().is_thread() && !c.is_archived() && synced)
+        });
+        self.merge_threads(threads);
+        self.refresh_guild_channels(guild_id);
+    }
+
+    fn merge_threads(&mut self, threads: Vec<Channel>) -> std::collections::HashSet<GuildId> {
+        let mut guild_ids = std::collections::HashSet::new();
+        for thread in threads {
+            let Some(guild_id) = thread.guild_id() else {
+                continue;
+            };
+            let Some(channels) = self.raw_channels.get_mut(&guild_id) else {
+                continue;
+            };
+
+            if let Some(selected) = &mut self.selected_channel
+                && selected.id() == thread.id()
+            {
+                let member = thread.is_thread_member() || selected.is_thread_member();
+                *selected = thread.clone().with_thread_member(member);
+            }
+
+            if let Some(existing) = channels.iter_mut().find(|c| c.id() == thread.id()) {
+                let member = thread.is_thread_member() || existing.is_thread_member();
+                *existing = thread.with_thread_member(member);
+            } else {
+                channels.push(thread);
+            }
+            guild_ids.insert(guild_id);
+        }
+        guild_ids
+    }
+
+    pub fn set_thread_member(&mut self, channel_id: ChannelId, member: bool) {
+        for channel in self.raw_channels.values_mut().flatten() {
+            if channel.id() == channel_id {
+                channel.set_thread_member(member);
+            }
+        }
+        if let Some(channel) = self.guilds_tree_data.get_channel_mut(channel_id) {
+            channel.set_thread_member(member);
+        }
+        if let Some(channel) = &mut self.selected_channel
+            && channel.id() == channel_id
+        {
+            channel.set_thread_member(member);
+        }
+    }
+
+    /// Whether the archived threads of `channel_id` still need fetching; marks
+    /// them as fetched.
+    pub fn should_fetch_archived_threads(&mut self, channel_id: ChannelId) -> bool {
+        self.guilds_tree_data.can_have_threads(channel_id)
+            && self.fetched_archived_threads.insert(channel_id)
+    }
+
+    /// Name for a thread started from `message`: its first line, shortened.
+    fn thread_name(message: &Message) -> String {
+        let name: String = message
+            .content()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .chars()
+            .take(40)
+            .collect();
+        if name.is_empty() {
+            "Thread".to_string()
+        } else {
+            name
+        }
+    }
+
+    fn start_thread(&self, message_id: MessageId) -> ChatKeyResult {
+        let Some(channel) = &self.selected_channel else {
+            return ChatKeyResult::Ignored;
+        };
+        if !matches!(
+            channel.kind(),
+            ChannelKind::Text | ChannelKind::Announcement
+        ) {
+            return ChatKeyResult::ShowNotification(
+                "Threads can only be started in text channels".to_string(),
+            );
+        }
+        let Some(message) = self
+            .message_pane_data
+            .messages()
+            .iter()
+            .find(|m| m.message.id() == message_id)
+        else {
+            return ChatKeyResult::Ignored;
+        };
+
+        ChatKeyResult::CreateThread(
+            CreateThreadRequest::new(channel.id(), Self::thread_name(&message.message))
+                .from_message(message_id),
+        )
+    }
+
+    /// Opens a thread of the current channel, such as one started from one of
+    /// its messages, keeping the channel visible beside it.
+    pub fn open_message_thread(&mut self, thread_id: ChannelId) -> Option<ChatKeyResult> {
+        if self.thread_parent.is_some() {
+            return None;
+        }
+        let starter = self
+            .message_pane_data
+            .messages()
+            .iter()
+            .find(|m| m.message.thread_id() == Some(thread_id));
+
+        if self.guilds_tree_data.get_channel(thread_id).is_none()
+            && let Some(starter) = starter
+            && let Some(guild_id) = self.selected_guild
+            && let Some(parent) = &self.selected_channel
+        {
+            let thread = Channel::new(
+                thread_id,
+                Self::thread_name(&starter.message),
+                ChannelKind::PublicThread,
+            )
+            .with_guild(guild_id)
+            .with_parent(parent.id());
+            self.upsert_threads(vec![thread]);
+        }
+        let thread = self.guilds_tree_data.get_channel(thread_id)?;
+        if !thread.kind().is_thread()
+            || thread.parent_id() != self.message_pane_data.channel_id()
+            || matches!(self.message_pane_state.view_mode, ViewMode::Forum(_))
+        {
+            return None;
+        }
+
+        let mut state = std::mem::take(&mut self.message_pane_state);
+        state.set_focused(false);
+        let parent = ThreadParent {
+            channel: self.selected_channel.clone(),
+            data: self.message_pane_data.split_off(),
+            state,
+        };
+        let result = self.on_channel_selected(thread_id);
+        self.thread_parent = Some(parent);
+        result
+    }
+
+    /// Closes the thread split and shows its channel again; false if no split
+    /// is open.
+    fn close_thread_split(&mut self) -> bool {
+        let Some(parent) = self.thread_parent.take() else {
+            return false;
+        };
+
+        self.selected_channel = parent.channel;
+        self.message_pane_data = parent.data;
+        self.message_pane_state = parent.state;
+        self.guilds_tree_data
+            .set_active_channel(self.selected_channel.as_ref().map(Channel::id));
+        self.message_input_state.clear();
+        self.focus_messages_list();
+        true
+    }
+
+    /// Channels of the guild the current user has `VIEW_CHANNEL` on.
+    fn visible_channels(&self, guild_id: GuildId) -> Vec<Channel> {
+        let Some(channels_ref) = self.raw_channels.get(&guild_id) else {
+            return Vec::new();
+        };
 
         let channel_map: std::collections::HashMap<ChannelId, &Channel> =
             channels_ref.iter().map(|c| (c.id(), c)).collect();
@@ -1016,7 +1622,7 @@ impl ChatScreenState {
             }
         }
 
-        let visible_channels: Vec<Channel> = channels_ref
+        channels_ref
             .iter()
             .filter(|c| {
                 if !visible_ids.contains(&c.id()) {
@@ -1033,41 +1639,83 @@ impl ChatScreenState {
                 true
             })
             .cloned()
-            .collect();
-
-        self.guilds_tree_data
-            .set_channels(guild_id, visible_channels);
-        self.recalculate_all_unread();
+            .collect()
     }
 
     pub fn set_dm_users(&mut self, users: Vec<DirectMessageChannel>) {
         self.dm_channels.clear();
         for dm in &users {
-            if let Ok(channel_id) = dm.channel_id.parse::<u64>() {
-                let user = User::new(
-                    dm.recipient_id.clone(),
-                    dm.recipient_username.clone(),
-                    &dm.recipient_discriminator,
-                    None,
-                    false,
-                    None,
-                )
-                .with_global_name(dm.recipient_global_name.clone().unwrap_or_default());
-
-                let name = IdentityResolver::with_preference(self.use_display_name).resolve(&user);
-                let info = DmChannelInfo::new(ChannelId(channel_id), name);
-                self.dm_channels.insert(dm.channel_id.clone(), info);
-            }
+            self.insert_dm_info(dm);
         }
         self.guilds_tree_data.set_dm_users(users);
     }
 
-    pub fn set_read_states(
-        &mut self,
-        read_states: std::collections::HashMap<ChannelId, crate::domain::entities::ReadState>,
-    ) {
-        self.read_states = read_states;
-        self.recalculate_all_unread();
+    fn insert_dm_info(&mut self, dm: &DirectMessageChannel) {
+        let Ok(channel_id) = dm.channel_id.parse::<u64>() else {
+            return;
+        };
+        let name = if dm.is_group {
+            dm.display_name(self.use_display_name)
+        } else {
+            let user = User::new(
+                dm.recipient_id().to_string(),
+                dm.recipient_username().to_string(),
+                dm.recipient_discriminator(),
+                None,
+                false,
+                None,
+            )
+            .with_global_name(dm.recipient_global_name().unwrap_or_default());
+            IdentityResolver::with_preference(self.use_display_name).resolve(&user)
+        };
+        let info = DmChannelInfo::new(ChannelId(channel_id), name).with_group(dm.is_group);
+        self.dm_channels.insert(dm.channel_id.clone(), info);
+    }
+
+    /// Adds a newly opened DM to the top of the DM list (or refreshes it) and selects it.
+    pub fn open_dm_channel(&mut self, dm: DirectMessageChannel) -> Option<ChatKeyResult> {
+        let channel_id = dm.channel_id.clone();
+        self.insert_dm_info(&dm);
+        let mut dms = self.guilds_tree_data.dm_users().to_vec();
+        dms.retain(|existing| existing.channel_id != channel_id);
+        dms.insert(0, dm);
+        self.guilds_tree_data.set_dm_users(dms);
+        self.on_dm_selected(&channel_id)
+    }
+
+    /// Applies a new group DM name to the DM list and, if it is open, the message pane.
+    pub fn rename_dm_channel(&mut self, channel_id: ChannelId, name: &str) {
+        let key = channel_id.to_string();
+        let mut dms = self.guilds_tree_data.dm_users().to_vec();
+        let Some(dm) = dms.iter_mut().find(|dm| dm.channel_id == key) else {
+            return;
+        };
+        *dm = dm.clone().with_name(name);
+        let dm = dm.clone();
+        self.insert_dm_info(&dm);
+        self.guilds_tree_data.set_dm_users(dms);
+
+        if self
+            .selected_channel
+            .as_ref()
+            .is_some_and(|channel| channel.id() == channel_id)
+        {
+            let display_name = dm.display_name(self.use_display_name);
+            self.selected_channel = Some(Channel::new(
+                channel_id,
+                display_name.clone(),
+                ChannelKind::GroupDm,
+            ));
+            self.message_pane_data.set_channel(channel_id, display_name);
+        }
+    }
+
+    pub fn set_read_states(
+        &mut self,
+        read_states: std::collections::HashMap<ChannelId, crate::domain::entities::ReadState>,
+    ) {
+        self.read_states = read_states;
+        self.recalculate_all_unread();
     }
 
     fn recalculate_all_unread(&mut self) {
@@ -1088,6 +1736,8 @@ impl ChatScreenState {
     }
 
     pub fn on_message_received(&mut self, message: &Message) {
+        self.inbox.note_message(message);
+
         if let Some(channel) = self.guilds_tree_data.get_channel_mut(message.channel_id()) {
             channel.set_last_message_id(Some(message.id()));
 
@@ -1140,6 +1790,41 @@ impl ChatScreenState {
         }
     }
 
+    pub fn toggle_member_list(&mut self) {
+        self.member_list_visible = !self.member_list_visible;
+        if !self.member_list_visible && self.focus == ChatFocus::MemberList {
+            self.focus_messages_list();
+        }
+    }
+
+    /// Whether the member list sidebar is drawn, which needs a guild channel open.
+    #[must_use]
+    pub fn is_member_list_shown(&self) -> bool {
+        self.member_list_visible
+            && self.member_list_state.channel_id().is_some()
+            && self.selected_channel.as_ref().map(Channel::id)
+                == self.member_list_state.channel_id()
+    }
+
+    pub fn focus_member_list(&mut self) {
+        if self.is_member_list_shown() {
+            self.set_focus(ChatFocus::MemberList);
+        }
+    }
+
+    /// Applies a member list update from the gateway to the open channel's list.
+    pub fn on_member_list_update(
+        &mut self,
+        guild_id: GuildId,
+        list_id: &str,
+        counts: (u32, u32),
+        groups: &[MemberListGroup],
+        ops: Vec<MemberListOp>,
+    ) {
+        self.member_list_state
+            .apply_update(guild_id, list_id, counts, groups, ops);
+    }
+
     pub fn focus_guilds_tree(&mut self) {
         if self.guilds_tree_visible {
             self.set_focus(ChatFocus::GuildsTree);
@@ -1155,15 +1840,163 @@ impl ChatScreenState {
     }
 
     pub fn focus_next(&mut self) {
-        let new_focus = self.focus.next(self.guilds_tree_visible);
+        let new_focus = self
+            .focus
+            .next(self.guilds_tree_visible, self.is_member_list_shown());
         self.set_focus(new_focus);
     }
 
     pub fn focus_previous(&mut self) {
-        let new_focus = self.focus.previous(self.guilds_tree_visible);
+        let new_focus = self
+            .focus
+            .previous(self.guilds_tree_visible, self.is_member_list_shown());
         self.set_focus(new_focus);
     }
 
+    /// Splits the content area into the guilds tree, when shown, and the messages area.
+    fn split_content_area(&self, area: Rect) -> (Option<Rect>, Rect) {
+        if !self.guilds_tree_visible {
+            return (None, area);
+        }
+
+        let guilds_constraint = self.guilds_tree_width.map_or(
+            Constraint::Percentage(GUILDS_TREE_WIDTH_PERCENT),
+            Constraint::Length,
+        );
+        let [guilds_area, messages_area] =
+            Layout::horizontal([guilds_constraint, Constraint::Min(0)]).areas(area);
+
+        let guilds_area = if guilds_area.width < GUILDS_TREE_MIN_WIDTH {
+            Rect {
+                width: GUILDS_TREE_MIN_WIDTH,
+                ..guilds_area
+            }
+        } else {
+            guilds_area
+        };
+        (Some(guilds_area), messages_area)
+    }
+
+    fn is_on_guilds_tree_border(&self, position: Position) -> bool {
+        self.layout.guilds_tree.is_some_and(|area| {
+            area.contains(position) && position.x == area.right().saturating_sub(1)
+        })
+    }
+
+    fn resize_guilds_tree(&mut self, column: u16) {
+        let content = self.layout.content;
+        let max_width = content
+            .width
+            .saturating_sub(GUILDS_TREE_MIN_WIDTH)
+            .max(GUILDS_TREE_MIN_WIDTH);
+        let width = column.saturating_sub(content.x).saturating_add(1);
+        self.guilds_tree_width = Some(width.clamp(GUILDS_TREE_MIN_WIDTH, max_width));
+    }
+
+    /// Routes a mouse event to the widget under the cursor.
+    ///
+    /// Clicks focus the widget they land on; dragging the sidebar border resizes it.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> ChatKeyResult {
+        use crate::presentation::widgets::GuildsTreeStyle;
+
+        if self.show_help
+            || self.show_file_explorer
+            || self.show_quick_switcher
+            || self.show_emoji_picker
+            || self.show_pins
+            || self.show_search
+            || self.show_forum_composer
+            || self.show_profile
+            || self.show_status_menu
+            || self.show_friends
+            || self.show_new_dm_picker
+            || self.show_notification_menu
+            || self.show_inbox
+            || self.focus == ChatFocus::ConfirmationModal
+        {
+            return ChatKeyResult::Ignored;
+        }
+
+        let position = Position::new(mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if self.is_on_guilds_tree_border(position) => {
+                self.resizing_guilds_tree = true;
+                return ChatKeyResult::Consumed;
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.resizing_guilds_tree => {
+                self.resize_guilds_tree(mouse.column);
+                return ChatKeyResult::Consumed;
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.resizing_guilds_tree => {
+                self.resizing_guilds_tree = false;
+                return ChatKeyResult::Consumed;
+            }
+            _ => {}
+        }
+
+        let is_click = mouse.kind == MouseEventKind::Down(MouseButton::Left);
+
+        if self
+            .layout
+            .guilds_tree
+            .is_some_and(|area| area.contains(position))
+        {
+            if !is_click {
+                return ChatKeyResult::Ignored;
+            }
+            self.focus_guilds_tree();
+            let style = GuildsTreeStyle::from_theme(&self.theme);
+            return self
+                .guilds_tree_state
+                .handle_click(
+                    position,
+                    &self.guilds_tree_data,
+                    &style,
+                    self.use_display_name,
+                )
+                .map_or(ChatKeyResult::Consumed, |action| {
+                    self.apply_guilds_tree_action(action)
+                });
+        }
+
+        if self.layout.message_pane.contains(position) {
+            if is_click {
+                self.focus_messages_list();
+            }
+            return self
+                .message_pane_state
+                .handle_mouse(mouse, &self.message_pane_data)
+                .map_or(ChatKeyResult::Consumed, |action| {
+                    self.apply_message_pane_action(action)
+                });
+        }
+
+        if is_click && self.layout.message_input.contains(position) {
+            self.focus_message_input();
+            return ChatKeyResult::Consumed;
+        }
+
+        if let Some(area) = self.layout.member_list
+            && area.contains(position)
+        {
+            let action = match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    self.focus_member_list();
+                    let row = position.y.saturating_sub(area.y + 1);
+                    self.member_list_state.click_row(usize::from(row))
+                }
+                MouseEventKind::ScrollDown => self.member_list_state.scroll_by(1),
+                MouseEventKind::ScrollUp => self.member_list_state.scroll_by(-1),
+                _ => return ChatKeyResult::Ignored,
+            };
+            return action.map_or(ChatKeyResult::Consumed, |action| {
+                self.apply_member_list_action(action)
+            });
+        }
+
+        ChatKeyResult::Ignored
+    }
+
     fn set_focus(&mut self, focus: ChatFocus) {
         self.focus = focus;
         self.guilds_tree_state
@@ -1172,6 +2005,29 @@ impl ChatScreenState {
             .set_focused(focus == ChatFocus::MessagesList);
         self.message_input_state
             .set_focused(focus == ChatFocus::MessageInput);
+        self.member_list_state
+            .set_focused(focus == ChatFocus::MemberList);
+    }
+
+    /// Routes keys to the overlay that captures all input while open, if any.
+    fn handle_modal_key(&mut self, key: KeyEvent) -> Option<ChatKeyResult> {
+        if self.show_forum_composer {
+            Some(self.handle_forum_composer_key(key))
+        } else if self.show_profile {
+            Some(self.handle_profile_key(key))
+        } else if self.show_status_menu {
+            Some(self.handle_status_menu_key(key))
+        } else if self.show_new_dm_picker {
+            Some(self.handle_new_dm_picker_key(key))
+        } else if self.show_notification_menu {
+            Some(self.handle_notification_menu_key(key))
+        } else if self.show_inbox {
+            Some(self.handle_inbox_key(key))
+        } else if self.show_friends {
+            Some(self.handle_friends_key(key))
+        } else {
+            None
+        }
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> ChatKeyResult {
@@ -1214,6 +2070,10 @@ impl ChatScreenState {
             return self.handle_file_explorer_key(key);
         }
 
+        if let Some(result) = self.handle_modal_key(key) {
+            return result;
+        }
+
         if let Some(action) = self.registry.find_action(key)
             && action == Action::ToggleQuickSwitcher
             && !self.show_quick_switcher
@@ -1232,6 +2092,18 @@ impl ChatScreenState {
             return self.handle_quick_switcher_key(key);
         }
 
+        if self.show_emoji_picker {
+            return self.handle_emoji_picker_key(key);
+        }
+
+        if self.show_pins {
+            return self.handle_pins_key(key);
+        }
+
+        if self.show_search {
+            return self.handle_search_key(key);
+        }
+
         if self.focus == ChatFocus::MessageInput {
             let result = self.handle_message_input_key(key);
             if result != ChatKeyResult::Ignored {
@@ -1251,6 +2123,12 @@ impl ChatScreenState {
                         return result;
                     }
                 }
+                ChatFocus::MemberList => {
+                    let result = self.handle_member_list_key(key);
+                    if result != ChatKeyResult::Ignored {
+                        return result;
+                    }
+                }
                 ChatFocus::ConfirmationModal => return ChatKeyResult::Consumed,
                 ChatFocus::MessageInput => unreachable!(),
             }
@@ -1295,6 +2173,10 @@ impl ChatScreenState {
                 self.toggle_guilds_tree();
                 Some(ChatKeyResult::Consumed)
             }
+            Some(Action::ToggleMemberList) => {
+                self.toggle_member_list();
+                Some(ChatKeyResult::Consumed)
+            }
             Some(Action::ToggleHelp) => {
                 if self.focus == ChatFocus::MessageInput && matches!(key.code, KeyCode::Char(_)) {
                     return None;
@@ -1308,6 +2190,28 @@ impl ChatScreenState {
                 self.toggle_quick_switcher();
                 Some(ChatKeyResult::Consumed)
             }
+            Some(Action::OpenPins) => Some(self.open_pins()),
+            Some(Action::SearchMessages) => {
+                self.open_search();
+                Some(ChatKeyResult::Consumed)
+            }
+            Some(Action::JumpToPresent) => self.jump_to_present(),
+            Some(Action::OpenClipboardLink) => Some(ChatKeyResult::OpenClipboardLink),
+            Some(Action::OpenStatusMenu) => {
+                self.open_status_menu();
+                Some(ChatKeyResult::Consumed)
+            }
+            Some(Action::NewDirectMessage) => {
+                self.open_new_dm_picker();
+                Some(ChatKeyResult::Consumed)
+            }
+            Some(Action::RenameGroupDm) => self
+                .open_rename_group_dm()
+                .then_some(ChatKeyResult::Consumed),
+            Some(Action::NotificationSettings) => self
+                .open_notification_menu()
+                .then_some(ChatKeyResult::Consumed),
+            Some(Action::OpenInbox) => Some(self.open_inbox()),
             _ => None,
         }
     }
@@ -1317,214 +2221,346 @@ impl ChatScreenState {
 
         let style = GuildsTreeStyle::from_theme(&self.theme);
 
-        if let Some(action) = self.guilds_tree_state.handle_key(
-            key,
-            &self.guilds_tree_data,
-            &self.registry,
-            &style,
-            self.use_display_name,
-        ) {
-            match action {
-                GuildsTreeAction::SelectChannel(channel_id) => {
-                    if let Some(result) = self.on_channel_selected(channel_id) {
-                        return result;
-                    }
+        self.guilds_tree_state
+            .handle_key(
+                key,
+                &self.guilds_tree_data,
+                &self.registry,
+                &style,
+                self.use_display_name,
+            )
+            .map_or(ChatKeyResult::Ignored, |action| {
+                self.apply_guilds_tree_action(action)
+            })
+    }
+
+    fn handle_member_list_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        self.member_list_state
+            .handle_key(key, &self.registry)
+            .map_or(ChatKeyResult::Ignored, |action| {
+                self.apply_member_list_action(action)
+            })
+    }
+
+    fn apply_member_list_action(&mut self, action: MemberListAction) -> ChatKeyResult {
+        match action {
+            MemberListAction::OpenProfile(member) => self.open_profile(vec![member.user_id]),
+            MemberListAction::RequestRanges(ranges) => {
+                let (Some(guild_id), Some(channel_id)) = (
+                    self.member_list_state.guild_id(),
+                    self.member_list_state.channel_id(),
+                ) else {
+                    return ChatKeyResult::Consumed;
+                };
+                ChatKeyResult::RequestMemberListRanges {
+                    guild_id,
+                    channel_id,
+                    ranges,
                 }
-                GuildsTreeAction::SelectGuild(guild_id) => {
-                    if let Some(result) = self.on_guild_selected(guild_id) {
-                        return result;
-                    }
+            }
+            MemberListAction::FocusMessages => {
+                self.focus_messages_list();
+                ChatKeyResult::Consumed
+            }
+        }
+    }
+
+    fn apply_guilds_tree_action(&mut self, action: GuildsTreeAction) -> ChatKeyResult {
+        match action {
+            GuildsTreeAction::SelectChannel(channel_id) => {
+                if let Some(result) = self.on_channel_selected(channel_id) {
+                    return result;
                 }
-                GuildsTreeAction::SelectDirectMessage(dm_channel_id) => {
-                    if let Some(result) = self.on_dm_selected(&dm_channel_id) {
-                        return result;
-                    }
+            }
+            GuildsTreeAction::SelectGuild(guild_id) => {
+                if let Some(result) = self.on_guild_selected(guild_id) {
+                    return result;
                 }
-                GuildsTreeAction::YankId(id) => {
-                    return ChatKeyResult::CopyToClipboard(id);
+            }
+            GuildsTreeAction::SelectDirectMessage(dm_channel_id) => {
+                if let Some(result) = self.on_dm_selected(&dm_channel_id) {
+                    return result;
                 }
-                GuildsTreeAction::LoadGuildChannels(guild_id) => {
-                    return ChatKeyResult::LoadGuildChannels(guild_id);
+            }
+            GuildsTreeAction::OpenFriends => {
+                self.friends_view.open();
+                self.show_friends = true;
+                return ChatKeyResult::Consumed;
+            }
+            GuildsTreeAction::YankId(id) => {
+                return ChatKeyResult::CopyToClipboard(id);
+            }
+            GuildsTreeAction::LoadGuildChannels(guild_id) => {
+                return ChatKeyResult::LoadGuildChannels(guild_id);
+            }
+            GuildsTreeAction::LoadArchivedThreads(channel_id) => {
+                if self.should_fetch_archived_threads(channel_id) {
+                    return ChatKeyResult::LoadArchivedThreads(channel_id);
                 }
+                return ChatKeyResult::Consumed;
             }
         }
         ChatKeyResult::Ignored
     }
 
-    #[allow(clippy::too_many_lines)]
     fn handle_messages_list_key(&mut self, key: KeyEvent) -> ChatKeyResult {
-        if let Some(action) = self.message_pane_state.handle_key(
-            key,
-            &self.message_pane_data,
-            &self.registry,
-            Some(&self.relationship_state),
-            self.hide_blocked_completely,
-        ) {
-            match action {
-                MessagePaneAction::ClearSelection | MessagePaneAction::SelectMessage(_) => {}
-                MessagePaneAction::Reply {
+        self.message_pane_state
+            .handle_key(
+                key,
+                &self.message_pane_data,
+                &self.registry,
+                Some(&self.relationship_state),
+                self.hide_blocked_completely,
+            )
+            .map_or(ChatKeyResult::Ignored, |action| {
+                self.apply_message_pane_action(action)
+            })
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn apply_message_pane_action(&mut self, action: MessagePaneAction) -> ChatKeyResult {
+        match action {
+            MessagePaneAction::ClearSelection | MessagePaneAction::SelectMessage(_) => {}
+            MessagePaneAction::Reply {
+                message_id,
+                mention,
+            } => {
+                return ChatKeyResult::ReplyToMessage {
                     message_id,
                     mention,
-                } => {
-                    return ChatKeyResult::ReplyToMessage {
-                        message_id,
-                        mention,
-                    };
-                }
-                MessagePaneAction::Edit(message_id) => {
-                    if let Some(message) = self
-                        .message_pane_data
-                        .messages()
-                        .iter()
-                        .find(|m| m.message.id() == message_id)
-                    {
-                        if message.message.can_be_edited_by(&self.user) {
-                            self.message_input_state
-                                .start_edit(message_id, message.message.content());
-                            self.focus_message_input();
-                        } else {
-                            return ChatKeyResult::ShowNotification(
-                                "You can only edit your own messages".to_string(),
-                            );
-                        }
-                    }
-                }
-                MessagePaneAction::EditExternal(message_id) => {
-                    if let Some(message) = self
-                        .message_pane_data
-                        .messages()
-                        .iter()
-                        .find(|m| m.message.id() == message_id)
-                    {
-                        if message.message.can_be_edited_by(&self.user) {
-                            return ChatKeyResult::OpenEditor {
-                                initial_content: message.message.content().to_string(),
-                                message_id: Some(message_id),
-                            };
-                        }
+                };
+            }
+            MessagePaneAction::Edit(message_id) => {
+                if let Some(message) = self
+                    .message_pane_data
+                    .messages()
+                    .iter()
+                    .find(|m| m.message.id() == message_id)
+                {
+                    if message.message.can_be_edited_by(&self.user) {
+                        self.message_input_state
+                            .start_edit(message_id, message.message.content());
+                        self.focus_message_input();
+                    } else {
                         return ChatKeyResult::ShowNotification(
                             "You can only edit your own messages".to_string(),
                         );
                     }
                 }
-                MessagePaneAction::Delete(message_id) => {
-                    if let Some(message) = self
-                        .message_pane_data
-                        .messages()
-                        .iter()
-                        .find(|m| m.message.id() == message_id)
-                    {
-                        if message.message.can_be_edited_by(&self.user) {
-                            self.pending_deletion_id = Some(message_id);
-                            self.set_focus(ChatFocus::ConfirmationModal);
-                            return ChatKeyResult::Consumed;
-                        }
-
-                        return ChatKeyResult::ShowNotification(
-                            "You can only delete your own messages".to_string(),
-                        );
+            }
+            MessagePaneAction::EditExternal(message_id) => {
+                if let Some(message) = self
+                    .message_pane_data
+                    .messages()
+                    .iter()
+                    .find(|m| m.message.id() == message_id)
+                {
+                    if message.message.can_be_edited_by(&self.user) {
+                        return ChatKeyResult::OpenEditor {
+                            initial_content: message.message.content().to_string(),
+                            message_id: Some(message_id),
+                        };
                     }
+                    return ChatKeyResult::ShowNotification(
+                        "You can only edit your own messages".to_string(),
+                    );
+                }
+            }
+            MessagePaneAction::Delete(message_id) => {
+                if let Some(message) = self
+                    .message_pane_data
+                    .messages()
+                    .iter()
+                    .find(|m| m.message.id() == message_id)
+                {
+                    if message.message.can_be_edited_by(&self.user) {
+                        self.pending_deletion_id = Some(message_id);
+                        self.set_focus(ChatFocus::ConfirmationModal);
+                        return ChatKeyResult::Consumed;
+                    }
+
+                    return ChatKeyResult::ShowNotification(
+                        "You can only delete your own messages".to_string(),
+                    );
+                }
+            }
+            MessagePaneAction::YankContent(content) | MessagePaneAction::YankUrl(content) => {
+                return ChatKeyResult::CopyToClipboard(content);
+            }
+            MessagePaneAction::CopyImage(image_id) => {
+                return ChatKeyResult::CopyImageToClipboard(image_id);
+            }
+            MessagePaneAction::YankId(id) => {
+                return ChatKeyResult::CopyToClipboard(id);
+            }
+            MessagePaneAction::OpenAttachments(message_id) => {
+                if let Some(action) = self
+                    .message_pane_data
+                    .messages()
+                    .iter()
+                    .find(|m| m.message.id() == message_id)
+                    .map(|m| MessageContentService::resolve(&m.message))
+                {
+                    return match action {
+                        MessageContentAction::OpenImages => {
+                            ChatKeyResult::OpenAttachments(message_id)
+                        }
+                        MessageContentAction::OpenLink(url) => self.open_link(url),
+                        MessageContentAction::None => ChatKeyResult::Ignored,
+                    };
                 }
-                MessagePaneAction::YankContent(content) | MessagePaneAction::YankUrl(content) => {
-                    return ChatKeyResult::CopyToClipboard(content);
+            }
+            MessagePaneAction::OpenLink(url) => return self.open_link(url),
+            MessagePaneAction::JumpToReply(message_id) => {
+                return ChatKeyResult::JumpToMessage(message_id);
+            }
+            MessagePaneAction::ToggleReaction {
+                message_id,
+                emoji,
+                add,
+            } => {
+                if add {
+                    self.message_pane_data
+                        .add_reaction(message_id, emoji.clone(), true);
+                    return ChatKeyResult::AddReaction { message_id, emoji };
                 }
-                MessagePaneAction::CopyImage(image_id) => {
-                    return ChatKeyResult::CopyImageToClipboard(image_id);
+                self.message_pane_data
+                    .remove_reaction(message_id, &emoji, true);
+                return ChatKeyResult::RemoveReaction { message_id, emoji };
+            }
+            MessagePaneAction::PickReaction(message_id) => {
+                self.open_emoji_picker(EmojiPickerTarget::Reaction(message_id));
+                return ChatKeyResult::Consumed;
+            }
+            MessagePaneAction::TogglePin { message_id, pinned } => {
+                if self.can_manage_messages() {
+                    return ChatKeyResult::SetMessagePinned { message_id, pinned };
                 }
-                MessagePaneAction::YankId(id) => {
-                    return ChatKeyResult::CopyToClipboard(id);
+                return ChatKeyResult::ShowNotification(
+                    "You need the Manage Messages permission to pin messages".to_string(),
+                );
+            }
+            MessagePaneAction::OpenPins => return self.open_pins(),
+            MessagePaneAction::OpenProfile(user_ids) => return self.open_profile(user_ids),
+            MessagePaneAction::NewForumPost => return self.open_forum_composer(),
+            MessagePaneAction::SetForumFilter(filter) => {
+                if let Some(channel_id) = self.message_pane_data.channel_id() {
+                    return ChatKeyResult::LoadForumThreads {
+                        channel_id,
+                        guild_id: self.selected_guild,
+                        filter,
+                        offset: 0,
+                    };
                 }
-                MessagePaneAction::OpenAttachments(message_id) => {
-                    if let Some(message) = self
-                        .message_pane_data
-                        .messages()
-                        .iter()
-                        .find(|m| m.message.id() == message_id)
-                        .map(|m| &m.message)
-                    {
-                        return match MessageContentService::resolve(message) {
-                            MessageContentAction::OpenImages => {
-                                ChatKeyResult::OpenAttachments(message_id)
-                            }
-                            MessageContentAction::OpenLink(url) => ChatKeyResult::OpenLink(url),
-                            MessageContentAction::None => ChatKeyResult::Ignored,
-                        };
-                    }
+            }
+            MessagePaneAction::StartThread(message_id) => return self.start_thread(message_id),
+            MessagePaneAction::ToggleThreadMembership => {
+                if let Some(channel) = &self.selected_channel
+                    && channel.kind().is_thread()
+                {
+                    return ChatKeyResult::SetThreadMembership {
+                        channel_id: channel.id(),
+                        join: !channel.is_thread_member(),
+                    };
                 }
-                MessagePaneAction::JumpToReply(message_id) => {
-                    return ChatKeyResult::JumpToMessage(message_id);
+                return ChatKeyResult::ShowNotification("Not in a thread".to_string());
+            }
+            MessagePaneAction::OpenThread(channel_id) => {
+                if let Some(result) = self.open_message_thread(channel_id) {
+                    return result;
                 }
-                MessagePaneAction::OpenThread(channel_id) => {
-                    if let Some(result) = self.on_channel_selected(channel_id) {
-                        return result;
-                    }
 
-                    if let ViewMode::Forum(state) = &self.message_pane_state.view_mode
-                        && let Some(thread) = state.threads.iter().find(|t| t.id == channel_id)
-                    {
-                        let parent_id = self.selected_channel.as_ref().map(Channel::id);
+                if let Some(result) = self.on_channel_selected(channel_id) {
+                    return result;
+                }
 
-                        let mut channel =
-                            Channel::new(thread.id, thread.name.clone(), ChannelKind::PublicThread)
-                                .with_guild(thread.guild_id.unwrap_or(GuildId(0)).as_u64());
+                if let ViewMode::Forum(state) = &self.message_pane_state.view_mode
+                    && let Some(thread) = state.threads.iter().find(|t| t.id == channel_id)
+                {
+                    let parent_id = self.selected_channel.as_ref().map(Channel::id);
 
-                        if let Some(pid) = parent_id {
-                            channel = channel.with_parent(pid);
-                        }
+                    let mut channel =
+                        Channel::new(thread.id, thread.name.clone(), ChannelKind::PublicThread)
+                            .with_guild(thread.guild_id.unwrap_or(GuildId(0)).as_u64());
 
-                        if let Some(guild_id) = thread.guild_id {
-                            self.selected_channel = Some(channel.clone());
-                            self.message_pane_data
-                                .set_channel(channel_id, channel.display_name());
-                            self.message_pane_state.on_channel_change();
-                            self.message_input_state.set_has_channel(true);
-                            self.message_input_state.clear();
-                            self.focus_messages_list();
-
-                            return ChatKeyResult::LoadChannelMessages {
-                                channel_id,
-                                guild_id: Some(guild_id),
-                            };
-                        }
+                    if let Some(pid) = parent_id {
+                        channel = channel.with_parent(pid);
                     }
 
-                    return ChatKeyResult::JumpToChannel(channel_id);
-                }
-                MessagePaneAction::CloseThread => {
-                    if let ViewMode::Forum(_) = &self.message_pane_state.view_mode {
-                        self.focus_guilds_tree();
-                        return ChatKeyResult::Consumed;
-                    }
-                    if let Some(current_channel) = &self.selected_channel
-                        && let Some(parent_id) = current_channel.parent_id()
-                        && let Some(result) = self.on_channel_selected(parent_id)
-                    {
-                        return result;
-                    }
-                    self.focus_guilds_tree();
-                }
-                MessagePaneAction::LoadHistory => {
-                    if let ViewMode::Forum(forum_state) = &self.message_pane_state.view_mode
-                        && let Some(channel_id) = self.message_pane_data.channel_id()
-                    {
-                        let offset = u32::try_from(forum_state.threads.len()).unwrap_or(0);
-                        let guild_id = self.selected_guild;
+                    if let Some(guild_id) = thread.guild_id {
+                        self.selected_channel = Some(channel.clone());
+                        self.message_pane_data
+                            .set_channel(channel_id, channel.display_name());
+                        self.message_pane_state.on_channel_change();
+                        self.message_input_state.set_has_channel(true);
+                        self.message_input_state.clear();
+                        self.focus_messages_list();
 
-                        return ChatKeyResult::LoadForumThreads {
+                        return ChatKeyResult::LoadChannelMessages {
                             channel_id,
-                            guild_id,
-                            offset,
+                            guild_id: Some(guild_id),
                         };
                     }
+                }
 
-                    if let Some(channel_id) = self.message_pane_data.channel_id()
-                        && let Some(first_msg) = self.message_pane_data.messages().iter().next()
-                    {
-                        return ChatKeyResult::LoadHistory {
-                            channel_id,
-                            before_message_id: first_msg.message.id(),
-                        };
-                    }
+                return ChatKeyResult::JumpToChannel(channel_id);
+            }
+            MessagePaneAction::CloseThread => {
+                if self.close_thread_split() {
+                    return ChatKeyResult::SaveState;
+                }
+                if let ViewMode::Forum(_) = &self.message_pane_state.view_mode {
+                    self.focus_guilds_tree();
+                    return ChatKeyResult::Consumed;
+                }
+                if let Some(current_channel) = &self.selected_channel
+                    && let Some(parent_id) = current_channel.parent_id()
+                    && let Some(result) = self.on_channel_selected(parent_id)
+                {
+                    return result;
+                }
+                self.focus_guilds_tree();
+            }
+            MessagePaneAction::LoadHistory => {
+                if let ViewMode::Forum(forum_state) = &self.message_pane_state.view_mode
+                    && let Some(channel_id) = self.message_pane_data.channel_id()
+                {
+                    let offset = u32::try_from(forum_state.threads.len()).unwrap_or(0);
+                    let guild_id = self.selected_guild;
+
+                    return ChatKeyResult::LoadForumThreads {
+                        channel_id,
+                        guild_id,
+                        filter: forum_state.filter,
+                        offset,
+                    };
+                }
+
+                if let Some(channel_id) = self.message_pane_data.channel_id()
+                    && let Some(first_msg) = self.message_pane_data.messages().iter().next()
+                {
+                    return ChatKeyResult::LoadHistory {
+                        channel_id,
+                        before_message_id: first_msg.message.id(),
+                    };
+                }
+            }
+            MessagePaneAction::LoadNewer => {
+                if self.message_pane_data.is_loading_newer() {
+                    return ChatKeyResult::Consumed;
+                }
+                if let Some(channel_id) = self.message_pane_data.channel_id()
+                    && let Some(after_message_id) = self
+                        .message_pane_data
+                        .messages()
+                        .back()
+                        .map(|last_msg| last_msg.message.id())
+                {
+                    self.message_pane_data.set_loading_newer(true);
+                    return ChatKeyResult::LoadNewerMessages {
+                        channel_id,
+                        after_message_id,
+                    };
                 }
             }
         }
@@ -1548,7 +2584,12 @@ impl ChatScreenState {
                         | Action::FocusPrevious
                         | Action::NextTab
                         | Action::ToggleGuildsTree
+                        | Action::ToggleMemberList
                         | Action::ToggleQuickSwitcher
+                        | Action::OpenStatusMenu
+                        | Action::NewDirectMessage
+                        | Action::RenameGroupDm
+                        | Action::OpenInbox
                 )
             )
         {
@@ -1610,6 +2651,10 @@ impl ChatScreenState {
                 MessageInputAction::Paste => {
                     return ChatKeyResult::Paste;
                 }
+                MessageInputAction::OpenEmojiPicker => {
+                    self.open_emoji_picker(EmojiPickerTarget::Input);
+                    return ChatKeyResult::Consumed;
+                }
                 MessageInputAction::StartTyping | MessageInputAction::CancelReply => {
                     if autocomplete_changed {
                         self.update_autocomplete_suggestions();
@@ -1632,7 +2677,8 @@ impl ChatScreenState {
     }
 
     fn handle_autocomplete_navigation(&mut self, key: KeyEvent) -> bool {
-        if !self.autocomplete_service.state().active {
+        let state = self.autocomplete_service.state();
+        if !state.active || (state.kind != AutocompleteKind::Mention && state.results.is_empty()) {
             return false;
         }
 
@@ -1646,12 +2692,33 @@ impl ChatScreenState {
                 true
             }
             KeyCode::Enter | KeyCode::Tab => {
-                if let Some(user) = self.autocomplete_service.state().selected_user() {
-                    let trigger_idx = self.autocomplete_service.state().trigger_index;
-                    let name =
-                        IdentityResolver::with_preference(self.use_display_name).resolve(user);
-                    self.message_input_state
-                        .insert_mention(trigger_idx, &name, user.id());
+                let state = self.autocomplete_service.state();
+                let trigger_idx = state.trigger_index;
+                match state.selected() {
+                    Some(AutocompleteSuggestion::User(user)) => {
+                        let name =
+                            IdentityResolver::with_preference(self.use_display_name).resolve(user);
+                        self.message_input_state
+                            .insert_mention(trigger_idx, &name, user.id());
+                    }
+                    Some(AutocompleteSuggestion::Role(role)) => {
+                        self.message_input_state.insert_role_mention(
+                            trigger_idx,
+                            &role.name,
+                            role.id,
+                        );
+                    }
+                    Some(AutocompleteSuggestion::Emoji(emoji)) => {
+                        self.message_input_state.insert_emoji(trigger_idx, emoji);
+                    }
+                    Some(AutocompleteSuggestion::Channel(channel)) => {
+                        self.message_input_state.insert_channel_mention(
+                            trigger_idx,
+                            channel.name(),
+                            channel.id(),
+                        );
+                    }
+                    None => {}
                 }
                 self.autocomplete_service.reset();
                 true
@@ -1665,7 +2732,24 @@ impl ChatScreenState {
     }
 
     fn update_autocomplete_suggestions(&mut self) {
-        if !self.autocomplete_service.state().active {
+        let state = self.autocomplete_service.state();
+        if !state.active {
+            return;
+        }
+
+        if state.kind == AutocompleteKind::Emoji {
+            let custom_emojis = self
+                .selected_guild
+                .and_then(|guild_id| self.guild_emojis.get(&guild_id))
+                .map_or(&[][..], Vec::as_slice);
+            self.autocomplete_service
+                .update_emoji_results(custom_emojis);
+            return;
+        }
+
+        if state.kind == AutocompleteKind::Channel {
+            let channels = self.channel_suggestions(&state.query.clone());
+            self.autocomplete_service.update_channel_results(channels);
             return;
         }
 
@@ -1704,7 +2788,49 @@ impl ChatScreenState {
             }
         }
 
-        self.autocomplete_service.update_results(candidates);
+        let roles = self.mentionable_roles();
+        self.autocomplete_service.update_results(candidates, roles);
+    }
+
+    /// Mentionable roles of the selected guild, highest first, without `@everyone`.
+    fn mentionable_roles(&self) -> Vec<Role> {
+        let Some(guild_id) = self.selected_guild else {
+            return Vec::new();
+        };
+        let mut roles: Vec<Role> = self
+            .guild_roles
+            .get(&guild_id)
+            .into_iter()
+            .flat_map(std::collections::HashMap::values)
+            .filter(|r| r.mentionable && r.id.0 != guild_id.as_u64())
+            .cloned()
+            .collect();
+        roles.sort_by_key(|r| std::cmp::Reverse(r.position));
+        roles
+    }
+
+    /// Viewable text channels and threads of the selected guild, best match first.
+    fn channel_suggestions(&self, query: &str) -> Vec<Channel> {
+        let Some(guild_id) = self.selected_guild else {
+            return Vec::new();
+        };
+
+        let candidates = self
+            .visible_channels(guild_id)
+            .into_iter()
+            .filter(|c| c.kind().is_text_based() || c.kind().is_thread());
+
+        if query.is_empty() {
+            let mut channels: Vec<Channel> = candidates.collect();
+            channels.sort_by_key(Channel::position);
+            return channels;
+        }
+
+        let mut scored: Vec<(i64, Channel)> = candidates
+            .filter_map(|c| self.channel_searcher.score(c.name(), query).map(|s| (s, c)))
+            .collect();
+        scored.sort_by_key(|(score, c)| (std::cmp::Reverse(*score), c.name().len()));
+        scored.into_iter().map(|(_, c)| c).collect()
     }
 
     fn register_channel_mentions(&mut self, messages: &[Message]) -> Vec<ChannelId> {
@@ -1771,6 +2897,7 @@ impl ChatScreenState {
         };
 
         if let Some((guild_id, mut channel, topic)) = channel_info {
+            self.thread_parent = None;
             if let Some(rs) = self.read_states.get_mut(&channel.id()) {
                 rs.mention_count = 0;
             }
@@ -1816,31 +2943,38 @@ impl ChatScreenState {
             }
 
             self.message_pane_data.set_channel(channel_id, channel_name);
+            self.message_pane_data.set_roles(
+                self.guild_roles
+                    .get(&guild_id)
+                    .into_iter()
+                    .flat_map(std::collections::HashMap::values),
+            );
             self.message_pane_state.on_channel_change();
 
             if channel.kind() == ChannelKind::Forum {
-                if let Some(saved_state) = self.forum_states.get(&channel_id) {
-                    self.message_pane_state.view_mode = ViewMode::Forum(saved_state.clone());
-                } else {
-                    self.message_pane_state.view_mode = ViewMode::Forum(ForumState::default());
-                }
+                self.restore_forum_view(&channel);
             }
 
+            // Threads are not listed under their own overwrites; their first SYNC picks the list.
+            let list_id = (!channel.kind().is_thread()).then(|| channel.member_list_id());
+            self.member_list_state
+                .reset(Some(guild_id), Some(channel_id), list_id);
             self.message_pane_data.set_channel_topic(topic);
             self.message_input_state.set_has_channel(true);
             self.message_input_state.clear();
             self.focus_messages_list();
 
             if channel.kind() == ChannelKind::Forum {
-                if let ViewMode::Forum(ref state) = self.message_pane_state.view_mode
-                    && !state.threads.is_empty()
-                {
-                    return None;
-                }
+                let filter = match &self.message_pane_state.view_mode {
+                    ViewMode::Forum(state) if !state.threads.is_empty() => return None,
+                    ViewMode::Forum(state) => state.filter,
+                    ViewMode::Messages => ForumFilter::default(),
+                };
 
                 return Some(ChatKeyResult::LoadForumThreads {
                     channel_id,
                     guild_id: Some(guild_id),
+                    filter,
                     offset: 0,
                 });
             }
@@ -1852,6 +2986,17 @@ impl ChatScreenState {
         None
     }
 
+    /// Shows a forum with the listing state it had when it was last left.
+    fn restore_forum_view(&mut self, channel: &Channel) {
+        let mut forum_state = self
+            .forum_states
+            .get(&channel.id())
+            .cloned()
+            .unwrap_or_default();
+        forum_state.available_tags = channel.available_tags().to_vec();
+        self.message_pane_state.view_mode = ViewMode::Forum(forum_state);
+    }
+
     fn on_guild_selected(&mut self, guild_id: GuildId) -> Option<ChatKeyResult> {
         if self.selected_guild == Some(guild_id) {
             return None;
@@ -1874,6 +3019,7 @@ impl ChatScreenState {
 
         self.selected_guild = Some(guild_id);
         self.selected_channel = None;
+        self.thread_parent = None;
         self.guilds_tree_data.set_active_channel(None);
         self.guilds_tree_data.set_active_guild(Some(guild_id));
 
@@ -1905,16 +3051,31 @@ impl ChatScreenState {
         }
         self.recalculate_all_unread();
 
-        let dm_channel = Channel::new(channel_id, recipient_name.clone(), ChannelKind::Dm);
+        let is_group = self
+            .dm_channels
+            .get(dm_channel_id)
+            .is_some_and(DmChannelInfo::is_group);
+        let kind = if is_group {
+            ChannelKind::GroupDm
+        } else {
+            ChannelKind::Dm
+        };
+        let dm_channel = Channel::new(channel_id, recipient_name.clone(), kind);
         self.selected_channel = Some(dm_channel);
+        self.thread_parent = None;
         self.selected_guild = None;
         self.guilds_tree_data.set_active_guild(None);
         self.guilds_tree_data.set_active_channel(None);
         self.guilds_tree_data
             .set_active_dm_user(Some(dm_channel_id.to_string()));
 
-        let display_name = format!("@{recipient_name}");
+        let display_name = if is_group {
+            recipient_name.clone()
+        } else {
+            format!("@{recipient_name}")
+        };
         self.message_pane_data.set_channel(channel_id, display_name);
+        self.message_pane_data.set_roles([]);
         self.message_pane_state.on_channel_change();
         self.message_input_state.set_has_channel(true);
         self.message_input_state.clear();
@@ -1950,6 +3111,51 @@ impl ChatScreenState {
         }
     }
 
+    /// Shows a window of older history, staying live if it already reaches the latest message.
+    pub fn set_detached_messages(&mut self, messages: Vec<Message>) -> Option<ChatKeyResult> {
+        let unknown = self.register_channel_mentions(&messages);
+        let reaches_present = self
+            .message_pane_data
+            .channel_id()
+            .and_then(|channel_id| self.channel_last_message_id(channel_id))
+            .is_some_and(|last_id| messages.iter().any(|message| message.id() == last_id));
+        if reaches_present {
+            self.message_pane_data.set_messages(messages);
+        } else {
+            self.message_pane_data.set_detached_messages(messages);
+        }
+        if unknown.is_empty() {
+            None
+        } else {
+            Some(ChatKeyResult::RequestChannelFetch(unknown))
+        }
+    }
+
+    pub fn append_messages(
+        &mut self,
+        messages: Vec<Message>,
+        reached_present: bool,
+    ) -> Option<ChatKeyResult> {
+        let unknown = self.register_channel_mentions(&messages);
+        self.message_pane_data
+            .append_messages(messages, reached_present);
+        if unknown.is_empty() {
+            None
+        } else {
+            Some(ChatKeyResult::RequestChannelFetch(unknown))
+        }
+    }
+
+    /// Returns to live mode; the latest messages still have to be fetched.
+    fn jump_to_present(&mut self) -> Option<ChatKeyResult> {
+        if !self.message_pane_data.is_detached() {
+            return None;
+        }
+        let channel_id = self.message_pane_data.channel_id()?;
+        self.message_pane_state.clear_selection();
+        Some(ChatKeyResult::JumpToPresent(channel_id))
+    }
+
     pub fn set_forum_threads(
         &mut self,
         mut threads: Vec<crate::domain::entities::ForumThread>,
@@ -2009,6 +3215,12 @@ impl ChatScreenState {
 
     pub fn add_message(&mut self, message: Message) -> Option<ChatKeyResult> {
         let unknown = self.register_channel_mentions(std::slice::from_ref(&message));
+        if let Some(parent) = &mut self.thread_parent
+            && parent.data.channel_id() == Some(message.channel_id())
+        {
+            parent.data.add_message(message.clone());
+            parent.state.on_new_message();
+        }
         self.message_pane_data.add_message(message);
         self.message_pane_state.on_new_message();
         if unknown.is_empty() {
@@ -2052,6 +3264,10 @@ impl ChatScreenState {
 
     pub fn update_message(&mut self, message: Message) -> Option<ChatKeyResult> {
         let unknown = self.register_channel_mentions(std::slice::from_ref(&message));
+        self.inbox.update_message(&message);
+        if let Some(parent) = &mut self.thread_parent {
+            parent.data.update_message(message.clone());
+        }
         self.message_pane_data.update_message(message);
         if unknown.is_empty() {
             None
@@ -2061,9 +3277,69 @@ impl ChatScreenState {
     }
 
     pub fn remove_message(&mut self, message_id: crate::domain::entities::MessageId) {
+        self.inbox.remove_message(message_id);
+        if let Some(parent) = &mut self.thread_parent {
+            parent.data.remove_message(message_id);
+        }
         self.message_pane_data.remove_message(message_id);
     }
 
+    /// Reflects a pin or unpin that Discord accepted.
+    pub fn set_message_pinned(
+        &mut self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        pinned: bool,
+    ) {
+        if let Some(parent) = &mut self.thread_parent
+            && parent.data.channel_id() == Some(channel_id)
+        {
+            parent.data.set_pinned(message_id, pinned);
+        }
+        if self.message_pane_data.channel_id() == Some(channel_id) {
+            self.message_pane_data.set_pinned(message_id, pinned);
+        }
+        if !pinned {
+            self.pins_overlay.confirm_unpin(message_id);
+        }
+    }
+
+    /// Lists a pin again after unpinning it failed.
+    pub fn restore_unpinned(&mut self, channel_id: ChannelId, message_id: MessageId) {
+        self.pins_overlay.restore(channel_id, message_id);
+    }
+
+    pub fn add_reaction(
+        &mut self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+        me: bool,
+    ) {
+        if self.message_pane_data.channel_id() == Some(channel_id) {
+            self.message_pane_data.add_reaction(message_id, emoji, me);
+        }
+    }
+
+    pub fn remove_reaction(
+        &mut self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionEmoji,
+        me: bool,
+    ) {
+        if self.message_pane_data.channel_id() == Some(channel_id) {
+            self.message_pane_data
+                .remove_reaction(message_id, emoji, me);
+        }
+    }
+
+    pub fn clear_reactions(&mut self, channel_id: ChannelId, message_id: MessageId) {
+        if self.message_pane_data.channel_id() == Some(channel_id) {
+            self.message_pane_data.clear_reactions(message_id);
+        }
+    }
+
     pub fn set_message_error(&mut self, error: String) {
         self.message_pane_data.set_error(error);
     }
@@ -2087,6 +3363,18 @@ impl ChatScreenState {
         self.message_pane_data.mark_dirty();
     }
 
+    /// Redraws the messages of a user whose presence changed, if any are shown.
+    pub fn on_presence_update(&mut self, user_id: &str) {
+        if self.message_pane_data.has_author(user_id) {
+            self.message_pane_data.mark_dirty();
+        }
+        if let Some(parent) = &mut self.thread_parent
+            && parent.data.has_author(user_id)
+        {
+            parent.data.mark_dirty();
+        }
+    }
+
     pub const fn message_pane_parts_mut(
         &mut self,
     ) -> (&mut MessagePaneData, &mut MessagePaneState) {
@@ -2221,7 +3509,7 @@ impl ChatScreenState {
         }
 
         for ui_msg in self.message_pane_data.ui_messages_mut() {
-            for attachment in &mut ui_msg.image_attachments {
+            for attachment in ui_msg.images_mut() {
                 if &attachment.id == id {
                     attachment.set_loaded(image.clone());
                 }
@@ -2268,7 +3556,7 @@ impl ChatScreenState {
                 let is_in_protocol_range = idx >= protocol_start && idx <= protocol_end;
                 let is_in_memory_range = idx >= keep_start && idx <= keep_end;
 
-                for attachment in &mut ui_msg.image_attachments {
+                for attachment in ui_msg.images_mut() {
                     if is_in_protocol_range {
                         if attachment.is_ready() && attachment.update_protocol_if_needed(picker) {
                             dirty = true;
@@ -2298,7 +3586,7 @@ impl ChatScreenState {
                 let messages = self.message_pane_data.ui_messages_mut();
                 for idx in effective_start..effective_end {
                     if let Some(ui_msg) = messages.get_mut(idx) {
-                        for attachment in &mut ui_msg.image_attachments {
+                        for attachment in ui_msg.images_mut() {
                             if attachment.is_ready() && attachment.update_protocol_if_needed(picker)
                             {
                                 dirty = true;
@@ -2316,7 +3604,7 @@ impl ChatScreenState {
 
     pub fn mark_image_downloading(&mut self, id: &crate::domain::entities::ImageId) {
         for ui_msg in self.message_pane_data.ui_messages_mut() {
-            for attachment in &mut ui_msg.image_attachments {
+            for attachment in ui_msg.images_mut() {
                 if &attachment.id == id {
                     attachment.set_downloading();
                 }
@@ -2326,7 +3614,7 @@ impl ChatScreenState {
 
     pub fn mark_image_failed(&mut self, id: &crate::domain::entities::ImageId, error: &str) {
         for ui_msg in self.message_pane_data.ui_messages_mut() {
-            for attachment in &mut ui_msg.image_attachments {
+            for attachment in ui_msg.images_mut() {
                 if &attachment.id == id {
                     attachment.set_failed(error.to_owned());
                 }
@@ -2335,24 +3623,205 @@ impl ChatScreenState {
     }
 
     pub fn add_attachment(&mut self, path: std::path::PathBuf) {
+        if self.show_forum_composer {
+            self.forum_composer.add_attachment(path);
+            return;
+        }
         self.message_input_state.add_attachment(path);
         self.focus_message_input();
     }
 
     pub fn insert_text(&mut self, text: &str) {
+        if self.show_forum_composer {
+            self.forum_composer.insert_text(text);
+            return;
+        }
         self.message_input_state.insert_text_at_cursor(text);
         self.focus_message_input();
     }
 
-    pub fn jump_to_message(&mut self, message_id: crate::domain::entities::MessageId) {
-        if let Some(index) = self
+    /// Selects a loaded message, returning `false` when it has to be fetched first.
+    pub fn jump_to_message(&mut self, message_id: crate::domain::entities::MessageId) -> bool {
+        let Some(index) = self
             .message_pane_data
             .messages()
             .iter()
             .position(|m| m.message.id() == message_id)
+        else {
+            return false;
+        };
+        self.message_pane_state.jump_to_index(index);
+        true
+    }
+
+    /// Selects `channel_id` as if it was picked in the guilds tree, for jumps that
+    /// load history around a message instead of the latest page. Returns the
+    /// guild of the channel.
+    pub fn select_channel_for_jump(&mut self, channel_id: ChannelId) -> Option<GuildId> {
+        if self.on_channel_selected(channel_id).is_some() {
+            self.reveal_in_guilds_tree(channel_id);
+            return self.selected_guild;
+        }
+        if self.on_dm_selected(&channel_id.to_string()).is_some() {
+            return None;
+        }
+
+        let name = self
+            .get_channel(channel_id)
+            .map_or_else(|| format!("{channel_id}"), Channel::display_name);
+        self.message_pane_data.set_channel(channel_id, name);
+        self.message_pane_state.on_channel_change();
+        self.message_input_state.set_has_channel(true);
+        self.message_input_state.clear();
+        self.focus_messages_list();
+        None
+    }
+
+    /// Expands the guild and parent of the selected channel and moves the tree
+    /// cursor onto it.
+    fn reveal_in_guilds_tree(&mut self, channel_id: ChannelId) {
+        if let Some(guild_id) = self.selected_guild {
+            self.guilds_tree_state.expand(TreeNodeId::Guild(guild_id));
+        }
+        if let Some(channel) = self.guilds_tree_data.get_channel(channel_id)
+            && let Some(parent_id) = channel.parent_id()
+        {
+            let parent = if channel.kind().is_thread() {
+                TreeNodeId::Channel(parent_id)
+            } else {
+                TreeNodeId::Category(parent_id)
+            };
+            self.guilds_tree_state.expand(parent);
+        }
+        self.guilds_tree_state
+            .select(TreeNodeId::Channel(channel_id));
+    }
+
+    /// Follows Discord links inside the client and hands anything else to the opener.
+    fn open_link(&mut self, url: String) -> ChatKeyResult {
+        match DiscordLink::parse(&url) {
+            Some(link) => self.open_discord_link(link),
+            None => ChatKeyResult::OpenLink(url),
+        }
+    }
+
+    /// Navigates to the channel, and optionally the message, a Discord link points at.
+    pub fn open_discord_link(&mut self, link: DiscordLink) -> ChatKeyResult {
+        self.open_link_target(link, true)
+    }
+
+    /// Opens a link whose channel was fetched because it was not loaded yet.
+    pub fn open_fetched_link(&mut self, link: DiscordLink) -> ChatKeyResult {
+        self.open_link_target(link, false)
+    }
+
+    fn open_link_target(&mut self, link: DiscordLink, fetch_unknown: bool) -> ChatKeyResult {
+        let DiscordLink {
+            guild_id,
+            channel_id,
+            message_id,
+        } = link;
+        self.pending_jump = None;
+
+        if self.message_pane_data.channel_id() == Some(channel_id) {
+            self.focus_messages_list();
+            return message_id.map_or(ChatKeyResult::Consumed, ChatKeyResult::JumpToMessage);
+        }
+
+        let Some(guild_id) = guild_id else {
+            self.pending_jump = message_id.map(|id| (channel_id, id));
+            return self
+                .on_dm_selected(&channel_id.to_string())
+                .unwrap_or(ChatKeyResult::JumpToChannel(channel_id));
+        };
+
+        if !self
+            .guilds_tree_data
+            .guilds()
+            .iter()
+            .any(|g| g.id() == guild_id)
+        {
+            return ChatKeyResult::ShowNotification(
+                "You are not a member of that server".to_string(),
+            );
+        }
+
+        if let Some(result @ ChatKeyResult::LoadGuildChannels(_)) = self.on_guild_selected(guild_id)
+        {
+            self.pending_link = Some(link);
+            return result;
+        }
+
+        if self.guilds_tree_data.get_channel(channel_id).is_none() {
+            if !fetch_unknown {
+                return ChatKeyResult::ShowNotification(
+                    "That channel is not available".to_string(),
+                );
+            }
+            self.pending_channel_link = Some(link);
+            return ChatKeyResult::RequestChannelFetch(vec![channel_id]);
+        }
+
+        match self
+            .open_message_thread(channel_id)
+            .or_else(|| self.on_channel_selected(channel_id))
         {
-            self.message_pane_state.jump_to_index(index);
+            Some(result @ ChatKeyResult::LoadChannelMessages { .. }) => {
+                self.pending_jump = message_id.map(|id| (channel_id, id));
+                result
+            }
+            Some(result) => result,
+            None => ChatKeyResult::ShowNotification("That channel cannot be opened".to_string()),
+        }
+    }
+
+    /// Takes the link that was waiting for `channel_id` to be fetched.
+    pub fn take_pending_channel_link(&mut self, channel_id: ChannelId) -> Option<DiscordLink> {
+        if self.pending_channel_link?.channel_id != channel_id {
+            return None;
+        }
+        self.pending_channel_link.take()
+    }
+
+    /// Takes the link that was waiting for `guild_id`'s channels.
+    pub fn take_pending_link(&mut self, guild_id: GuildId) -> Option<DiscordLink> {
+        if self.pending_link?.guild_id != Some(guild_id) {
+            return None;
+        }
+        self.pending_link.take()
+    }
+
+    /// Takes the message a link asked to select once `channel_id` is loaded.
+    pub fn take_pending_jump(&mut self, channel_id: ChannelId) -> Option<MessageId> {
+        let (pending_channel, message_id) = self.pending_jump?;
+        if pending_channel != channel_id {
+            return None;
         }
+        self.pending_jump = None;
+        Some(message_id)
+    }
+
+    /// Whether the message mentions a role the current user holds in its guild.
+    #[must_use]
+    pub fn mentions_own_role(&self, message: &Message) -> bool {
+        let Some(member) = message
+            .guild_id()
+            .and_then(|guild_id| self.guild_members.get(&guild_id))
+        else {
+            return false;
+        };
+        message
+            .mention_roles()
+            .iter()
+            .any(|role_id| member.roles.contains(role_id))
+    }
+
+    /// Unacknowledged mentions of us in `channel_id`.
+    #[must_use]
+    pub fn mention_count(&self, channel_id: ChannelId) -> u32 {
+        self.read_states
+            .get(&channel_id)
+            .map_or(0, |read_state| read_state.mention_count)
     }
 
     pub fn increment_mention_count(&mut self, channel_id: ChannelId) {
@@ -2372,58 +3841,996 @@ impl ChatScreenState {
         self.recalculate_all_unread();
     }
 
-    #[must_use]
-    pub fn get_channel(&self, channel_id: ChannelId) -> Option<&Channel> {
-        self.guilds_tree_data.get_channel(channel_id)
+    #[must_use]
+    pub fn get_channel(&self, channel_id: ChannelId) -> Option<&Channel> {
+        self.guilds_tree_data.get_channel(channel_id)
+    }
+
+    pub fn toggle_file_explorer(&mut self) {
+        self.show_file_explorer = !self.show_file_explorer;
+        if self.show_file_explorer {
+            self.file_explorer = Some(FileExplorerComponent::new());
+        } else {
+            self.file_explorer = None;
+        }
+    }
+
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    fn handle_file_explorer_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        if self.registry.find_action(key) == Some(Action::ToggleHiddenFiles)
+            && let Some(explorer) = &mut self.file_explorer
+        {
+            explorer.toggle_hidden();
+            return ChatKeyResult::Consumed;
+        }
+
+        if let Some(explorer) = &mut self.file_explorer {
+            match explorer.handle_key(key) {
+                FileExplorerAction::SelectFile(path) => {
+                    if self.show_forum_composer {
+                        self.forum_composer.add_attachment(path);
+                    } else {
+                        self.message_input_state.add_attachment(path);
+                    }
+                    self.show_file_explorer = false;
+                    self.file_explorer = None;
+                    ChatKeyResult::Consumed
+                }
+                FileExplorerAction::Close => {
+                    self.show_file_explorer = false;
+                    self.file_explorer = None;
+                    ChatKeyResult::Consumed
+                }
+                FileExplorerAction::None => ChatKeyResult::Consumed,
+            }
+        } else {
+            ChatKeyResult::Consumed
+        }
+    }
+
+    pub fn toggle_quick_switcher(&mut self) {
+        self.show_quick_switcher = !self.show_quick_switcher;
+        if self.show_quick_switcher {
+            self.quick_switcher.reset();
+            self.perform_search("");
+        }
+    }
+
+    /// Opens the emoji picker with the custom emoji of the selected guild.
+    pub fn open_emoji_picker(&mut self, target: EmojiPickerTarget) {
+        let custom_emojis = self
+            .selected_guild
+            .and_then(|guild_id| self.guild_emojis.get(&guild_id))
+            .cloned()
+            .unwrap_or_default();
+        self.emoji_picker.open(target, custom_emojis);
+        self.show_emoji_picker = true;
+    }
+
+    fn close_emoji_picker(&mut self) {
+        self.show_emoji_picker = false;
+        self.emoji_picker.reset();
+    }
+
+    fn handle_emoji_picker_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match key.code {
+            KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.emoji_picker.select_previous();
+                return ChatKeyResult::Consumed;
+            }
+            KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.emoji_picker.select_next();
+                return ChatKeyResult::Consumed;
+            }
+            _ => {}
+        }
+
+        match self.emoji_picker.handle_key(key) {
+            EmojiPickerAction::Close => {
+                self.close_emoji_picker();
+                ChatKeyResult::Consumed
+            }
+            EmojiPickerAction::Select(emoji) => {
+                let target = self.emoji_picker.target;
+                self.close_emoji_picker();
+                self.apply_picked_emoji(target, &emoji)
+            }
+            EmojiPickerAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    fn apply_picked_emoji(&mut self, target: EmojiPickerTarget, emoji: &Emoji) -> ChatKeyResult {
+        match target {
+            EmojiPickerTarget::Input => {
+                self.message_input_state
+                    .insert_text_at_cursor(&emoji.markup());
+                self.focus_message_input();
+                ChatKeyResult::StartTyping
+            }
+            EmojiPickerTarget::Reaction(message_id) => {
+                let emoji = emoji.to_reaction();
+                let already_reacted = self
+                    .message_pane_data
+                    .messages()
+                    .iter()
+                    .find(|m| m.message.id() == message_id)
+                    .is_some_and(|m| {
+                        m.message
+                            .reactions()
+                            .iter()
+                            .any(|r| r.me && r.emoji.is_same(&emoji))
+                    });
+
+                if already_reacted {
+                    self.message_pane_data
+                        .remove_reaction(message_id, &emoji, true);
+                    ChatKeyResult::RemoveReaction { message_id, emoji }
+                } else {
+                    self.message_pane_data
+                        .add_reaction(message_id, emoji.clone(), true);
+                    ChatKeyResult::AddReaction { message_id, emoji }
+                }
+            }
+        }
+    }
+
+    /// Opens the pinned messages of the current channel; the pins still have to be fetched.
+    pub fn open_pins(&mut self) -> ChatKeyResult {
+        let Some(channel_id) = self.message_pane_data.channel_id() else {
+            return ChatKeyResult::Consumed;
+        };
+        let can_manage = self.can_manage_messages();
+        self.pins_overlay.open(channel_id, can_manage);
+        self.show_pins = true;
+        ChatKeyResult::LoadPinnedMessages(channel_id)
+    }
+
+    fn close_pins(&mut self) {
+        self.show_pins = false;
+        self.pins_overlay.reset();
+    }
+
+    /// The channel whose pins are on screen, if the overlay is open.
+    #[must_use]
+    pub fn pins_channel_id(&self) -> Option<ChannelId> {
+        self.pins_overlay.channel_id.filter(|_| self.show_pins)
+    }
+
+    pub fn set_pinned_messages(&mut self, channel_id: ChannelId, messages: Vec<Message>) {
+        self.pins_overlay.set_messages(channel_id, messages);
+    }
+
+    pub fn set_pinned_messages_error(&mut self, channel_id: ChannelId, error: String) {
+        self.pins_overlay.set_error(channel_id, error);
+    }
+
+    fn handle_pins_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.pins_overlay.handle_key(key) {
+            PinsOverlayAction::Close => {
+                self.close_pins();
+                ChatKeyResult::Consumed
+            }
+            PinsOverlayAction::Jump(message_id) => {
+                self.close_pins();
+                self.focus_messages_list();
+                ChatKeyResult::JumpToMessage(message_id)
+            }
+            PinsOverlayAction::Unpin(message_id) => {
+                self.pins_overlay.remove(message_id);
+                ChatKeyResult::SetMessagePinned {
+                    message_id,
+                    pinned: false,
+                }
+            }
+            PinsOverlayAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    /// Whether `message` is newer than what we last read in its channel.
+    fn is_message_unread(&self, message: &Message) -> bool {
+        self.read_states
+            .get(&message.channel_id())
+            .and_then(|read_state| read_state.last_read_message_id)
+            .is_none_or(|last_read| message.id().as_u64() > last_read.as_u64())
+    }
+
+    /// Where a message was posted, e.g. `Rust › #general` or the DM name.
+    fn inbox_location(&self, message: &Message) -> (Option<GuildId>, String) {
+        let scope = self.message_scope(message.channel_id());
+        let guild_id = scope.guild_id.or_else(|| message.guild_id());
+        let guild_name = scope.guild_name.or_else(|| {
+            let guild_id = guild_id?;
+            self.guilds_tree_data
+                .guilds()
+                .iter()
+                .find(|guild| guild.id() == guild_id)
+                .map(|guild| guild.name().to_string())
+        });
+        let location = match (guild_name, scope.channel_name) {
+            (Some(guild), Some(channel)) => format!("{guild} › #{channel}"),
+            (None, Some(channel)) => format!("#{channel}"),
+            (Some(guild), None) => guild,
+            (None, None) => {
+                let key = message.channel_id().to_string();
+                self.guilds_tree_data
+                    .dm_users()
+                    .iter()
+                    .find(|dm| dm.channel_id == key)
+                    .map_or_else(
+                        || "Unknown channel".to_string(),
+                        |dm| dm.display_name(self.use_display_name),
+                    )
+            }
+        };
+        (guild_id, location)
+    }
+
+    fn inbox_mention(&self, message: Message) -> InboxMention {
+        let (guild_id, location) = self.inbox_location(&message);
+        InboxMention {
+            unread: self.is_message_unread(&message),
+            message,
+            guild_id,
+            location,
+        }
+    }
+
+    /// Adds a message that mentioned us to the inbox.
+    pub fn record_mention(&mut self, message: &Message) {
+        let mention = self.inbox_mention(message.clone());
+        self.inbox.add_mentions([mention]);
+    }
+
+    pub fn set_recent_mentions(&mut self, messages: Vec<Message>) {
+        let mentions: Vec<InboxMention> = messages
+            .into_iter()
+            .map(|message| self.inbox_mention(message))
+            .collect();
+        self.inbox.add_mentions(mentions);
+        self.inbox.loading = false;
+        self.inbox.error = None;
+    }
+
+    pub fn set_recent_mentions_error(&mut self, error: String) {
+        self.inbox.loading = false;
+        self.inbox.error = Some(error);
+    }
+
+    pub fn set_inbox_previews(&mut self, channel_ids: &[ChannelId], messages: &[Message]) {
+        self.inbox.set_previews(channel_ids, messages);
+    }
+
+    /// Channels with unread messages, grouped by guild with direct messages last.
+    fn unread_channels(&self) -> Vec<UnreadChannel> {
+        let mut unreads: Vec<UnreadChannel> = self
+            .guilds_tree_data
+            .guilds()
+            .iter()
+            .filter_map(|guild| {
+                let channels = self.guilds_tree_data.channels(guild.id())?;
+                Some(
+                    channels
+                        .iter()
+                        .filter(|channel| channel.has_unread())
+                        .map(|channel| UnreadChannel {
+                            guild_id: Some(guild.id()),
+                            group: guild.name().to_string(),
+                            channel_id: channel.id(),
+                            name: format!("#{}", channel.name()),
+                            mention_count: channel.mention_count(),
+                        }),
+                )
+            })
+            .flatten()
+            .collect();
+        unreads.extend(
+            self.guilds_tree_data
+                .dm_users()
+                .iter()
+                .filter(|dm| dm.has_unread)
+                .map(|dm| UnreadChannel {
+                    guild_id: None,
+                    group: "Direct Messages".to_string(),
+                    channel_id: ChannelId::from(dm.channel_id.as_str()),
+                    name: dm.display_name(self.use_display_name),
+                    mention_count: dm.mention_count,
+                }),
+        );
+        unreads
+    }
+
+    fn refresh_inbox(&mut self) {
+        let unreads = self.unread_channels();
+        self.inbox.set_unreads(unreads);
+        let mentions: Vec<InboxMention> = self
+            .inbox
+            .mentions
+            .iter()
+            .map(|mention| InboxMention {
+                unread: self.is_message_unread(&mention.message),
+                ..mention.clone()
+            })
+            .collect();
+        self.inbox.mentions = mentions;
+    }
+
+    /// Opens the inbox; recent mentions and missing unread previews still have to be fetched.
+    pub fn open_inbox(&mut self) -> ChatKeyResult {
+        self.refresh_inbox();
+        self.inbox.loading = true;
+        self.inbox.error = None;
+        self.show_inbox = true;
+        ChatKeyResult::LoadInbox {
+            preview_channels: self.inbox.take_missing_previews(MAX_INBOX_PREVIEWS),
+        }
+    }
+
+    /// The last message id known for a guild channel or DM.
+    fn channel_last_message_id(&self, channel_id: ChannelId) -> Option<MessageId> {
+        self.get_channel(channel_id)
+            .and_then(Channel::last_message_id)
+            .or_else(|| {
+                let key = channel_id.to_string();
+                self.guilds_tree_data
+                    .dm_users()
+                    .iter()
+                    .find(|dm| dm.channel_id == key)
+                    .and_then(|dm| dm.last_message_id)
+            })
+    }
+
+    /// The newest message we know of in `channel_id`.
+    fn latest_message_id(&self, channel_id: ChannelId) -> Option<MessageId> {
+        [
+            self.channel_last_message_id(channel_id),
+            self.inbox.preview(channel_id).map(Message::id),
+            self.inbox
+                .mentions
+                .iter()
+                .find(|mention| mention.message.channel_id() == channel_id)
+                .map(|mention| mention.message.id()),
+        ]
+        .into_iter()
+        .flatten()
+        .max_by_key(|id| id.as_u64())
+    }
+
+    fn handle_inbox_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.inbox.handle_key(key) {
+            InboxAction::Close => {
+                self.show_inbox = false;
+                ChatKeyResult::Consumed
+            }
+            InboxAction::Jump {
+                guild_id,
+                channel_id,
+                message_id,
+            } => {
+                self.show_inbox = false;
+                self.open_discord_link(DiscordLink {
+                    guild_id,
+                    channel_id,
+                    message_id,
+                })
+            }
+            InboxAction::MarkRead(channel_id) => {
+                let Some(message_id) = self.latest_message_id(channel_id) else {
+                    return ChatKeyResult::Consumed;
+                };
+                if let Some(read_state) = self.read_states.get_mut(&channel_id) {
+                    read_state.mention_count = 0;
+                }
+                self.mark_channel_read(channel_id, message_id);
+                self.refresh_inbox();
+                ChatKeyResult::AcknowledgeChannel {
+                    channel_id,
+                    message_id,
+                }
+            }
+            InboxAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    /// Opens the profile of the first of `user_ids`, in the selected guild if any.
+    pub fn open_profile(&mut self, user_ids: Vec<String>) -> ChatKeyResult {
+        if user_ids.is_empty() {
+            return ChatKeyResult::Consumed;
+        }
+        self.profile_popup.open(user_ids, self.selected_guild);
+        self.show_profile = true;
+        self.load_profile()
+    }
+
+    /// Shows the popup's current user from the cache, or asks for their profile.
+    fn load_profile(&mut self) -> ChatKeyResult {
+        let Some(user_id) = self.profile_popup.user_id().map(String::from) else {
+            return ChatKeyResult::Consumed;
+        };
+
+        self.profile_popup.fallback_name = self
+            .member_list_state
+            .find_member(&user_id)
+            .map(|member| member.display_name().to_string())
+            .or_else(|| self.user_cache.get_display_name(&user_id));
+        self.profile_popup.presence = user_id
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| self.presences.get(UserId(id)));
+
+        let guild_id = self.profile_popup.guild_id();
+        if let Some(profile) = self.user_cache.get_profile(&user_id, guild_id) {
+            self.profile_popup.set_profile(profile);
+            return ChatKeyResult::Consumed;
+        }
+        ChatKeyResult::LoadUserProfile {
+            user_id: UserId::from(user_id),
+            guild_id,
+        }
+    }
+
+    fn close_profile(&mut self) {
+        self.show_profile = false;
+        self.profile_popup = ProfilePopup::new();
+    }
+
+    /// Caches a fetched profile and shows it if its user is still on screen.
+    pub fn on_user_profile_loaded(&mut self, profile: UserProfile) {
+        self.user_cache.insert_profile(profile.clone());
+        self.profile_popup.set_profile(profile);
+    }
+
+    pub fn set_profile_error(&mut self, user_id: UserId, error: String) {
+        self.profile_popup.set_error(&user_id.to_string(), error);
+    }
+
+    fn handle_profile_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.profile_popup.handle_key(key) {
+            ProfilePopupAction::Close => {
+                self.close_profile();
+                ChatKeyResult::Consumed
+            }
+            ProfilePopupAction::Show(_) => self.load_profile(),
+            ProfilePopupAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    pub fn set_notification_settings(&mut self, settings: NotificationSettingsStore) {
+        self.guilds_tree_data
+            .set_notification_settings(settings.clone());
+        self.notification_settings = settings;
+    }
+
+    pub fn set_notification_rules(&mut self, rules: NotificationRules) {
+        self.notification_rules = rules;
+    }
+
+    /// Guild and channel names that notification rules match against.
+    #[must_use]
+    pub fn message_scope(&self, channel_id: ChannelId) -> MessageScope {
+        let channel = self.get_channel(channel_id);
+        let guild_id = channel.and_then(Channel::guild_id);
+        let guild_name = guild_id
+            .and_then(|guild_id| {
+                self.guilds_tree_data
+                    .guilds()
+                    .iter()
+                    .find(|guild| guild.id() == guild_id)
+            })
+            .map(|guild| guild.name().to_string());
+        MessageScope {
+            guild_id,
+            guild_name,
+            channel_name: channel.map(|channel| channel.name().to_string()),
+        }
+    }
+
+    /// The guild, category or channel the notification menu edits, with its name.
+    fn notification_menu_target(&self) -> Option<(NotificationMenuTarget, String)> {
+        let selected = if self.focus == ChatFocus::GuildsTree {
+            self.guilds_tree_state.selected()
+        } else {
+            None
+        };
+        let channel_target = |channel: &Channel| {
+            let target = NotificationMenuTarget::Channel {
+                guild_id: channel.guild_id(),
+                channel_id: channel.id(),
+            };
+            (target, channel.name().to_string())
+        };
+        match selected {
+            Some(TreeNodeId::Guild(guild_id)) => {
+                let guild = self
+                    .guilds_tree_data
+                    .guilds()
+                    .iter()
+                    .find(|guild| guild.id() == *guild_id)?;
+                let target = NotificationMenuTarget::Guild(Some(*guild_id));
+                Some((target, guild.name().to_string()))
+            }
+            Some(TreeNodeId::Category(channel_id) | TreeNodeId::Channel(channel_id)) => self
+                .guilds_tree_data
+                .get_channel(*channel_id)
+                .map(channel_target),
+            Some(TreeNodeId::DirectMessageUser(channel_id)) => {
+                let dm = self
+                    .guilds_tree_data
+                    .dm_users()
+                    .iter()
+                    .find(|dm| dm.channel_id == *channel_id)?;
+                let target = NotificationMenuTarget::Channel {
+                    guild_id: None,
+                    channel_id: ChannelId::from(channel_id.as_str()),
+                };
+                Some((target, dm.display_name(self.use_display_name)))
+            }
+            Some(TreeNodeId::DirectMessages) => Some((
+                NotificationMenuTarget::Guild(None),
+                "Direct Messages".to_string(),
+            )),
+            _ => self.selected_channel.as_ref().map(channel_target),
+        }
+    }
+
+    fn open_notification_menu(&mut self) -> bool {
+        let Some((target, title)) = self.notification_menu_target() else {
+            return false;
+        };
+        let guild_id = match target {
+            NotificationMenuTarget::Guild(guild_id)
+            | NotificationMenuTarget::Channel { guild_id, .. } => guild_id,
+        };
+        let settings = self.notification_settings.guild(guild_id);
+        self.notification_menu.open(target, title, &settings);
+        self.show_notification_menu = true;
+        true
+    }
+
+    fn handle_notification_menu_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.notification_menu.handle_key(key) {
+            NotificationMenuAction::Close => {
+                self.show_notification_menu = false;
+                ChatKeyResult::Consumed
+            }
+            NotificationMenuAction::Save(update) => {
+                self.show_notification_menu = false;
+                let previous = self.notification_settings.guild(update.guild_id());
+                let mut settings = previous.clone();
+                update.apply(&mut settings);
+                self.notification_settings.upsert(settings);
+                ChatKeyResult::UpdateNotificationSettings { update, previous }
+            }
+            NotificationMenuAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    pub fn set_own_status(&mut self, status: PresenceStatus, custom_status: Option<CustomStatus>) {
+        self.own_status = status;
+        self.own_custom_status = custom_status;
+    }
+
+    fn open_status_menu(&mut self) {
+        self.status_menu
+            .open(self.own_status, self.own_custom_status.as_ref());
+        self.show_status_menu = true;
+    }
+
+    fn handle_status_menu_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.status_menu.handle_key(key) {
+            StatusMenuAction::Close => {
+                self.show_status_menu = false;
+                ChatKeyResult::Consumed
+            }
+            StatusMenuAction::Save {
+                status,
+                custom_status,
+            } => {
+                self.show_status_menu = false;
+                self.set_own_status(status, custom_status.clone());
+                ChatKeyResult::UpdateStatus {
+                    status,
+                    custom_status,
+                }
+            }
+            StatusMenuAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    fn handle_friends_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        let entries = self
+            .friends_view
+            .entries(&self.relationship_state, &self.presences);
+        match self.friends_view.handle_key(key, &entries) {
+            FriendsViewAction::Close => {
+                self.show_friends = false;
+                ChatKeyResult::Consumed
+            }
+            FriendsViewAction::Change(change) => ChatKeyResult::ModifyRelationship(change),
+            FriendsViewAction::OpenProfile(user_id) => self.open_profile(vec![user_id.to_string()]),
+            FriendsViewAction::Message(user_id) => {
+                self.show_friends = false;
+                self.open_direct_message(vec![user_id], None)
+            }
+            FriendsViewAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    fn open_new_dm_picker(&mut self) {
+        let candidates = self.dm_candidates();
+        self.new_dm_picker.open(candidates);
+        self.show_new_dm_picker = true;
+    }
+
+    /// Users to offer in the new DM picker: friends, the current member list and
+    /// everyone else seen this session, minus ourselves and bots.
+    fn dm_candidates(&self) -> Vec<DmCandidate> {
+        let own_id = self.user.id_str();
+        let mut candidates: Vec<DmCandidate> = self
+            .relationship_state
+            .relationships()
+            .into_iter()
+            .filter(|rel| rel.relationship_type.is_friend())
+            .map(|rel| {
+                DmCandidate::new(
+                    rel.user_id,
+                    rel.display_name(),
+                    rel.username().unwrap_or_default(),
+                    DmCandidateSource::Friend,
+                )
+            })
+            .collect();
+
+        candidates.extend(
+            self.member_list_state
+                .items()
+                .iter()
+                .filter_map(|item| match item {
+                    Some(MemberListItem::Member(member)) if !member.bot => Some(member),
+                    _ => None,
+                })
+                .filter_map(|member| {
+                    let user_id = member.user_id.parse::<u64>().ok()?;
+                    Some(DmCandidate::new(
+                        UserId(user_id),
+                        member.display_name(),
+                        &member.username,
+                        DmCandidateSource::Member,
+                    ))
+                }),
+        );
+
+        candidates.extend(
+            self.user_cache
+                .users()
+                .into_iter()
+                .filter(|user| !user.is_bot())
+                .filter_map(|user| {
+                    let user_id = user.id().parse::<u64>().ok()?;
+                    Some(DmCandidate::new(
+                        UserId(user_id),
+                        user.global_name().unwrap_or(user.username()),
+                        user.username(),
+                        DmCandidateSource::Cached,
+                    ))
+                }),
+        );
+
+        candidates.retain(|candidate| candidate.user_id.to_string() != own_id);
+        candidates
+    }
+
+    /// Opens the rename prompt when the selected channel is a group DM.
+    fn open_rename_group_dm(&mut self) -> bool {
+        let Some(channel) = self
+            .selected_channel
+            .as_ref()
+            .filter(|channel| channel.kind() == ChannelKind::GroupDm)
+        else {
+            return false;
+        };
+        let channel_id = channel.id();
+        let current_name = self
+            .guilds_tree_data
+            .dm_users()
+            .iter()
+            .find(|dm| dm.channel_id == channel_id.to_string())
+            .and_then(|dm| dm.name.clone());
+        self.new_dm_picker
+            .open_rename(channel_id, current_name.as_deref());
+        self.show_new_dm_picker = true;
+        true
+    }
+
+    fn handle_new_dm_picker_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.new_dm_picker.handle_key(key) {
+            NewDmPickerAction::Close => {
+                self.show_new_dm_picker = false;
+                ChatKeyResult::Consumed
+            }
+            NewDmPickerAction::Open { recipients, name } => {
+                self.show_new_dm_picker = false;
+                self.open_direct_message(recipients, name)
+            }
+            NewDmPickerAction::Rename { channel_id, name } => {
+                self.show_new_dm_picker = false;
+                ChatKeyResult::RenameGroupDm { channel_id, name }
+            }
+            NewDmPickerAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    /// Selects an existing 1:1 DM, or asks for the DM to be opened or created.
+    fn open_direct_message(
+        &mut self,
+        recipients: Vec<UserId>,
+        name: Option<String>,
+    ) -> ChatKeyResult {
+        if let [user_id] = recipients.as_slice() {
+            let user_id = user_id.to_string();
+            let existing = self
+                .guilds_tree_data
+                .dm_users()
+                .iter()
+                .find(|dm| dm.is_direct_with(&user_id))
+                .map(|dm| dm.channel_id.clone());
+            if let Some(result) = existing.and_then(|channel_id| self.on_dm_selected(&channel_id)) {
+                return result;
+            }
+        }
+        ChatKeyResult::OpenDirectMessage { recipients, name }
+    }
+
+    fn open_forum_composer(&mut self) -> ChatKeyResult {
+        let Some(channel) = self
+            .selected_channel
+            .as_ref()
+            .filter(|c| c.kind() == ChannelKind::Forum)
+        else {
+            return ChatKeyResult::Consumed;
+        };
+        let tags = channel
+            .available_tags()
+            .iter()
+            .filter(|tag| !tag.moderated || self.can_manage_threads())
+            .cloned()
+            .collect();
+        let require_tag = channel.flags().contains(ChannelFlags::REQUIRE_TAG);
+        self.forum_composer
+            .open(channel.id(), channel.name(), tags, require_tag);
+        self.show_forum_composer = true;
+        ChatKeyResult::Consumed
+    }
+
+    fn close_forum_composer(&mut self) {
+        self.show_forum_composer = false;
+        self.forum_composer.reset();
+    }
+
+    fn handle_forum_composer_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.forum_composer.handle_key(key) {
+            ForumComposerAction::Close => {
+                self.close_forum_composer();
+                ChatKeyResult::Consumed
+            }
+            ForumComposerAction::Submit(request) => ChatKeyResult::CreateForumPost(request),
+            ForumComposerAction::PickAttachment => {
+                self.toggle_file_explorer();
+                ChatKeyResult::Consumed
+            }
+            ForumComposerAction::Paste => ChatKeyResult::Paste,
+            ForumComposerAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    /// Closes the composer once its post exists and returns the reload of the
+    /// forum listing, so the post shows up under the current sort and filter.
+    pub fn on_forum_post_created(&mut self, post: &ForumThread) -> Option<ChatKeyResult> {
+        if self.forum_composer.channel_id == post.parent_id {
+            self.close_forum_composer();
+        }
+        let channel_id = post.parent_id?;
+        if self.message_pane_data.channel_id() != Some(channel_id) {
+            self.forum_states.remove(&channel_id);
+            return None;
+        }
+        let ViewMode::Forum(state) = &mut self.message_pane_state.view_mode else {
+            return None;
+        };
+        state.reset_threads();
+        Some(ChatKeyResult::LoadForumThreads {
+            channel_id,
+            guild_id: post.guild_id.or(self.selected_guild),
+            filter: state.filter,
+            offset: 0,
+        })
+    }
+
+    pub fn set_forum_post_error(&mut self, error: String) {
+        self.forum_composer.set_error(error);
+    }
+
+    pub fn open_search(&mut self) {
+        self.show_search = true;
+        self.search_pane.focus = SearchPaneFocus::Input;
+    }
+
+    pub fn set_search_results(&mut self, request: &SearchMessagesRequest, page: MessageSearchPage) {
+        for message in &page.messages {
+            let channel_id = message.channel_id();
+            let name = self.get_channel(channel_id).map_or_else(
+                || {
+                    self.dm_channels
+                        .get(channel_id.to_string().as_str())
+                        .map(|dm| format!("@{}", dm.recipient_name()))
+                },
+                |channel| Some(channel.display_name()),
+            );
+            if let Some(name) = name {
+                self.search_pane.channel_names.insert(channel_id, name);
+            }
+        }
+        self.search_pane.set_results(request, page);
+    }
+
+    pub fn set_search_error(&mut self, request: &SearchMessagesRequest, error: String) {
+        self.search_pane.set_error(request, error);
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) -> ChatKeyResult {
+        match self.search_pane.handle_key(key) {
+            SearchPaneAction::Close => {
+                self.show_search = false;
+                ChatKeyResult::Consumed
+            }
+            SearchPaneAction::Submit(query) => {
+                match MessageSearchQuery::parse(&query)
+                    .and_then(|query| self.build_search_request(&query))
+                {
+                    Ok(request) => ChatKeyResult::SearchMessages(self.search_pane.start(request)),
+                    Err(error) => {
+                        self.search_pane.set_query_error(error);
+                        ChatKeyResult::Consumed
+                    }
+                }
+            }
+            SearchPaneAction::Search(request) => ChatKeyResult::SearchMessages(request),
+            SearchPaneAction::Jump {
+                channel_id,
+                message_id,
+            } => {
+                if self.message_pane_data.channel_id() == Some(channel_id)
+                    && self.jump_to_message(message_id)
+                {
+                    ChatKeyResult::Consumed
+                } else {
+                    ChatKeyResult::LoadMessagesAround {
+                        channel_id,
+                        message_id,
+                    }
+                }
+            }
+            SearchPaneAction::None => ChatKeyResult::Consumed,
+        }
+    }
+
+    /// Resolves the names in a typed query against the caches and picks where to search.
+    ///
+    /// Direct messages are searched one channel at a time; anything else searches the
+    /// selected guild.
+    fn build_search_request(
+        &self,
+        query: &MessageSearchQuery,
+    ) -> Result<SearchMessagesRequest, String> {
+        let open_channel = self.message_pane_data.channel_id();
+        let in_dm =
+            open_channel.is_some_and(|id| self.dm_channels.contains_key(id.to_string().as_str()));
+        let scope = match (self.selected_guild, open_channel) {
+            (Some(guild_id), _) if !in_dm => SearchScope::Guild(guild_id),
+            (_, Some(channel_id)) => SearchScope::Channel(channel_id),
+            (_, None) => return Err("Open a server or conversation to search".to_string()),
+        };
+
+        let mut request = SearchMessagesRequest::new(scope);
+        request.content = Some(query.content.clone()).filter(|c| !c.is_empty());
+        request.author_ids = query
+            .from
+            .iter()
+            .map(|name| self.resolve_search_user(name))
+            .collect::<Result<_, _>>()?;
+        request.mention_ids = query
+            .mentions
+            .iter()
+            .map(|name| self.resolve_search_user(name))
+            .collect::<Result<_, _>>()?;
+        request.channel_ids = query
+            .in_channels
+            .iter()
+            .map(|name| self.resolve_search_channel(scope, name))
+            .collect::<Result<_, _>>()?;
+        request.has.clone_from(&query.has);
+        request.min_id = query.min_id();
+        request.max_id = query.max_id();
+        Ok(request)
+    }
+
+    fn resolve_search_user(&self, value: &str) -> Result<u64, String> {
+        let id = value
+            .trim_start_matches("<@")
+            .trim_start_matches('!')
+            .trim_end_matches('>');
+        if let Ok(id) = id.parse() {
+            return Ok(id);
+        }
+        let name = value.trim_start_matches('@');
+        self.user_cache
+            .find_by_name(name)
+            .and_then(|user| user.id().parse().ok())
+            .ok_or_else(|| format!("Unknown user '{name}'"))
     }
 
-    pub fn toggle_file_explorer(&mut self) {
-        self.show_file_explorer = !self.show_file_explorer;
-        if self.show_file_explorer {
-            self.file_explorer = Some(FileExplorerComponent::new());
-        } else {
-            self.file_explorer = None;
+    fn resolve_search_channel(&self, scope: SearchScope, value: &str) -> Result<ChannelId, String> {
+        let id = value.trim_start_matches("<#").trim_end_matches('>');
+        if let Ok(id) = id.parse() {
+            return Ok(ChannelId(id));
         }
+        let name = value.trim_start_matches('#');
+        let SearchScope::Guild(guild_id) = scope else {
+            return Err(format!("Unknown channel '{name}'"));
+        };
+        self.raw_channels
+            .get(&guild_id)
+            .and_then(|channels| {
+                channels
+                    .iter()
+                    .find(|c| c.name().eq_ignore_ascii_case(name))
+            })
+            .map(Channel::id)
+            .ok_or_else(|| format!("Unknown channel '{name}'"))
     }
 
-    pub fn toggle_help(&mut self) {
-        self.show_help = !self.show_help;
+    /// Whether the current user holds `MANAGE_MESSAGES` in the open channel.
+    ///
+    /// Anyone may pin in direct messages; threads inherit their parent's permissions.
+    fn can_manage_messages(&self) -> bool {
+        self.has_channel_permission(Permissions::MANAGE_MESSAGES)
     }
 
-    fn handle_file_explorer_key(&mut self, key: KeyEvent) -> ChatKeyResult {
-        if self.registry.find_action(key) == Some(Action::ToggleHiddenFiles)
-            && let Some(explorer) = &mut self.file_explorer
-        {
-            explorer.toggle_hidden();
-            return ChatKeyResult::Consumed;
-        }
-
-        if let Some(explorer) = &mut self.file_explorer {
-            match explorer.handle_key(key) {
-                FileExplorerAction::SelectFile(path) => {
-                    self.message_input_state.add_attachment(path);
-                    self.show_file_explorer = false;
-                    self.file_explorer = None;
-                    ChatKeyResult::Consumed
-                }
-                FileExplorerAction::Close => {
-                    self.show_file_explorer = false;
-                    self.file_explorer = None;
-                    ChatKeyResult::Consumed
-                }
-                FileExplorerAction::None => ChatKeyResult::Consumed,
-            }
-        } else {
-            ChatKeyResult::Consumed
-        }
+    fn can_manage_threads(&self) -> bool {
+        self.has_channel_permission(Permissions::MANAGE_THREADS)
     }
 
-    pub fn toggle_quick_switcher(&mut self) {
-        self.show_quick_switcher = !self.show_quick_switcher;
-        if self.show_quick_switcher {
-            self.quick_switcher.reset();
-            self.perform_search("");
-        }
+    /// Whether the current user holds `permission` in the selected channel,
+    /// or in its parent when the channel is a thread.
+    fn has_channel_permission(&self, permission: Permissions) -> bool {
+        let Some(channel) = &self.selected_channel else {
+            return false;
+        };
+        let Some(guild_id) = channel.guild_id().or(self.selected_guild) else {
+            return true;
+        };
+        let (Some(member), Some(roles)) = (
+            self.guild_members.get(&guild_id),
+            self.guild_roles.get(&guild_id),
+        ) else {
+            return false;
+        };
+
+        let channel = channel
+            .parent_id()
+            .filter(|_| channel.kind().is_thread())
+            .and_then(|parent_id| {
+                self.raw_channels
+                    .get(&guild_id)?
+                    .iter()
+                    .find(|c| c.id() == parent_id)
+            })
+            .unwrap_or(channel);
+
+        PermissionCalculator::compute_permissions(guild_id.as_u64(), channel, member, roles)
+            .contains(permission)
     }
 
     pub fn set_quick_switcher_results(
@@ -2555,13 +4962,7 @@ impl ChatScreenState {
                     let dms = self.guilds_tree_data.dm_users();
                     tracing::debug!("Mixed Mode: Adding {} DMs", dms.len());
                     for dm in dms {
-                        let name = if self.use_display_name {
-                            dm.recipient_global_name
-                                .as_ref()
-                                .unwrap_or(&dm.recipient_username)
-                        } else {
-                            &dm.recipient_username
-                        };
+                        let name = dm.display_name(self.use_display_name);
                         if !results
                             .iter()
                             .any(|r| r.kind == SearchKind::DM && r.id == dm.channel_id)
@@ -2641,6 +5042,7 @@ impl ChatScreenState {
                 {
                     res.is_favorite = true;
                 }
+                res.is_online_friend = self.is_online_friend_dm(res);
             }
 
             tracing::debug!(
@@ -2692,13 +5094,29 @@ impl ChatScreenState {
             {
                 res.is_favorite = true;
             }
+            res.is_online_friend = self.is_online_friend_dm(res);
         }
 
-        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
 
         self.quick_switcher.set_results(results);
     }
 
+    /// Whether the result is a DM with a friend who is currently online.
+    fn is_online_friend_dm(&self, result: &SearchResult) -> bool {
+        result.kind == SearchKind::DM
+            && self
+                .guilds_tree_data
+                .dm_users()
+                .iter()
+                .find(|dm| dm.channel_id == result.id && !dm.is_group)
+                .and_then(|dm| dm.recipient_id().parse::<u64>().ok())
+                .map(UserId)
+                .is_some_and(|id| {
+                    self.relationship_state.is_friend(id) && self.presences.is_online(id)
+                })
+    }
+
     fn collect_searchable_channels(
         &self,
         prefix: SearchPrefix,
@@ -2847,6 +5265,7 @@ impl ChatScreenState {
 }
 
 impl HasCommands for ChatScreenState {
+    #[allow(clippy::too_many_lines)]
     fn get_commands(&self, registry: &CommandRegistry) -> Vec<Keybind> {
         let mut commands = Vec::new();
 
@@ -2894,6 +5313,24 @@ impl HasCommands for ChatScreenState {
             return commands;
         }
 
+        if self.show_emoji_picker {
+            commands.push(
+                Keybind::new(KeyEvent::from(KeyCode::Up), Action::NavigateUp, "Nav")
+                    .with_display("Ctrl+k/j"),
+            );
+            commands.push(Keybind::new(
+                KeyEvent::from(KeyCode::Enter),
+                Action::Select,
+                "Select",
+            ));
+            commands.push(Keybind::new(
+                KeyEvent::from(KeyCode::Esc),
+                Action::Cancel,
+                "Close",
+            ));
+            return commands;
+        }
+
         if self.show_file_explorer {
             if let Some(key) = registry.get_first(Action::Select) {
                 commands.push(Keybind::new(key, Action::Select, "Select"));
@@ -2917,6 +5354,9 @@ impl HasCommands for ChatScreenState {
                 ChatFocus::MessageInput => {
                     commands.extend(self.get_message_input_commands(registry));
                 }
+                ChatFocus::MemberList => {
+                    commands.extend(Self::get_member_list_commands(registry));
+                }
                 ChatFocus::ConfirmationModal => {
                     commands.push(Keybind::new(
                         KeyEvent::from(KeyCode::Enter),
@@ -2940,6 +5380,24 @@ impl HasCommands for ChatScreenState {
 }
 
 impl ChatScreenState {
+    fn get_member_list_commands(registry: &CommandRegistry) -> Vec<Keybind> {
+        let mut commands = Vec::new();
+        if let Some(key) = registry.get_first(Action::NavigateDown) {
+            let mut bind = Keybind::new(key, Action::NavigateDown, "Nav");
+            if let KeyCode::Char('j') = key.code {
+                bind = bind.with_display("j/k");
+            }
+            commands.push(bind);
+        }
+        if let Some(key) = registry.get_first(Action::Select) {
+            commands.push(Keybind::new(key, Action::Select, "Profile"));
+        }
+        if let Some(key) = registry.get_first(Action::ToggleMemberList) {
+            commands.push(Keybind::new(key, Action::ToggleMemberList, "Hide"));
+        }
+        commands
+    }
+
     #[allow(clippy::unused_self)]
     fn get_guilds_tree_commands(&self, registry: &CommandRegistry) -> Vec<Keybind> {
         let mut commands = Vec::new();
@@ -3047,6 +5505,22 @@ impl ChatScreenState {
         if let Some(key) = registry.get_first(Action::JumpToReply) {
             commands.push(Keybind::new(key, Action::JumpToReply, "Jump"));
         }
+        if let Some(key) = registry.get_first(Action::ToggleReaction) {
+            let has_reactions = self
+                .message_pane_state
+                .selected_index()
+                .and_then(|idx| self.message_pane_data.get_message(idx))
+                .is_some_and(|m| !m.reactions().is_empty());
+
+            if has_reactions {
+                commands.push(Keybind::new(key, Action::ToggleReaction, "React"));
+            }
+        }
+        if let Some(key) = registry.get_first(Action::AddReaction)
+            && self.message_pane_state.selected_index().is_some()
+        {
+            commands.push(Keybind::new(key, Action::AddReaction, "Add React"));
+        }
         if let Some(key) = registry.get_first(Action::FocusInput) {
             commands.push(Keybind::new(key, Action::FocusInput, "Focus Input"));
         }
@@ -3071,6 +5545,9 @@ impl ChatScreenState {
         if let Some(key) = registry.get_first(Action::OpenEditor) {
             commands.push(Keybind::new(key, Action::OpenEditor, "Editor"));
         }
+        if let Some(key) = registry.get_first(Action::OpenEmojiPicker) {
+            commands.push(Keybind::new(key, Action::OpenEmojiPicker, "Emoji"));
+        }
         if let Some(key) = registry.get_first(Action::ToggleFileExplorer) {
             commands.push(Keybind::new(key, Action::ToggleFileExplorer, "Attach"));
         }
@@ -3095,13 +5572,18 @@ impl ChatScreenState {
 #[cfg(not(windows))]
 mod tests {
     use super::*;
+    use crate::domain::entities::MessageFlags;
 
     fn setup_permissive_guild_data(state: &mut ChatScreenState, guild_id: GuildId) {
+        setup_guild_data(state, guild_id, Permissions::all()); // Allow everything
+    }
+
+    fn setup_guild_data(state: &mut ChatScreenState, guild_id: GuildId, permissions: Permissions) {
         let user = state.user().clone();
         let role = Role {
             id: crate::domain::entities::RoleId(guild_id.as_u64()), // @everyone has same ID as guild
             name: "@everyone".to_string(),
-            permissions: Permissions::all(), // Allow everything
+            permissions,
             color: 0,
             hoist: false,
             icon: None,
@@ -3143,6 +5625,7 @@ mod tests {
             true,
             CommandRegistry::default(),
             RelationshipState::new(),
+            PresenceStore::new(),
             false,
             QuickSwitcherSortMode::default(),
             vec![],
@@ -3150,6 +5633,312 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_dragging_sidebar_border_resizes_guilds_tree() {
+        let mut state = create_test_state(create_test_user());
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        ChatScreen::new().render(area, &mut buf, &mut state);
+
+        let border = state.layout.guilds_tree.unwrap().right() - 1;
+        let mouse = |kind, column| MouseEvent {
+            kind,
+            column,
+            row: 5,
+            modifiers: KeyModifiers::NONE,
+        };
+        state.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), border));
+        state.handle_mouse(mouse(MouseEventKind::Drag(MouseButton::Left), 39));
+        state.handle_mouse(mouse(MouseEventKind::Up(MouseButton::Left), 39));
+        ChatScreen::new().render(area, &mut buf, &mut state);
+
+        assert_eq!(state.layout.guilds_tree.unwrap().width, 40);
+        assert!(!state.resizing_guilds_tree);
+    }
+
+    #[test]
+    fn test_member_list_joins_focus_cycle_for_guild_channels() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel = Channel::new(ChannelId(10), "general", ChannelKind::Text);
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![channel.clone()]);
+        state.on_guild_selected(guild.id());
+        state.on_channel_selected(channel.id());
+
+        state.toggle_member_list();
+        assert!(state.is_member_list_shown());
+
+        state.focus_message_input();
+        state.focus_next();
+        assert_eq!(state.focus(), ChatFocus::MemberList);
+        state.focus_next();
+        assert_eq!(state.focus(), ChatFocus::GuildsTree);
+
+        state.focus_member_list();
+        state.toggle_member_list();
+        assert_eq!(state.focus(), ChatFocus::MessagesList);
+        assert!(!state.is_member_list_shown());
+    }
+
+    #[test]
+    fn test_profiles_are_fetched_once_per_guild() {
+        let mut state = create_test_state(create_test_user());
+        let open = |state: &mut ChatScreenState| state.open_profile(vec!["42".to_string()]);
+
+        assert_eq!(
+            open(&mut state),
+            ChatKeyResult::LoadUserProfile {
+                user_id: UserId(42),
+                guild_id: None,
+            }
+        );
+        let user = User::new(42_u64, "alice", "0", None, false, None);
+        state.on_user_profile_loaded(UserProfile::new(user, None));
+        assert!(state.profile_popup.profile.is_some());
+
+        state.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(!state.show_profile);
+        assert_eq!(open(&mut state), ChatKeyResult::Consumed);
+        assert!(state.profile_popup.profile.is_some());
+    }
+
+    #[test]
+    fn test_pinning_requires_manage_messages() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel = Channel::new(ChannelId(10), "general", ChannelKind::Text);
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![channel.clone()]);
+        state.on_guild_selected(guild.id());
+        state.on_channel_selected(channel.id());
+
+        let toggle = MessagePaneAction::TogglePin {
+            message_id: MessageId(5),
+            pinned: true,
+        };
+        assert_eq!(
+            state.apply_message_pane_action(toggle.clone()),
+            ChatKeyResult::SetMessagePinned {
+                message_id: MessageId(5),
+                pinned: true,
+            }
+        );
+        assert_eq!(
+            state.open_pins(),
+            ChatKeyResult::LoadPinnedMessages(channel.id())
+        );
+        assert_eq!(state.pins_channel_id(), Some(channel.id()));
+
+        setup_guild_data(&mut state, guild.id(), Permissions::VIEW_CHANNEL);
+        assert!(matches!(
+            state.apply_message_pane_action(toggle),
+            ChatKeyResult::ShowNotification(_)
+        ));
+    }
+
+    #[test]
+    fn test_thread_opens_beside_parent_channel() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel =
+            Channel::new(ChannelId(10), "general", ChannelKind::Text).with_guild(guild.id());
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![channel.clone()]);
+        state.on_guild_selected(guild.id());
+        state.on_channel_selected(channel.id());
+
+        let message = |id: u64, content: &str| Message::test(id, channel.id().0, content);
+        state.set_messages(vec![
+            message(50, "Release plan\nDetails").with_flags(MessageFlags::HAS_THREAD),
+            message(51, ""),
+        ]);
+
+        assert_eq!(
+            state.apply_message_pane_action(MessagePaneAction::StartThread(MessageId(51))),
+            ChatKeyResult::CreateThread(
+                CreateThreadRequest::new(channel.id(), "Thread").from_message(MessageId(51))
+            )
+        );
+
+        assert_eq!(
+            state.apply_message_pane_action(MessagePaneAction::OpenThread(ChannelId(50))),
+            ChatKeyResult::LoadChannelMessages {
+                channel_id: ChannelId(50),
+                guild_id: Some(guild.id()),
+            }
+        );
+        let thread = state.selected_channel().unwrap();
+        assert_eq!(thread.name(), "Release plan");
+        assert_eq!(thread.parent_id(), Some(channel.id()));
+        assert!(state.thread_parent.is_some());
+
+        state.add_message(message(52, "later"));
+
+        assert_eq!(
+            state.apply_message_pane_action(MessagePaneAction::CloseThread),
+            ChatKeyResult::SaveState
+        );
+        assert!(state.thread_parent.is_none());
+        assert_eq!(
+            state.selected_channel().map(Channel::id),
+            Some(channel.id())
+        );
+        assert_eq!(state.message_pane_data().messages().len(), 3);
+    }
+
+    #[test]
+    fn test_discord_link_waits_for_guild_channels() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel = Channel::new(ChannelId(10), "general", ChannelKind::Text);
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+
+        let link = DiscordLink {
+            guild_id: Some(guild.id()),
+            channel_id: channel.id(),
+            message_id: Some(MessageId(5)),
+        };
+        assert_eq!(
+            state.open_link("https://discord.com/channels/1/10/5".to_string()),
+            ChatKeyResult::LoadGuildChannels(guild.id())
+        );
+
+        state.set_channels(guild.id(), vec![channel.clone()]);
+        let pending = state.take_pending_link(guild.id()).unwrap();
+        assert_eq!(pending, link);
+        assert_eq!(
+            state.open_discord_link(pending),
+            ChatKeyResult::LoadChannelMessages {
+                channel_id: channel.id(),
+                guild_id: Some(guild.id()),
+            }
+        );
+        assert_eq!(state.take_pending_jump(channel.id()), Some(MessageId(5)));
+        assert_eq!(
+            state.open_discord_link(link),
+            ChatKeyResult::JumpToMessage(MessageId(5))
+        );
+    }
+
+    #[test]
+    fn test_thread_link_opens_beside_shown_parent() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel =
+            Channel::new(ChannelId(10), "general", ChannelKind::Text).with_guild(guild.id());
+        let thread = Channel::new(ChannelId(50), "plans", ChannelKind::PublicThread)
+            .with_guild(guild.id())
+            .with_parent(channel.id());
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![channel.clone(), thread.clone()]);
+        state.on_guild_selected(guild.id());
+        state.on_channel_selected(channel.id());
+
+        let link = DiscordLink {
+            guild_id: Some(guild.id()),
+            channel_id: thread.id(),
+            message_id: Some(MessageId(5)),
+        };
+        assert_eq!(
+            state.open_discord_link(link),
+            ChatKeyResult::LoadChannelMessages {
+                channel_id: thread.id(),
+                guild_id: Some(guild.id()),
+            }
+        );
+        assert!(state.thread_parent.is_some());
+        assert_eq!(state.take_pending_jump(thread.id()), Some(MessageId(5)));
+    }
+
+    #[test]
+    fn test_unknown_thread_link_is_fetched_before_opening() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let channel =
+            Channel::new(ChannelId(10), "general", ChannelKind::Text).with_guild(guild.id());
+        let thread = Channel::new(ChannelId(50), "old", ChannelKind::PublicThread)
+            .with_guild(guild.id())
+            .with_parent(channel.id());
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![channel.clone()]);
+        state.on_guild_selected(guild.id());
+
+        let link = DiscordLink {
+            guild_id: Some(guild.id()),
+            channel_id: thread.id(),
+            message_id: None,
+        };
+        assert_eq!(
+            state.open_discord_link(link),
+            ChatKeyResult::RequestChannelFetch(vec![thread.id()])
+        );
+
+        let pending = state.take_pending_channel_link(thread.id()).unwrap();
+        assert!(matches!(
+            state.open_fetched_link(pending),
+            ChatKeyResult::ShowNotification(_)
+        ));
+
+        state.upsert_threads(vec![thread.clone()]);
+        state.open_fetched_link(pending);
+        assert_eq!(state.selected_channel().map(Channel::id), Some(thread.id()));
+    }
+
+    #[test]
+    fn test_forum_link_drops_message_jump() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let forum = Channel::new(ChannelId(10), "ideas", ChannelKind::Forum).with_guild(guild.id());
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![forum.clone()]);
+        state.on_guild_selected(guild.id());
+
+        let result = state.open_discord_link(DiscordLink {
+            guild_id: Some(guild.id()),
+            channel_id: forum.id(),
+            message_id: Some(MessageId(5)),
+        });
+        assert!(matches!(result, ChatKeyResult::LoadForumThreads { .. }));
+        assert_eq!(state.take_pending_jump(forum.id()), None);
+    }
+
+    #[test]
+    fn test_jump_to_other_channel_selects_it() {
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        let general =
+            Channel::new(ChannelId(10), "general", ChannelKind::Text).with_guild(guild.id());
+        let random =
+            Channel::new(ChannelId(11), "random", ChannelKind::Text).with_guild(guild.id());
+
+        state.set_guilds(vec![guild.clone()]);
+        setup_permissive_guild_data(&mut state, guild.id());
+        state.set_channels(guild.id(), vec![general.clone(), random.clone()]);
+        state.on_guild_selected(guild.id());
+        state.on_channel_selected(general.id());
+
+        assert_eq!(state.select_channel_for_jump(random.id()), Some(guild.id()));
+        assert_eq!(state.selected_channel().map(Channel::id), Some(random.id()));
+        assert_eq!(state.message_pane_data().channel_id(), Some(random.id()));
+        assert_eq!(
+            state.guilds_tree_state.selected(),
+            Some(&TreeNodeId::Channel(random.id()))
+        );
+    }
+
     #[test]
     fn test_reselecting_same_guild_preserves_channel() {
         let mut state = create_test_state(create_test_user());
@@ -3174,4 +5963,121 @@ mod tests {
             "Channel selection should be preserved when reselecting same guild"
         );
     }
+
+    #[test]
+    fn test_emoji_picker_inserts_guild_emoji_into_input() {
+        let mut state = create_test_state(create_test_user());
+
+        let guild = Guild::new(1_u64, "Guild");
+        state.set_guilds(vec![guild.clone()]);
+        state.set_guild_emojis(guild.id(), vec![CustomEmoji::new(5, "blob")]);
+        state.on_guild_selected(guild.id());
+
+        state.open_emoji_picker(EmojiPickerTarget::Input);
+        let result = state.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(result, ChatKeyResult::StartTyping);
+        assert_eq!(state.message_input_state.value(), "<:blob:5>");
+        assert!(!state.show_emoji_picker);
+    }
+
+    #[test]
+    fn test_mentions_own_role() {
+        let mut state = create_test_state(create_test_user());
+        let guild_id = GuildId(1);
+        let member = Member {
+            user: Some(state.user().clone()),
+            roles: vec![crate::domain::entities::RoleId(5)],
+            nick: None,
+            avatar: None,
+            joined_at: String::new(),
+            premium_since: None,
+            deaf: false,
+            mute: false,
+            pending: false,
+            permissions: None,
+            communication_disabled_until: None,
+        };
+        state.set_guild_data(guild_id, vec![], vec![member]);
+
+        let message = |roles: Vec<u64>, guild: Option<GuildId>| {
+            Message::test(1, 10, "<@&5>")
+                .with_guild_id(guild)
+                .with_mention_roles(
+                    roles
+                        .into_iter()
+                        .map(crate::domain::entities::RoleId)
+                        .collect(),
+                )
+        };
+
+        assert!(state.mentions_own_role(&message(vec![5], Some(guild_id))));
+        assert!(!state.mentions_own_role(&message(vec![6], Some(guild_id))));
+        assert!(!state.mentions_own_role(&message(vec![5], None)));
+    }
+
+    #[test]
+    fn test_channel_autocomplete_skips_hidden_channels() {
+        use crate::domain::entities::{OverwriteType, PermissionOverwrite};
+
+        let mut state = create_test_state(create_test_user());
+        let guild = Guild::new(1_u64, "Guild");
+        state.set_guilds(vec![guild.clone()]);
+
+        let everyone = Role {
+            id: crate::domain::entities::RoleId(1),
+            name: "@everyone".to_string(),
+            permissions: Permissions::VIEW_CHANNEL,
+            color: 0,
+            hoist: false,
+            icon: None,
+            unicode_emoji: None,
+            position: 0,
+            managed: false,
+            mentionable: false,
+        };
+        let member = Member {
+            user: Some(state.user().clone()),
+            roles: vec![],
+            nick: None,
+            avatar: None,
+            joined_at: String::new(),
+            premium_since: None,
+            deaf: false,
+            mute: false,
+            pending: false,
+            permissions: None,
+            communication_disabled_until: None,
+        };
+        state.set_guild_data(guild.id(), vec![everyone], vec![member]);
+
+        let hidden = PermissionOverwrite {
+            id: "1".to_string(),
+            overwrite_type: OverwriteType::Role,
+            allow: "0".to_string(),
+            deny: Permissions::VIEW_CHANNEL.bits().to_string(),
+        };
+        state.set_channels(
+            guild.id(),
+            vec![
+                Channel::new(10_u64, "general", ChannelKind::Text).with_guild(1_u64),
+                Channel::new(11_u64, "gen-secret", ChannelKind::Text)
+                    .with_guild(1_u64)
+                    .with_permission_overwrites(vec![hidden]),
+                Channel::new(12_u64, "gen-voice", ChannelKind::Voice).with_guild(1_u64),
+            ],
+        );
+        state.on_guild_selected(guild.id());
+        state.focus_message_input();
+
+        for c in "#gen".chars() {
+            state.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        let results = &state.autocomplete_service.state().results;
+        assert_eq!(results.len(), 1);
+
+        state.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(state.message_input_state.value(), "#general ");
+        assert_eq!(state.message_input_state.message_content(), "<#10> ");
+    }
 }
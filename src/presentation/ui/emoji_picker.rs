@@ -0,0 +1,338 @@
+use crate::domain::entities::{CustomEmoji, Emoji, MessageId};
+use crate::infrastructure::search::FuzzySearcher;
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::centered_rect;
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+/// Upper bound on listed emoji; the Unicode table alone has close to two thousand entries.
+const MAX_RESULTS: usize = 200;
+
+/// What the picked emoji is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmojiPickerTarget {
+    #[default]
+    Input,
+    Reaction(MessageId),
+}
+
+pub struct EmojiPicker {
+    pub input: String,
+    pub results: Vec<Emoji>,
+    pub list_state: ListState,
+    pub target: EmojiPickerTarget,
+    custom_emojis: Vec<CustomEmoji>,
+    searcher: FuzzySearcher,
+}
+
+impl Default for EmojiPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmojiPicker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            input: String::new(),
+            results: Vec::new(),
+            list_state: ListState::default(),
+            target: EmojiPickerTarget::default(),
+            custom_emojis: Vec::new(),
+            searcher: FuzzySearcher::new(),
+        }
+    }
+
+    /// Resets the picker for a new target, listing the given guild emoji ahead of Unicode ones.
+    pub fn open(&mut self, target: EmojiPickerTarget, custom_emojis: Vec<CustomEmoji>) {
+        self.target = target;
+        self.custom_emojis = custom_emojis;
+        self.input.clear();
+        self.update_results();
+    }
+
+    pub fn reset(&mut self) {
+        self.input.clear();
+        self.results.clear();
+        self.custom_emojis.clear();
+        self.list_state.select(None);
+    }
+
+    fn update_results(&mut self) {
+        let query = self.input.trim().trim_start_matches(':');
+        let candidates = self
+            .custom_emojis
+            .iter()
+            .filter(|e| e.available)
+            .cloned()
+            .map(Emoji::Custom)
+            .chain(Emoji::unicode_catalog().iter().cloned());
+
+        self.results = if query.is_empty() {
+            candidates.take(MAX_RESULTS).collect()
+        } else {
+            let mut scored: Vec<(i64, Emoji)> = candidates
+                .filter_map(|e| self.searcher.score(e.name(), query).map(|s| (s, e)))
+                .collect();
+            scored.sort_by_key(|(score, e)| (std::cmp::Reverse(*score), e.name().len()));
+            scored
+                .into_iter()
+                .take(MAX_RESULTS)
+                .map(|(_, e)| e)
+                .collect()
+        };
+
+        if self.results.is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    #[must_use]
+    pub fn selected_emoji(&self) -> Option<&Emoji> {
+        self.list_state.selected().and_then(|i| self.results.get(i))
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> EmojiPickerAction {
+        match key.code {
+            KeyCode::Esc => EmojiPickerAction::Close,
+            KeyCode::Enter | KeyCode::Tab => self
+                .selected_emoji()
+                .map_or(EmojiPickerAction::None, |emoji| {
+                    EmojiPickerAction::Select(emoji.clone())
+                }),
+            KeyCode::Up => {
+                self.select_previous();
+                EmojiPickerAction::None
+            }
+            KeyCode::Down => {
+                self.select_next();
+                EmojiPickerAction::None
+            }
+            KeyCode::Char('h' | 'w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.input.clear();
+                self.update_results();
+                EmojiPickerAction::None
+            }
+            KeyCode::Char(c) => {
+                self.input.push(c);
+                self.update_results();
+                EmojiPickerAction::None
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.update_results();
+                EmojiPickerAction::None
+            }
+            _ => EmojiPickerAction::None,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.results.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(i) if i + 1 < self.results.len() => i + 1,
+            _ => 0,
+        };
+        self.list_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.results.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(0) | None => self.results.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.list_state.select(Some(i));
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EmojiPickerAction {
+    None,
+    Close,
+    Select(Emoji),
+}
+
+pub struct EmojiPickerWidget<'a> {
+    picker: &'a EmojiPicker,
+    theme: &'a Theme,
+}
+
+impl<'a> EmojiPickerWidget<'a> {
+    #[must_use]
+    pub fn new(picker: &'a EmojiPicker, theme: &'a Theme) -> Self {
+        Self { picker, theme }
+    }
+
+    fn render_results_list(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let items: Vec<ListItem> = self
+            .picker
+            .results
+            .iter()
+            .map(|emoji| {
+                let (icon, label) = match emoji {
+                    Emoji::Unicode { value, .. } => (format!(" {value} "), None),
+                    Emoji::Custom(custom) => (
+                        " ◆ ".to_string(),
+                        Some(if custom.animated {
+                            " (animated)"
+                        } else {
+                            " (server)"
+                        }),
+                    ),
+                };
+
+                let mut spans = vec![
+                    Span::styled(icon, Style::default().fg(self.theme.accent)),
+                    Span::styled(format!(" :{}:", emoji.name()), self.theme.base_style),
+                ];
+                if let Some(label) = label {
+                    spans.push(Span::styled(label, self.theme.dimmed_style));
+                }
+
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(self.theme.selection_style);
+
+        let mut state = self.picker.list_state;
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
+
+impl Widget for EmojiPickerWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(40, 40, area);
+
+        Clear.render(area, buf);
+
+        let title = match self.picker.target {
+            EmojiPickerTarget::Input => " Emoji ",
+            EmojiPickerTarget::Reaction(_) => " Add Reaction ",
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(title);
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        let search_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(8),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
+            .split(layout[0]);
+
+        let search_label = Paragraph::new(" Search ")
+            .style(Style::default().bg(self.theme.accent).fg(Color::Black));
+        search_label.render(search_layout[0], buf);
+
+        let input = Paragraph::new(self.picker.input.as_str()).style(self.theme.base_style);
+        input.render(search_layout[2], buf);
+
+        self.render_results_list(layout[1], buf);
+
+        let footer_style = FooterBarStyle::from_theme(self.theme);
+        let select_label = match self.picker.target {
+            EmojiPickerTarget::Input => "Insert",
+            EmojiPickerTarget::Reaction(_) => "React",
+        };
+
+        KeyHints::new(&[("Enter", select_label), ("Esc", "Close")])
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(layout[2], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picker_with_custom() -> EmojiPicker {
+        let mut picker = EmojiPicker::new();
+        picker.open(
+            EmojiPickerTarget::Input,
+            vec![
+                CustomEmoji::new(1, "blobwave"),
+                CustomEmoji::new(2, "hidden").with_available(false),
+            ],
+        );
+        picker
+    }
+
+    #[test]
+    fn test_open_lists_custom_emoji_first() {
+        let picker = picker_with_custom();
+        assert_eq!(picker.results[0].name(), "blobwave");
+        assert!(picker.results.iter().all(|e| e.name() != "hidden"));
+        assert_eq!(picker.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_search_by_shortcode() {
+        let mut picker = picker_with_custom();
+        for c in ":rocket".chars() {
+            picker.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        let action = picker.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(
+            action,
+            EmojiPickerAction::Select(Emoji::from_shortcode("rocket").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_select_custom_emoji_for_reaction() {
+        let mut picker = EmojiPicker::new();
+        picker.open(
+            EmojiPickerTarget::Reaction(MessageId(7)),
+            vec![CustomEmoji::new(1, "blobwave")],
+        );
+
+        let EmojiPickerAction::Select(emoji) = picker.handle_key(KeyEvent::from(KeyCode::Enter))
+        else {
+            panic!("Expected Select action");
+        };
+        assert_eq!(emoji.markup(), "<:blobwave:1>");
+        assert_eq!(picker.target, EmojiPickerTarget::Reaction(MessageId(7)));
+    }
+
+    #[test]
+    fn test_escape_closes() {
+        let mut picker = picker_with_custom();
+        assert_eq!(
+            picker.handle_key(KeyEvent::from(KeyCode::Esc)),
+            EmojiPickerAction::Close
+        );
+    }
+}
@@ -0,0 +1,597 @@
+use std::path::PathBuf;
+
+use crate::domain::entities::{ChannelId, ForumTag};
+use crate::domain::ports::CreateForumPostRequest;
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::centered_rect;
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+use tui_textarea::{CursorMove, TextArea};
+
+/// Discord rejects posts with more tags than this.
+const MAX_APPLIED_TAGS: usize = 5;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ComposerField {
+    #[default]
+    Title,
+    Body,
+    Tags,
+    Attachments,
+}
+
+impl ComposerField {
+    const fn next(self) -> Self {
+        match self {
+            Self::Title => Self::Body,
+            Self::Body => Self::Tags,
+            Self::Tags => Self::Attachments,
+            Self::Attachments => Self::Title,
+        }
+    }
+
+    const fn previous(self) -> Self {
+        match self {
+            Self::Title => Self::Attachments,
+            Self::Body => Self::Title,
+            Self::Tags => Self::Body,
+            Self::Attachments => Self::Tags,
+        }
+    }
+}
+
+/// Draft of a new forum post.
+#[derive(Default)]
+pub struct ForumComposer {
+    pub channel_id: Option<ChannelId>,
+    pub forum_name: String,
+    pub title: TextArea<'static>,
+    pub body: TextArea<'static>,
+    pub tags: Vec<ForumTag>,
+    pub selected_tags: Vec<u64>,
+    pub tag_cursor: usize,
+    /// The forum only accepts posts with at least one tag.
+    pub require_tag: bool,
+    pub attachments: Vec<PathBuf>,
+    pub field: ComposerField,
+    pub error: Option<String>,
+    /// A post is on its way to the server; further submits are ignored.
+    pub submitting: bool,
+    /// Esc was pressed on a non-empty draft; pressing it again discards the draft.
+    pub confirm_discard: bool,
+}
+
+impl ForumComposer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts an empty draft for `channel_id`.
+    pub fn open(
+        &mut self,
+        channel_id: ChannelId,
+        forum_name: impl Into<String>,
+        tags: Vec<ForumTag>,
+        require_tag: bool,
+    ) {
+        *self = Self {
+            channel_id: Some(channel_id),
+            forum_name: forum_name.into(),
+            tags,
+            require_tag,
+            ..Self::default()
+        };
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn add_attachment(&mut self, path: PathBuf) {
+        if !self.attachments.contains(&path) {
+            self.attachments.push(path);
+        }
+    }
+
+    /// Inserts pasted text into the focused text field; the title stays on one line.
+    pub fn insert_text(&mut self, text: &str) {
+        match self.field {
+            ComposerField::Title => {
+                self.title.insert_str(text.replace(['\r', '\n'], " "));
+            }
+            ComposerField::Body => {
+                self.body.insert_str(text);
+            }
+            ComposerField::Tags | ComposerField::Attachments => {}
+        }
+    }
+
+    /// Whether anything was typed, tagged or attached.
+    #[must_use]
+    pub fn has_draft(&self) -> bool {
+        !self.title.is_empty()
+            || !self.body.is_empty()
+            || !self.selected_tags.is_empty()
+            || !self.attachments.is_empty()
+    }
+
+    /// Surfaces a failed submit and lets the user try again.
+    pub fn set_error(&mut self, error: String) {
+        self.submitting = false;
+        self.error = Some(error);
+    }
+
+    fn toggle_tag(&mut self) {
+        let Some(tag) = self.tags.get(self.tag_cursor) else {
+            return;
+        };
+        if let Some(pos) = self.selected_tags.iter().position(|id| *id == tag.id) {
+            self.selected_tags.remove(pos);
+        } else if self.selected_tags.len() < MAX_APPLIED_TAGS {
+            self.selected_tags.push(tag.id);
+        } else {
+            self.error = Some(format!("A post can have at most {MAX_APPLIED_TAGS} tags"));
+        }
+    }
+
+    /// Builds the request, or records why the draft cannot be posted yet.
+    fn build_request(&mut self) -> Option<CreateForumPostRequest> {
+        let channel_id = self.channel_id?;
+        let title = self.title.lines().join(" ");
+        let title = title.trim();
+        let body = self.body.lines().join("\n");
+
+        let error = if title.is_empty() {
+            Some("A post needs a title")
+        } else if body.trim().is_empty() && self.attachments.is_empty() {
+            Some("A post needs a message or an attachment")
+        } else if self.require_tag && self.selected_tags.is_empty() {
+            Some("This forum requires at least one tag")
+        } else {
+            None
+        };
+
+        if let Some(error) = error {
+            self.error = Some(error.to_string());
+            return None;
+        }
+
+        Some(
+            CreateForumPostRequest::new(channel_id, title, body.trim_end())
+                .with_tags(self.selected_tags.clone())
+                .with_attachments(self.attachments.clone()),
+        )
+    }
+
+    /// Applies an editing key to the focused text field.
+    fn edit_text(&mut self, key: KeyEvent) {
+        let multiline = self.field == ComposerField::Body;
+        let text = match self.field {
+            ComposerField::Title => &mut self.title,
+            ComposerField::Body => &mut self.body,
+            ComposerField::Tags | ComposerField::Attachments => return,
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let word = ctrl || key.modifiers.contains(KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char('w' | 'h') if ctrl => {
+                text.delete_word();
+            }
+            KeyCode::Char(c) if !word => text.insert_char(c),
+            KeyCode::Enter if multiline => text.insert_newline(),
+            KeyCode::Backspace if word => {
+                text.delete_word();
+            }
+            KeyCode::Backspace => {
+                text.delete_char();
+            }
+            KeyCode::Delete if word => {
+                text.delete_next_word();
+            }
+            KeyCode::Delete => {
+                text.delete_next_char();
+            }
+            KeyCode::Left if word => text.move_cursor(CursorMove::WordBack),
+            KeyCode::Left => text.move_cursor(CursorMove::Back),
+            KeyCode::Right if word => text.move_cursor(CursorMove::WordForward),
+            KeyCode::Right => text.move_cursor(CursorMove::Forward),
+            KeyCode::Up => text.move_cursor(CursorMove::Up),
+            KeyCode::Down => text.move_cursor(CursorMove::Down),
+            KeyCode::Home => text.move_cursor(CursorMove::Head),
+            KeyCode::End => text.move_cursor(CursorMove::End),
+            _ => {}
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ForumComposerAction {
+        if key.code == KeyCode::Esc {
+            if !self.has_draft() || self.confirm_discard {
+                return ForumComposerAction::Close;
+            }
+            self.confirm_discard = true;
+            return ForumComposerAction::None;
+        }
+        self.confirm_discard = false;
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return match key.code {
+                KeyCode::Char('s') if !self.submitting => {
+                    self.build_request()
+                        .map_or(ForumComposerAction::None, |request| {
+                            self.submitting = true;
+                            self.error = None;
+                            ForumComposerAction::Submit(request)
+                        })
+                }
+                KeyCode::Char('a') => ForumComposerAction::PickAttachment,
+                KeyCode::Char('v') => ForumComposerAction::Paste,
+                _ => {
+                    self.edit_text(key);
+                    ForumComposerAction::None
+                }
+            };
+        }
+
+        match key.code {
+            KeyCode::Tab => self.field = self.field.next(),
+            KeyCode::BackTab => self.field = self.field.previous(),
+            KeyCode::Enter if self.field == ComposerField::Title => {
+                self.field = self.field.next();
+            }
+            _ => match self.field {
+                ComposerField::Title | ComposerField::Body => self.edit_text(key),
+                ComposerField::Tags => match key.code {
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        self.tag_cursor = self.tag_cursor.saturating_sub(1);
+                    }
+                    KeyCode::Right | KeyCode::Char('l') => {
+                        self.tag_cursor =
+                            (self.tag_cursor + 1).min(self.tags.len().saturating_sub(1));
+                    }
+                    KeyCode::Char(' ') | KeyCode::Enter => self.toggle_tag(),
+                    _ => {}
+                },
+                ComposerField::Attachments => match key.code {
+                    KeyCode::Enter => return ForumComposerAction::PickAttachment,
+                    KeyCode::Backspace | KeyCode::Delete => {
+                        self.attachments.pop();
+                    }
+                    _ => {}
+                },
+            },
+        }
+        ForumComposerAction::None
+    }
+}
+
+#[derive(Debug)]
+pub enum ForumComposerAction {
+    None,
+    Close,
+    Submit(CreateForumPostRequest),
+    /// Opens the file explorer to attach a file to the draft.
+    PickAttachment,
+    /// Pastes the clipboard into the focused field.
+    Paste,
+}
+
+pub struct ForumComposerWidget<'a> {
+    composer: &'a ForumComposer,
+    theme: &'a Theme,
+}
+
+impl<'a> ForumComposerWidget<'a> {
+    #[must_use]
+    pub fn new(composer: &'a ForumComposer, theme: &'a Theme) -> Self {
+        Self { composer, theme }
+    }
+
+    fn field_block(&self, title: &str, field: ComposerField) -> Block<'static> {
+        let border_style = if self.composer.field == field {
+            Style::default().fg(self.theme.accent)
+        } else {
+            self.theme.dimmed_style
+        };
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!(" {title} "))
+    }
+
+    /// Lines of a text field, with the cursor drawn when it is focused.
+    /// Lines of a text field, with the cursor drawn when it is focused.
+    fn text_lines(&self, field: ComposerField) -> Vec<Line<'a>> {
+        let composer: &'a ForumComposer = self.composer;
+        let text = if field == ComposerField::Title {
+            &composer.title
+        } else {
+            &composer.body
+        };
+        let focused = composer.field == field;
+        let (row, col) = text.cursor();
+        text.lines()
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if !focused || i != row {
+                    return Line::from(line.as_str());
+                }
+                let split = line
+                    .char_indices()
+                    .nth(col)
+                    .map_or(line.len(), |(at, _)| at);
+                let (before, rest) = line.split_at(split);
+                let mut chars = rest.chars();
+                let cursor = chars.next().map_or_else(|| " ".to_string(), String::from);
+                Line::from(vec![
+                    Span::raw(before),
+                    Span::styled(cursor, self.theme.selection_style),
+                    Span::raw(chars.as_str()),
+                ])
+            })
+            .collect()
+    }
+
+    fn tags_line(&self) -> Line<'_> {
+        if self.composer.tags.is_empty() {
+            return Line::from(Span::styled(
+                "This forum has no tags",
+                self.theme.dimmed_style,
+            ));
+        }
+
+        let focused = self.composer.field == ComposerField::Tags;
+        let mut spans = Vec::new();
+        for (i, tag) in self.composer.tags.iter().enumerate() {
+            let selected = self.composer.selected_tags.contains(&tag.id);
+            let mut style = if selected {
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                self.theme.base_style
+            };
+            if focused && i == self.composer.tag_cursor {
+                style = style.patch(self.theme.selection_style);
+            }
+            let marker = if selected { "x" } else { " " };
+            spans.push(Span::styled(format!("[{marker}] {}", tag.label()), style));
+            spans.push(Span::raw("  "));
+        }
+        Line::from(spans)
+    }
+
+    fn attachments_text(&self) -> Vec<Line<'_>> {
+        if self.composer.attachments.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No attachments",
+                self.theme.dimmed_style,
+            ))];
+        }
+        self.composer
+            .attachments
+            .iter()
+            .map(|path| {
+                let name = path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                Line::from(Span::styled(name, self.theme.base_style))
+            })
+            .collect()
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings = [
+            ("Tab", "Next field"),
+            ("Space", "Toggle tag"),
+            ("Ctrl+a", "Attach"),
+            ("Ctrl+s", "Post"),
+            (
+                "Esc",
+                if self.composer.confirm_discard {
+                    "Discard draft"
+                } else {
+                    "Cancel"
+                },
+            ),
+        ];
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for ForumComposerWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(60, 70, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(format!(" New Post in {} ", self.composer.forum_name));
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+                Constraint::Length(4),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        Paragraph::new(self.text_lines(ComposerField::Title))
+            .style(self.theme.base_style)
+            .block(self.field_block("Title", ComposerField::Title))
+            .render(layout[0], buf);
+
+        Paragraph::new(self.text_lines(ComposerField::Body))
+            .style(self.theme.base_style)
+            .wrap(Wrap { trim: false })
+            .block(self.field_block("Message", ComposerField::Body))
+            .render(layout[1], buf);
+
+        let tags_title = if self.composer.require_tag {
+            "Tags (required)"
+        } else {
+            "Tags"
+        };
+        Paragraph::new(self.tags_line())
+            .block(self.field_block(tags_title, ComposerField::Tags))
+            .render(layout[2], buf);
+
+        Paragraph::new(self.attachments_text())
+            .block(self.field_block("Attachments", ComposerField::Attachments))
+            .render(layout[3], buf);
+
+        let status = if self.composer.submitting {
+            Some(Span::styled("Posting...", self.theme.dimmed_style))
+        } else if self.composer.confirm_discard {
+            Some(Span::styled(
+                "Press Esc again to discard this draft",
+                Style::default().fg(ratatui::style::Color::Yellow),
+            ))
+        } else {
+            self.composer
+                .error
+                .as_deref()
+                .map(|error| Span::styled(error, Style::default().fg(ratatui::style::Color::Red)))
+        };
+        if let Some(status) = status {
+            Paragraph::new(Line::from(status)).render(layout[4], buf);
+        }
+
+        self.render_footer(layout[5], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(composer: &mut ForumComposer, text: &str) {
+        for c in text.chars() {
+            composer.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    fn submit(composer: &mut ForumComposer) -> ForumComposerAction {
+        composer.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+    }
+
+    fn composer_with_tags(require_tag: bool) -> ForumComposer {
+        let mut composer = ForumComposer::new();
+        composer.open(
+            ChannelId(1),
+            "help",
+            vec![ForumTag::new(10, "bug"), ForumTag::new(11, "question")],
+            require_tag,
+        );
+        composer
+    }
+
+    #[test]
+    fn test_submit_builds_request_with_selected_tags() {
+        let mut composer = composer_with_tags(false);
+        type_text(&mut composer, "Crash on start");
+        composer.handle_key(KeyEvent::from(KeyCode::Enter));
+        type_text(&mut composer, "It panics");
+
+        composer.handle_key(KeyEvent::from(KeyCode::Tab));
+        composer.handle_key(KeyEvent::from(KeyCode::Right));
+        composer.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+
+        let ForumComposerAction::Submit(request) = submit(&mut composer) else {
+            panic!("expected a submit");
+        };
+        assert_eq!(request.channel_id, ChannelId(1));
+        assert_eq!(request.title, "Crash on start");
+        assert_eq!(request.content, "It panics");
+        assert_eq!(request.applied_tags, vec![11]);
+        assert!(composer.submitting);
+        assert!(matches!(submit(&mut composer), ForumComposerAction::None));
+    }
+
+    #[test]
+    fn test_text_fields_edit_at_the_cursor() {
+        let mut composer = composer_with_tags(false);
+        type_text(&mut composer, "Crash start");
+        for _ in 0..5 {
+            composer.handle_key(KeyEvent::from(KeyCode::Left));
+        }
+        type_text(&mut composer, "on ");
+        composer.handle_key(KeyEvent::from(KeyCode::End));
+        composer.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        composer.insert_text("boot\nup");
+        assert_eq!(composer.title.lines(), ["Crash on boot up"]);
+    }
+
+    #[test]
+    fn test_esc_asks_before_discarding_a_draft() {
+        let mut composer = composer_with_tags(false);
+        assert!(matches!(
+            composer.handle_key(KeyEvent::from(KeyCode::Esc)),
+            ForumComposerAction::Close
+        ));
+
+        type_text(&mut composer, "Draft");
+        assert!(matches!(
+            composer.handle_key(KeyEvent::from(KeyCode::Esc)),
+            ForumComposerAction::None
+        ));
+        assert!(composer.confirm_discard);
+
+        type_text(&mut composer, "!");
+        assert!(!composer.confirm_discard, "typing keeps the draft");
+        composer.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(
+            composer.handle_key(KeyEvent::from(KeyCode::Esc)),
+            ForumComposerAction::Close
+        ));
+    }
+
+    #[test]
+    fn test_submit_validates_draft() {
+        let mut composer = composer_with_tags(true);
+        assert!(matches!(submit(&mut composer), ForumComposerAction::None));
+        assert_eq!(composer.error.as_deref(), Some("A post needs a title"));
+
+        type_text(&mut composer, "Title");
+        assert!(matches!(submit(&mut composer), ForumComposerAction::None));
+        assert_eq!(
+            composer.error.as_deref(),
+            Some("A post needs a message or an attachment")
+        );
+
+        composer.add_attachment(PathBuf::from("/tmp/log.txt"));
+        assert!(matches!(submit(&mut composer), ForumComposerAction::None));
+        assert_eq!(
+            composer.error.as_deref(),
+            Some("This forum requires at least one tag")
+        );
+
+        composer.field = ComposerField::Tags;
+        composer.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert!(matches!(
+            submit(&mut composer),
+            ForumComposerAction::Submit(_)
+        ));
+    }
+}
@@ -0,0 +1,541 @@
+use crate::domain::entities::{
+    PresenceStatus, PresenceStore, Relationship, RelationshipState, RelationshipType, UserId,
+};
+use crate::domain::ports::RelationshipChange;
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, clean_text, presence_color};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FriendsTab {
+    #[default]
+    Online,
+    All,
+    /// Incoming and outgoing friend requests.
+    Pending,
+    Blocked,
+}
+
+impl FriendsTab {
+    const ALL: [Self; 4] = [Self::Online, Self::All, Self::Pending, Self::Blocked];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::All => "All",
+            Self::Pending => "Pending",
+            Self::Blocked => "Blocked",
+        }
+    }
+
+    fn includes(self, relationship: &Relationship, presences: &PresenceStore) -> bool {
+        match self {
+            Self::Online => {
+                relationship.relationship_type.is_friend()
+                    && presences.is_online(relationship.user_id)
+            }
+            Self::All => relationship.relationship_type.is_friend(),
+            Self::Pending => relationship.relationship_type.is_pending(),
+            Self::Blocked => relationship.is_blocked(),
+        }
+    }
+
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Friends, pending requests and blocked users, opened from the DM section.
+#[derive(Debug, Default)]
+pub struct FriendsView {
+    pub tab: FriendsTab,
+    pub selected: usize,
+    /// Username typed for a new friend request, while the prompt is open.
+    pub add_friend: Option<String>,
+    /// Destructive change waiting for confirmation.
+    pub confirm: Option<(RelationshipChange, String)>,
+}
+
+impl FriendsView {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Entries of the current tab; incoming requests come before outgoing ones.
+    #[must_use]
+    pub fn entries(
+        &self,
+        relationships: &RelationshipState,
+        presences: &PresenceStore,
+    ) -> Vec<Relationship> {
+        Self::tab_entries(self.tab, relationships, presences)
+    }
+
+    fn tab_entries(
+        tab: FriendsTab,
+        relationships: &RelationshipState,
+        presences: &PresenceStore,
+    ) -> Vec<Relationship> {
+        let mut entries: Vec<_> = relationships
+            .relationships()
+            .into_iter()
+            .filter(|rel| tab.includes(rel, presences))
+            .collect();
+        if tab == FriendsTab::Pending {
+            entries.sort_by_key(|rel| rel.relationship_type != RelationshipType::PendingIncoming);
+        }
+        entries
+    }
+
+    fn set_tab(&mut self, tab: FriendsTab) {
+        self.tab = tab;
+        self.selected = 0;
+    }
+
+    fn handle_add_friend_key(&mut self, key: KeyEvent) -> FriendsViewAction {
+        let Some(username) = self.add_friend.as_mut() else {
+            return FriendsViewAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => self.add_friend = None,
+            KeyCode::Enter => {
+                let username = username.trim().trim_start_matches('@').to_string();
+                if !username.is_empty() {
+                    self.add_friend = None;
+                    return FriendsViewAction::Change(RelationshipChange::SendRequest(username));
+                }
+            }
+            KeyCode::Backspace => {
+                username.pop();
+            }
+            KeyCode::Char(c) => username.push(c),
+            _ => {}
+        }
+        FriendsViewAction::None
+    }
+
+    /// Removing a friend or blocking someone asks first; declining and cancelling do not.
+    fn request_change(&mut self, change: RelationshipChange, name: String) -> FriendsViewAction {
+        if matches!(change, RelationshipChange::Accept(_)) {
+            return FriendsViewAction::Change(change);
+        }
+        let needs_confirm = match self.tab {
+            FriendsTab::Online | FriendsTab::All => true,
+            FriendsTab::Pending | FriendsTab::Blocked => {
+                matches!(change, RelationshipChange::Block(_))
+            }
+        };
+        if needs_confirm {
+            self.confirm = Some((change, name));
+            FriendsViewAction::None
+        } else {
+            FriendsViewAction::Change(change)
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, entries: &[Relationship]) -> FriendsViewAction {
+        if self.add_friend.is_some() {
+            return self.handle_add_friend_key(key);
+        }
+
+        if let Some((change, _)) = self.confirm.take() {
+            return if key.code == KeyCode::Char('y') {
+                FriendsViewAction::Change(change)
+            } else {
+                FriendsViewAction::None
+            };
+        }
+
+        let selected = entries.get(self.selected);
+        match key.code {
+            KeyCode::Esc => return FriendsViewAction::Close,
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => self.set_tab(self.tab.next()),
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.set_tab(self.tab.previous());
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(entries.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('n' | '+') => self.add_friend = Some(String::new()),
+            KeyCode::Char('a') => {
+                if let Some(rel) = selected
+                    && rel.relationship_type == RelationshipType::PendingIncoming
+                {
+                    return self
+                        .request_change(RelationshipChange::Accept(rel.user_id), String::new());
+                }
+            }
+            KeyCode::Char('x' | 'd') => {
+                if let Some(rel) = selected {
+                    return self.request_change(
+                        RelationshipChange::Remove(rel.user_id),
+                        rel.display_name(),
+                    );
+                }
+            }
+            KeyCode::Char('b') => {
+                if let Some(rel) = selected
+                    && !rel.is_blocked()
+                {
+                    return self.request_change(
+                        RelationshipChange::Block(rel.user_id),
+                        rel.display_name(),
+                    );
+                }
+            }
+            KeyCode::Char('u') => {
+                if let Some(rel) = selected {
+                    return FriendsViewAction::OpenProfile(rel.user_id);
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(rel) = selected
+                    && rel.relationship_type.is_friend()
+                {
+                    return FriendsViewAction::Message(rel.user_id);
+                }
+            }
+            _ => {}
+        }
+        FriendsViewAction::None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FriendsViewAction {
+    None,
+    Close,
+    Change(RelationshipChange),
+    OpenProfile(UserId),
+    /// Opens the DM with a friend.
+    Message(UserId),
+}
+
+pub struct FriendsViewWidget<'a> {
+    view: &'a FriendsView,
+    relationships: &'a RelationshipState,
+    presences: &'a PresenceStore,
+    theme: &'a Theme,
+}
+
+impl<'a> FriendsViewWidget<'a> {
+    #[must_use]
+    pub fn new(
+        view: &'a FriendsView,
+        relationships: &'a RelationshipState,
+        presences: &'a PresenceStore,
+        theme: &'a Theme,
+    ) -> Self {
+        Self {
+            view,
+            relationships,
+            presences,
+            theme,
+        }
+    }
+
+    fn tabs_line(&self) -> Line<'_> {
+        let relationships = self.relationships.relationships();
+        let mut spans = Vec::new();
+        for tab in FriendsTab::ALL {
+            let count = relationships
+                .iter()
+                .filter(|rel| tab.includes(rel, self.presences))
+                .count();
+            let style = if tab == self.view.tab {
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            } else {
+                self.theme.base_style
+            };
+            spans.push(Span::styled(format!("{} ({count})", tab.label()), style));
+            spans.push(Span::raw("   "));
+        }
+        Line::from(spans)
+    }
+
+    fn entry_item(&self, relationship: &Relationship) -> ListItem<'static> {
+        let status = self
+            .presences
+            .status(relationship.user_id)
+            .unwrap_or(PresenceStatus::Offline);
+        let mut spans = vec![
+            Span::styled(
+                format!("{} ", status.display_indicator()),
+                Style::default().fg(presence_color(status)),
+            ),
+            Span::styled(
+                clean_text(&relationship.display_name()),
+                self.theme.base_style,
+            ),
+        ];
+        if let Some(username) = relationship.username() {
+            spans.push(Span::styled(
+                format!("  {username}"),
+                self.theme.dimmed_style,
+            ));
+        }
+        let tag = match relationship.relationship_type {
+            RelationshipType::PendingIncoming => Some("Incoming request"),
+            RelationshipType::PendingOutgoing => Some("Outgoing request"),
+            _ => None,
+        };
+        if let Some(tag) = tag {
+            spans.push(Span::styled(
+                format!("  · {tag}"),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        ListItem::new(Line::from(spans))
+    }
+
+    fn empty_text(&self) -> &'static str {
+        match self.view.tab {
+            FriendsTab::Online => "No friends online",
+            FriendsTab::All => "No friends yet. Press n to add one",
+            FriendsTab::Pending => "No pending friend requests",
+            FriendsTab::Blocked => "No blocked users",
+        }
+    }
+
+    fn prompt_line(&self) -> Option<Line<'_>> {
+        if let Some(username) = &self.view.add_friend {
+            return Some(Line::from(vec![
+                Span::styled("Add friend by username: ", self.theme.dimmed_style),
+                Span::styled(format!("{username}█"), self.theme.base_style),
+            ]));
+        }
+        self.view.confirm.as_ref().map(|(change, name)| {
+            let question = match change {
+                RelationshipChange::Block(_) => format!("Block {name}?"),
+                _ => format!("Remove {name} from your friends?"),
+            };
+            Line::from(vec![
+                Span::styled(question, Style::default().fg(self.theme.accent)),
+                Span::styled(
+                    "  y to confirm, any other key to cancel",
+                    self.theme.dimmed_style,
+                ),
+            ])
+        })
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings: &[(&str, &str)] = match self.view.tab {
+            FriendsTab::Online | FriendsTab::All => &[
+                ("Tab", "Next tab"),
+                ("Enter", "Message"),
+                ("n", "Add friend"),
+                ("u", "Profile"),
+                ("x", "Remove"),
+                ("b", "Block"),
+                ("Esc", "Close"),
+            ],
+            FriendsTab::Pending => &[
+                ("Tab", "Next tab"),
+                ("a", "Accept"),
+                ("x", "Decline/Cancel"),
+                ("b", "Block"),
+                ("Esc", "Close"),
+            ],
+            FriendsTab::Blocked => &[
+                ("Tab", "Next tab"),
+                ("u", "Profile"),
+                ("x", "Unblock"),
+                ("Esc", "Close"),
+            ],
+        };
+
+        KeyHints::new(bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for FriendsViewWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(60, 70, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(" Friends ");
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        Paragraph::new(self.tabs_line()).render(layout[0], buf);
+
+        let entries = self.view.entries(self.relationships, self.presences);
+        if entries.is_empty() {
+            Paragraph::new(Span::styled(self.empty_text(), self.theme.dimmed_style))
+                .render(layout[1], buf);
+        } else {
+            let items: Vec<ListItem> = entries.iter().map(|rel| self.entry_item(rel)).collect();
+            let list = List::new(items).highlight_style(self.theme.selection_style);
+            let mut list_state =
+                ListState::default().with_selected(Some(self.view.selected.min(entries.len() - 1)));
+            StatefulWidget::render(list, layout[1], buf, &mut list_state);
+        }
+
+        if let Some(prompt) = self.prompt_line() {
+            Paragraph::new(prompt).render(layout[2], buf);
+        }
+
+        self.render_footer(layout[3], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{User, UserPresence};
+
+    fn state() -> (RelationshipState, PresenceStore) {
+        let relationships = RelationshipState::new();
+        relationships.initialize_from_relationships(&[
+            Relationship::new(UserId(1), RelationshipType::Friend)
+                .with_user(User::new(1u64, "alice", "0", None, false, None)),
+            Relationship::new(UserId(2), RelationshipType::Friend)
+                .with_user(User::new(2u64, "bob", "0", None, false, None)),
+            Relationship::new(UserId(3), RelationshipType::PendingOutgoing),
+            Relationship::new(UserId(4), RelationshipType::PendingIncoming),
+            Relationship::new(UserId(5), RelationshipType::Blocked),
+        ]);
+        let presences = PresenceStore::new();
+        presences.update(UserId(2), UserPresence::new(PresenceStatus::Idle, vec![]));
+        (relationships, presences)
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::from(code)
+    }
+
+    #[test]
+    fn test_tabs_filter_relationships() {
+        let (relationships, presences) = state();
+        let mut view = FriendsView::new();
+
+        let online = view.entries(&relationships, &presences);
+        assert_eq!(online.len(), 1);
+        assert_eq!(online[0].user_id, UserId(2));
+
+        view.handle_key(key(KeyCode::Tab), &online);
+        assert_eq!(view.entries(&relationships, &presences).len(), 2);
+
+        view.handle_key(key(KeyCode::Tab), &[]);
+        let pending = view.entries(&relationships, &presences);
+        assert_eq!(pending[0].user_id, UserId(4));
+        assert_eq!(pending[1].user_id, UserId(3));
+
+        view.handle_key(key(KeyCode::BackTab), &pending);
+        view.handle_key(key(KeyCode::BackTab), &[]);
+        view.handle_key(key(KeyCode::BackTab), &[]);
+        assert_eq!(view.tab, FriendsTab::Blocked);
+    }
+
+    #[test]
+    fn test_pending_actions() {
+        let (relationships, presences) = state();
+        let mut view = FriendsView::new();
+        view.tab = FriendsTab::Pending;
+        let entries = view.entries(&relationships, &presences);
+
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('a')), &entries),
+            FriendsViewAction::Change(RelationshipChange::Accept(UserId(4)))
+        );
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('x')), &entries),
+            FriendsViewAction::Change(RelationshipChange::Remove(UserId(4)))
+        );
+
+        view.handle_key(key(KeyCode::Char('j')), &entries);
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('a')), &entries),
+            FriendsViewAction::None
+        );
+    }
+
+    #[test]
+    fn test_remove_friend_needs_confirmation() {
+        let (relationships, presences) = state();
+        let mut view = FriendsView::new();
+        view.tab = FriendsTab::All;
+        let entries = view.entries(&relationships, &presences);
+
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('x')), &entries),
+            FriendsViewAction::None
+        );
+        assert!(view.confirm.is_some());
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('n')), &entries),
+            FriendsViewAction::None
+        );
+        assert!(view.confirm.is_none());
+
+        view.handle_key(key(KeyCode::Char('b')), &entries);
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('y')), &entries),
+            FriendsViewAction::Change(RelationshipChange::Block(UserId(1)))
+        );
+        assert_eq!(
+            view.handle_key(key(KeyCode::Enter), &entries),
+            FriendsViewAction::Message(UserId(1))
+        );
+    }
+
+    #[test]
+    fn test_send_friend_request() {
+        let mut view = FriendsView::new();
+        view.handle_key(key(KeyCode::Char('n')), &[]);
+        for c in "@ferris".chars() {
+            view.handle_key(key(KeyCode::Char(c)), &[]);
+        }
+        assert_eq!(
+            view.handle_key(key(KeyCode::Enter), &[]),
+            FriendsViewAction::Change(RelationshipChange::SendRequest("ferris".to_string()))
+        );
+        assert!(view.add_friend.is_none());
+        assert_eq!(
+            view.handle_key(key(KeyCode::Esc), &[]),
+            FriendsViewAction::Close
+        );
+    }
+}
@@ -0,0 +1,594 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::entities::{ChannelId, GuildId, Message, MessageId};
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, clean_text};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+/// How many mentions the inbox keeps, newest first.
+pub const MAX_INBOX_MENTIONS: usize = 50;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InboxTab {
+    #[default]
+    Mentions,
+    Unreads,
+}
+
+/// A message that mentioned us, or one of our roles.
+#[derive(Debug, Clone)]
+pub struct InboxMention {
+    pub message: Message,
+    pub guild_id: Option<GuildId>,
+    /// Where it was posted, e.g. `Rust › #general` or `@alice`.
+    pub location: String,
+    pub unread: bool,
+}
+
+/// A channel with messages we have not read yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreadChannel {
+    pub guild_id: Option<GuildId>,
+    /// Guild name, or "Direct Messages".
+    pub group: String,
+    pub channel_id: ChannelId,
+    pub name: String,
+    pub mention_count: u32,
+}
+
+/// Recent mentions and unread channels across every guild.
+#[derive(Debug, Default)]
+pub struct Inbox {
+    pub tab: InboxTab,
+    pub mentions: Vec<InboxMention>,
+    pub unreads: Vec<UnreadChannel>,
+    pub mentions_state: ListState,
+    pub unreads_state: ListState,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// Latest message seen in each unread channel, shown as its preview.
+    previews: HashMap<ChannelId, Message>,
+    /// Channels whose preview has been asked for and not arrived yet.
+    requested_previews: HashSet<ChannelId>,
+}
+
+impl Inbox {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds mentions, keeping the newest [`MAX_INBOX_MENTIONS`] without duplicates.
+    pub fn add_mentions(&mut self, mentions: impl IntoIterator<Item = InboxMention>) {
+        for mention in mentions {
+            let id = mention.message.id();
+            if let Some(existing) = self.mentions.iter_mut().find(|m| m.message.id() == id) {
+                *existing = mention;
+            } else {
+                self.mentions.push(mention);
+            }
+        }
+        self.mentions
+            .sort_by_key(|mention| std::cmp::Reverse(mention.message.id().as_u64()));
+        self.mentions.truncate(MAX_INBOX_MENTIONS);
+        clamp_selection(&mut self.mentions_state, self.mentions.len());
+    }
+
+    /// Replaces the unread channels, dropping previews of channels that were read.
+    pub fn set_unreads(&mut self, unreads: Vec<UnreadChannel>) {
+        self.unreads = unreads;
+        let unread_ids: HashSet<ChannelId> = self.unreads.iter().map(|u| u.channel_id).collect();
+        self.previews
+            .retain(|channel_id, _| unread_ids.contains(channel_id));
+        clamp_selection(&mut self.unreads_state, self.unreads.len());
+    }
+
+    fn is_unread(&self, channel_id: ChannelId) -> bool {
+        self.unreads
+            .iter()
+            .any(|unread| unread.channel_id == channel_id)
+    }
+
+    /// Remembers the latest message of an unread channel for its preview.
+    pub fn note_message(&mut self, message: &Message) {
+        if !self.is_unread(message.channel_id()) {
+            return;
+        }
+        let is_newer = self
+            .previews
+            .get(&message.channel_id())
+            .is_none_or(|latest| latest.id().as_u64() <= message.id().as_u64());
+        if is_newer {
+            self.previews.insert(message.channel_id(), message.clone());
+        }
+    }
+
+    /// Stores fetched previews; requested channels that came back empty may be asked for again.
+    pub fn set_previews(&mut self, channel_ids: &[ChannelId], messages: &[Message]) {
+        for channel_id in channel_ids {
+            self.requested_previews.remove(channel_id);
+        }
+        for message in messages {
+            self.note_message(message);
+        }
+    }
+
+    /// Applies an edit to the preview or mention showing the message.
+    pub fn update_message(&mut self, message: &Message) {
+        if let Some(preview) = self.previews.get_mut(&message.channel_id())
+            && preview.id() == message.id()
+        {
+            *preview = message.clone();
+        }
+        for mention in &mut self.mentions {
+            if mention.message.id() == message.id() {
+                mention.message = message.clone();
+            }
+        }
+    }
+
+    /// Forgets a deleted message; a channel left without a preview is fetched again.
+    pub fn remove_message(&mut self, message_id: MessageId) {
+        self.previews
+            .retain(|_, preview| preview.id() != message_id);
+        self.mentions
+            .retain(|mention| mention.message.id() != message_id);
+        clamp_selection(&mut self.mentions_state, self.mentions.len());
+    }
+
+    #[must_use]
+    pub fn preview(&self, channel_id: ChannelId) -> Option<&Message> {
+        self.previews.get(&channel_id)
+    }
+
+    /// Unread channels without a preview that have not been asked for yet, marked as asked.
+    pub fn take_missing_previews(&mut self, limit: usize) -> Vec<ChannelId> {
+        let missing: Vec<ChannelId> = self
+            .unreads
+            .iter()
+            .map(|unread| unread.channel_id)
+            .filter(|id| !self.previews.contains_key(id) && !self.requested_previews.contains(id))
+            .take(limit)
+            .collect();
+        self.requested_previews.extend(missing.iter().copied());
+        missing
+    }
+
+    fn selected_mention(&self) -> Option<&InboxMention> {
+        self.mentions_state
+            .selected()
+            .and_then(|i| self.mentions.get(i))
+    }
+
+    fn selected_unread(&self) -> Option<&UnreadChannel> {
+        self.unreads_state
+            .selected()
+            .and_then(|i| self.unreads.get(i))
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        let (state, len) = match self.tab {
+            InboxTab::Mentions => (&mut self.mentions_state, self.mentions.len()),
+            InboxTab::Unreads => (&mut self.unreads_state, self.unreads.len()),
+        };
+        if len == 0 {
+            return;
+        }
+        let index = match (state.selected(), forward) {
+            (Some(i), true) if i + 1 < len => i + 1,
+            (_, true) => 0,
+            (Some(0) | None, false) => len - 1,
+            (Some(i), false) => i - 1,
+        };
+        state.select(Some(index));
+    }
+
+    fn jump(&self) -> InboxAction {
+        match self.tab {
+            InboxTab::Mentions => self
+                .selected_mention()
+                .map_or(InboxAction::None, |mention| InboxAction::Jump {
+                    guild_id: mention.guild_id,
+                    channel_id: mention.message.channel_id(),
+                    message_id: Some(mention.message.id()),
+                }),
+            InboxTab::Unreads => {
+                self.selected_unread()
+                    .map_or(InboxAction::None, |unread| InboxAction::Jump {
+                        guild_id: unread.guild_id,
+                        channel_id: unread.channel_id,
+                        message_id: None,
+                    })
+            }
+        }
+    }
+
+    fn mark_read(&self) -> InboxAction {
+        let channel_id = match self.tab {
+            InboxTab::Mentions => self
+                .selected_mention()
+                .map(|mention| mention.message.channel_id()),
+            InboxTab::Unreads => self.selected_unread().map(|unread| unread.channel_id),
+        };
+        channel_id.map_or(InboxAction::None, InboxAction::MarkRead)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InboxAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return InboxAction::Close,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Char('h' | 'l') => {
+                self.tab = match self.tab {
+                    InboxTab::Mentions => InboxTab::Unreads,
+                    InboxTab::Unreads => InboxTab::Mentions,
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Enter => return self.jump(),
+            KeyCode::Char('r') => return self.mark_read(),
+            _ => {}
+        }
+        InboxAction::None
+    }
+}
+
+fn clamp_selection(state: &mut ListState, len: usize) {
+    if len == 0 {
+        state.select(None);
+    } else {
+        state.select(Some(state.selected().unwrap_or(0).min(len - 1)));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InboxAction {
+    None,
+    Close,
+    /// Opens the channel, at the message if one is given.
+    Jump {
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+        message_id: Option<MessageId>,
+    },
+    MarkRead(ChannelId),
+}
+
+pub struct InboxWidget<'a> {
+    inbox: &'a Inbox,
+    theme: &'a Theme,
+    timestamp_format: &'a str,
+}
+
+impl<'a> InboxWidget<'a> {
+    #[must_use]
+    pub const fn new(inbox: &'a Inbox, theme: &'a Theme) -> Self {
+        Self {
+            inbox,
+            theme,
+            timestamp_format: "%H:%M",
+        }
+    }
+
+    #[must_use]
+    pub const fn with_timestamp_format(mut self, format: &'a str) -> Self {
+        self.timestamp_format = format;
+        self
+    }
+
+    fn preview(message: &Message) -> String {
+        let first_line = message.content().lines().next().unwrap_or_default();
+        if !first_line.is_empty() {
+            return clean_text(first_line);
+        }
+        match message.attachments().len() {
+            0 if message.embeds().is_empty() => String::new(),
+            0 => "[Embed]".to_string(),
+            1 => "[1 attachment]".to_string(),
+            n => format!("[{n} attachments]"),
+        }
+    }
+
+    fn render_tabs(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let unread_mentions = self.inbox.mentions.iter().filter(|m| m.unread).count();
+        let tabs = [
+            (
+                InboxTab::Mentions,
+                format!(" Mentions ({unread_mentions}) "),
+            ),
+            (
+                InboxTab::Unreads,
+                format!(" Unreads ({}) ", self.inbox.unreads.len()),
+            ),
+        ];
+        let mut spans = Vec::new();
+        for (tab, label) in tabs {
+            let style = if tab == self.inbox.tab {
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                self.theme.dimmed_style
+            };
+            spans.push(Span::styled(label, style));
+            spans.push(Span::raw(" "));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn mention_items(&self) -> Vec<ListItem<'static>> {
+        self.inbox
+            .mentions
+            .iter()
+            .map(|mention| {
+                let marker = if mention.unread { "● " } else { "  " };
+                let header = Line::from(vec![
+                    Span::styled(marker, Style::default().fg(self.theme.accent)),
+                    Span::styled(clean_text(&mention.location), self.theme.dimmed_style),
+                    Span::styled(
+                        format!(" {}", mention.message.author().raw_display_name()),
+                        Style::default().fg(self.theme.accent),
+                    ),
+                    Span::styled(
+                        format!(
+                            " {}",
+                            mention.message.timestamp().format(self.timestamp_format)
+                        ),
+                        self.theme.dimmed_style,
+                    ),
+                ]);
+                let preview = Line::from(Span::styled(
+                    format!("    {}", Self::preview(&mention.message)),
+                    self.theme.base_style,
+                ));
+                ListItem::new(vec![header, preview])
+            })
+            .collect()
+    }
+
+    /// Unread channels grouped under their guild; the group header rides on the first row.
+    fn unread_items(&self) -> Vec<ListItem<'static>> {
+        let mut previous_group: Option<&str> = None;
+        self.inbox
+            .unreads
+            .iter()
+            .map(|unread| {
+                let mut lines = Vec::new();
+                if previous_group != Some(unread.group.as_str()) {
+                    lines.push(Line::from(Span::styled(
+                        clean_text(&unread.group),
+                        Style::default()
+                            .fg(self.theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )));
+                    previous_group = Some(unread.group.as_str());
+                }
+
+                let mut header = vec![Span::styled(
+                    format!("  {}", clean_text(&unread.name)),
+                    self.theme.base_style.add_modifier(Modifier::BOLD),
+                )];
+                if unread.mention_count > 0 {
+                    header.push(Span::styled(
+                        format!(" @{}", unread.mention_count),
+                        self.theme.mention_style,
+                    ));
+                }
+                lines.push(Line::from(header));
+
+                let preview = self.inbox.preview(unread.channel_id).map_or_else(
+                    || "No preview yet".to_string(),
+                    |message| {
+                        format!(
+                            "{}: {}",
+                            message.author().raw_display_name(),
+                            Self::preview(message)
+                        )
+                    },
+                );
+                lines.push(Line::from(Span::styled(
+                    format!("    {preview}"),
+                    self.theme.dimmed_style,
+                )));
+                ListItem::new(lines)
+            })
+            .collect()
+    }
+
+    fn render_list(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let (items, mut state, empty) = match self.inbox.tab {
+            InboxTab::Mentions => (
+                self.mention_items(),
+                self.inbox.mentions_state,
+                "No recent mentions",
+            ),
+            InboxTab::Unreads => (
+                self.unread_items(),
+                self.inbox.unreads_state,
+                "You're all caught up",
+            ),
+        };
+
+        if items.is_empty() {
+            let on_mentions = self.inbox.tab == InboxTab::Mentions;
+            let status = match &self.inbox.error {
+                _ if on_mentions && self.inbox.loading => "Loading mentions...".to_string(),
+                Some(error) if on_mentions => format!("Failed to load mentions: {error}"),
+                _ => empty.to_string(),
+            };
+            Paragraph::new(status)
+                .style(self.theme.dimmed_style)
+                .render(area, buf);
+            return;
+        }
+
+        let list = List::new(items).highlight_style(self.theme.selection_style);
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings = [
+            ("Tab", "Switch"),
+            ("Enter", "Jump"),
+            ("r", "Mark Read"),
+            ("Esc", "Close"),
+        ];
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for InboxWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(60, 70, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(" Inbox ");
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        self.render_tabs(layout[0], buf);
+        self.render_list(layout[1], buf);
+        self.render_footer(layout[2], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: u64, channel_id: u64) -> Message {
+        Message::test(id, channel_id, &format!("message {id}"))
+    }
+
+    fn mention(id: u64, channel_id: u64) -> InboxMention {
+        InboxMention {
+            message: message(id, channel_id),
+            guild_id: Some(GuildId(1)),
+            location: "Rust › #general".to_string(),
+            unread: true,
+        }
+    }
+
+    fn unread(channel_id: u64) -> UnreadChannel {
+        UnreadChannel {
+            guild_id: None,
+            group: "Direct Messages".to_string(),
+            channel_id: ChannelId(channel_id),
+            name: "@alice".to_string(),
+            mention_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_mentions_are_deduplicated_newest_first() {
+        let mut inbox = Inbox::new();
+        inbox.add_mentions([mention(10, 1), mention(30, 2)]);
+        inbox.add_mentions([mention(20, 1), mention(30, 2)]);
+
+        let ids: Vec<u64> = inbox
+            .mentions
+            .iter()
+            .map(|m| m.message.id().as_u64())
+            .collect();
+        assert_eq!(ids, [30, 20, 10]);
+
+        inbox.add_mentions((100..100 + MAX_INBOX_MENTIONS as u64).map(|id| mention(id, 3)));
+        assert_eq!(inbox.mentions.len(), MAX_INBOX_MENTIONS);
+        assert!(
+            inbox
+                .mentions
+                .iter()
+                .all(|m| m.message.id().as_u64() >= 100)
+        );
+    }
+
+    #[test]
+    fn test_jump_and_mark_read_follow_the_tab() {
+        let mut inbox = Inbox::new();
+        inbox.add_mentions([mention(10, 1)]);
+        inbox.set_unreads(vec![unread(5), unread(6)]);
+
+        assert_eq!(
+            inbox.handle_key(KeyEvent::from(KeyCode::Enter)),
+            InboxAction::Jump {
+                guild_id: Some(GuildId(1)),
+                channel_id: ChannelId(1),
+                message_id: Some(MessageId(10)),
+            }
+        );
+
+        inbox.handle_key(KeyEvent::from(KeyCode::Tab));
+        inbox.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(
+            inbox.handle_key(KeyEvent::from(KeyCode::Char('r'))),
+            InboxAction::MarkRead(ChannelId(6))
+        );
+        assert_eq!(
+            inbox.handle_key(KeyEvent::from(KeyCode::Enter)),
+            InboxAction::Jump {
+                guild_id: None,
+                channel_id: ChannelId(6),
+                message_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_previews_keep_the_latest_message() {
+        let mut inbox = Inbox::new();
+        inbox.set_unreads(vec![unread(5), unread(6), unread(7)]);
+        inbox.note_message(&message(20, 5));
+        inbox.note_message(&message(10, 5));
+        inbox.note_message(&message(30, 8));
+
+        assert_eq!(
+            inbox.preview(ChannelId(5)).map(Message::id),
+            Some(MessageId(20))
+        );
+        assert!(
+            inbox.preview(ChannelId(8)).is_none(),
+            "read channels keep no preview"
+        );
+        assert_eq!(inbox.take_missing_previews(1), [ChannelId(6)]);
+        assert_eq!(inbox.take_missing_previews(5), [ChannelId(7)]);
+        assert!(inbox.take_missing_previews(5).is_empty());
+
+        inbox.set_previews(&[ChannelId(6), ChannelId(7)], &[message(40, 6)]);
+        assert_eq!(
+            inbox.take_missing_previews(5),
+            [ChannelId(7)],
+            "an empty answer can be asked for again"
+        );
+
+        inbox.remove_message(MessageId(20));
+        assert!(inbox.preview(ChannelId(5)).is_none());
+
+        inbox.set_unreads(vec![unread(5)]);
+        assert!(inbox.preview(ChannelId(6)).is_none());
+    }
+}
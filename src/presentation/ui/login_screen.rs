@@ -126,10 +126,8 @@ impl LoginScreen {
         }
 
         match key.code {
-            KeyCode::Enter => {
-                if !self.token_input.value().is_empty() {
-                    return LoginAction::Submit;
-                }
+            KeyCode::Enter if !self.token_input.value().is_empty() => {
+                return LoginAction::Submit;
             }
             KeyCode::Char('d') if key.modifiers.contains(crossterm::event::KeyModifiers::ALT) => {
                 return LoginAction::DeleteToken;
@@ -3,11 +3,21 @@
 mod app;
 pub mod backend;
 mod chat_screen;
+pub mod emoji_picker;
+pub mod forum_composer;
+pub mod friends_view;
+pub mod inbox;
 mod login_screen;
 mod main_screen;
+pub mod new_dm_picker;
+pub mod notification_menu;
 pub mod notification_popup;
+pub mod pins_overlay;
+pub mod profile_popup;
 pub mod quick_switcher;
+pub mod search_pane;
 pub mod splash_screen;
+pub mod status_menu;
 pub mod utils;
 
 pub use app::{App, AppConfig};
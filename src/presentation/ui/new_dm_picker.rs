@@ -0,0 +1,472 @@
+use crate::domain::entities::{ChannelId, UserId};
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, clean_text};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+/// Discord caps group DMs at ten members, ourselves included.
+pub const MAX_GROUP_RECIPIENTS: usize = 9;
+
+/// Where a DM candidate was found; earlier sources are listed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DmCandidateSource {
+    Friend,
+    Member,
+    Cached,
+}
+
+impl DmCandidateSource {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Friend => "Friend",
+            Self::Member => "Member",
+            Self::Cached => "Seen",
+        }
+    }
+}
+
+/// A user that can be added to a new DM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DmCandidate {
+    pub user_id: UserId,
+    pub name: String,
+    pub username: String,
+    pub source: DmCandidateSource,
+}
+
+impl DmCandidate {
+    #[must_use]
+    pub fn new(
+        user_id: UserId,
+        name: impl Into<String>,
+        username: impl Into<String>,
+        source: DmCandidateSource,
+    ) -> Self {
+        Self {
+            user_id,
+            name: name.into(),
+            username: username.into(),
+            source,
+        }
+    }
+
+    fn matches(&self, filter: &str) -> bool {
+        filter.is_empty()
+            || self.name.to_lowercase().contains(filter)
+            || self.username.to_lowercase().contains(filter)
+    }
+}
+
+/// Picks the recipients of a new DM or group DM, or renames an existing group DM.
+#[derive(Debug, Default)]
+pub struct NewDmPicker {
+    candidates: Vec<DmCandidate>,
+    pub filter: String,
+    pub selected: usize,
+    /// Users added to the group, in the order they were picked.
+    pub chosen: Vec<DmCandidate>,
+    /// Name typed for the new group DM, once the recipients are confirmed.
+    pub group_name: Option<String>,
+    /// Group DM being renamed, with the name typed so far.
+    pub rename: Option<(ChannelId, String)>,
+}
+
+impl NewDmPicker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the picker with a fresh candidate list, deduplicated by user.
+    pub fn open(&mut self, mut candidates: Vec<DmCandidate>) {
+        candidates.sort_by(|a, b| {
+            a.source
+                .cmp(&b.source)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        let mut seen = std::collections::HashSet::new();
+        candidates.retain(|candidate| seen.insert(candidate.user_id));
+        *self = Self {
+            candidates,
+            ..Self::default()
+        };
+    }
+
+    pub fn open_rename(&mut self, channel_id: ChannelId, current_name: Option<&str>) {
+        *self = Self {
+            rename: Some((channel_id, current_name.unwrap_or_default().to_string())),
+            ..Self::default()
+        };
+    }
+
+    /// Candidates matching the filter.
+    #[must_use]
+    pub fn filtered(&self) -> Vec<&DmCandidate> {
+        let filter = self.filter.trim().to_lowercase();
+        self.candidates
+            .iter()
+            .filter(|candidate| candidate.matches(&filter))
+            .collect()
+    }
+
+    fn is_chosen(&self, user_id: UserId) -> bool {
+        self.chosen.iter().any(|c| c.user_id == user_id)
+    }
+
+    fn toggle_selected(&mut self) {
+        let Some(candidate) = self.filtered().get(self.selected).map(|c| (*c).clone()) else {
+            return;
+        };
+        if self.is_chosen(candidate.user_id) {
+            self.chosen.retain(|c| c.user_id != candidate.user_id);
+        } else if self.chosen.len() < MAX_GROUP_RECIPIENTS {
+            self.chosen.push(candidate);
+        }
+    }
+
+    /// Recipients to open a DM with: the picked users, or the highlighted one.
+    fn recipients(&self) -> Vec<UserId> {
+        if self.chosen.is_empty() {
+            self.filtered()
+                .get(self.selected)
+                .map(|c| vec![c.user_id])
+                .unwrap_or_default()
+        } else {
+            self.chosen.iter().map(|c| c.user_id).collect()
+        }
+    }
+
+    fn edit_text(text: &mut String, key: KeyEvent) {
+        match key.code {
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) => text.push(c),
+            _ => {}
+        }
+    }
+
+    fn handle_rename_key(&mut self, key: KeyEvent) -> NewDmPickerAction {
+        let Some((channel_id, name)) = self.rename.as_mut() else {
+            return NewDmPickerAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => NewDmPickerAction::Close,
+            KeyCode::Enter => NewDmPickerAction::Rename {
+                channel_id: *channel_id,
+                name: name.trim().to_string(),
+            },
+            _ => {
+                Self::edit_text(name, key);
+                NewDmPickerAction::None
+            }
+        }
+    }
+
+    fn handle_group_name_key(&mut self, key: KeyEvent) -> NewDmPickerAction {
+        let Some(name) = self.group_name.as_mut() else {
+            return NewDmPickerAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => self.group_name = None,
+            KeyCode::Enter => {
+                let name = name.trim().to_string();
+                return NewDmPickerAction::Open {
+                    recipients: self.recipients(),
+                    name: (!name.is_empty()).then_some(name),
+                };
+            }
+            _ => Self::edit_text(name, key),
+        }
+        NewDmPickerAction::None
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> NewDmPickerAction {
+        if self.rename.is_some() {
+            return self.handle_rename_key(key);
+        }
+        if self.group_name.is_some() {
+            return self.handle_group_name_key(key);
+        }
+
+        let count = self.filtered().len();
+        match key.code {
+            KeyCode::Esc => return NewDmPickerAction::Close,
+            KeyCode::Down => self.selected = (self.selected + 1).min(count.saturating_sub(1)),
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Tab => self.toggle_selected(),
+            KeyCode::Enter => {
+                let recipients = self.recipients();
+                match recipients.len() {
+                    0 => {}
+                    1 => {
+                        return NewDmPickerAction::Open {
+                            recipients,
+                            name: None,
+                        };
+                    }
+                    _ => self.group_name = Some(String::new()),
+                }
+            }
+            _ => {
+                Self::edit_text(&mut self.filter, key);
+                self.selected = 0;
+            }
+        }
+        NewDmPickerAction::None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NewDmPickerAction {
+    None,
+    Close,
+    Open {
+        recipients: Vec<UserId>,
+        name: Option<String>,
+    },
+    Rename {
+        channel_id: ChannelId,
+        name: String,
+    },
+}
+
+pub struct NewDmPickerWidget<'a> {
+    picker: &'a NewDmPicker,
+    theme: &'a Theme,
+}
+
+impl<'a> NewDmPickerWidget<'a> {
+    #[must_use]
+    pub const fn new(picker: &'a NewDmPicker, theme: &'a Theme) -> Self {
+        Self { picker, theme }
+    }
+
+    fn input_line<'b>(&self, label: &'b str, text: &str) -> Line<'b> {
+        Line::from(vec![
+            Span::styled(label, self.theme.dimmed_style),
+            Span::styled(format!("{text}█"), self.theme.base_style),
+        ])
+    }
+
+    fn chosen_line(&self) -> Line<'_> {
+        if self.picker.chosen.is_empty() {
+            return Line::from(Span::styled(
+                "Tab adds users to start a group DM",
+                self.theme.dimmed_style,
+            ));
+        }
+        let names: Vec<_> = self
+            .picker
+            .chosen
+            .iter()
+            .map(|c| clean_text(&c.name))
+            .collect();
+        Line::from(vec![
+            Span::styled(
+                format!("To ({}/{MAX_GROUP_RECIPIENTS}): ", self.picker.chosen.len()),
+                self.theme.dimmed_style,
+            ),
+            Span::styled(names.join(", "), Style::default().fg(self.theme.accent)),
+        ])
+    }
+
+    fn candidate_item(&self, candidate: &DmCandidate) -> ListItem<'static> {
+        let marker = if self.picker.is_chosen(candidate.user_id) {
+            "[x] "
+        } else {
+            "[ ] "
+        };
+        ListItem::new(Line::from(vec![
+            Span::styled(marker, Style::default().fg(self.theme.accent)),
+            Span::styled(clean_text(&candidate.name), self.theme.base_style),
+            Span::styled(format!("  {}", candidate.username), self.theme.dimmed_style),
+            Span::styled(
+                format!("  · {}", candidate.source.label()),
+                self.theme.dimmed_style,
+            ),
+        ]))
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings: &[(&str, &str)] =
+            if self.picker.rename.is_some() || self.picker.group_name.is_some() {
+                &[("Enter", "Confirm"), ("Esc", "Back")]
+            } else {
+                &[
+                    ("↑↓", "Move"),
+                    ("Tab", "Add to group"),
+                    ("Enter", "Open DM"),
+                    ("Esc", "Close"),
+                ]
+            };
+
+        KeyHints::new(bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for NewDmPickerWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(60, 70, area);
+
+        Clear.render(area, buf);
+
+        let title = if self.picker.rename.is_some() {
+            " Rename Group DM "
+        } else {
+            " New Direct Message "
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(title);
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(2),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        if let Some((_, name)) = &self.picker.rename {
+            Paragraph::new(self.input_line("Group name: ", name)).render(layout[0], buf);
+            self.render_footer(layout[3], buf);
+            return;
+        }
+
+        let input = match &self.picker.group_name {
+            Some(name) => self.input_line("Group name (optional): ", name),
+            None => self.input_line("Find a user: ", &self.picker.filter),
+        };
+        Paragraph::new(input).render(layout[0], buf);
+        Paragraph::new(self.chosen_line()).render(layout[1], buf);
+
+        let candidates = self.picker.filtered();
+        if candidates.is_empty() {
+            Paragraph::new(Span::styled("No users found", self.theme.dimmed_style))
+                .render(layout[2], buf);
+        } else {
+            let items: Vec<ListItem> = candidates
+                .iter()
+                .map(|candidate| self.candidate_item(candidate))
+                .collect();
+            let highlight = if self.picker.group_name.is_some() {
+                Style::default()
+            } else {
+                self.theme.selection_style.add_modifier(Modifier::BOLD)
+            };
+            let list = List::new(items).highlight_style(highlight);
+            let mut list_state = ListState::default()
+                .with_selected(Some(self.picker.selected.min(candidates.len() - 1)));
+            StatefulWidget::render(list, layout[2], buf, &mut list_state);
+        }
+
+        self.render_footer(layout[3], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::from(code)
+    }
+
+    fn picker() -> NewDmPicker {
+        let mut picker = NewDmPicker::new();
+        picker.open(vec![
+            DmCandidate::new(UserId(3), "Carol", "carol", DmCandidateSource::Cached),
+            DmCandidate::new(UserId(2), "Bob", "bob", DmCandidateSource::Member),
+            DmCandidate::new(UserId(1), "Alice", "alice", DmCandidateSource::Friend),
+            DmCandidate::new(UserId(2), "Bobby", "bob", DmCandidateSource::Cached),
+        ]);
+        picker
+    }
+
+    #[test]
+    fn test_candidates_are_deduplicated_and_filtered() {
+        let mut picker = picker();
+        let ids: Vec<_> = picker.filtered().iter().map(|c| c.user_id).collect();
+        assert_eq!(ids, vec![UserId(1), UserId(2), UserId(3)]);
+
+        for c in "CAR".chars() {
+            picker.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(picker.filtered().len(), 1);
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            NewDmPickerAction::Open {
+                recipients: vec![UserId(3)],
+                name: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_multi_select_opens_named_group() {
+        let mut picker = picker();
+        picker.handle_key(key(KeyCode::Tab));
+        picker.handle_key(key(KeyCode::Down));
+        picker.handle_key(key(KeyCode::Tab));
+        picker.handle_key(key(KeyCode::Down));
+        picker.handle_key(key(KeyCode::Tab));
+        picker.handle_key(key(KeyCode::Tab));
+        assert_eq!(picker.chosen.len(), 2);
+
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            NewDmPickerAction::None
+        );
+        for c in "crew".chars() {
+            picker.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            NewDmPickerAction::Open {
+                recipients: vec![UserId(1), UserId(2)],
+                name: Some("crew".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rename_group() {
+        let mut picker = NewDmPicker::new();
+        picker.open_rename(ChannelId(10), Some("old"));
+        for _ in 0..3 {
+            picker.handle_key(key(KeyCode::Backspace));
+        }
+        for c in "new".chars() {
+            picker.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            NewDmPickerAction::Rename {
+                channel_id: ChannelId(10),
+                name: "new".to_string(),
+            }
+        );
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Esc)),
+            NewDmPickerAction::Close
+        );
+    }
+}
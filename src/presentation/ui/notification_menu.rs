@@ -0,0 +1,416 @@
+use chrono::{DateTime, Duration, Local, Utc};
+
+use crate::domain::entities::{
+    ChannelId, ChannelNotificationSettings, GuildId, GuildNotificationSettings,
+    MessageNotificationLevel, MuteState,
+};
+use crate::domain::ports::NotificationSettingsUpdate;
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, clean_text};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// How long a mute lasts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MuteDuration {
+    #[default]
+    Off,
+    FifteenMinutes,
+    OneHour,
+    EightHours,
+    OneDay,
+    Forever,
+    /// A temporary mute that was already running when the menu opened.
+    Until(DateTime<Utc>),
+}
+
+impl MuteDuration {
+    const ALL: [Self; 6] = [
+        Self::Off,
+        Self::FifteenMinutes,
+        Self::OneHour,
+        Self::EightHours,
+        Self::OneDay,
+        Self::Forever,
+    ];
+
+    fn from_mute(mute: MuteState, now: DateTime<Utc>) -> Self {
+        if !mute.is_active(now) {
+            return Self::Off;
+        }
+        mute.until.map_or(Self::Forever, Self::Until)
+    }
+
+    #[must_use]
+    pub fn label(self) -> String {
+        match self {
+            Self::Off => "Unmuted".to_string(),
+            Self::FifteenMinutes => "15 minutes".to_string(),
+            Self::OneHour => "1 hour".to_string(),
+            Self::EightHours => "8 hours".to_string(),
+            Self::OneDay => "24 hours".to_string(),
+            Self::Forever => "Until I turn it back on".to_string(),
+            Self::Until(until) => format!(
+                "Until {}",
+                until.with_timezone(&Local).format("%b %-d %H:%M")
+            ),
+        }
+    }
+
+    /// Mute state for a mute started at `now`.
+    #[must_use]
+    pub fn to_mute(self, now: DateTime<Utc>) -> MuteState {
+        let until = match self {
+            Self::Off => return MuteState::default(),
+            Self::Forever => return MuteState::muted_until(None),
+            Self::FifteenMinutes => now + Duration::minutes(15),
+            Self::OneHour => now + Duration::hours(1),
+            Self::EightHours => now + Duration::hours(8),
+            Self::OneDay => now + Duration::days(1),
+            Self::Until(until) => until,
+        };
+        MuteState::muted_until(Some(until))
+    }
+
+    fn cycle(self, forward: bool) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self);
+        let index = match (index, forward) {
+            (Some(index), true) => (index + 1).min(Self::ALL.len() - 1),
+            (Some(index), false) => index.saturating_sub(1),
+            // Leaving a running mute starts from the first fixed duration.
+            (None, true) => 1,
+            (None, false) => 0,
+        };
+        Self::ALL[index]
+    }
+}
+
+/// What the menu edits: a whole guild (or all DMs), or a single channel or category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationMenuTarget {
+    Guild(Option<GuildId>),
+    Channel {
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NotificationMenuRow {
+    #[default]
+    Mute,
+    Level,
+    SuppressEveryone,
+    SuppressRoles,
+}
+
+impl NotificationMenuRow {
+    const GUILD_ROWS: [Self; 4] = [
+        Self::Mute,
+        Self::Level,
+        Self::SuppressEveryone,
+        Self::SuppressRoles,
+    ];
+    const CHANNEL_ROWS: [Self; 2] = [Self::Mute, Self::Level];
+}
+
+/// Mute and notification settings for the selected guild, category or channel.
+#[derive(Debug, Default)]
+pub struct NotificationMenu {
+    pub target: Option<NotificationMenuTarget>,
+    pub title: String,
+    pub row: NotificationMenuRow,
+    pub mute: MuteDuration,
+    pub level: MessageNotificationLevel,
+    pub suppress_everyone: bool,
+    pub suppress_roles: bool,
+}
+
+impl NotificationMenu {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts editing `target` from its current settings.
+    pub fn open(
+        &mut self,
+        target: NotificationMenuTarget,
+        title: impl Into<String>,
+        settings: &GuildNotificationSettings,
+    ) {
+        let now = Utc::now();
+        let (mute, level) = match target {
+            NotificationMenuTarget::Guild(_) => (settings.mute, settings.level),
+            NotificationMenuTarget::Channel { channel_id, .. } => {
+                let channel = settings.channel(channel_id);
+                (channel.mute, channel.level)
+            }
+        };
+        *self = Self {
+            target: Some(target),
+            title: title.into(),
+            mute: MuteDuration::from_mute(mute, now),
+            level,
+            suppress_everyone: settings.suppress_everyone,
+            suppress_roles: settings.suppress_roles,
+            ..Self::default()
+        };
+    }
+
+    fn rows(&self) -> &'static [NotificationMenuRow] {
+        match self.target {
+            Some(NotificationMenuTarget::Guild(Some(_))) => &NotificationMenuRow::GUILD_ROWS,
+            _ => &NotificationMenuRow::CHANNEL_ROWS,
+        }
+    }
+
+    fn move_row(&mut self, forward: bool) {
+        let rows = self.rows();
+        let index = rows.iter().position(|row| *row == self.row).unwrap_or(0);
+        let index = if forward {
+            (index + 1).min(rows.len() - 1)
+        } else {
+            index.saturating_sub(1)
+        };
+        self.row = rows[index];
+    }
+
+    fn change_value(&mut self, forward: bool) {
+        match self.row {
+            NotificationMenuRow::Mute => self.mute = self.mute.cycle(forward),
+            NotificationMenuRow::Level => {
+                let levels = MessageNotificationLevel::ALL;
+                let index = levels.iter().position(|l| *l == self.level).unwrap_or(0);
+                let index = if forward {
+                    (index + 1) % levels.len()
+                } else {
+                    (index + levels.len() - 1) % levels.len()
+                };
+                self.level = levels[index];
+            }
+            NotificationMenuRow::SuppressEveryone => {
+                self.suppress_everyone = !self.suppress_everyone;
+            }
+            NotificationMenuRow::SuppressRoles => self.suppress_roles = !self.suppress_roles,
+        }
+    }
+
+    /// The update to save, for a mute started at `now`.
+    #[must_use]
+    pub fn update(&self, now: DateTime<Utc>) -> Option<NotificationSettingsUpdate> {
+        let mute = self.mute.to_mute(now);
+        Some(match self.target? {
+            NotificationMenuTarget::Guild(guild_id) => NotificationSettingsUpdate::Guild {
+                guild_id,
+                mute,
+                level: self.level,
+                suppress_everyone: self.suppress_everyone,
+                suppress_roles: self.suppress_roles,
+            },
+            NotificationMenuTarget::Channel {
+                guild_id,
+                channel_id,
+            } => NotificationSettingsUpdate::Channel {
+                guild_id,
+                channel_id,
+                settings: ChannelNotificationSettings {
+                    mute,
+                    level: self.level,
+                },
+            },
+        })
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> NotificationMenuAction {
+        match key.code {
+            KeyCode::Esc => return NotificationMenuAction::Close,
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => self.move_row(true),
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => self.move_row(false),
+            KeyCode::Right | KeyCode::Char('l' | ' ') => self.change_value(true),
+            KeyCode::Left | KeyCode::Char('h') => self.change_value(false),
+            KeyCode::Enter => {
+                if let Some(update) = self.update(Utc::now()) {
+                    return NotificationMenuAction::Save(update);
+                }
+            }
+            _ => {}
+        }
+        NotificationMenuAction::None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotificationMenuAction {
+    None,
+    Close,
+    Save(NotificationSettingsUpdate),
+}
+
+pub struct NotificationMenuWidget<'a> {
+    menu: &'a NotificationMenu,
+    theme: &'a Theme,
+}
+
+impl<'a> NotificationMenuWidget<'a> {
+    #[must_use]
+    pub const fn new(menu: &'a NotificationMenu, theme: &'a Theme) -> Self {
+        Self { menu, theme }
+    }
+
+    fn row_line(&self, row: NotificationMenuRow) -> Line<'static> {
+        let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" }.to_string();
+        let (label, value) = match row {
+            NotificationMenuRow::Mute => ("Mute", format!("◂ {} ▸", self.menu.mute.label())),
+            NotificationMenuRow::Level => (
+                "Notify me about",
+                format!("◂ {} ▸", self.menu.level.label()),
+            ),
+            NotificationMenuRow::SuppressEveryone => (
+                "Suppress @everyone and @here",
+                checkbox(self.menu.suppress_everyone),
+            ),
+            NotificationMenuRow::SuppressRoles => (
+                "Suppress all role @mentions",
+                checkbox(self.menu.suppress_roles),
+            ),
+        };
+
+        let selected = row == self.menu.row;
+        let marker = if selected { "> " } else { "  " };
+        let value_style = if selected {
+            self.theme.selection_style.add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(self.theme.accent)
+        };
+        Line::from(vec![
+            Span::styled(marker, value_style),
+            Span::styled(format!("{label:<30}"), self.theme.base_style),
+            Span::styled(value, value_style),
+        ])
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings = [
+            ("j/k", "Move"),
+            ("h/l", "Change"),
+            ("Enter", "Save"),
+            ("Esc", "Cancel"),
+        ];
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for NotificationMenuWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(50, 40, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(format!(" Notifications: {} ", clean_text(&self.menu.title)));
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner_area);
+
+        let lines: Vec<Line> = self
+            .menu
+            .rows()
+            .iter()
+            .map(|row| self.row_line(*row))
+            .collect();
+        Paragraph::new(lines).render(layout[0], buf);
+
+        self.render_footer(layout[1], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::from(code)
+    }
+
+    #[test]
+    fn test_guild_menu_edits_every_setting() {
+        let mut menu = NotificationMenu::new();
+        menu.open(
+            NotificationMenuTarget::Guild(Some(GuildId(1))),
+            "Rust",
+            &GuildNotificationSettings::new(Some(GuildId(1))),
+        );
+
+        menu.handle_key(key(KeyCode::Char('l')));
+        menu.handle_key(key(KeyCode::Char('l')));
+        menu.handle_key(key(KeyCode::Char('j')));
+        menu.handle_key(key(KeyCode::Char('l')));
+        menu.handle_key(key(KeyCode::Char('l')));
+        menu.handle_key(key(KeyCode::Char('j')));
+        menu.handle_key(key(KeyCode::Char(' ')));
+
+        let now = Utc::now();
+        assert_eq!(
+            menu.update(now),
+            Some(NotificationSettingsUpdate::Guild {
+                guild_id: Some(GuildId(1)),
+                mute: MuteState::muted_until(Some(now + Duration::hours(1))),
+                level: MessageNotificationLevel::OnlyMentions,
+                suppress_everyone: true,
+                suppress_roles: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_channel_menu_keeps_running_mute() {
+        let until = Utc::now() + Duration::hours(3);
+        let mut settings = GuildNotificationSettings::new(None);
+        settings.channel_overrides.insert(
+            ChannelId(5),
+            ChannelNotificationSettings {
+                mute: MuteState::muted_until(Some(until)),
+                level: MessageNotificationLevel::Default,
+            },
+        );
+        let mut menu = NotificationMenu::new();
+        let target = NotificationMenuTarget::Channel {
+            guild_id: None,
+            channel_id: ChannelId(5),
+        };
+        menu.open(target, "alice", &settings);
+        assert_eq!(menu.mute, MuteDuration::Until(until));
+
+        for _ in 0..3 {
+            menu.handle_key(key(KeyCode::Down));
+        }
+        assert_eq!(menu.row, NotificationMenuRow::Level);
+
+        let Some(NotificationSettingsUpdate::Channel { settings, .. }) = menu.update(Utc::now())
+        else {
+            panic!("Expected a channel update");
+        };
+        assert_eq!(settings.mute, MuteState::muted_until(Some(until)));
+
+        menu.handle_key(key(KeyCode::Up));
+        menu.handle_key(key(KeyCode::Left));
+        assert_eq!(menu.mute, MuteDuration::Off);
+    }
+}
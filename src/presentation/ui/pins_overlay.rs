@@ -0,0 +1,368 @@
+use crate::domain::entities::{ChannelId, Message, MessageId};
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::centered_rect;
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+/// Pinned messages of a single channel.
+pub struct PinsOverlay {
+    pub channel_id: Option<ChannelId>,
+    pub messages: Vec<Message>,
+    pub list_state: ListState,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// Whether the current user may unpin messages in this channel.
+    pub can_manage: bool,
+    /// Pins removed ahead of Discord confirming the unpin, with their former index.
+    unpinning: Vec<(usize, Message)>,
+}
+
+impl Default for PinsOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PinsOverlay {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            channel_id: None,
+            messages: Vec::new(),
+            list_state: ListState::default(),
+            loading: false,
+            error: None,
+            can_manage: false,
+            unpinning: Vec::new(),
+        }
+    }
+
+    /// Resets the overlay for a channel whose pins are about to be fetched.
+    pub fn open(&mut self, channel_id: ChannelId, can_manage: bool) {
+        self.reset();
+        self.channel_id = Some(channel_id);
+        self.can_manage = can_manage;
+        self.loading = true;
+    }
+
+    pub fn reset(&mut self) {
+        self.channel_id = None;
+        self.messages.clear();
+        self.list_state.select(None);
+        self.loading = false;
+        self.error = None;
+        self.can_manage = false;
+        self.unpinning.clear();
+    }
+
+    /// Replaces the listed pins, ignoring results for any other channel.
+    pub fn set_messages(&mut self, channel_id: ChannelId, messages: Vec<Message>) {
+        if self.channel_id != Some(channel_id) {
+            return;
+        }
+        self.messages = messages;
+        self.loading = false;
+        self.error = None;
+        self.clamp_selection();
+    }
+
+    pub fn set_error(&mut self, channel_id: ChannelId, error: String) {
+        if self.channel_id != Some(channel_id) {
+            return;
+        }
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Drops a pin from the list ahead of the gateway confirming it.
+    pub fn remove(&mut self, message_id: MessageId) {
+        if let Some(index) = self.messages.iter().position(|m| m.id() == message_id) {
+            let message = self.messages.remove(index);
+            self.unpinning.push((index, message));
+        }
+        self.clamp_selection();
+    }
+
+    /// Forgets a removed pin once Discord accepted the unpin.
+    pub fn confirm_unpin(&mut self, message_id: MessageId) {
+        self.unpinning.retain(|(_, m)| m.id() != message_id);
+    }
+
+    /// Puts back a pin whose unpin failed.
+    pub fn restore(&mut self, channel_id: ChannelId, message_id: MessageId) {
+        if self.channel_id != Some(channel_id) {
+            return;
+        }
+        let Some(pos) = self
+            .unpinning
+            .iter()
+            .position(|(_, m)| m.id() == message_id)
+        else {
+            return;
+        };
+        let (index, message) = self.unpinning.remove(pos);
+        if !self.messages.iter().any(|m| m.id() == message_id) {
+            let index = index.min(self.messages.len());
+            self.messages.insert(index, message);
+        }
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
+        if self.messages.is_empty() {
+            self.list_state.select(None);
+        } else {
+            let index = self
+                .list_state
+                .selected()
+                .unwrap_or(0)
+                .min(self.messages.len() - 1);
+            self.list_state.select(Some(index));
+        }
+    }
+
+    #[must_use]
+    pub fn selected_message(&self) -> Option<&Message> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.messages.get(i))
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> PinsOverlayAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => PinsOverlayAction::Close,
+            KeyCode::Enter => self
+                .selected_message()
+                .map_or(PinsOverlayAction::None, |m| PinsOverlayAction::Jump(m.id())),
+            KeyCode::Char('u') | KeyCode::Delete if self.can_manage => self
+                .selected_message()
+                .map_or(PinsOverlayAction::None, |m| {
+                    PinsOverlayAction::Unpin(m.id())
+                }),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.select_previous();
+                PinsOverlayAction::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.select_next();
+                PinsOverlayAction::None
+            }
+            _ => PinsOverlayAction::None,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.messages.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(i) if i + 1 < self.messages.len() => i + 1,
+            _ => 0,
+        };
+        self.list_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.messages.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(0) | None => self.messages.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.list_state.select(Some(i));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PinsOverlayAction {
+    None,
+    Close,
+    Jump(MessageId),
+    Unpin(MessageId),
+}
+
+pub struct PinsOverlayWidget<'a> {
+    overlay: &'a PinsOverlay,
+    theme: &'a Theme,
+}
+
+impl<'a> PinsOverlayWidget<'a> {
+    #[must_use]
+    pub fn new(overlay: &'a PinsOverlay, theme: &'a Theme) -> Self {
+        Self { overlay, theme }
+    }
+
+    fn preview(message: &Message) -> String {
+        let first_line = message.content().lines().next().unwrap_or_default();
+        if !first_line.is_empty() {
+            return first_line.to_string();
+        }
+        match message.attachments().len() {
+            0 => String::new(),
+            1 => "[1 attachment]".to_string(),
+            n => format!("[{n} attachments]"),
+        }
+    }
+
+    fn render_list(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let status = if self.overlay.loading {
+            Some("Loading pinned messages...".to_string())
+        } else if let Some(error) = &self.overlay.error {
+            Some(format!("Failed to load pins: {error}"))
+        } else if self.overlay.messages.is_empty() {
+            Some("This channel has no pinned messages".to_string())
+        } else {
+            None
+        };
+
+        if let Some(status) = status {
+            Paragraph::new(status)
+                .style(self.theme.dimmed_style)
+                .render(area, buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .overlay
+            .messages
+            .iter()
+            .map(|message| {
+                let header = Line::from(vec![
+                    Span::styled(
+                        message.author().raw_display_name(),
+                        Style::default().fg(self.theme.accent),
+                    ),
+                    Span::styled(
+                        format!(" {}", message.timestamp().format("%Y-%m-%d %H:%M")),
+                        self.theme.dimmed_style,
+                    ),
+                ]);
+                let preview = Line::from(Span::styled(
+                    format!("  {}", Self::preview(message)),
+                    self.theme.base_style,
+                ));
+                ListItem::new(vec![header, preview])
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(self.theme.selection_style);
+
+        let mut state = self.overlay.list_state;
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
+
+impl Widget for PinsOverlayWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(50, 60, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(format!(
+                " Pinned Messages ({}) ",
+                self.overlay.messages.len()
+            ));
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner_area);
+
+        self.render_list(layout[0], buf);
+
+        let mut bindings = vec![("Enter", "Jump")];
+        if self.overlay.can_manage {
+            bindings.push(("u", "Unpin"));
+        }
+        bindings.push(("Esc", "Close"));
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(layout[1], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pin(id: u64, content: &str) -> Message {
+        Message::test(id, 1, content).with_pinned(true)
+    }
+
+    #[test]
+    fn test_results_for_other_channels_are_ignored() {
+        let mut overlay = PinsOverlay::new();
+        overlay.open(ChannelId(1), false);
+        overlay.set_messages(ChannelId(2), vec![pin(10, "elsewhere")]);
+        assert!(overlay.loading);
+        assert!(overlay.messages.is_empty());
+
+        overlay.set_messages(ChannelId(1), vec![pin(10, "rules"), pin(11, "faq")]);
+        assert!(!overlay.loading);
+        assert_eq!(overlay.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_jump_and_unpin_selected() {
+        let mut overlay = PinsOverlay::new();
+        overlay.open(ChannelId(1), false);
+        overlay.set_messages(ChannelId(1), vec![pin(10, "rules"), pin(11, "faq")]);
+
+        overlay.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(
+            overlay.handle_key(KeyEvent::from(KeyCode::Enter)),
+            PinsOverlayAction::Jump(MessageId(11))
+        );
+        assert_eq!(
+            overlay.handle_key(KeyEvent::from(KeyCode::Char('u'))),
+            PinsOverlayAction::None
+        );
+
+        overlay.can_manage = true;
+        assert_eq!(
+            overlay.handle_key(KeyEvent::from(KeyCode::Char('u'))),
+            PinsOverlayAction::Unpin(MessageId(11))
+        );
+
+        overlay.remove(MessageId(11));
+        assert_eq!(
+            overlay.selected_message().map(Message::id),
+            Some(MessageId(10))
+        );
+    }
+
+    #[test]
+    fn test_failed_unpin_restores_the_pin() {
+        let mut overlay = PinsOverlay::new();
+        overlay.open(ChannelId(1), true);
+        overlay.set_messages(
+            ChannelId(1),
+            vec![pin(10, "rules"), pin(11, "faq"), pin(12, "links")],
+        );
+
+        overlay.remove(MessageId(11));
+        overlay.remove(MessageId(12));
+        overlay.confirm_unpin(MessageId(12));
+        overlay.restore(ChannelId(1), MessageId(12));
+        overlay.restore(ChannelId(1), MessageId(11));
+
+        let ids: Vec<_> = overlay.messages.iter().map(Message::id).collect();
+        assert_eq!(ids, [MessageId(10), MessageId(11)]);
+    }
+}
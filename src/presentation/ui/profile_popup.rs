@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+
+use crate::domain::entities::{Guild, GuildId, Role, RoleId, UserPresence, UserProfile};
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, presence_color, u32_to_color};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Profile of one user, switchable between the users it was opened for,
+/// e.g. a message's author and the users the message mentions.
+#[derive(Default)]
+pub struct ProfilePopup {
+    user_ids: Vec<String>,
+    index: usize,
+    guild_id: Option<GuildId>,
+    /// Name shown while the profile loads.
+    pub fallback_name: Option<String>,
+    pub profile: Option<UserProfile>,
+    /// Last known presence of the shown user.
+    pub presence: Option<UserPresence>,
+    pub loading: bool,
+    pub error: Option<String>,
+    scroll: u16,
+}
+
+impl ProfilePopup {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the popup for users whose profiles are shown in `guild_id`, starting with the first.
+    pub fn open(&mut self, user_ids: Vec<String>, guild_id: Option<GuildId>) {
+        *self = Self {
+            user_ids,
+            guild_id,
+            ..Self::default()
+        };
+        self.show_current();
+    }
+
+    /// Clears what was shown for the previous user before loading the current one.
+    fn show_current(&mut self) {
+        self.fallback_name = None;
+        self.profile = None;
+        self.presence = None;
+        self.error = None;
+        self.scroll = 0;
+        self.loading = true;
+    }
+
+    #[must_use]
+    pub const fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+
+    #[must_use]
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_ids.get(self.index).map(String::as_str)
+    }
+
+    /// Shows a loaded profile, ignoring profiles of users not currently shown.
+    pub fn set_profile(&mut self, profile: UserProfile) {
+        if self.user_id() != Some(profile.user().id_str().as_str())
+            || profile.guild_id() != self.guild_id
+        {
+            return;
+        }
+        self.profile = Some(profile);
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_error(&mut self, user_id: &str, error: String) {
+        if self.user_id() != Some(user_id) {
+            return;
+        }
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ProfilePopupAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => ProfilePopupAction::Close,
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => self.switch(1),
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.switch(self.user_ids.len().saturating_sub(1))
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scroll = self.scroll.saturating_add(1);
+                ProfilePopupAction::None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+                ProfilePopupAction::None
+            }
+            _ => ProfilePopupAction::None,
+        }
+    }
+
+    fn switch(&mut self, step: usize) -> ProfilePopupAction {
+        if self.user_ids.len() < 2 {
+            return ProfilePopupAction::None;
+        }
+        self.index = (self.index + step) % self.user_ids.len();
+        self.show_current();
+        self.user_id().map_or(ProfilePopupAction::None, |id| {
+            ProfilePopupAction::Show(id.to_string())
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProfilePopupAction {
+    None,
+    Close,
+    /// Another user was switched to; their profile needs loading.
+    Show(String),
+}
+
+pub struct ProfilePopupWidget<'a> {
+    popup: &'a ProfilePopup,
+    theme: &'a Theme,
+    roles: Option<&'a HashMap<RoleId, Role>>,
+    guilds: &'a [Guild],
+}
+
+impl<'a> ProfilePopupWidget<'a> {
+    #[must_use]
+    pub const fn new(popup: &'a ProfilePopup, theme: &'a Theme) -> Self {
+        Self {
+            popup,
+            theme,
+            roles: None,
+            guilds: &[],
+        }
+    }
+
+    /// Roles of the profile's guild, used to name and colour the member's roles.
+    #[must_use]
+    pub const fn with_roles(mut self, roles: Option<&'a HashMap<RoleId, Role>>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    /// Guilds of the current user, used to name mutual guilds.
+    #[must_use]
+    pub const fn with_guilds(mut self, guilds: &'a [Guild]) -> Self {
+        self.guilds = guilds;
+        self
+    }
+
+    fn section(&self, title: &str) -> [Line<'static>; 2] {
+        [
+            Line::default(),
+            Line::from(Span::styled(
+                title.to_uppercase(),
+                self.theme.dimmed_style.add_modifier(Modifier::BOLD),
+            )),
+        ]
+    }
+
+    fn header_lines(&self, profile: &UserProfile) -> Vec<Line<'static>> {
+        let user = profile.user();
+        let name_style = user.accent_color().map_or_else(
+            || Style::default().fg(self.theme.accent),
+            |color| Style::default().fg(u32_to_color(color)),
+        );
+        let mut name = vec![Span::styled(
+            profile.display_name(),
+            name_style.add_modifier(Modifier::BOLD),
+        )];
+        if user.is_bot() {
+            name.push(Span::styled(" BOT", self.theme.dimmed_style));
+        }
+
+        let mut handle = vec![Span::styled(
+            format!("@{}", user.username()),
+            self.theme.base_style,
+        )];
+        if let Some(pronouns) = profile.pronouns() {
+            handle.push(Span::styled(
+                format!(" · {pronouns}"),
+                self.theme.dimmed_style,
+            ));
+        }
+
+        let mut lines = vec![Line::from(name), Line::from(handle)];
+        if let Some(presence) = &self.popup.presence {
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{} {}",
+                    presence.status.display_indicator(),
+                    presence.status.as_str()
+                ),
+                Style::default().fg(presence_color(presence.status)),
+            )];
+            for activity in &presence.activities {
+                spans.push(Span::styled(
+                    format!(" · {}", activity.label()),
+                    self.theme.dimmed_style,
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines
+    }
+
+    fn role_spans(&self, role_ids: &[RoleId]) -> Vec<Span<'static>> {
+        let mut roles: Vec<&Role> = role_ids
+            .iter()
+            .filter_map(|id| self.roles?.get(id))
+            .collect();
+        roles.sort_by_key(|role| std::cmp::Reverse(role.position));
+
+        let mut spans = Vec::new();
+        for role in roles {
+            let color = if role.color == 0 {
+                self.theme.dimmed_style
+            } else {
+                Style::default().fg(u32_to_color(role.color))
+            };
+            spans.push(Span::styled("● ", color));
+            spans.push(Span::styled(
+                format!("{}  ", role.name),
+                self.theme.base_style,
+            ));
+        }
+        spans
+    }
+
+    fn profile_lines(&self, profile: &UserProfile) -> Vec<Line<'static>> {
+        let mut lines = self.header_lines(profile);
+
+        if let Some(bio) = profile.bio() {
+            lines.extend(self.section("About me"));
+            lines.extend(
+                bio.lines()
+                    .map(|line| Line::styled(line.to_string(), self.theme.base_style)),
+            );
+        }
+
+        if let Some(member) = profile.member() {
+            let roles = self.role_spans(&member.roles);
+            if !roles.is_empty() {
+                lines.extend(self.section("Roles"));
+                lines.push(Line::from(roles));
+            }
+        }
+
+        lines.extend(self.section("Member since"));
+        let date = |label: &str, at: chrono::DateTime<chrono::Utc>| {
+            Line::from(vec![
+                Span::styled(format!("{label}: "), self.theme.dimmed_style),
+                Span::styled(at.format("%b %-d, %Y").to_string(), self.theme.base_style),
+            ])
+        };
+        lines.push(date("Discord", profile.created_at()));
+        if let Some(joined) = profile.joined_at() {
+            lines.push(date("Server", joined));
+        }
+        if let Some(since) = profile.premium_since() {
+            lines.push(date("Nitro", since));
+        }
+
+        if !profile.mutual_guilds().is_empty() {
+            lines.extend(self.section(&format!(
+                "Mutual servers ({})",
+                profile.mutual_guilds().len()
+            )));
+            lines.extend(profile.mutual_guilds().iter().map(|id| {
+                let name = self
+                    .guilds
+                    .iter()
+                    .find(|guild| guild.id() == *id)
+                    .map_or_else(|| id.to_string(), |guild| guild.name().to_string());
+                Line::styled(name, self.theme.base_style)
+            }));
+        }
+        lines
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let mut bindings = vec![("j/k", "Scroll")];
+        if self.popup.user_ids.len() > 1 {
+            bindings.push(("Tab", "Next user"));
+        }
+        bindings.push(("Esc", "Close"));
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for ProfilePopupWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(40, 60, area);
+
+        Clear.render(area, buf);
+
+        let position = if self.popup.user_ids.len() > 1 {
+            format!("({}/{}) ", self.popup.index + 1, self.popup.user_ids.len())
+        } else {
+            String::new()
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(format!(" Profile {position}"));
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner_area);
+
+        let lines = if let Some(profile) = &self.popup.profile {
+            self.profile_lines(profile)
+        } else {
+            let name = self.popup.fallback_name.as_deref().unwrap_or("user");
+            let status = self.popup.error.as_ref().map_or_else(
+                || format!("Loading profile of {name}..."),
+                |error| format!("Failed to load profile of {name}: {error}"),
+            );
+            vec![Line::styled(status, self.theme.dimmed_style)]
+        };
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.popup.scroll, 0))
+            .render(layout[0], buf);
+
+        self.render_footer(layout[1], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::User;
+
+    fn profile(id: u64) -> UserProfile {
+        UserProfile::new(
+            User::new(id, format!("user{id}"), "0", None, false, None),
+            Some(GuildId(1)),
+        )
+    }
+
+    #[test]
+    fn test_switching_users_drops_stale_profiles() {
+        let mut popup = ProfilePopup::new();
+        popup.open(vec!["2".to_string(), "3".to_string()], Some(GuildId(1)));
+
+        popup.set_profile(profile(3));
+        assert!(
+            popup.profile.is_none(),
+            "only the shown user's profile is kept"
+        );
+        popup.set_profile(profile(2));
+        assert!(!popup.loading);
+
+        assert_eq!(
+            popup.handle_key(KeyEvent::from(KeyCode::Tab)),
+            ProfilePopupAction::Show("3".to_string())
+        );
+        assert!(popup.profile.is_none());
+        assert!(popup.loading);
+
+        popup.set_error("2", "gone".to_string());
+        assert!(popup.error.is_none());
+        popup.set_error("3", "gone".to_string());
+        assert_eq!(popup.error.as_deref(), Some("gone"));
+    }
+
+    #[test]
+    fn test_single_user_cannot_be_switched() {
+        let mut popup = ProfilePopup::new();
+        popup.open(vec!["2".to_string()], None);
+        assert_eq!(
+            popup.handle_key(KeyEvent::from(KeyCode::Tab)),
+            ProfilePopupAction::None
+        );
+        assert_eq!(
+            popup.handle_key(KeyEvent::from(KeyCode::Esc)),
+            ProfilePopupAction::Close
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::domain::search::{RecentItem, SearchKind, SearchResult};
 use crate::infrastructure::config::app_config::QuickSwitcherSortMode;
 use crate::presentation::theme::Theme;
 use crate::presentation::ui::utils::{centered_rect, sanitize_channel_name};
-use crate::presentation::widgets::FooterBarStyle;
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -49,7 +49,8 @@ impl QuickSwitcher {
     pub fn toggle_sort_mode(&mut self) {
         self.sort_mode = match self.sort_mode {
             QuickSwitcherSortMode::Recents => QuickSwitcherSortMode::Mixed,
-            QuickSwitcherSortMode::Mixed => QuickSwitcherSortMode::Recents,
+            QuickSwitcherSortMode::Mixed => QuickSwitcherSortMode::Online,
+            QuickSwitcherSortMode::Online => QuickSwitcherSortMode::Recents,
         };
         self.apply_sort();
     }
@@ -124,7 +125,7 @@ impl QuickSwitcher {
                     .map(|r| (r.clone(), get_timestamp(r, &self.recents)))
                     .collect();
 
-                results_with_time.sort_by(|a, b| b.1.cmp(&a.1));
+                results_with_time.sort_by_key(|r| std::cmp::Reverse(r.1));
 
                 let top_recents_ids: Vec<(String, SearchKind)> = results_with_time
                     .iter()
@@ -173,6 +174,15 @@ impl QuickSwitcher {
                     }
                 });
             }
+            QuickSwitcherSortMode::Online => {
+                self.results.sort_by(|a, b| {
+                    b.is_online_friend
+                        .cmp(&a.is_online_friend)
+                        .then_with(|| b.is_favorite.cmp(&a.is_favorite))
+                        .then_with(|| b.score.cmp(&a.score))
+                        .then_with(|| a.name.cmp(&b.name))
+                });
+            }
         }
     }
 
@@ -422,8 +432,6 @@ impl Widget for QuickSwitcherWidget<'_> {
 
         self.render_results_list(layout[1], buf);
 
-        let footer_style = FooterBarStyle::from_theme(self.theme);
-
         let prefixes = [
             ("*", "Servers"),
             ("#", "Channels"),
@@ -432,23 +440,9 @@ impl Widget for QuickSwitcherWidget<'_> {
             ("^", "Threads"),
         ];
 
-        let mut footer_spans = Vec::new();
-        for (i, (prefix, label)) in prefixes.iter().enumerate() {
-            if i > 0 {
-                footer_spans.push(Span::raw(" "));
-            }
-            footer_spans.push(Span::styled(
-                format!(" {prefix} "),
-                footer_style.label_style,
-            ));
-            footer_spans.push(Span::styled(format!(" {label} "), footer_style.key_style));
-        }
-
-        let footer = Paragraph::new(Line::from(footer_spans));
-        for x in layout[3].left()..layout[3].right() {
-            buf[(x, layout[3].y)].set_style(footer_style.background);
-        }
-        footer.render(layout[3], buf);
+        KeyHints::new(&prefixes)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(layout[3], buf);
     }
 }
 
@@ -550,6 +544,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_online_mode_sorts_online_friends_first() {
+        let mut switcher = QuickSwitcher::new(QuickSwitcherSortMode::Mixed);
+        switcher.toggle_sort_mode();
+        assert_eq!(switcher.sort_mode, QuickSwitcherSortMode::Online);
+
+        let mut online = SearchResult::new("2", "bob", SearchKind::DM);
+        online.is_online_friend = true;
+        switcher.set_results(vec![
+            SearchResult::new("1", "alice", SearchKind::DM).with_score(50),
+            online,
+        ]);
+        assert_eq!(switcher.results[0].id, "2");
+        assert_eq!(switcher.results[1].id, "1");
+    }
+
     #[test]
     fn test_select_none() {
         let mut switcher = QuickSwitcher::new(QuickSwitcherSortMode::default());
@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+
+use crate::domain::entities::{ChannelId, Message, MessageId};
+use crate::domain::ports::{MessageSearchPage, SearchMessagesRequest};
+use crate::presentation::theme::Theme;
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+/// Discord's search endpoints always return pages of this many hits.
+pub const SEARCH_PAGE_SIZE: u32 = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchPaneFocus {
+    Input,
+    Results,
+}
+
+/// Message search query and the page of results currently shown.
+pub struct SearchPane {
+    pub query: String,
+    pub focus: SearchPaneFocus,
+    pub results: Vec<Message>,
+    pub total_results: u32,
+    pub offset: u32,
+    pub list_state: ListState,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// Display names of the channels the current results were found in.
+    pub channel_names: HashMap<ChannelId, String>,
+    request: Option<SearchMessagesRequest>,
+}
+
+impl Default for SearchPane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchPane {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            focus: SearchPaneFocus::Input,
+            results: Vec::new(),
+            total_results: 0,
+            offset: 0,
+            list_state: ListState::default(),
+            loading: false,
+            error: None,
+            channel_names: HashMap::new(),
+            request: None,
+        }
+    }
+
+    /// Records a new search and returns the request for its first page.
+    pub fn start(&mut self, request: SearchMessagesRequest) -> SearchMessagesRequest {
+        self.results.clear();
+        self.total_results = 0;
+        self.list_state.select(None);
+        self.offset = 0;
+        self.loading = true;
+        self.error = None;
+        let request = request.with_offset(0);
+        self.request = Some(request.clone());
+        request
+    }
+
+    fn request_page(&mut self, offset: u32) -> Option<SearchMessagesRequest> {
+        let request = self.request.clone()?.with_offset(offset);
+        self.offset = offset;
+        self.loading = true;
+        self.error = None;
+        Some(request)
+    }
+
+    /// Whether `request` is the page currently being waited for.
+    fn is_pending(&self, request: &SearchMessagesRequest) -> bool {
+        self.loading
+            && self
+                .request
+                .as_ref()
+                .is_some_and(|current| current.clone().with_offset(self.offset) == *request)
+    }
+
+    /// Stores a page of results, ignoring pages that are no longer wanted.
+    pub fn set_results(&mut self, request: &SearchMessagesRequest, page: MessageSearchPage) {
+        if !self.is_pending(request) {
+            return;
+        }
+        self.loading = false;
+        self.total_results = page.total_results;
+        self.results = page.messages;
+        self.list_state
+            .select((!self.results.is_empty()).then_some(0));
+        if !self.results.is_empty() {
+            self.focus = SearchPaneFocus::Results;
+        }
+    }
+
+    /// Records a failed search, ignoring failures of searches that are no longer wanted.
+    pub fn set_error(&mut self, request: &SearchMessagesRequest, error: String) {
+        if self.is_pending(request) {
+            self.set_query_error(error);
+        }
+    }
+
+    /// Shows why the typed query could not be searched.
+    pub fn set_query_error(&mut self, error: String) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    #[must_use]
+    pub const fn page(&self) -> u32 {
+        self.offset / SEARCH_PAGE_SIZE + 1
+    }
+
+    #[must_use]
+    pub const fn page_count(&self) -> u32 {
+        self.total_results.div_ceil(SEARCH_PAGE_SIZE)
+    }
+
+    #[must_use]
+    pub fn selected_message(&self) -> Option<&Message> {
+        self.list_state.selected().and_then(|i| self.results.get(i))
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SearchPaneAction {
+        if key.code == KeyCode::Esc {
+            return SearchPaneAction::Close;
+        }
+        match self.focus {
+            SearchPaneFocus::Input => self.handle_input_key(key),
+            SearchPaneFocus::Results => self.handle_results_key(key),
+        }
+    }
+
+    fn handle_input_key(&mut self, key: KeyEvent) -> SearchPaneAction {
+        match key.code {
+            KeyCode::Enter if !self.query.trim().is_empty() => {
+                SearchPaneAction::Submit(self.query.clone())
+            }
+            KeyCode::Tab | KeyCode::Down if !self.results.is_empty() => {
+                self.focus = SearchPaneFocus::Results;
+                SearchPaneAction::None
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                SearchPaneAction::None
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.query.clear();
+                SearchPaneAction::None
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.query.push(c);
+                SearchPaneAction::None
+            }
+            _ => SearchPaneAction::None,
+        }
+    }
+
+    fn handle_results_key(&mut self, key: KeyEvent) -> SearchPaneAction {
+        match key.code {
+            KeyCode::Char('q') => SearchPaneAction::Close,
+            KeyCode::Tab | KeyCode::Char('/') => {
+                self.focus = SearchPaneFocus::Input;
+                SearchPaneAction::None
+            }
+            KeyCode::Enter => {
+                self.selected_message()
+                    .map_or(SearchPaneAction::None, |m| SearchPaneAction::Jump {
+                        channel_id: m.channel_id(),
+                        message_id: m.id(),
+                    })
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.select_previous();
+                SearchPaneAction::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.select_next();
+                SearchPaneAction::None
+            }
+            KeyCode::Right | KeyCode::PageDown | KeyCode::Char('n')
+                if !self.loading && self.page() < self.page_count() =>
+            {
+                self.request_page(self.offset + SEARCH_PAGE_SIZE)
+                    .map_or(SearchPaneAction::None, SearchPaneAction::Search)
+            }
+            KeyCode::Left | KeyCode::PageUp | KeyCode::Char('p')
+                if !self.loading && self.offset > 0 =>
+            {
+                self.request_page(self.offset.saturating_sub(SEARCH_PAGE_SIZE))
+                    .map_or(SearchPaneAction::None, SearchPaneAction::Search)
+            }
+            _ => SearchPaneAction::None,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.results.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(i) if i + 1 < self.results.len() => i + 1,
+            _ => 0,
+        };
+        self.list_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.results.is_empty() {
+            return;
+        }
+        let i = match self.list_state.selected() {
+            Some(0) | None => self.results.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.list_state.select(Some(i));
+    }
+}
+
+#[derive(Debug)]
+pub enum SearchPaneAction {
+    None,
+    Close,
+    /// Run a new search for the typed query.
+    Submit(String),
+    /// Fetch another page of the current search.
+    Search(SearchMessagesRequest),
+    Jump {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+}
+
+pub struct SearchPaneWidget<'a> {
+    pane: &'a SearchPane,
+    theme: &'a Theme,
+}
+
+impl<'a> SearchPaneWidget<'a> {
+    #[must_use]
+    pub fn new(pane: &'a SearchPane, theme: &'a Theme) -> Self {
+        Self { pane, theme }
+    }
+
+    fn preview(message: &Message) -> String {
+        let first_line = message.content().lines().next().unwrap_or_default();
+        if !first_line.is_empty() {
+            return first_line.to_string();
+        }
+        match message.attachments().len() {
+            0 => String::new(),
+            1 => "[1 attachment]".to_string(),
+            n => format!("[{n} attachments]"),
+        }
+    }
+
+    fn status(&self) -> String {
+        if self.pane.loading {
+            "Searching...".to_string()
+        } else if let Some(error) = &self.pane.error {
+            format!("Search failed: {error}")
+        } else if self.pane.results.is_empty() {
+            "from: in: has: mentions: before: after:".to_string()
+        } else {
+            format!(
+                "{} results - page {}/{}",
+                self.pane.total_results,
+                self.pane.page(),
+                self.pane.page_count()
+            )
+        }
+    }
+
+    fn render_results(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let items: Vec<ListItem> = self
+            .pane
+            .results
+            .iter()
+            .map(|message| {
+                let channel = self
+                    .pane
+                    .channel_names
+                    .get(&message.channel_id())
+                    .map_or_else(|| message.channel_id().to_string(), Clone::clone);
+                let header = Line::from(vec![
+                    Span::styled(
+                        message.author().raw_display_name(),
+                        Style::default().fg(self.theme.accent),
+                    ),
+                    Span::styled(
+                        format!(
+                            " {channel} {}",
+                            message.timestamp().format("%Y-%m-%d %H:%M")
+                        ),
+                        self.theme.dimmed_style,
+                    ),
+                ]);
+                let preview = Line::from(Span::styled(
+                    format!("  {}", Self::preview(message)),
+                    self.theme.base_style,
+                ));
+                ListItem::new(vec![header, preview])
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(self.theme.selection_style);
+
+        let mut state = self.pane.list_state;
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings: &[(&str, &str)] = match self.pane.focus {
+            SearchPaneFocus::Input => &[("Enter", "Search"), ("Esc", "Close")],
+            SearchPaneFocus::Results => &[
+                ("Enter", "Jump"),
+                ("n/p", "Page"),
+                ("/", "Query"),
+                ("Esc", "Close"),
+            ],
+        };
+
+        KeyHints::new(bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for SearchPaneWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(" Search Messages ");
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        let cursor = if self.pane.focus == SearchPaneFocus::Input {
+            "\u{258f}"
+        } else {
+            ""
+        };
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::default().fg(self.theme.accent)),
+            Span::styled(
+                format!("{}{cursor}", self.pane.query),
+                self.theme.base_style,
+            ),
+        ]))
+        .render(layout[0], buf);
+
+        Paragraph::new(self.status())
+            .style(self.theme.dimmed_style)
+            .render(layout[1], buf);
+
+        self.render_results(layout[2], buf);
+        self.render_footer(layout[3], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::GuildId;
+    use crate::domain::ports::SearchScope;
+
+    fn hit(id: u64) -> Message {
+        Message::test(id, 7, "hello")
+    }
+
+    fn page(total_results: u32, ids: &[u64]) -> MessageSearchPage {
+        MessageSearchPage {
+            total_results,
+            messages: ids.iter().copied().map(hit).collect(),
+        }
+    }
+
+    #[test]
+    fn test_paging_requests_next_offset() {
+        let mut pane = SearchPane::new();
+        let request = pane.start(SearchMessagesRequest::new(SearchScope::Guild(GuildId(1))));
+        assert_eq!(request.offset, 0);
+
+        pane.set_results(&request, page(30, &[10, 11]));
+        assert_eq!(pane.focus, SearchPaneFocus::Results);
+        assert_eq!(pane.page_count(), 2);
+
+        let SearchPaneAction::Search(next) = pane.handle_key(KeyEvent::from(KeyCode::Char('n')))
+        else {
+            panic!("expected a request for the next page");
+        };
+        assert_eq!(next.offset, SEARCH_PAGE_SIZE);
+
+        pane.set_results(&request, page(30, &[10]));
+        assert!(pane.loading, "stale page should be ignored");
+
+        pane.set_results(&next, page(30, &[12]));
+        assert!(matches!(
+            pane.handle_key(KeyEvent::from(KeyCode::Char('n'))),
+            SearchPaneAction::None
+        ));
+    }
+
+    #[test]
+    fn test_enter_jumps_to_selected_hit() {
+        let mut pane = SearchPane::new();
+        let request = pane.start(SearchMessagesRequest::new(SearchScope::Channel(ChannelId(
+            7,
+        ))));
+        pane.set_results(&request, page(2, &[10, 11]));
+
+        pane.handle_key(KeyEvent::from(KeyCode::Down));
+        assert!(matches!(
+            pane.handle_key(KeyEvent::from(KeyCode::Enter)),
+            SearchPaneAction::Jump {
+                channel_id: ChannelId(7),
+                message_id: MessageId(11)
+            }
+        ));
+    }
+
+    #[test]
+    fn test_errors_of_superseded_searches_are_ignored() {
+        let mut pane = SearchPane::new();
+        let old = pane.start(SearchMessagesRequest::new(SearchScope::Guild(GuildId(1))));
+        let mut newer = SearchMessagesRequest::new(SearchScope::Guild(GuildId(1)));
+        newer.content = Some("rust".to_string());
+        let newer = pane.start(newer);
+
+        pane.set_error(&old, "timed out".to_string());
+        assert!(pane.loading);
+        assert!(pane.error.is_none());
+
+        pane.set_error(&newer, "timed out".to_string());
+        assert!(!pane.loading);
+        assert_eq!(pane.error.as_deref(), Some("timed out"));
+    }
+}
@@ -0,0 +1,536 @@
+use chrono::{DateTime, Duration, Local, Utc};
+
+use crate::domain::entities::{CustomStatus, Emoji, PresenceStatus};
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{centered_rect, presence_color};
+use crate::presentation::widgets::{FooterBarStyle, KeyHints};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Statuses offered by the menu, in display order.
+const STATUSES: [PresenceStatus; 4] = [
+    PresenceStatus::Online,
+    PresenceStatus::Idle,
+    PresenceStatus::DoNotDisturb,
+    PresenceStatus::Invisible,
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatusMenuField {
+    #[default]
+    Status,
+    Text,
+    Emoji,
+    Expiry,
+}
+
+impl StatusMenuField {
+    const fn next(self) -> Self {
+        match self {
+            Self::Status => Self::Text,
+            Self::Text => Self::Emoji,
+            Self::Emoji => Self::Expiry,
+            Self::Expiry => Self::Status,
+        }
+    }
+
+    const fn previous(self) -> Self {
+        match self {
+            Self::Status => Self::Expiry,
+            Self::Text => Self::Status,
+            Self::Emoji => Self::Text,
+            Self::Expiry => Self::Emoji,
+        }
+    }
+}
+
+/// When the custom status clears itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatusExpiry {
+    #[default]
+    Never,
+    ThirtyMinutes,
+    OneHour,
+    FourHours,
+    /// Until local midnight.
+    Today,
+    /// Keeps the expiry of the custom status being edited.
+    Until(DateTime<Utc>),
+}
+
+impl StatusExpiry {
+    const ALL: [Self; 5] = [
+        Self::Never,
+        Self::ThirtyMinutes,
+        Self::OneHour,
+        Self::FourHours,
+        Self::Today,
+    ];
+
+    #[must_use]
+    pub fn label(self) -> String {
+        match self {
+            Self::Never => "Don't clear".to_string(),
+            Self::ThirtyMinutes => "30 minutes".to_string(),
+            Self::OneHour => "1 hour".to_string(),
+            Self::FourHours => "4 hours".to_string(),
+            Self::Today => "Today".to_string(),
+            Self::Until(expires_at) => {
+                let expires_at = expires_at.with_timezone(&Local);
+                if expires_at.date_naive() == Local::now().date_naive() {
+                    format!("Until {}", expires_at.format("%H:%M"))
+                } else {
+                    format!("Until {}", expires_at.format("%b %-d %H:%M"))
+                }
+            }
+        }
+    }
+
+    /// Expiry timestamp for a status set at `now`.
+    #[must_use]
+    pub fn expires_at(self, now: DateTime<Local>) -> Option<DateTime<Utc>> {
+        let expires_at = match self {
+            Self::Never => return None,
+            Self::Until(expires_at) => return Some(expires_at),
+            Self::ThirtyMinutes => now + Duration::minutes(30),
+            Self::OneHour => now + Duration::hours(1),
+            Self::FourHours => now + Duration::hours(4),
+            Self::Today => (now.date_naive() + Duration::days(1))
+                .and_hms_opt(0, 0, 0)?
+                .and_local_timezone(Local)
+                .earliest()?,
+        };
+        Some(expires_at.with_timezone(&Utc))
+    }
+}
+
+/// Menu for picking our own status and custom status.
+#[derive(Debug, Default)]
+pub struct StatusMenu {
+    pub status: PresenceStatus,
+    pub text: String,
+    /// Unicode emoji or `:shortcode:`.
+    pub emoji: String,
+    pub expiry: StatusExpiry,
+    /// Expiry of the custom status the menu was opened with, offered as an option.
+    kept_expiry: Option<DateTime<Utc>>,
+    pub field: StatusMenuField,
+    pub error: Option<String>,
+}
+
+impl StatusMenu {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts editing from the current status.
+    pub fn open(&mut self, status: PresenceStatus, custom_status: Option<&CustomStatus>) {
+        let status = if STATUSES.contains(&status) {
+            status
+        } else {
+            PresenceStatus::Online
+        };
+        let kept_expiry = custom_status
+            .and_then(|custom| custom.expires_at)
+            .filter(|expires_at| *expires_at > Utc::now());
+        *self = Self {
+            status,
+            text: custom_status
+                .and_then(|custom| custom.text.clone())
+                .unwrap_or_default(),
+            emoji: custom_status
+                .and_then(|custom| custom.emoji.clone())
+                .unwrap_or_default(),
+            expiry: kept_expiry.map_or(StatusExpiry::Never, StatusExpiry::Until),
+            kept_expiry,
+            ..Self::default()
+        };
+    }
+
+    fn move_status(&mut self, forward: bool) {
+        let index = STATUSES.iter().position(|s| *s == self.status).unwrap_or(0);
+        let index = if forward {
+            (index + 1).min(STATUSES.len() - 1)
+        } else {
+            index.saturating_sub(1)
+        };
+        self.status = STATUSES[index];
+    }
+
+    /// Expiry choices, led by the current expiry when the status had one.
+    fn expiry_options(&self) -> Vec<StatusExpiry> {
+        self.kept_expiry
+            .map(StatusExpiry::Until)
+            .into_iter()
+            .chain(StatusExpiry::ALL)
+            .collect()
+    }
+
+    fn move_expiry(&mut self, forward: bool) {
+        let options = self.expiry_options();
+        let index = options.iter().position(|e| *e == self.expiry).unwrap_or(0);
+        let index = if forward {
+            (index + 1).min(options.len() - 1)
+        } else {
+            index.saturating_sub(1)
+        };
+        self.expiry = options[index];
+    }
+
+    fn clear_custom_status(&mut self) {
+        self.text.clear();
+        self.emoji.clear();
+        self.expiry = StatusExpiry::Never;
+        self.error = None;
+    }
+
+    /// Resolves the emoji field, accepting shortcodes with or without colons.
+    fn resolve_emoji(&self) -> Result<Option<String>, String> {
+        let emoji = self.emoji.trim();
+        if emoji.is_empty() {
+            return Ok(None);
+        }
+        if !emoji.is_ascii() {
+            return Ok(Some(emoji.to_string()));
+        }
+        let shortcode = emoji.trim_matches(':');
+        Emoji::from_shortcode(shortcode)
+            .map(|emoji| Some(emoji.markup()))
+            .ok_or_else(|| format!("Unknown emoji :{shortcode}:"))
+    }
+
+    /// Builds the custom status, or records why it is invalid.
+    fn build_custom_status(&mut self, now: DateTime<Local>) -> Result<Option<CustomStatus>, ()> {
+        let emoji = self.resolve_emoji().map_err(|error| {
+            self.error = Some(error);
+        })?;
+        let text = self.text.trim();
+        if text.is_empty() && emoji.is_none() {
+            return Ok(None);
+        }
+
+        let mut custom_status = CustomStatus::default();
+        if !text.is_empty() {
+            custom_status = CustomStatus::new(text);
+        }
+        if let Some(emoji) = emoji {
+            custom_status = custom_status.with_emoji(emoji);
+        }
+        if let Some(expires_at) = self.expiry.expires_at(now) {
+            custom_status = custom_status.with_expires_at(expires_at);
+        }
+        Ok(Some(custom_status))
+    }
+
+    fn edit_text(&mut self, key: KeyEvent) {
+        let text = match self.field {
+            StatusMenuField::Text => &mut self.text,
+            StatusMenuField::Emoji => &mut self.emoji,
+            StatusMenuField::Status | StatusMenuField::Expiry => return,
+        };
+        match key.code {
+            KeyCode::Char(c) => text.push(c),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            _ => {}
+        }
+        self.error = None;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> StatusMenuAction {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            if key.code == KeyCode::Char('d') {
+                self.clear_custom_status();
+            }
+            return StatusMenuAction::None;
+        }
+
+        match key.code {
+            KeyCode::Esc => return StatusMenuAction::Close,
+            KeyCode::Tab => self.field = self.field.next(),
+            KeyCode::BackTab => self.field = self.field.previous(),
+            KeyCode::Enter => {
+                if let Ok(custom_status) = self.build_custom_status(Local::now()) {
+                    return StatusMenuAction::Save {
+                        status: self.status,
+                        custom_status,
+                    };
+                }
+            }
+            _ => match self.field {
+                StatusMenuField::Status => match key.code {
+                    KeyCode::Down | KeyCode::Char('j') => self.move_status(true),
+                    KeyCode::Up | KeyCode::Char('k') => self.move_status(false),
+                    _ => {}
+                },
+                StatusMenuField::Text | StatusMenuField::Emoji => self.edit_text(key),
+                StatusMenuField::Expiry => match key.code {
+                    KeyCode::Right | KeyCode::Char('l') => self.move_expiry(true),
+                    KeyCode::Left | KeyCode::Char('h') => self.move_expiry(false),
+                    _ => {}
+                },
+            },
+        }
+        StatusMenuAction::None
+    }
+}
+
+#[derive(Debug)]
+pub enum StatusMenuAction {
+    None,
+    Close,
+    Save {
+        status: PresenceStatus,
+        custom_status: Option<CustomStatus>,
+    },
+}
+
+pub struct StatusMenuWidget<'a> {
+    menu: &'a StatusMenu,
+    theme: &'a Theme,
+}
+
+impl<'a> StatusMenuWidget<'a> {
+    #[must_use]
+    pub fn new(menu: &'a StatusMenu, theme: &'a Theme) -> Self {
+        Self { menu, theme }
+    }
+
+    fn field_block(&self, title: &str, field: StatusMenuField) -> Block<'static> {
+        let border_style = if self.menu.field == field {
+            Style::default().fg(self.theme.accent)
+        } else {
+            self.theme.dimmed_style
+        };
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!(" {title} "))
+    }
+
+    fn status_lines(&self) -> Vec<Line<'_>> {
+        STATUSES
+            .iter()
+            .map(|status| {
+                let selected = *status == self.menu.status;
+                let mut style = self.theme.base_style;
+                if selected {
+                    style = style.add_modifier(Modifier::BOLD);
+                    if self.menu.field == StatusMenuField::Status {
+                        style = style.patch(self.theme.selection_style);
+                    }
+                }
+                let marker = if selected { ">" } else { " " };
+                Line::from(vec![
+                    Span::styled(format!("{marker} "), style),
+                    Span::styled(
+                        status.display_indicator(),
+                        Style::default().fg(presence_color(*status)),
+                    ),
+                    Span::styled(format!(" {}", status.label()), style),
+                ])
+            })
+            .collect()
+    }
+
+    fn expiry_line(&self) -> Line<'_> {
+        let focused = self.menu.field == StatusMenuField::Expiry;
+        let mut spans = Vec::new();
+        for expiry in self.menu.expiry_options() {
+            let mut style = if expiry == self.menu.expiry {
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                self.theme.base_style
+            };
+            if focused && expiry == self.menu.expiry {
+                style = style.patch(self.theme.selection_style);
+            }
+            spans.push(Span::styled(expiry.label(), style));
+            spans.push(Span::raw("  "));
+        }
+        Line::from(spans)
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let bindings = [
+            ("Tab", "Next field"),
+            ("Ctrl+d", "Clear status"),
+            ("Enter", "Save"),
+            ("Esc", "Cancel"),
+        ];
+
+        KeyHints::new(&bindings)
+            .style(FooterBarStyle::from_theme(self.theme))
+            .render(area, buf);
+    }
+}
+
+impl Widget for StatusMenuWidget<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let area = centered_rect(50, 60, area);
+
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent))
+            .title(" Set Status ");
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(6),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        Paragraph::new(self.status_lines())
+            .block(self.field_block("Status", StatusMenuField::Status))
+            .render(layout[0], buf);
+
+        Paragraph::new(self.menu.text.as_str())
+            .style(self.theme.base_style)
+            .block(self.field_block("Custom Status", StatusMenuField::Text))
+            .render(layout[1], buf);
+
+        Paragraph::new(self.menu.emoji.as_str())
+            .style(self.theme.base_style)
+            .block(self.field_block("Emoji", StatusMenuField::Emoji))
+            .render(layout[2], buf);
+
+        Paragraph::new(self.expiry_line())
+            .block(self.field_block("Clear After", StatusMenuField::Expiry))
+            .render(layout[3], buf);
+
+        if let Some(error) = self.menu.error.as_deref() {
+            Paragraph::new(Line::from(Span::styled(
+                error,
+                Style::default().fg(ratatui::style::Color::Red),
+            )))
+            .render(layout[4], buf);
+        }
+
+        self.render_footer(layout[5], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(menu: &mut StatusMenu, text: &str) {
+        for c in text.chars() {
+            menu.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_save_builds_custom_status() {
+        let mut menu = StatusMenu::new();
+        menu.open(PresenceStatus::Online, None);
+        menu.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        menu.handle_key(KeyEvent::from(KeyCode::Char('j')));
+
+        menu.handle_key(KeyEvent::from(KeyCode::Tab));
+        type_text(&mut menu, "Writing Rust");
+        menu.handle_key(KeyEvent::from(KeyCode::Tab));
+        type_text(&mut menu, ":crab:");
+        menu.handle_key(KeyEvent::from(KeyCode::Tab));
+        menu.handle_key(KeyEvent::from(KeyCode::Right));
+
+        let StatusMenuAction::Save {
+            status,
+            custom_status,
+        } = menu.handle_key(KeyEvent::from(KeyCode::Enter))
+        else {
+            panic!("expected a save");
+        };
+        assert_eq!(status, PresenceStatus::DoNotDisturb);
+        let custom_status = custom_status.expect("custom status");
+        assert_eq!(custom_status.label(), "🦀 Writing Rust");
+        assert!(custom_status.expires_at.is_some());
+    }
+
+    #[test]
+    fn test_unknown_emoji_and_clear() {
+        let mut menu = StatusMenu::new();
+        menu.open(
+            PresenceStatus::Idle,
+            Some(&CustomStatus::new("Away").with_emoji("☕")),
+        );
+        assert_eq!(menu.text, "Away");
+
+        menu.emoji = "not_an_emoji".to_string();
+        assert!(matches!(
+            menu.handle_key(KeyEvent::from(KeyCode::Enter)),
+            StatusMenuAction::None
+        ));
+        assert_eq!(menu.error.as_deref(), Some("Unknown emoji :not_an_emoji:"));
+
+        menu.handle_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL));
+        assert!(matches!(
+            menu.handle_key(KeyEvent::from(KeyCode::Enter)),
+            StatusMenuAction::Save {
+                status: PresenceStatus::Idle,
+                custom_status: None,
+            }
+        ));
+    }
+
+    #[test]
+    fn test_reopening_keeps_the_expiry() {
+        let expires_at = Utc::now() + Duration::hours(2);
+        let mut menu = StatusMenu::new();
+        menu.open(
+            PresenceStatus::Online,
+            Some(&CustomStatus::new("Lunch").with_expires_at(expires_at)),
+        );
+        assert_eq!(menu.expiry, StatusExpiry::Until(expires_at));
+
+        menu.field = StatusMenuField::Expiry;
+        menu.handle_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(menu.expiry, StatusExpiry::Never);
+        menu.handle_key(KeyEvent::from(KeyCode::Left));
+
+        let StatusMenuAction::Save { custom_status, .. } =
+            menu.handle_key(KeyEvent::from(KeyCode::Enter))
+        else {
+            panic!("expected a save");
+        };
+        assert_eq!(
+            custom_status.and_then(|custom| custom.expires_at),
+            Some(expires_at)
+        );
+    }
+
+    #[test]
+    fn test_expiry_today_is_next_midnight() {
+        let now = Local::now();
+        let expires_at = StatusExpiry::Today
+            .expires_at(now)
+            .expect("midnight exists")
+            .with_timezone(&Local);
+        assert!(expires_at > now);
+        assert_eq!(
+            expires_at.date_naive(),
+            now.date_naive() + Duration::days(1)
+        );
+        assert_eq!(StatusExpiry::Never.expires_at(now), None);
+    }
+}
@@ -5,7 +5,7 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Color;
 use regex::Regex;
 
-use crate::domain::entities::{MessageAuthor, User};
+use crate::domain::entities::{MessageAuthor, PresenceStatus, User};
 
 /// Removes emojis and symbols from the given string.
 ///
@@ -68,6 +68,17 @@ pub fn u32_to_color(color: u32) -> Color {
     )
 }
 
+/// Colour of the dot shown for a presence status.
+#[must_use]
+pub const fn presence_color(status: PresenceStatus) -> Color {
+    match status {
+        PresenceStatus::Online => Color::Green,
+        PresenceStatus::Idle => Color::Yellow,
+        PresenceStatus::DoNotDisturb => Color::Red,
+        PresenceStatus::Invisible | PresenceStatus::Offline => Color::DarkGray,
+    }
+}
+
 #[must_use]
 pub fn get_author_color(author: &MessageAuthor) -> Color {
     if let Some(c) = author.color() {
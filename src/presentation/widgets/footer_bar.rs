@@ -15,6 +15,7 @@ pub enum FocusContext {
     GuildsTree,
     MessagesList,
     MessageInput,
+    MemberList,
     ConfirmationModal,
 }
 
@@ -25,6 +26,7 @@ impl FocusContext {
             Self::GuildsTree => "GUILDS",
             Self::MessagesList => "MESSAGES",
             Self::MessageInput => "INPUT",
+            Self::MemberList => "MEMBERS",
             Self::ConfirmationModal => "CONFIRM",
         }
     }
@@ -210,3 +212,47 @@ impl Widget for FooterBar<'_> {
         }
     }
 }
+
+/// Footer hints of an overlay, each drawn as a key followed by what it does.
+pub struct KeyHints<'a> {
+    hints: &'a [(&'a str, &'a str)],
+    style: FooterBarStyle,
+}
+
+impl<'a> KeyHints<'a> {
+    #[must_use]
+    pub fn new(hints: &'a [(&'a str, &'a str)]) -> Self {
+        Self {
+            hints,
+            style: FooterBarStyle::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn style(mut self, style: FooterBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for KeyHints<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+
+        let mut spans = Vec::new();
+        for (i, (key, label)) in self.hints.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(" "));
+            }
+            spans.push(Span::styled(format!(" {key} "), self.style.label_style));
+            spans.push(Span::styled(format!(" {label} "), self.style.key_style));
+        }
+
+        for x in area.left()..area.right() {
+            buf[(x, area.y)].set_style(self.style.background);
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+}
@@ -5,7 +5,7 @@ use std::collections::HashSet;
 use crossterm::event::KeyEvent;
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, StatefulWidget, Widget},
@@ -13,19 +13,25 @@ use ratatui::{
 
 use crate::application::services::identity_resolver::IdentityResolver;
 use crate::domain::entities::{
-    Channel, ChannelId, ChannelKind, Guild, GuildFolder, GuildId, ReadState, User,
+    Channel, ChannelId, ChannelKind, Guild, GuildFolder, GuildId, NotificationSettingsStore,
+    NotificationTarget, PresenceStore, ReadState, RelationshipState, User,
 };
 use crate::domain::keybinding::Action;
 use crate::domain::ports::DirectMessageChannel;
 use crate::presentation::commands::CommandRegistry;
 use crate::presentation::theme::Theme;
-use crate::presentation::ui::utils::clean_text;
+use crate::presentation::ui::utils::{clean_text, presence_color};
+
+/// Shown in place of the presence dot for group DMs.
+const GROUP_DM_MARKER: &str = "◇ ";
 
 /// Unique identifier for nodes in the guilds tree.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TreeNodeId {
     DirectMessages,
 
+    Friends,
+
     DirectMessageUser(String),
 
     Folder(Option<u64>),
@@ -43,6 +49,7 @@ impl std::fmt::Display for TreeNodeId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::DirectMessages => write!(f, "dm"),
+            Self::Friends => write!(f, "dm:friends"),
             Self::DirectMessageUser(id) => write!(f, "dm:{id}"),
             Self::Folder(id) => write!(f, "folder:{id:?}"),
             Self::Guild(id) => write!(f, "guild:{id}"),
@@ -62,9 +69,15 @@ pub enum GuildsTreeAction {
 
     SelectDirectMessage(String),
 
+    /// The friends entry of the DM section was activated.
+    OpenFriends,
+
     YankId(String),
 
     LoadGuildChannels(GuildId),
+
+    /// A channel was expanded to show its threads.
+    LoadArchivedThreads(ChannelId),
 }
 
 /// State for the guilds tree widget.
@@ -74,6 +87,8 @@ pub struct GuildsTreeState {
     focused: bool,
 
     list_state: ratatui::widgets::ListState,
+    /// Area the rows were drawn in during the last frame.
+    list_area: Rect,
 }
 
 impl GuildsTreeState {
@@ -84,6 +99,7 @@ impl GuildsTreeState {
             selected: None,
             focused: false,
             list_state: ratatui::widgets::ListState::default(),
+            list_area: Rect::default(),
         }
     }
 
@@ -187,20 +203,25 @@ impl GuildsTreeState {
             }
             Some(Action::NavigateRight) => {
                 if let Some(selected) = &self.selected {
-                    let can_expand = matches!(
-                        selected,
+                    let can_expand = match selected {
                         TreeNodeId::Guild(_)
-                            | TreeNodeId::Category(_)
-                            | TreeNodeId::DirectMessages
-                            | TreeNodeId::Folder(_)
-                    );
+                        | TreeNodeId::Category(_)
+                        | TreeNodeId::DirectMessages
+                        | TreeNodeId::Folder(_) => true,
+                        TreeNodeId::Channel(id) => data.can_have_threads(*id),
+                        _ => false,
+                    };
 
                     if can_expand && !self.expanded.contains(selected) {
                         self.expanded.insert(selected.clone());
-                        if let TreeNodeId::Guild(id) = selected
-                            && data.channels(*id).is_none()
-                        {
-                            return Some(GuildsTreeAction::LoadGuildChannels(*id));
+                        match selected {
+                            TreeNodeId::Guild(id) if data.channels(*id).is_none() => {
+                                return Some(GuildsTreeAction::LoadGuildChannels(*id));
+                            }
+                            TreeNodeId::Channel(id) => {
+                                return Some(GuildsTreeAction::LoadArchivedThreads(*id));
+                            }
+                            _ => {}
                         }
                     } else {
                         return self.get_selection_action();
@@ -223,31 +244,11 @@ impl GuildsTreeState {
                 }
                 None
             }
-            Some(Action::Select) => {
-                if let Some(selected) = self.selected.clone() {
-                    match &selected {
-                        TreeNodeId::Guild(id) => {
-                            self.toggle_current();
-                            if self.expanded.contains(&selected) && data.channels(*id).is_none() {
-                                return Some(GuildsTreeAction::LoadGuildChannels(*id));
-                            }
-                            None
-                        }
-                        TreeNodeId::Category(_)
-                        | TreeNodeId::DirectMessages
-                        | TreeNodeId::Folder(_) => {
-                            self.toggle_current();
-                            None
-                        }
-                        _ => self.get_selection_action(),
-                    }
-                } else {
-                    None
-                }
-            }
+            Some(Action::Select) => self.activate_selected(data),
             Some(Action::YankId) => self.selected.as_ref().map(|node| {
                 let id = match node {
                     TreeNodeId::DirectMessages => "direct_messages".to_string(),
+                    TreeNodeId::Friends => "friends".to_string(),
                     TreeNodeId::DirectMessageUser(id) => id.clone(),
                     TreeNodeId::Folder(id) => format!("{id:?}"),
                     TreeNodeId::Guild(id) | TreeNodeId::Placeholder(id) => id.to_string(),
@@ -259,6 +260,46 @@ impl GuildsTreeState {
         }
     }
 
+    /// Selects the row under a left click and activates it like [`Action::Select`].
+    pub fn handle_click(
+        &mut self,
+        position: Position,
+        data: &GuildsTreeData,
+        style: &GuildsTreeStyle,
+        use_display_name: bool,
+    ) -> Option<GuildsTreeAction> {
+        if !self.list_area.contains(position) {
+            return None;
+        }
+
+        let row = usize::from(position.y - self.list_area.y);
+        let index = self.list_state.offset() + row;
+        let flattened = data.flatten(self, u16::MAX, style, use_display_name);
+        let node = flattened.get(index)?;
+
+        self.selected = Some(node.id.clone());
+        self.list_state.select(Some(index));
+        self.activate_selected(data)
+    }
+
+    fn activate_selected(&mut self, data: &GuildsTreeData) -> Option<GuildsTreeAction> {
+        let selected = self.selected.clone()?;
+        match &selected {
+            TreeNodeId::Guild(id) => {
+                self.toggle_current();
+                if self.expanded.contains(&selected) && data.channels(*id).is_none() {
+                    return Some(GuildsTreeAction::LoadGuildChannels(*id));
+                }
+                None
+            }
+            TreeNodeId::Category(_) | TreeNodeId::DirectMessages | TreeNodeId::Folder(_) => {
+                self.toggle_current();
+                None
+            }
+            _ => self.get_selection_action(),
+        }
+    }
+
     fn get_selection_action(&self) -> Option<GuildsTreeAction> {
         self.selected.as_ref().and_then(|node| match node {
             TreeNodeId::Channel(id) => Some(GuildsTreeAction::SelectChannel(*id)),
@@ -266,6 +307,7 @@ impl GuildsTreeState {
             TreeNodeId::DirectMessageUser(id) => {
                 Some(GuildsTreeAction::SelectDirectMessage(id.clone()))
             }
+            TreeNodeId::Friends => Some(GuildsTreeAction::OpenFriends),
             _ => None,
         })
     }
@@ -296,6 +338,8 @@ pub struct GuildsTreeStyle {
     pub tree_guide_style: Style,
     pub folder_style: Style,
     pub mention_style: Style,
+    /// Muted guilds, categories and channels.
+    pub muted_style: Style,
 }
 
 impl GuildsTreeStyle {
@@ -316,6 +360,7 @@ impl GuildsTreeStyle {
             tree_guide_style: theme.dimmed_style,
             folder_style: Style::default().fg(theme.accent),
             mention_style: Style::default().fg(theme.accent),
+            muted_style: theme.dimmed_style,
             ..Self::default()
         }
     }
@@ -351,6 +396,9 @@ impl Default for GuildsTreeStyle {
             tree_guide_style: Style::default().fg(Color::Gray),
             folder_style: Style::default().fg(Color::Blue),
             mention_style: Style::default().fg(Color::Red),
+            muted_style: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
         }
     }
 }
@@ -397,6 +445,9 @@ pub struct GuildsTreeData {
     active_guild_id: Option<GuildId>,
     active_channel_id: Option<ChannelId>,
     active_dm_user_id: Option<String>,
+    presences: PresenceStore,
+    relationships: RelationshipState,
+    notification_settings: NotificationSettingsStore,
 }
 
 impl GuildsTreeData {
@@ -411,6 +462,9 @@ impl GuildsTreeData {
             active_guild_id: None,
             active_channel_id: None,
             active_dm_user_id: None,
+            presences: PresenceStore::new(),
+            relationships: RelationshipState::new(),
+            notification_settings: NotificationSettingsStore::new(),
         }
     }
 
@@ -509,7 +563,12 @@ impl GuildsTreeData {
         };
 
         orphans.sort_by(sort_fn);
-        threads.sort_by(sort_fn);
+        threads.sort_by_key(|thread| {
+            (
+                thread.is_archived(),
+                std::cmp::Reverse(thread.id().as_u64()),
+            )
+        });
 
         let mut categories: Vec<CategoryNode> = category_map
             .into_values()
@@ -544,6 +603,47 @@ impl GuildsTreeData {
         self.dm_users = users;
     }
 
+    /// Shares the presence store used for DM recipient status dots.
+    pub fn set_presences(&mut self, presences: PresenceStore) {
+        self.presences = presences;
+    }
+
+    /// Shares the relationship state used for the pending friend request count.
+    pub fn set_relationships(&mut self, relationships: RelationshipState) {
+        self.relationships = relationships;
+    }
+
+    /// Shares the notification settings used to dim muted entries.
+    pub fn set_notification_settings(&mut self, settings: NotificationSettingsStore) {
+        self.notification_settings = settings;
+    }
+
+    fn is_guild_muted(&self, guild_id: GuildId) -> bool {
+        self.notification_settings
+            .is_guild_muted(guild_id, chrono::Utc::now())
+    }
+
+    fn is_channel_muted(&self, guild_id: Option<GuildId>, channel: &Channel) -> bool {
+        self.notification_settings.is_muted(
+            NotificationTarget::for_channel(guild_id, channel, |id| self.get_channel(id)),
+            chrono::Utc::now(),
+        )
+    }
+
+    fn is_dm_muted(&self, dm: &DirectMessageChannel) -> bool {
+        dm.channel_id.parse::<u64>().is_ok_and(|channel_id| {
+            self.notification_settings.is_muted(
+                NotificationTarget {
+                    guild_id: None,
+                    channel_id: ChannelId(channel_id),
+                    parent_id: None,
+                    category_id: None,
+                },
+                chrono::Utc::now(),
+            )
+        })
+    }
+
     pub fn remove_guild(&mut self, guild_id: GuildId) {
         self.guilds.retain(|g| g.id() != guild_id);
         self.channels_by_guild.remove(&guild_id);
@@ -571,6 +671,19 @@ impl GuildsTreeData {
         }
     }
 
+    /// Whether `channel_id` can list threads beneath it. Text and announcement
+    /// channels always can, so their archived threads are reachable without
+    /// active ones; forum posts are shown as cards instead.
+    #[must_use]
+    pub fn can_have_threads(&self, channel_id: ChannelId) -> bool {
+        self.get_channel(channel_id).is_some_and(|channel| {
+            matches!(
+                channel.kind(),
+                ChannelKind::Text | ChannelKind::Announcement
+            )
+        })
+    }
+
     #[must_use]
     pub fn guilds(&self) -> &[Guild] {
         &self.guilds
@@ -858,6 +971,23 @@ impl GuildsTreeData {
         }
     }
 
+    /// Joined recipient names for group DMs, the preferred recipient name otherwise.
+    fn dm_name(dm: &DirectMessageChannel, use_display_name: bool) -> String {
+        if dm.is_group {
+            return clean_text(&dm.display_name(use_display_name));
+        }
+        let user = User::new(
+            dm.recipient_id().to_string(),
+            dm.recipient_username().to_string(),
+            dm.recipient_discriminator(),
+            None,
+            false,
+            None,
+        )
+        .with_global_name(dm.recipient_global_name().unwrap_or_default());
+        clean_text(&IdentityResolver::with_preference(use_display_name).resolve(&user))
+    }
+
     fn render_dm_node<'a>(
         &'a self,
         nodes: &mut Vec<FlattenedNode<'a>>,
@@ -902,6 +1032,8 @@ impl GuildsTreeData {
         });
 
         if expanded {
+            self.render_friends_node(nodes, width, style, children_base_indent);
+
             for (i, dm) in self.dm_users.iter().enumerate() {
                 let is_last = i == self.dm_users.len() - 1;
                 let prefix = if is_last { "└── " } else { "├── " };
@@ -909,34 +1041,34 @@ impl GuildsTreeData {
                 let is_active = self.active_dm_user_id() == Some(&dm.channel_id);
                 let current_style = if is_active {
                     style.active_channel_style
+                } else if self.is_dm_muted(dm) {
+                    style.muted_style
                 } else if dm.has_unread {
                     style.channel_unread_style
                 } else {
                     style.dm_style
                 };
 
-                let mut clean_name = clean_text(&dm.recipient_username);
-
-                if let Ok(_channel_id) = dm.channel_id.parse::<u64>() {
-                    let user = User::new(
-                        dm.recipient_id.clone(),
-                        dm.recipient_username.clone(),
-                        &dm.recipient_discriminator,
-                        None,
-                        false,
-                        None,
-                    )
-                    .with_global_name(dm.recipient_global_name.clone().unwrap_or_default());
-
-                    let preferred_name =
-                        IdentityResolver::with_preference(use_display_name).resolve(&user);
-                    clean_name = clean_text(&preferred_name);
-                }
+                let clean_name = Self::dm_name(dm, use_display_name);
+
+                let status = (!dm.is_group)
+                    .then(|| self.presences.status_str(dm.recipient_id()))
+                    .flatten();
+                let group_marker = if dm.is_group { GROUP_DM_MARKER } else { "@ " };
+                let marker = status.map_or_else(
+                    || Span::styled(group_marker, current_style),
+                    |status| {
+                        Span::styled(
+                            format!("{} ", status.display_indicator()),
+                            Style::default().fg(presence_color(status)),
+                        )
+                    },
+                );
 
                 let mut spans = vec![
                     Span::styled(children_base_indent, style.tree_guide_style),
                     Span::styled(prefix, style.tree_guide_style),
-                    Span::styled("@ ", current_style),
+                    marker,
                     Span::styled(clean_name.clone(), current_style),
                 ];
 
@@ -976,6 +1108,47 @@ impl GuildsTreeData {
         }
     }
 
+    fn render_friends_node<'a>(
+        &self,
+        nodes: &mut Vec<FlattenedNode<'a>>,
+        width: u16,
+        style: &GuildsTreeStyle,
+        children_base_indent: &'a str,
+    ) {
+        let prefix = if self.dm_users.is_empty() {
+            "└── "
+        } else {
+            "├── "
+        };
+        let label = "♥ Friends";
+        let mut spans = vec![
+            Span::styled(children_base_indent, style.tree_guide_style),
+            Span::styled(prefix, style.tree_guide_style),
+            Span::styled(label, style.dm_style),
+        ];
+
+        let pending = self.relationships.pending_incoming_count();
+        if pending > 0 {
+            let badge = format!("{pending}");
+            let used_width = u16::try_from(
+                unicode_width::UnicodeWidthStr::width(children_base_indent)
+                    + unicode_width::UnicodeWidthStr::width(prefix)
+                    + unicode_width::UnicodeWidthStr::width(label)
+                    + unicode_width::UnicodeWidthStr::width(badge.as_str()),
+            )
+            .unwrap_or(u16::MAX);
+            let padding_needed = width.saturating_sub(used_width).saturating_sub(1);
+            spans.push(Span::raw(" ".repeat(usize::from(padding_needed.max(1)))));
+            spans.push(Span::styled(badge, style.mention_style));
+        }
+
+        nodes.push(FlattenedNode {
+            id: TreeNodeId::Friends,
+            label: Line::from(spans),
+            depth: 1,
+        });
+    }
+
     fn render_folder_node<'a>(
         &'a self,
         nodes: &mut Vec<FlattenedNode<'a>>,
@@ -1054,6 +1227,8 @@ impl GuildsTreeData {
         let is_active = self.active_guild_id() == Some(guild_id);
         let guild_style = if is_active {
             style.active_guild_style
+        } else if self.is_guild_muted(guild_id) {
+            style.muted_style
         } else if guild.has_unread() {
             style.guild_unread_style
         } else {
@@ -1144,6 +1319,8 @@ impl GuildsTreeData {
         let is_active = self.active_guild_id() == Some(guild_id);
         let guild_style = if is_active {
             style.active_guild_style
+        } else if self.is_guild_muted(guild_id) {
+            style.muted_style
         } else if guild.has_unread() {
             style.guild_unread_style
         } else {
@@ -1223,18 +1400,17 @@ impl GuildsTreeData {
     ) {
         for (i, channel) in channels.orphans.iter().enumerate() {
             let is_last = i == channels.orphans.len() - 1 && channels.categories.is_empty();
-            let prefix = if is_last { "└── " } else { "├── " };
-            if let Some(node) = self.create_channel_node(
+            self.push_channel_with_threads(
+                nodes,
+                channels,
                 channel,
-                1,
-                prefix,
+                (1, "", is_last),
+                state,
                 width,
                 style,
                 base_indent_1,
                 base_indent_2,
-            ) {
-                nodes.push(node);
-            }
+            );
         }
 
         for (i, cat_node) in channels.categories.iter().enumerate() {
@@ -1263,7 +1439,14 @@ impl GuildsTreeData {
                 Span::styled(base_indent_2, style.tree_guide_style),
                 Span::styled(cat_prefix, style.tree_guide_style),
                 Span::styled(arrow, style.tree_guide_style),
-                Span::styled(clean_name.to_uppercase(), style.category_style),
+                Span::styled(
+                    clean_name.to_uppercase(),
+                    if self.is_channel_muted(category.guild_id(), category) {
+                        style.muted_style
+                    } else {
+                        style.category_style
+                    },
+                ),
             ];
 
             if cat_mentions > 0 {
@@ -1307,29 +1490,98 @@ impl GuildsTreeData {
             if expanded {
                 for (j, child) in cat_node.children.iter().enumerate() {
                     let is_last_child = j == cat_node.children.len() - 1;
-                    let mut prefix = child_indent_comp.to_string();
-                    prefix.push_str(if is_last_child {
-                        "└── "
-                    } else {
-                        "├── "
-                    });
-
-                    if let Some(node) = self.create_channel_node(
+                    self.push_channel_with_threads(
+                        nodes,
+                        channels,
                         child,
-                        2,
-                        &prefix,
+                        (2, child_indent_comp, is_last_child),
+                        state,
                         width,
                         style,
                         base_indent_1,
                         base_indent_2,
-                    ) {
-                        nodes.push(node);
-                    }
+                    );
                 }
             }
         }
     }
 
+    /// Pushes a channel row and, when the channel is expanded, its threads.
+    /// `position` is the row depth, the guide indent and whether it is the
+    /// last sibling.
+    #[allow(clippy::too_many_arguments)]
+    fn push_channel_with_threads<'a>(
+        &'a self,
+        nodes: &mut Vec<FlattenedNode<'a>>,
+        channels: &'a SortedGuildChannels,
+        channel: &'a Channel,
+        position: (usize, &str, bool),
+        state: &GuildsTreeState,
+        width: u16,
+        style: &GuildsTreeStyle,
+        base_indent_1: &'a str,
+        base_indent_2: &'a str,
+    ) {
+        let (depth, indent, is_last) = position;
+        let threads: Vec<&Channel> = if channel.kind() == ChannelKind::Forum {
+            Vec::new()
+        } else {
+            channels
+                .threads
+                .iter()
+                .filter(|thread| thread.parent_id() == Some(channel.id()))
+                .collect()
+        };
+        let expanded =
+            !threads.is_empty() && state.expanded.contains(&TreeNodeId::Channel(channel.id()));
+
+        let mut prefix = indent.to_string();
+        prefix.push_str(if is_last { "└── " } else { "├── " });
+        if !threads.is_empty() {
+            prefix.push_str(if expanded { "▾ " } else { "▸ " });
+        }
+
+        let Some(node) = self.create_channel_node(
+            channel,
+            depth,
+            &prefix,
+            width,
+            style,
+            base_indent_1,
+            base_indent_2,
+        ) else {
+            return;
+        };
+        nodes.push(node);
+
+        if !expanded {
+            return;
+        }
+
+        let mut thread_indent = indent.to_string();
+        thread_indent.push_str(if is_last { "    " } else { "│   " });
+        for (i, thread) in threads.iter().enumerate() {
+            let mut prefix = thread_indent.clone();
+            prefix.push_str(if i == threads.len() - 1 {
+                "└── "
+            } else {
+                "├── "
+            });
+
+            if let Some(node) = self.create_channel_node(
+                thread,
+                depth + 1,
+                &prefix,
+                width,
+                style,
+                base_indent_1,
+                base_indent_2,
+            ) {
+                nodes.push(node);
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_channel_node<'a>(
         &'a self,
@@ -1351,11 +1603,18 @@ impl GuildsTreeData {
         let is_active = self.active_channel_id() == Some(channel.id());
         let channel_style = if is_active {
             style.active_channel_style
+        } else if self.is_channel_muted(channel.guild_id(), channel) {
+            style.muted_style
         } else if channel.has_unread() {
             style.channel_unread_style
         } else {
             style.channel_style
         };
+        let channel_style = if channel.is_archived() {
+            channel_style.add_modifier(Modifier::DIM)
+        } else {
+            channel_style
+        };
 
         let channel_icon = channel.kind().prefix();
         let channel_icon_width =
@@ -1503,6 +1762,7 @@ impl StatefulWidget for GuildsTree<'_> {
             ));
 
         let inner_area = block.inner(area);
+        state.list_area = inner_area;
 
         let flattened_nodes =
             self.data
@@ -1620,6 +1880,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_dm_lists_every_recipient() {
+        use crate::domain::ports::DirectMessageRecipient;
+
+        let recipient = |id: &str, username: &str| DirectMessageRecipient {
+            id: id.to_string(),
+            username: username.to_string(),
+            discriminator: "0".to_string(),
+            global_name: None,
+        };
+        let mut data = GuildsTreeData::new();
+        data.set_dm_users(vec![DirectMessageChannel::new(
+            "10",
+            vec![recipient("1", "alice"), recipient("2", "bob")],
+        )]);
+        let mut state = GuildsTreeState::new();
+        state.expand(TreeNodeId::DirectMessages);
+
+        let style = GuildsTreeStyle::default();
+        let nodes = data.flatten(&state, 100, &style, true);
+        let node = nodes
+            .iter()
+            .find(|node| node.id == TreeNodeId::DirectMessageUser("10".to_string()))
+            .unwrap();
+        let label: String = node
+            .label
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert!(label.contains(GROUP_DM_MARKER));
+        assert!(label.contains("alice, bob"));
+    }
+
+    #[test]
+    fn test_click_selects_and_expands_guild() {
+        let mut state = GuildsTreeState::new();
+        let mut data = GuildsTreeData::new();
+        data.set_guilds(vec![Guild::new(1_u64, "One"), Guild::new(2_u64, "Two")]);
+        let style = GuildsTreeStyle::default();
+
+        let area = Rect::new(0, 0, 30, 10);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(GuildsTree::new(&data), area, &mut buf, &mut state);
+
+        let action = state.handle_click(Position::new(5, 3), &data, &style, true);
+        assert!(matches!(
+            action,
+            Some(GuildsTreeAction::LoadGuildChannels(id)) if id == GuildId(2)
+        ));
+        assert_eq!(state.selected(), Some(&TreeNodeId::Guild(GuildId(2))));
+        assert!(
+            state
+                .handle_click(Position::new(5, 9), &data, &style, true)
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_flatten_includes_all_guilds() {
         let mut data = GuildsTreeData::new();
@@ -1714,4 +2032,80 @@ mod tests {
             "Thread should not appear in the flattened tree"
         );
     }
+
+    #[test]
+    fn test_channel_without_active_threads_fetches_archived_threads() {
+        let mut data = GuildsTreeData::new();
+        let guild_id = GuildId(1);
+        data.set_guilds(vec![Guild::new(guild_id, "Test Guild")]);
+        data.set_channels(
+            guild_id,
+            vec![Channel::new(10_u64, "general", ChannelKind::Text).with_guild(guild_id)],
+        );
+
+        let registry = CommandRegistry::default();
+        let style = GuildsTreeStyle::default();
+        let mut state = GuildsTreeState::new();
+        state.expand(TreeNodeId::Guild(guild_id));
+        state.select(TreeNodeId::Channel(ChannelId(10)));
+
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        assert!(matches!(
+            state.handle_key(right, &data, &registry, &style, true),
+            Some(GuildsTreeAction::LoadArchivedThreads(ChannelId(10)))
+        ));
+    }
+
+    #[test]
+    fn test_threads_listed_under_expanded_parent() {
+        let mut data = GuildsTreeData::new();
+        let guild_id = GuildId(1);
+        data.set_guilds(vec![Guild::new(guild_id, "Test Guild")]);
+        data.set_channels(
+            guild_id,
+            vec![
+                Channel::new(10_u64, "general", ChannelKind::Text).with_guild(guild_id),
+                Channel::new(20_u64, "old", ChannelKind::PublicThread)
+                    .with_guild(guild_id)
+                    .with_parent(10_u64)
+                    .with_thread_metadata(crate::domain::entities::ThreadMetadata {
+                        archived: true,
+                        auto_archive_duration: 1440,
+                        archive_timestamp: String::new(),
+                        locked: false,
+                        invitable: None,
+                        create_timestamp: None,
+                    }),
+                Channel::new(21_u64, "new", ChannelKind::PublicThread)
+                    .with_guild(guild_id)
+                    .with_parent(10_u64),
+            ],
+        );
+
+        let registry = CommandRegistry::default();
+        let style = GuildsTreeStyle::default();
+        let mut state = GuildsTreeState::new();
+        state.expand(TreeNodeId::Guild(guild_id));
+        state.select(TreeNodeId::Channel(ChannelId(10)));
+
+        let right = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        assert!(matches!(
+            state.handle_key(right, &data, &registry, &style, true),
+            Some(GuildsTreeAction::LoadArchivedThreads(ChannelId(10)))
+        ));
+
+        let ids: Vec<TreeNodeId> = data
+            .flatten(&state, 100, &style, true)
+            .into_iter()
+            .map(|node| node.id)
+            .collect();
+        assert_eq!(
+            &ids[ids.len() - 3..],
+            &[
+                TreeNodeId::Channel(ChannelId(10)),
+                TreeNodeId::Channel(ChannelId(21)),
+                TreeNodeId::Channel(ChannelId(20)),
+            ]
+        );
+    }
 }
@@ -1,5 +1,8 @@
 use crate::domain::ConnectionStatus;
+use crate::domain::entities::{CustomStatus, PresenceStatus};
 use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::presence_color;
+use chrono::Utc;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -80,6 +83,8 @@ pub struct HeaderBar<'a> {
     app_name: &'a str,
     version: &'a str,
     connection_status: ConnectionStatus,
+    presence: Option<PresenceStatus>,
+    custom_status: Option<String>,
     style: HeaderBarStyle,
 }
 
@@ -90,6 +95,8 @@ impl<'a> HeaderBar<'a> {
             app_name,
             version,
             connection_status: ConnectionStatus::default(),
+            presence: None,
+            custom_status: None,
             style: HeaderBarStyle::default(),
         }
     }
@@ -100,6 +107,20 @@ impl<'a> HeaderBar<'a> {
         self
     }
 
+    /// Shows our own status, and the custom status unless it has expired.
+    #[must_use]
+    pub fn presence(
+        mut self,
+        status: PresenceStatus,
+        custom_status: Option<&CustomStatus>,
+    ) -> Self {
+        self.presence = Some(status);
+        self.custom_status = custom_status
+            .filter(|custom| !custom.is_empty() && !custom.is_expired(Utc::now()))
+            .map(CustomStatus::label);
+        self
+    }
+
     #[must_use]
     pub const fn style(mut self, style: HeaderBarStyle) -> Self {
         self.style = style;
@@ -124,8 +145,24 @@ impl<'a> HeaderBar<'a> {
         let status_text = self.connection_status.display_text().to_string();
 
         let text = format!(" {indicator} {status_text} ");
-        let width = text.chars().count() as u16;
-        let spans = vec![Span::styled(text, status_style)];
+        let mut width = text.chars().count() as u16;
+        let mut spans = Vec::new();
+
+        if let Some(presence) = self.presence {
+            let mut presence_text =
+                format!(" {} {}", presence.display_indicator(), presence.label());
+            if let Some(custom_status) = &self.custom_status {
+                presence_text.push_str(" · ");
+                presence_text.push_str(custom_status);
+            }
+            presence_text.push(' ');
+            width += presence_text.chars().count() as u16;
+            spans.push(Span::styled(
+                presence_text,
+                Style::default().fg(presence_color(presence)),
+            ));
+        }
+        spans.push(Span::styled(text, status_style));
 
         (spans, width)
     }
@@ -198,4 +235,19 @@ mod tests {
         assert_eq!(header.app_name, "oxicord");
         assert_eq!(header.version, "0.0.1");
     }
+
+    #[test]
+    fn test_header_bar_presence() {
+        let custom = CustomStatus::new("Writing Rust").with_emoji("🦀");
+        let header =
+            HeaderBar::new("oxicord", "0.0.1").presence(PresenceStatus::Idle, Some(&custom));
+        let (spans, _) = header.build_status_spans();
+        assert_eq!(spans[0].content, " ◐ Idle · 🦀 Writing Rust ");
+
+        let expired = custom.with_expires_at(Utc::now() - chrono::Duration::minutes(1));
+        let header =
+            HeaderBar::new("oxicord", "0.0.1").presence(PresenceStatus::Idle, Some(&expired));
+        let (spans, _) = header.build_status_spans();
+        assert_eq!(spans[0].content, " ◐ Idle ");
+    }
 }
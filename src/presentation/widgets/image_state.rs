@@ -194,8 +194,7 @@ impl ImageManager {
         std::env::var("TMUX").is_ok()
             || std::env::var("ZELLIJ").is_ok()
             || std::env::var("TERM_PROGRAM")
-                .map(|v| v.contains("tmux") || v.contains("zellij"))
-                .unwrap_or(false)
+                .is_ok_and(|v| v.contains("tmux") || v.contains("zellij"))
     }
 
     #[must_use]
@@ -210,6 +209,12 @@ impl ImageManager {
         self.picker.protocol_type()
     }
 
+    /// Whether the protocol can draw an image as small as a single cell row.
+    #[must_use]
+    pub fn supports_inline_images(&self) -> bool {
+        self.protocol_type() != ProtocolType::Halfblocks
+    }
+
     #[must_use]
     pub const fn picker(&self) -> &Picker {
         &self.picker
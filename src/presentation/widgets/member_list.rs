@@ -0,0 +1,632 @@
+//! Member list sidebar for the current guild channel.
+
+use std::collections::HashMap;
+
+use crossterm::event::KeyEvent;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::domain::entities::{
+    ChannelId, GuildId, MemberListEntry, MemberListGroup, MemberListItem, MemberListOp, Role,
+    RoleId,
+};
+use crate::domain::keybinding::Action;
+use crate::presentation::commands::CommandRegistry;
+use crate::presentation::theme::Theme;
+use crate::presentation::ui::utils::{presence_color, u32_to_color};
+
+/// Members Discord syncs per subscribed range.
+const RANGE_SIZE: u32 = 100;
+/// Discord accepts at most this many ranges per channel subscription.
+const MAX_RANGES: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberListAction {
+    OpenProfile(MemberListEntry),
+    /// The selection moved into member ranges that are not subscribed yet.
+    RequestRanges(Vec<(u32, u32)>),
+    /// Leave the member list for the messages list.
+    FocusMessages,
+}
+
+/// Item list of a channel's member list, filled in range by range as Discord syncs it.
+///
+/// Rows that have not been synced yet are `None` so the list can be scrolled
+/// through its full length without holding every member.
+#[derive(Debug, Default)]
+pub struct MemberListState {
+    guild_id: Option<GuildId>,
+    channel_id: Option<ChannelId>,
+    list_id: Option<String>,
+    items: Vec<Option<MemberListItem>>,
+    member_count: u32,
+    online_count: u32,
+    subscribed: Vec<(u32, u32)>,
+    selected: usize,
+    scroll_offset: usize,
+    focused: bool,
+}
+
+impl MemberListState {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts an empty list for a channel; the typing subscription covers the first range.
+    ///
+    /// `list_id` is the id Discord syncs the channel's list under, when it is known.
+    /// Reopening the channel already shown keeps its list, as the gateway keeps it in sync.
+    pub fn reset(
+        &mut self,
+        guild_id: Option<GuildId>,
+        channel_id: Option<ChannelId>,
+        list_id: Option<String>,
+    ) {
+        if self.guild_id == guild_id && self.channel_id == channel_id {
+            return;
+        }
+        let focused = self.focused;
+        *self = Self {
+            guild_id,
+            channel_id,
+            list_id,
+            subscribed: vec![(0, RANGE_SIZE - 1)],
+            focused,
+            ..Self::default()
+        };
+    }
+
+    #[must_use]
+    pub const fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+
+    #[must_use]
+    pub const fn channel_id(&self) -> Option<ChannelId> {
+        self.channel_id
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[Option<MemberListItem>] {
+        &self.items
+    }
+
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    #[must_use]
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    #[must_use]
+    pub fn selected_member(&self) -> Option<&MemberListEntry> {
+        match self.items.get(self.selected) {
+            Some(Some(MemberListItem::Member(member))) => Some(member),
+            _ => None,
+        }
+    }
+
+    /// The synced entry of a member, if they are in a loaded range.
+    #[must_use]
+    pub fn find_member(&self, user_id: &str) -> Option<&MemberListEntry> {
+        self.items.iter().find_map(|item| match item {
+            Some(MemberListItem::Member(member)) if member.user_id == user_id => Some(member),
+            _ => None,
+        })
+    }
+
+    /// Applies a `GUILD_MEMBER_LIST_UPDATE`, ignoring lists of other guilds.
+    ///
+    /// Updates for any list but the channel's, such as those of a previous
+    /// channel's subscription, are ignored. When the channel's list id is not
+    /// known, the first update carrying a SYNC picks it.
+    pub fn apply_update(
+        &mut self,
+        guild_id: GuildId,
+        list_id: &str,
+        (member_count, online_count): (u32, u32),
+        groups: &[MemberListGroup],
+        ops: Vec<MemberListOp>,
+    ) {
+        if self.guild_id != Some(guild_id) {
+            return;
+        }
+        match self.list_id.as_deref() {
+            Some(current) if current != list_id => return,
+            Some(_) => {}
+            None => {
+                if !ops.iter().any(|op| matches!(op, MemberListOp::Sync { .. })) {
+                    return;
+                }
+                self.list_id = Some(list_id.to_string());
+            }
+        }
+
+        for op in ops {
+            self.apply_op(op);
+        }
+
+        let total: usize = groups
+            .iter()
+            .filter(|group| group.count > 0)
+            .map(|group| 1 + group.count as usize)
+            .sum();
+        self.items.resize(total, None);
+
+        self.member_count = member_count;
+        self.online_count = online_count;
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+    }
+
+    fn apply_op(&mut self, op: MemberListOp) {
+        match op {
+            MemberListOp::Sync { range, items } => {
+                let start = range.0 as usize;
+                if self.items.len() < start + items.len() {
+                    self.items.resize(start + items.len(), None);
+                }
+                for (offset, item) in items.into_iter().enumerate() {
+                    self.items[start + offset] = Some(item);
+                }
+            }
+            MemberListOp::Insert { index, item } => {
+                let index = index as usize;
+                if index > self.items.len() {
+                    self.items.resize(index, None);
+                }
+                self.items.insert(index, Some(item));
+            }
+            MemberListOp::Update { index, item } => {
+                let index = index as usize;
+                if index >= self.items.len() {
+                    self.items.resize(index + 1, None);
+                }
+                self.items[index] = Some(item);
+            }
+            MemberListOp::Delete { index } => {
+                let index = index as usize;
+                if index < self.items.len() {
+                    self.items.remove(index);
+                }
+            }
+            MemberListOp::Invalidate { range } => {
+                let end = (range.1 as usize + 1).min(self.items.len());
+                for item in self.items.iter_mut().take(end).skip(range.0 as usize) {
+                    *item = None;
+                }
+            }
+        }
+    }
+
+    /// Ranges covering the first page and the pages around the selection.
+    fn wanted_ranges(&self) -> Vec<(u32, u32)> {
+        let page = u32::try_from(self.selected).unwrap_or(u32::MAX) / RANGE_SIZE;
+        let mut ranges = vec![(0, RANGE_SIZE - 1)];
+        for page in [page, page + 1] {
+            let range = (page * RANGE_SIZE, page * RANGE_SIZE + RANGE_SIZE - 1);
+            if !ranges.contains(&range) && ranges.len() < MAX_RANGES {
+                ranges.push(range);
+            }
+        }
+        ranges
+    }
+
+    fn select(&mut self, index: usize) -> Option<MemberListAction> {
+        self.selected = index.min(self.items.len().saturating_sub(1));
+
+        let ranges = self.wanted_ranges();
+        if ranges == self.subscribed {
+            return None;
+        }
+        self.subscribed.clone_from(&ranges);
+        Some(MemberListAction::RequestRanges(ranges))
+    }
+
+    pub fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        registry: &CommandRegistry,
+    ) -> Option<MemberListAction> {
+        match registry.find_action(key) {
+            Some(Action::NavigateDown) => self.select(self.selected + 1),
+            Some(Action::NavigateUp) => self.select(self.selected.saturating_sub(1)),
+            Some(Action::ScrollToTop) => self.select(0),
+            Some(Action::ScrollToBottom) => self.select(self.items.len().saturating_sub(1)),
+            Some(Action::Select | Action::NavigateRight) => self
+                .selected_member()
+                .cloned()
+                .map(MemberListAction::OpenProfile),
+            Some(Action::NavigateLeft | Action::Cancel) => Some(MemberListAction::FocusMessages),
+            _ => None,
+        }
+    }
+
+    /// Selects the row at `row` lines below the top of the list.
+    pub fn click_row(&mut self, row: usize) -> Option<MemberListAction> {
+        let index = self.scroll_offset + row;
+        if index >= self.items.len() {
+            return None;
+        }
+        self.select(index)
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) -> Option<MemberListAction> {
+        self.select(self.selected.saturating_add_signed(delta))
+    }
+
+    fn clamp_scroll(&mut self, height: usize) {
+        if height == 0 {
+            return;
+        }
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if self.selected >= self.scroll_offset + height {
+            self.scroll_offset = self.selected + 1 - height;
+        }
+        self.scroll_offset = self
+            .scroll_offset
+            .min(self.items.len().saturating_sub(height));
+    }
+}
+
+pub struct MemberListStyle {
+    pub border_style: Style,
+    pub border_style_focused: Style,
+    pub title_style: Style,
+    pub group_style: Style,
+    pub member_style: Style,
+    pub offline_style: Style,
+    pub activity_style: Style,
+    pub selected_style: Style,
+}
+
+impl MemberListStyle {
+    #[must_use]
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            border_style: theme.border_style,
+            border_style_focused: Style::default().fg(theme.accent),
+            title_style: Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+            group_style: theme.dimmed_style.add_modifier(Modifier::BOLD),
+            member_style: theme.base_style,
+            offline_style: theme.dimmed_style,
+            activity_style: theme.dimmed_style,
+            selected_style: theme.selection_style,
+        }
+    }
+}
+
+pub struct MemberList<'a> {
+    roles: Option<&'a HashMap<RoleId, Role>>,
+    style: MemberListStyle,
+}
+
+impl<'a> MemberList<'a> {
+    #[must_use]
+    pub const fn new(style: MemberListStyle) -> Self {
+        Self { roles: None, style }
+    }
+
+    /// Roles of the guild, used for group names and member colours.
+    #[must_use]
+    pub const fn roles(mut self, roles: Option<&'a HashMap<RoleId, Role>>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    fn role(&self, id: &str) -> Option<&Role> {
+        self.roles?.get(&RoleId(id.parse().ok()?))
+    }
+
+    fn group_line(&self, group: &MemberListGroup) -> Line<'static> {
+        let name = match group.id.as_str() {
+            "online" => "Online".to_string(),
+            "offline" => "Offline".to_string(),
+            id => self
+                .role(id)
+                .map_or_else(|| "Role".to_string(), |role| role.name.clone()),
+        };
+        Line::from(Span::styled(
+            format!("{} — {}", name.to_uppercase(), group.count),
+            self.style.group_style,
+        ))
+    }
+
+    /// Colour of the member's highest coloured role.
+    fn member_color(&self, member: &MemberListEntry) -> Option<Color> {
+        member
+            .roles
+            .iter()
+            .filter_map(|id| self.role(id))
+            .filter(|role| role.color != 0)
+            .max_by_key(|role| role.position)
+            .map(|role| u32_to_color(role.color))
+    }
+
+    fn member_line(&self, member: &MemberListEntry) -> Line<'static> {
+        let mut name_style = if member.status.is_online() {
+            self.style.member_style
+        } else {
+            self.style.offline_style
+        };
+        if member.status.is_online()
+            && let Some(color) = self.member_color(member)
+        {
+            name_style = name_style.fg(color);
+        }
+
+        let mut spans = vec![
+            Span::styled(
+                format!(" {} ", member.status.display_indicator()),
+                Style::default().fg(presence_color(member.status)),
+            ),
+            Span::styled(member.display_name().to_string(), name_style),
+        ];
+        if member.bot {
+            spans.push(Span::styled(" BOT", self.style.group_style));
+        }
+        if let Some(activity) = member.activities.first() {
+            spans.push(Span::styled(
+                format!("  {}", activity.label()),
+                self.style.activity_style,
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+impl StatefulWidget for MemberList<'_> {
+    type State = MemberListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let border_style = if state.is_focused() {
+            self.style.border_style_focused
+        } else {
+            self.style.border_style
+        };
+        let title = if state.member_count > 0 {
+            format!(" Members — {} online ", state.online_count)
+        } else {
+            " Members ".to_string()
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(Span::styled(title, self.style.title_style));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if state.items.is_empty() {
+            Paragraph::new(" Loading members...")
+                .style(self.style.offline_style)
+                .render(inner, buf);
+            return;
+        }
+
+        let height = usize::from(inner.height);
+        state.clamp_scroll(height);
+
+        for (row, index) in (state.scroll_offset..state.items.len())
+            .take(height)
+            .enumerate()
+        {
+            let line = match &state.items[index] {
+                Some(MemberListItem::Group(group)) => self.group_line(group),
+                Some(MemberListItem::Member(member)) => self.member_line(member),
+                None => Line::from(Span::styled(" …", self.style.offline_style)),
+            };
+            let y = inner.y + u16::try_from(row).unwrap_or(u16::MAX);
+            let row_area = Rect::new(inner.x, y, inner.width, 1);
+            if state.focused && index == state.selected {
+                buf.set_style(row_area, self.style.selected_style);
+            }
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::PresenceStatus;
+
+    fn member(id: &str) -> MemberListItem {
+        MemberListItem::Member(MemberListEntry {
+            user_id: id.to_string(),
+            username: format!("user{id}"),
+            global_name: None,
+            nick: None,
+            bot: false,
+            roles: Vec::new(),
+            status: PresenceStatus::Online,
+            activities: Vec::new(),
+        })
+    }
+
+    fn group(id: &str, count: u32) -> MemberListGroup {
+        MemberListGroup {
+            id: id.to_string(),
+            count,
+        }
+    }
+
+    #[test]
+    fn test_updates_keep_unsynced_rows_as_placeholders() {
+        let mut state = MemberListState::new();
+        state.reset(Some(GuildId(1)), Some(ChannelId(2)), None);
+
+        state.apply_update(
+            GuildId(1),
+            "everyone",
+            (250, 250),
+            &[group("online", 250)],
+            vec![MemberListOp::Sync {
+                range: (0, 2),
+                items: vec![
+                    MemberListItem::Group(group("online", 250)),
+                    member("1"),
+                    member("2"),
+                ],
+            }],
+        );
+        assert_eq!(state.items().len(), 251);
+        assert!(state.items()[3].is_none());
+
+        state.apply_update(
+            GuildId(1),
+            "everyone",
+            (249, 249),
+            &[group("online", 249)],
+            vec![MemberListOp::Delete { index: 1 }],
+        );
+        assert_eq!(state.items().len(), 250);
+        assert_eq!(state.items()[1], Some(member("2")));
+
+        state.apply_update(GuildId(9), "everyone", (0, 0), &[], Vec::new());
+        assert_eq!(state.items().len(), 250, "other guilds are ignored");
+    }
+
+    #[test]
+    fn test_updates_for_other_lists_are_ignored() {
+        let mut state = MemberListState::new();
+        state.reset(Some(GuildId(1)), Some(ChannelId(2)), None);
+
+        state.apply_update(
+            GuildId(1),
+            "other",
+            (5, 5),
+            &[group("online", 5)],
+            vec![MemberListOp::Update {
+                index: 1,
+                item: member("9"),
+            }],
+        );
+        assert!(state.items().is_empty(), "a list must start with a SYNC");
+
+        state.apply_update(
+            GuildId(1),
+            "everyone",
+            (2, 2),
+            &[group("online", 2)],
+            vec![MemberListOp::Sync {
+                range: (0, 2),
+                items: vec![
+                    MemberListItem::Group(group("online", 2)),
+                    member("1"),
+                    member("2"),
+                ],
+            }],
+        );
+        let synced = state.items().to_vec();
+
+        state.apply_update(
+            GuildId(1),
+            "other",
+            (1, 1),
+            &[group("online", 1)],
+            vec![MemberListOp::Sync {
+                range: (0, 1),
+                items: vec![MemberListItem::Group(group("online", 1)), member("9")],
+            }],
+        );
+        assert_eq!(state.items(), synced.as_slice());
+
+        state.reset(Some(GuildId(1)), Some(ChannelId(3)), None);
+        state.apply_update(
+            GuildId(1),
+            "other",
+            (1, 1),
+            &[group("online", 1)],
+            vec![MemberListOp::Sync {
+                range: (0, 1),
+                items: vec![MemberListItem::Group(group("online", 1)), member("9")],
+            }],
+        );
+        assert_eq!(state.items()[1], Some(member("9")));
+    }
+
+    #[test]
+    fn test_known_list_id_ignores_syncs_of_other_lists() {
+        let mut state = MemberListState::new();
+        state.reset(
+            Some(GuildId(1)),
+            Some(ChannelId(2)),
+            Some("everyone".to_string()),
+        );
+
+        let sync = |members: u32| {
+            vec![MemberListOp::Sync {
+                range: (0, 1),
+                items: vec![
+                    MemberListItem::Group(group("online", members)),
+                    member(&members.to_string()),
+                ],
+            }]
+        };
+        state.apply_update(GuildId(1), "other", (1, 1), &[group("online", 1)], sync(1));
+        assert!(
+            state.items().is_empty(),
+            "a stale SYNC must not pick the list"
+        );
+
+        state.apply_update(
+            GuildId(1),
+            "everyone",
+            (2, 2),
+            &[group("online", 2)],
+            sync(2),
+        );
+        assert_eq!(state.items()[1], Some(member("2")));
+    }
+
+    #[test]
+    fn test_moving_down_requests_following_ranges() {
+        let registry = CommandRegistry::default();
+        let mut state = MemberListState::new();
+        state.reset(Some(GuildId(1)), Some(ChannelId(2)), None);
+        state.apply_update(
+            GuildId(1),
+            "everyone",
+            (300, 300),
+            &[group("online", 300)],
+            vec![MemberListOp::Sync {
+                range: (0, 0),
+                items: vec![MemberListItem::Group(group("online", 300))],
+            }],
+        );
+
+        assert_eq!(
+            state.click_row(0),
+            Some(MemberListAction::RequestRanges(vec![(0, 99), (100, 199)]))
+        );
+        assert_eq!(
+            state.select(150),
+            Some(MemberListAction::RequestRanges(vec![
+                (0, 99),
+                (100, 199),
+                (200, 299)
+            ]))
+        );
+        assert_eq!(state.select(160), None);
+        assert_eq!(
+            state.handle_key(KeyEvent::from(crossterm::event::KeyCode::Enter), &registry),
+            None,
+            "unsynced rows cannot be opened"
+        );
+    }
+}
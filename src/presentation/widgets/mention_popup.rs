@@ -6,8 +6,12 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, StatefulWidget, Widget},
 };
 
-use crate::application::services::autocomplete_service::AutocompleteState;
+use crate::application::services::autocomplete_service::{
+    AutocompleteKind, AutocompleteState, AutocompleteSuggestion,
+};
 use crate::application::services::identity_resolver::IdentityResolver;
+use crate::domain::entities::Emoji;
+use crate::presentation::ui::utils::u32_to_color;
 
 pub struct MentionPopup {
     resolver: IdentityResolver,
@@ -36,15 +40,44 @@ impl StatefulWidget for MentionPopup {
 
         Widget::render(Clear, area, buf);
 
+        let title = match state.kind {
+            AutocompleteKind::Mention => " Mention User ",
+            AutocompleteKind::Emoji => " Emoji ",
+            AutocompleteKind::Channel => " Mention Channel ",
+        };
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" Mention User ")
+            .title(title)
             .title_style(Style::default().add_modifier(Modifier::BOLD));
 
         let items: Vec<ListItem> = state
             .results
             .iter()
-            .map(|user| ListItem::new(Span::raw(self.resolver.resolve(user))))
+            .map(|suggestion| match suggestion {
+                AutocompleteSuggestion::User(user) => {
+                    ListItem::new(Span::raw(self.resolver.resolve(user)))
+                }
+                AutocompleteSuggestion::Role(role) => {
+                    let style = if role.color == 0 {
+                        Style::default()
+                    } else {
+                        Style::default().fg(u32_to_color(role.color))
+                    };
+                    ListItem::new(Span::styled(format!("@{}", role.name), style))
+                }
+                AutocompleteSuggestion::Emoji(emoji) => {
+                    let icon = match emoji {
+                        Emoji::Unicode { value, .. } => *value,
+                        Emoji::Custom(_) => "◆",
+                    };
+                    ListItem::new(Span::raw(format!("{icon} :{}:", emoji.name())))
+                }
+                AutocompleteSuggestion::Channel(channel) => ListItem::new(Span::raw(format!(
+                    "{}{}",
+                    channel.kind().prefix(),
+                    channel.name()
+                ))),
+            })
             .collect();
 
         let list = List::new(items)
@@ -9,7 +9,7 @@ use ratatui::{
 };
 use tui_textarea::TextArea;
 
-use crate::domain::entities::MessageId;
+use crate::domain::entities::{ChannelId, Emoji, MessageId, RoleId};
 use crate::domain::keybinding::Action;
 use crate::presentation::commands::CommandRegistry;
 use crate::presentation::theme::Theme;
@@ -48,6 +48,7 @@ pub enum MessageInputAction {
     CancelReply,
     ExitInput,
     OpenEditor,
+    OpenEmojiPicker,
     Paste,
 }
 
@@ -59,6 +60,7 @@ pub struct MessageInputState<'a> {
     attachments: Vec<PathBuf>,
     scroll_offset: usize,
     last_width: usize,
+    /// Inserted tokens (`@user`, `#channel`, `:emoji:`) mapped to the markup sent in their place.
     mentions: std::collections::HashMap<String, String>,
 }
 
@@ -110,16 +112,48 @@ impl MessageInputState<'_> {
         self.has_channel
     }
 
+    /// The input with inserted tokens replaced by their markup.
+    ///
+    /// Only whole tokens are replaced: markup already in the text, such as
+    /// custom emoji from the picker, code spans and longer words like
+    /// `#general-chat` or URLs are left as typed.
     #[must_use]
     pub fn message_content(&self) -> String {
-        let mut content = self.value();
+        let content = self.value();
         let mut sorted_mentions: Vec<_> = self.mentions.iter().collect();
-        sorted_mentions.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
-
-        for (name, id) in sorted_mentions {
-            content = content.replace(name, &format!("<@{id}>"));
+        sorted_mentions.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+
+        let mut result = String::with_capacity(content.len());
+        let mut rest = content.as_str();
+        let ends_token = |after: &str| after.chars().next().is_none_or(|c| !is_token_char(c));
+        let mut prev: Option<char> = None;
+        let mut in_code = false;
+        while let Some(c) = rest.chars().next() {
+            if !in_code && let Some(markup) = markup_len(rest) {
+                result.push_str(&rest[..markup]);
+                rest = &rest[markup..];
+                prev = Some('>');
+                continue;
+            }
+            let at_word_start = !in_code && prev.is_none_or(|p| !is_token_char(p));
+            if at_word_start
+                && let Some((name, markup)) = sorted_mentions
+                    .iter()
+                    .find(|(name, _)| rest.strip_prefix(name.as_str()).is_some_and(ends_token))
+            {
+                result.push_str(markup);
+                rest = &rest[name.len()..];
+                prev = name.chars().last();
+                continue;
+            }
+            if c == '`' {
+                in_code = !in_code;
+            }
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+            prev = Some(c);
         }
-        content
+        result
     }
 
     #[must_use]
@@ -199,7 +233,12 @@ impl MessageInputState<'_> {
                 index += line.len() + 1;
             }
         }
-        index + col
+        let col_bytes = lines.get(row).map_or(col, |line| {
+            line.char_indices()
+                .nth(col)
+                .map_or(line.len(), |(byte_index, _)| byte_index)
+        });
+        index + col_bytes
     }
 
     fn get_visual_info(&self, width: usize) -> (Vec<String>, usize, usize) {
@@ -334,10 +373,47 @@ impl MessageInputState<'_> {
     }
 
     pub fn insert_mention(&mut self, trigger_index: usize, resolved_name: &str, user_id: &str) {
-        let content = self.value();
-        let mention_text = format!("@{resolved_name} ");
         self.mentions
-            .insert(format!("@{resolved_name}"), user_id.to_string());
+            .insert(format!("@{resolved_name}"), format!("<@{user_id}>"));
+        self.replace_token(trigger_index, &format!("@{resolved_name} "));
+    }
+
+    /// Replaces the `@query` token with the role name, sent as `<@&id>`.
+    pub fn insert_role_mention(&mut self, trigger_index: usize, name: &str, role_id: RoleId) {
+        self.mentions
+            .insert(format!("@{name}"), format!("<@&{role_id}>"));
+        self.replace_token(trigger_index, &format!("@{name} "));
+    }
+
+    /// Replaces the `#query` token with the channel name, sent as `<#id>`.
+    pub fn insert_channel_mention(
+        &mut self,
+        trigger_index: usize,
+        name: &str,
+        channel_id: ChannelId,
+    ) {
+        self.mentions
+            .insert(format!("#{name}"), format!("<#{channel_id}>"));
+        self.replace_token(trigger_index, &format!("#{name} "));
+    }
+
+    /// Replaces the `:query` token with the emoji. Unicode emoji are inserted
+    /// as-is; custom emoji show as `:name:` and are sent as their markup.
+    pub fn insert_emoji(&mut self, trigger_index: usize, emoji: &Emoji) {
+        let text = match emoji {
+            Emoji::Unicode { value, .. } => (*value).to_string(),
+            Emoji::Custom(custom) => {
+                let shortcode = format!(":{}:", custom.name);
+                self.mentions.insert(shortcode.clone(), custom.markup());
+                shortcode
+            }
+        };
+        self.replace_token(trigger_index, &format!("{text} "));
+    }
+
+    /// Replaces the text between `trigger_index` and the cursor.
+    fn replace_token(&mut self, trigger_index: usize, replacement: &str) {
+        let content = self.value();
 
         if trigger_index >= content.len() {
             return;
@@ -355,7 +431,7 @@ impl MessageInputState<'_> {
         let prefix = &content[..trigger_index];
         let suffix = &content[cursor_idx..];
 
-        let new_content = format!("{prefix}{mention_text}{suffix}");
+        let new_content = format!("{prefix}{replacement}{suffix}");
 
         self.set_content(&new_content);
     }
@@ -515,6 +591,7 @@ impl MessageInputState<'_> {
                 Some(MessageInputAction::StartTyping)
             }
             Some(Action::OpenEditor) => Some(MessageInputAction::OpenEditor),
+            Some(Action::OpenEmojiPicker) => Some(MessageInputAction::OpenEmojiPicker),
             Some(Action::ClearInput) => {
                 self.clear();
                 None
@@ -778,6 +855,18 @@ impl MessageInputState<'_> {
     }
 }
 
+/// Length of the `<…>` markup at the start of `text`, if any.
+fn markup_len(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('<')?;
+    let end = inner.find(|c: char| c == '>' || c.is_whitespace())?;
+    (end > 0 && inner[end..].starts_with('>')).then_some(end + 2)
+}
+
+/// Characters that continue a word, so a token next to them is part of a longer one.
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '/' | '#' | '@')
+}
+
 impl Default for MessageInputState<'_> {
     fn default() -> Self {
         Self::new()
@@ -858,6 +947,7 @@ impl MessageInput {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::CustomEmoji;
     use crossterm::event::KeyModifiers;
     use test_case::test_case;
 
@@ -1043,6 +1133,66 @@ mod tests {
         assert!(!state.mentions.contains_key("@Antonio"));
     }
 
+    #[test]
+    fn test_insert_emoji_replaces_shortcode_token() {
+        use tui_textarea::CursorMove;
+
+        let mut state = MessageInputState::new();
+        state.set_content("nice :roc");
+        state.textarea.move_cursor(CursorMove::End);
+        state.insert_emoji(5, &Emoji::from_shortcode("rocket").unwrap());
+        assert_eq!(state.value(), "nice 🚀 ");
+
+        state.insert_text_at_cursor(":bl");
+        state.insert_emoji(10, &Emoji::Custom(CustomEmoji::new(42, "blob")));
+        assert_eq!(state.value(), "nice 🚀 :blob: ");
+        assert_eq!(state.message_content(), "nice 🚀 <:blob:42> ");
+    }
+
+    #[test]
+    fn test_picker_markup_is_not_substituted_again() {
+        use tui_textarea::CursorMove;
+
+        let mut state = MessageInputState::new();
+        state.set_content(":bl");
+        state.textarea.move_cursor(CursorMove::End);
+        state.insert_emoji(0, &Emoji::Custom(CustomEmoji::new(42, "blob")));
+        state.insert_text_at_cursor(&CustomEmoji::new(42, "blob").markup());
+
+        assert_eq!(state.value(), ":blob: <:blob:42>");
+        assert_eq!(state.message_content(), "<:blob:42> <:blob:42>");
+    }
+
+    #[test]
+    fn test_insert_channel_mention_sends_channel_markup() {
+        use tui_textarea::CursorMove;
+
+        let mut state = MessageInputState::new();
+        state.set_content("see #gen");
+        state.textarea.move_cursor(CursorMove::End);
+        state.insert_channel_mention(4, "general", ChannelId(99));
+
+        assert_eq!(state.value(), "see #general ");
+        assert_eq!(state.message_content(), "see <#99> ");
+    }
+
+    #[test]
+    fn test_channel_mention_only_replaces_whole_tokens() {
+        use tui_textarea::CursorMove;
+
+        let mut state = MessageInputState::new();
+        state.set_content("#gen");
+        state.textarea.move_cursor(CursorMove::End);
+        state.insert_channel_mention(0, "general", ChannelId(99));
+        state.insert_text_at_cursor("and #general. not #general-chat, ");
+        state.insert_text_at_cursor("`#general` or x.io/#general");
+
+        assert_eq!(
+            state.message_content(),
+            "<#99> and <#99>. not #general-chat, `#general` or x.io/#general"
+        );
+    }
+
     #[test_case(KeyCode::Delete, KeyModifiers::CONTROL, "hello world", true, " world" ; "ctrl+delete from head")]
     #[test_case(KeyCode::Backspace, KeyModifiers::CONTROL, "hello world", false, "hello " ; "ctrl+backspace from end")]
     #[test_case(KeyCode::Char('w'), KeyModifiers::CONTROL, "hello world", false, "hello " ; "ctrl+w from end")]
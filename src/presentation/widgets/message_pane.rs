@@ -3,21 +3,28 @@ use std::sync::Arc;
 
 use crate::application::services::identity_resolver::IdentityResolver;
 use crate::application::services::markdown_parser::{
-    MdBlock, MdInline, MentionResolver, parse_markdown,
+    MdBlock, MdInline, MentionResolver, contains_relative_timestamp, custom_emoji_ids, link_urls,
+    parse_markdown,
 };
+use crate::application::services::notification_rules::{MessageScope, NotificationRules};
 use crate::application::services::url_extractor::UrlExtractor;
 use crate::domain::entities::{
-    ChannelId, Embed, ForumThread, ImageId, Message, MessageId, RelationshipState, USER_MENTION_RE,
+    ChannelId, Embed, ForumFilter, ForumTag, ForumThread, ImageId, Message, MessageId,
+    PresenceStore, Reaction, ReactionEmoji, RelationshipState, Role, USER_MENTION_RE, User,
 };
 use crate::domain::keybinding::Action;
 
+use crate::infrastructure::image::emoji_url;
 use crate::presentation::commands::CommandRegistry;
-use crate::presentation::services::markdown_renderer::MarkdownRenderer;
+use crate::presentation::services::markdown_renderer::{
+    EMOJI_PLACEHOLDER, MarkdownRenderer, display_url, is_link_style,
+};
+use crate::presentation::ui::utils::presence_color;
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, Padding, Paragraph, StatefulWidget, Widget},
@@ -34,6 +41,8 @@ const SCROLLBAR_MARGIN: u16 = 2;
 const CHANNEL_NAME_PREFIX: &str = "[ ";
 const CHANNEL_NAME_SUFFIX: &str = " ]";
 const DM_CHANNEL_PREFIX: &str = "[ ";
+/// Shown after the channel title; clicking the header opens the pinned messages.
+const PINS_MARKER: &str = " \u{1f4cc} ";
 const TIMESTAMP_WIDTH: usize = 6;
 const CONTENT_INDENT: usize = 6;
 const EMBED_INDENT: usize = 6;
@@ -79,12 +88,20 @@ pub struct UiMessage {
     pub parsed_content: Vec<MdBlock>,
     /// Image attachments for this message.
     pub image_attachments: Vec<ImageAttachment>,
+    /// Images of the custom emoji used in the content, one per emoji id.
+    pub emoji_images: Vec<ImageAttachment>,
+    /// Where emoji images are drawn within `rendered_content`.
+    pub emoji_slots: Vec<EmojiSlot>,
+    /// Where links are drawn within `rendered_content`.
+    pub link_spans: Vec<LinkSpan>,
     /// Pre-calculated embed layouts.
     pub rendered_embeds: Vec<RenderedEmbed>,
     /// Cached reply preview line
     pub reply_preview: Option<Line<'static>>,
     pub group: MessageGroup,
     pub rendered_generation: Option<usize>,
+    /// Matched by a notification rule with the `highlight` action.
+    pub highlighted: bool,
 }
 
 impl UiMessage {
@@ -105,16 +122,29 @@ impl UiMessage {
             }
         }
 
+        let mut emoji_images: Vec<ImageAttachment> = Vec::new();
+        for emoji_id in custom_emoji_ids(&parsed_content, true) {
+            let url = emoji_url(&emoji_id);
+            if !emoji_images.iter().any(|img| img.url == url) {
+                let id = ImageId::from_url(&url);
+                emoji_images.push(ImageAttachment::new(id, url, None, None));
+            }
+        }
+
         Self {
             message: Arc::new(message),
             estimated_height: 1,
             rendered_content: None,
             parsed_content,
             image_attachments,
+            emoji_images,
+            emoji_slots: Vec::new(),
+            link_spans: Vec::new(),
             rendered_embeds: Vec::new(),
             reply_preview: None,
             group: MessageGroup::Start,
             rendered_generation: None,
+            highlighted: false,
         }
     }
 
@@ -142,19 +172,126 @@ impl UiMessage {
     }
 
     /// Collects image IDs that need loading.
+    ///
+    /// Emoji images are only requested once the layout has placed them.
     #[must_use]
     pub fn collect_image_loads(&self) -> Vec<(ImageId, String)> {
+        let emoji_images = self
+            .emoji_images
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.emoji_slots.iter().any(|slot| slot.image == *idx))
+            .map(|(_, img)| img);
+
         self.image_attachments
             .iter()
+            .chain(emoji_images)
             .filter(|img| img.needs_load())
             .map(|img| (img.id.clone(), img.url.clone()))
             .collect()
     }
+
+    /// Attachment and emoji images of this message.
+    pub fn images_mut(&mut self) -> impl Iterator<Item = &mut ImageAttachment> {
+        self.image_attachments
+            .iter_mut()
+            .chain(self.emoji_images.iter_mut())
+    }
+}
+
+/// A custom emoji image placed over an [`EMOJI_PLACEHOLDER`] in the rendered content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmojiSlot {
+    /// Index into [`UiMessage::emoji_images`].
+    pub image: usize,
+    pub line: u16,
+    pub column: u16,
+}
+
+/// Finds the placeholders left by the renderer in wrapped text and pairs them,
+/// in order, with the emoji they stand for.
+fn locate_emoji_slots(
+    text: &Text<'_>,
+    emoji_ids: &[String],
+    images: &[ImageAttachment],
+) -> Vec<EmojiSlot> {
+    let mut emoji_ids = emoji_ids.iter();
+    let mut slots = Vec::new();
+
+    for (line_idx, line) in text.lines.iter().enumerate() {
+        let mut column = 0;
+        for span in &line.spans {
+            if span.content == EMOJI_PLACEHOLDER
+                && let Some(emoji_id) = emoji_ids.next()
+            {
+                let url = emoji_url(emoji_id);
+                if let Some(image) = images.iter().position(|img| img.url == url) {
+                    slots.push(EmojiSlot {
+                        image,
+                        line: u16::try_from(line_idx).unwrap_or(u16::MAX),
+                        column: u16::try_from(column).unwrap_or(u16::MAX),
+                    });
+                }
+            }
+            column += span.width();
+        }
+    }
+
+    slots
+}
+
+/// A link drawn over columns `start..end` of a line of the rendered content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkSpan {
+    pub url: String,
+    pub line: u16,
+    pub start: u16,
+    pub end: u16,
+}
+
+/// Finds the link-styled spans in wrapped text and pairs them, in order, with
+/// the URLs they display; a link wrapped over several lines gets one span per line.
+fn locate_link_spans(text: &Text<'_>, urls: &[String]) -> Vec<LinkSpan> {
+    let mut urls = urls.iter().peekable();
+    let mut current: Option<&String> = None;
+    let mut remaining = String::new();
+    let mut links = Vec::new();
+
+    for (line_idx, line) in text.lines.iter().enumerate() {
+        let mut column = 0;
+        for span in &line.spans {
+            let width = span.width();
+            let content = span.content.as_ref();
+            if is_link_style(span.style) && !content.is_empty() {
+                if !remaining.starts_with(content)
+                    && let Some(next) = urls.next_if(|url| display_url(url).starts_with(content))
+                {
+                    current = Some(next);
+                    remaining = display_url(next);
+                }
+                if let Some(url) = current
+                    && remaining.starts_with(content)
+                {
+                    remaining.drain(..content.len());
+                    links.push(LinkSpan {
+                        url: url.clone(),
+                        line: u16::try_from(line_idx).unwrap_or(u16::MAX),
+                        start: u16::try_from(column).unwrap_or(u16::MAX),
+                        end: u16::try_from(column + width).unwrap_or(u16::MAX),
+                    });
+                }
+            }
+            column += width;
+        }
+    }
+
+    links
 }
 
 struct HashMapResolver<'a> {
     authors: &'a HashMap<String, String>,
     channels: &'a HashMap<String, String>,
+    roles: &'a HashMap<String, (String, u32)>,
 }
 
 impl MentionResolver for HashMapResolver<'_> {
@@ -165,6 +302,10 @@ impl MentionResolver for HashMapResolver<'_> {
     fn resolve_channel(&self, channel_id: &str) -> Option<String> {
         self.channels.get(channel_id).cloned()
     }
+
+    fn resolve_role(&self, role_id: &str) -> Option<(String, u32)> {
+        self.roles.get(role_id).cloned()
+    }
 }
 
 fn calculate_embed_layout(
@@ -246,10 +387,47 @@ pub enum MessagePaneAction {
     YankUrl(String),
     YankId(String),
     OpenAttachments(MessageId),
+    OpenLink(String),
     JumpToReply(MessageId),
     LoadHistory,
+    /// Load messages after the last one shown; only raised for detached history.
+    LoadNewer,
     OpenThread(ChannelId),
     CloseThread,
+    /// Starts a thread from the message.
+    StartThread(MessageId),
+    /// Joins the open thread, or leaves it if already a member.
+    ToggleThreadMembership,
+    ToggleReaction {
+        message_id: MessageId,
+        emoji: ReactionEmoji,
+        add: bool,
+    },
+    PickReaction(MessageId),
+    /// Pins the message when `pinned`, unpins it otherwise.
+    TogglePin {
+        message_id: MessageId,
+        pinned: bool,
+    },
+    OpenPins,
+    /// Opens the composer for a new post in the forum.
+    NewForumPost,
+    /// Reloads the forum listing with a new sort order or tag filter.
+    SetForumFilter(ForumFilter),
+    /// Shows the profile of the first user; the others can be switched to.
+    OpenProfile(Vec<String>),
+}
+
+/// A message's author followed by the users it mentions.
+fn profile_user_ids(message: &Message) -> Vec<String> {
+    let mut ids = vec![message.author().id.clone()];
+    for user in message.mentions() {
+        let id = user.id_str();
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -260,6 +438,7 @@ pub enum LoadingState {
     Error,
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub struct MessagePaneData {
     channel_id: Option<ChannelId>,
     channel_name: Option<String>,
@@ -273,12 +452,22 @@ pub struct MessagePaneData {
     typing_indicator: Option<String>,
     authors: HashMap<String, String>,
     channels: HashMap<String, String>,
+    /// Role names and colours of the current guild, keyed by role id.
+    roles: HashMap<String, (String, u32)>,
     last_layout_width: Option<u16>,
     last_show_spoilers: Option<bool>,
     last_image_preview: Option<bool>,
+    /// Whether the terminal can draw custom emoji as images.
+    inline_emoji_images: bool,
     is_dirty: bool,
     use_display_name: bool,
     authors_generation: usize,
+    /// The messages are a window of older history rather than the live tail of the channel.
+    detached: bool,
+    /// A page of newer messages has been requested and has not arrived yet.
+    loading_newer: bool,
+    highlight_rules: NotificationRules,
+    highlight_scope: MessageScope,
 }
 
 impl MessagePaneData {
@@ -297,12 +486,18 @@ impl MessagePaneData {
             typing_indicator: None,
             authors: HashMap::new(),
             channels: HashMap::new(),
+            roles: HashMap::new(),
             last_layout_width: None,
             last_show_spoilers: None,
             last_image_preview: None,
+            inline_emoji_images: false,
             is_dirty: true,
             use_display_name,
             authors_generation: 0,
+            detached: false,
+            loading_newer: false,
+            highlight_rules: NotificationRules::default(),
+            highlight_scope: MessageScope::default(),
         }
     }
 
@@ -314,7 +509,33 @@ impl MessagePaneData {
         self.messages.clear();
         self.loading_state = LoadingState::Loading;
         self.error_message = None;
+        self.detached = false;
+        self.loading_newer = false;
+        self.is_dirty = true;
+    }
+
+    /// Moves the loaded channel into a new pane, keeping the author, channel
+    /// and role lookups on both sides.
+    #[must_use]
+    pub fn split_off(&mut self) -> Self {
+        let mut split = Self::new(self.use_display_name);
+        split.channel_id = self.channel_id;
+        split.channel_name.clone_from(&self.channel_name);
+        split.channel_topic.clone_from(&self.channel_topic);
+        split.channel_icon.clone_from(&self.channel_icon);
+        split.online_count = self.online_count;
+        split.messages = std::mem::take(&mut self.messages);
+        split.loading_state = self.loading_state;
+        split.is_dm = self.is_dm;
+        split.authors.clone_from(&self.authors);
+        split.channels.clone_from(&self.channels);
+        split.roles.clone_from(&self.roles);
+        split.inline_emoji_images = self.inline_emoji_images;
+        split.detached = self.detached;
+        split.highlight_rules = self.highlight_rules.clone();
+        split.highlight_scope.clone_from(&self.highlight_scope);
         self.is_dirty = true;
+        split
     }
 
     pub fn set_channel_topic(&mut self, topic: Option<String>) {
@@ -342,15 +563,75 @@ impl MessagePaneData {
                 );
             }
         }
-        self.messages = messages.into_iter().map(UiMessage::new).collect();
+        self.messages = messages
+            .into_iter()
+            .map(|msg| self.ui_message(msg))
+            .collect();
         self.update_grouping();
         self.loading_state = LoadingState::Loaded;
         self.error_message = None;
+        self.detached = false;
+        self.loading_newer = false;
+        self.is_dirty = true;
+    }
+
+    /// Replaces the messages with a window of older history.
+    ///
+    /// Live messages are not appended until the window is paged forward to the present.
+    pub fn set_detached_messages(&mut self, messages: Vec<Message>) {
+        self.set_messages(messages);
+        self.detached = true;
+    }
+
+    #[must_use]
+    pub const fn is_detached(&self) -> bool {
+        self.detached
+    }
+
+    #[must_use]
+    pub const fn is_loading_newer(&self) -> bool {
+        self.loading_newer
+    }
+
+    /// Marks a request for newer messages as in flight, or finished when `loading` is false.
+    pub const fn set_loading_newer(&mut self, loading: bool) {
+        self.loading_newer = loading;
+    }
+
+    /// Adds newer messages after the detached window; `reached_present` re-attaches to live mode.
+    pub fn append_messages(&mut self, new_messages: Vec<Message>, reached_present: bool) -> usize {
+        let existing_ids: HashSet<_> = self.messages.iter().map(|m| m.message.id()).collect();
+        let mut added = 0;
+        for msg in new_messages {
+            if !existing_ids.contains(&msg.id()) {
+                self.update_author(
+                    msg.author().id(),
+                    IdentityResolver::with_preference(self.use_display_name).resolve(msg.author()),
+                );
+                for mention in msg.mentions() {
+                    self.update_author(
+                        mention.id().to_string(),
+                        IdentityResolver::with_preference(self.use_display_name).resolve(mention),
+                    );
+                }
+                self.messages.push_back(self.ui_message(msg));
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.update_grouping();
+        }
+        if reached_present {
+            self.detached = false;
+        }
+        self.loading_newer = false;
         self.is_dirty = true;
+        added
     }
 
     pub fn add_message(&mut self, message: Message) {
         if self.channel_id == Some(message.channel_id())
+            && !self.detached
             && !self.messages.iter().any(|m| m.message.id() == message.id())
         {
             self.update_author(
@@ -363,7 +644,7 @@ impl MessagePaneData {
                     IdentityResolver::with_preference(self.use_display_name).resolve(mention),
                 );
             }
-            self.messages.push_back(UiMessage::new(message));
+            self.messages.push_back(self.ui_message(message));
             self.update_grouping();
             self.is_dirty = true;
         }
@@ -384,7 +665,7 @@ impl MessagePaneData {
                         IdentityResolver::with_preference(self.use_display_name).resolve(mention),
                     );
                 }
-                self.messages.push_front(UiMessage::new(msg));
+                self.messages.push_front(self.ui_message(msg));
                 added += 1;
             }
         }
@@ -401,7 +682,7 @@ impl MessagePaneData {
             .iter()
             .position(|m| m.message.id() == updated.id())
         {
-            let new_msg = UiMessage::new(updated);
+            let new_msg = self.ui_message(updated);
             self.messages[pos] = new_msg;
             self.update_grouping();
             self.is_dirty = true;
@@ -414,6 +695,58 @@ impl MessagePaneData {
         self.is_dirty = true;
     }
 
+    pub fn set_pinned(&mut self, message_id: MessageId, pinned: bool) {
+        self.modify_message(message_id, |message| message.set_pinned(pinned));
+    }
+
+    pub fn add_reaction(&mut self, message_id: MessageId, emoji: ReactionEmoji, me: bool) {
+        self.modify_message(message_id, |message| message.add_reaction(emoji, me));
+    }
+
+    pub fn remove_reaction(&mut self, message_id: MessageId, emoji: &ReactionEmoji, me: bool) {
+        self.modify_message(message_id, |message| message.remove_reaction(emoji, me));
+    }
+
+    pub fn clear_reactions(&mut self, message_id: MessageId) {
+        self.modify_message(message_id, Message::clear_reactions);
+    }
+
+    /// Sets the rules deciding which messages are highlighted, and where the
+    /// messages were posted. Messages are only re-evaluated when either changes.
+    pub fn set_highlight_rules(&mut self, rules: &NotificationRules, scope: MessageScope) {
+        if self.highlight_rules.ptr_eq(rules) && self.highlight_scope == scope {
+            return;
+        }
+        self.highlight_rules = rules.clone();
+        self.highlight_scope = scope;
+        for ui_msg in &mut self.messages {
+            ui_msg.highlighted = self
+                .highlight_rules
+                .highlights(&ui_msg.message, &self.highlight_scope);
+        }
+    }
+
+    fn ui_message(&self, message: Message) -> UiMessage {
+        let mut ui_msg = UiMessage::new(message);
+        ui_msg.highlighted = self
+            .highlight_rules
+            .highlights(&ui_msg.message, &self.highlight_scope);
+        ui_msg
+    }
+
+    /// Mutates a loaded message in place and schedules it for re-layout.
+    fn modify_message(&mut self, message_id: MessageId, f: impl FnOnce(&mut Message)) {
+        if let Some(ui_msg) = self
+            .messages
+            .iter_mut()
+            .find(|m| m.message.id() == message_id)
+        {
+            f(Arc::make_mut(&mut ui_msg.message));
+            ui_msg.rendered_content = None;
+            self.is_dirty = true;
+        }
+    }
+
     fn update_author<'a>(&mut self, id: impl Into<std::borrow::Cow<'a, str>>, name: String) {
         let id = id.into();
         if self.authors.get(id.as_ref()) != Some(&name) {
@@ -529,6 +862,77 @@ impl MessagePaneData {
         self.channels.insert(id, name);
     }
 
+    /// Replaces the roles used to render `<@&id>` mentions.
+    pub fn set_roles<'a>(&mut self, roles: impl IntoIterator<Item = &'a Role>) {
+        let roles: HashMap<String, (String, u32)> = roles
+            .into_iter()
+            .map(|r| (r.id.to_string(), (r.name.clone(), r.color)))
+            .collect();
+        if self.roles != roles {
+            self.roles = roles;
+            self.authors_generation = self.authors_generation.wrapping_add(1);
+        }
+    }
+
+    /// Span drawn at `column` of a line of a message's rendered content.
+    fn span_at(&self, index: usize, line: usize, column: u16) -> Option<&Span<'static>> {
+        let line = self
+            .messages
+            .get(index)?
+            .rendered_content
+            .as_ref()?
+            .lines
+            .get(line)?;
+
+        let mut x = 0u16;
+        line.spans.iter().find(|span| {
+            let width = u16::try_from(span.width()).unwrap_or(u16::MAX);
+            let hit = (x..x.saturating_add(width)).contains(&column);
+            x = x.saturating_add(width);
+            hit
+        })
+    }
+
+    /// URL of the link drawn at `column` of a line of a message's rendered content.
+    fn link_at(&self, index: usize, line: usize, column: u16) -> Option<String> {
+        let line = u16::try_from(line).ok()?;
+        self.messages
+            .get(index)?
+            .link_spans
+            .iter()
+            .find(|link| link.line == line && (link.start..link.end).contains(&column))
+            .map(|link| link.url.clone())
+    }
+
+    /// ID of the user whose mention is drawn at `column` of a line of a message's rendered content.
+    fn mention_at(&self, index: usize, line: usize, column: u16) -> Option<String> {
+        let text = self.span_at(index, line, column)?.content.trim();
+        if let Some(id) = text.strip_prefix("<@").and_then(|id| id.strip_suffix('>')) {
+            return Some(id.trim_start_matches('!').to_string());
+        }
+
+        let name = text.strip_prefix('@')?;
+        self.messages
+            .get(index)?
+            .message
+            .mentions()
+            .iter()
+            .find(|user| {
+                user.display_name() == name
+                    || user.username() == name
+                    || user.global_name() == Some(name)
+            })
+            .map(User::id_str)
+    }
+
+    /// Draws custom emoji as images when image previews are on.
+    pub fn set_inline_emoji_images(&mut self, enabled: bool) {
+        if self.inline_emoji_images != enabled {
+            self.inline_emoji_images = enabled;
+            self.last_layout_width = None;
+        }
+    }
+
     #[must_use]
     pub fn is_channel_known(&self, id: &str) -> bool {
         self.channels.contains_key(id)
@@ -539,6 +943,24 @@ impl MessagePaneData {
         self.is_dirty = true;
     }
 
+    /// Whether any loaded message was written by the user.
+    #[must_use]
+    pub fn has_author(&self, user_id: &str) -> bool {
+        self.messages
+            .iter()
+            .any(|m| m.message.author().id() == user_id)
+    }
+
+    /// Re-renders messages with relative `<t:...:R>` timestamps so their text stays current.
+    pub fn refresh_relative_timestamps(&mut self) {
+        for ui_msg in &mut self.messages {
+            if contains_relative_timestamp(&ui_msg.parsed_content) {
+                ui_msg.rendered_content = None;
+                self.is_dirty = true;
+            }
+        }
+    }
+
     /// Forces a re-layout of all messages, including re-parsing if resolver data changed.
     pub fn force_refresh_layout(&mut self) {
         self.is_dirty = true;
@@ -629,8 +1051,14 @@ impl MessagePaneData {
 
         let authors = &self.authors;
         let channels = &self.channels;
-        let resolver = HashMapResolver { authors, channels };
+        let roles = &self.roles;
+        let resolver = HashMapResolver {
+            authors,
+            channels,
+            roles,
+        };
         let current_generation = self.authors_generation;
+        let emoji_images = image_preview && self.inline_emoji_images;
 
         for ui_msg in &mut self.messages {
             if !full_invalidation
@@ -647,6 +1075,7 @@ impl MessagePaneData {
                 content_style,
                 show_spoilers,
                 image_preview,
+                emoji_images,
                 &resolver,
                 authors,
                 self.use_display_name,
@@ -661,7 +1090,11 @@ impl MessagePaneData {
         self.is_dirty = false;
     }
 
-    #[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+    #[allow(
+        clippy::too_many_lines,
+        clippy::too_many_arguments,
+        clippy::fn_params_excessive_bools
+    )]
     fn layout_message(
         ui_msg: &mut UiMessage,
         content_width: u16,
@@ -669,6 +1102,7 @@ impl MessagePaneData {
         content_style: Style,
         show_spoilers: bool,
         image_preview: bool,
+        emoji_images: bool,
         resolver: &HashMapResolver<'_>,
         authors: &HashMap<String, String>,
         use_display_name: bool,
@@ -676,15 +1110,34 @@ impl MessagePaneData {
     ) {
         let message = &ui_msg.message;
 
-        let text =
+        let emoji_images = emoji_images && !ui_msg.emoji_images.is_empty();
+        let text = if emoji_images {
+            markdown_service.render_with_emoji_images(
+                ui_msg.parsed_content.clone(),
+                Some(resolver),
+                show_spoilers,
+                content_style,
+            )
+        } else {
             markdown_service.render(
                 ui_msg.parsed_content.clone(),
                 Some(resolver),
                 show_spoilers,
                 content_style,
-            );
+            )
+        };
 
         let wrapped_text = wrap_styled_text(text, content_width);
+        ui_msg.emoji_slots = if emoji_images {
+            locate_emoji_slots(
+                &wrapped_text,
+                &custom_emoji_ids(&ui_msg.parsed_content, show_spoilers),
+                &ui_msg.emoji_images,
+            )
+        } else {
+            Vec::new()
+        };
+        ui_msg.link_spans = locate_link_spans(&wrapped_text, &link_urls(&ui_msg.parsed_content));
         let content_lines = u16::try_from(wrapped_text.lines.len()).unwrap_or(0);
 
         ui_msg.rendered_content = Some(wrapped_text);
@@ -721,6 +1174,10 @@ impl MessagePaneData {
         }
         ui_msg.rendered_embeds = rendered_embeds;
 
+        if !message.reactions().is_empty() {
+            height += 1;
+        }
+
         if message.is_reply() {
             if let Some(referenced) = message.referenced() {
                 let content = referenced.content();
@@ -804,6 +1261,41 @@ pub struct ForumState {
     pub selected_idx: usize,
     pub scroll_offset: u16,
     pub needs_scroll_to_selection: bool,
+    pub filter: ForumFilter,
+    /// Tags offered by the forum channel, used for names and the tag filter.
+    pub available_tags: Vec<ForumTag>,
+}
+
+impl ForumState {
+    /// Name of an applied tag, falling back to its id for tags the forum no longer offers.
+    #[must_use]
+    pub fn tag_label(&self, tag_id: u64) -> String {
+        self.available_tags
+            .iter()
+            .find(|tag| tag.id == tag_id)
+            .map_or_else(|| tag_id.to_string(), ForumTag::label)
+    }
+
+    /// Steps the tag filter through "all posts" and then each available tag.
+    pub fn cycle_tag_filter(&mut self) {
+        let next_idx = match self.filter.tag_id {
+            None => 0,
+            Some(current) => self
+                .available_tags
+                .iter()
+                .position(|tag| tag.id == current)
+                .map_or(0, |idx| idx + 1),
+        };
+        self.filter.tag_id = self.available_tags.get(next_idx).map(|tag| tag.id);
+    }
+
+    /// Drops the loaded posts so the listing can be fetched again from the start.
+    pub fn reset_threads(&mut self) {
+        self.threads.clear();
+        self.selected_idx = 0;
+        self.scroll_offset = 0;
+        self.needs_scroll_to_selection = false;
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -821,6 +1313,10 @@ impl MentionResolver for MessagePaneData {
     fn resolve_channel(&self, channel_id: &str) -> Option<String> {
         self.channels.get(channel_id).cloned()
     }
+
+    fn resolve_role(&self, role_id: &str) -> Option<(String, u32)> {
+        self.roles.get(role_id).cloned()
+    }
 }
 
 impl Default for MessagePaneData {
@@ -840,10 +1336,32 @@ pub struct MessagePaneState {
     pub vertical_scroll: usize,
     pub show_spoilers: bool,
     selected_index: Option<usize>,
+    /// Reaction highlighted on the selected message, keyed by message so it
+    /// resets when the selection moves.
+    reaction_cursor: Option<(MessageId, usize)>,
     flags: MessagePaneFlags,
     content_height: usize,
     viewport_height: u16,
     last_width: u16,
+    /// Screen regions drawn in the last frame, used to resolve mouse clicks.
+    hit_areas: Vec<(Rect, MessageHit)>,
+}
+
+/// What was drawn in a region of the message pane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MessageHit {
+    /// The whole area of a message, or of a run of blocked messages.
+    Message(usize),
+    /// A line of a message's rendered content.
+    ContentLine { index: usize, line: usize },
+    /// A non-image attachment.
+    Link(String),
+    /// An image attachment of a message.
+    Images(MessageId),
+    /// A thread card in forum view.
+    Thread(ChannelId),
+    /// The channel header.
+    Pins,
 }
 
 impl MessagePaneState {
@@ -854,6 +1372,7 @@ impl MessagePaneState {
             vertical_scroll: 0,
             show_spoilers: false,
             selected_index: None,
+            reaction_cursor: None,
             flags: MessagePaneFlags {
                 focused: false,
                 is_following: true,
@@ -862,6 +1381,7 @@ impl MessagePaneState {
             content_height: 0,
             viewport_height: 0,
             last_width: 0,
+            hit_areas: Vec::new(),
         }
     }
 
@@ -889,6 +1409,26 @@ impl MessagePaneState {
         self.last_width
     }
 
+    /// Index of the highlighted reaction on the given message, if it is selected.
+    #[must_use]
+    pub fn focused_reaction(&self, message: &Message) -> Option<usize> {
+        let count = message.reactions().len();
+        if count == 0 {
+            return None;
+        }
+        match self.reaction_cursor {
+            Some((id, idx)) if id == message.id() => Some(idx.min(count - 1)),
+            _ => Some(0),
+        }
+    }
+
+    fn select_next_reaction(&mut self, message: &Message) {
+        let count = message.reactions().len();
+        if let Some(current) = self.focused_reaction(message) {
+            self.reaction_cursor = Some((message.id(), (current + 1) % count));
+        }
+    }
+
     pub fn toggle_spoiler(&mut self) {
         self.show_spoilers = !self.show_spoilers;
     }
@@ -1069,6 +1609,119 @@ impl MessagePaneState {
         self.vertical_scroll = 0;
     }
 
+    fn scroll_up_or_load_history(&mut self) -> Option<MessagePaneAction> {
+        if self.vertical_scroll == 0 {
+            return Some(MessagePaneAction::LoadHistory);
+        }
+        self.scroll_up();
+        None
+    }
+
+    /// Detached history cannot follow the live tail; reaching its bottom pages forward instead.
+    fn scroll_down_or_load_newer(&mut self, data: &MessagePaneData) -> Option<MessagePaneAction> {
+        self.scroll_down_and_follow();
+        if data.is_detached() && self.flags.is_following {
+            self.flags.is_following = false;
+            if !data.is_loading_newer() {
+                return Some(MessagePaneAction::LoadNewer);
+            }
+        }
+        None
+    }
+
+    fn scroll_down_and_follow(&mut self) {
+        self.scroll_down();
+        let max_scroll = self
+            .content_height
+            .saturating_sub(self.viewport_height as usize);
+        if self.vertical_scroll == max_scroll {
+            self.flags.is_following = true;
+        }
+    }
+
+    /// Handles wheel scrolling and left clicks inside the pane.
+    pub fn handle_mouse(
+        &mut self,
+        mouse: MouseEvent,
+        data: &MessagePaneData,
+    ) -> Option<MessagePaneAction> {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => {
+                if let ViewMode::Forum(forum_state) = &mut self.view_mode {
+                    forum_state.scroll_offset = forum_state.scroll_offset.saturating_sub(1);
+                    return None;
+                }
+                self.scroll_up_or_load_history()
+            }
+            MouseEventKind::ScrollDown => {
+                if let ViewMode::Forum(forum_state) = &mut self.view_mode {
+                    let last = u16::try_from(forum_state.threads.len().saturating_sub(1))
+                        .unwrap_or(u16::MAX);
+                    forum_state.scroll_offset = (forum_state.scroll_offset + 1).min(last);
+                    return None;
+                }
+                self.scroll_down_or_load_newer(data)
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.handle_click(Position::new(mouse.column, mouse.row), data)
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_click(
+        &mut self,
+        position: Position,
+        data: &MessagePaneData,
+    ) -> Option<MessagePaneAction> {
+        let hit = self
+            .hit_areas
+            .iter()
+            .rev()
+            .find(|(area, hit)| area.contains(position) && !matches!(hit, MessageHit::Message(_)))
+            .cloned();
+
+        if let Some((_, MessageHit::Pins)) = hit {
+            return Some(MessagePaneAction::OpenPins);
+        }
+
+        if let Some((_, MessageHit::Thread(thread_id))) = hit {
+            if let ViewMode::Forum(forum_state) = &mut self.view_mode
+                && let Some(idx) = forum_state.threads.iter().position(|t| t.id == thread_id)
+            {
+                forum_state.selected_idx = idx;
+            }
+            return Some(MessagePaneAction::OpenThread(thread_id));
+        }
+
+        let index = self.hit_areas.iter().find_map(|(area, hit)| match hit {
+            MessageHit::Message(idx) if area.contains(position) => Some(*idx),
+            _ => None,
+        })?;
+        let message_id = data.messages.get(index)?.message.id();
+
+        self.selected_index = Some(index);
+        self.flags.is_following = false;
+
+        match hit {
+            Some((_, MessageHit::Link(url))) => Some(MessagePaneAction::OpenLink(url)),
+            Some((_, MessageHit::Images(message_id))) => {
+                Some(MessagePaneAction::OpenAttachments(message_id))
+            }
+            Some((area, MessageHit::ContentLine { index, line })) => {
+                let column = position.x - area.x;
+                if let Some(user_id) = data.mention_at(index, line, column) {
+                    return Some(MessagePaneAction::OpenProfile(vec![user_id]));
+                }
+                Some(data.link_at(index, line, column).map_or(
+                    MessagePaneAction::SelectMessage(message_id),
+                    MessagePaneAction::OpenLink,
+                ))
+            }
+            _ => Some(MessagePaneAction::SelectMessage(message_id)),
+        }
+    }
+
     pub fn update_dimensions(&mut self, content_height: usize, viewport_height: u16) {
         self.content_height = content_height;
         self.viewport_height = viewport_height;
@@ -1131,6 +1784,20 @@ impl MessagePaneState {
                 Some(Action::Cancel | Action::NavigateLeft) => {
                     return Some(MessagePaneAction::CloseThread);
                 }
+                Some(Action::NewForumPost) => return Some(MessagePaneAction::NewForumPost),
+                Some(Action::CycleForumSort) => {
+                    forum_state.filter.sort = forum_state.filter.sort.next();
+                    forum_state.reset_threads();
+                    return Some(MessagePaneAction::SetForumFilter(forum_state.filter));
+                }
+                Some(Action::CycleForumTagFilter) => {
+                    if forum_state.available_tags.is_empty() {
+                        return None;
+                    }
+                    forum_state.cycle_tag_filter();
+                    forum_state.reset_threads();
+                    return Some(MessagePaneAction::SetForumFilter(forum_state.filter));
+                }
                 _ => return None,
             }
         }
@@ -1145,6 +1812,9 @@ impl MessagePaneState {
 
         match registry.find_action(key) {
             Some(Action::NavigateDown) => {
+                if data.is_detached() && self.selected_index == message_count.checked_sub(1) {
+                    return (!data.is_loading_newer()).then_some(MessagePaneAction::LoadNewer);
+                }
                 self.select_next_visible(&render_items, message_count);
                 None
             }
@@ -1162,23 +1832,8 @@ impl MessagePaneState {
                 self.select_previous_visible(&render_items, message_count);
                 None
             }
-            Some(Action::ScrollDown) => {
-                self.scroll_down();
-                let max_scroll = self
-                    .content_height
-                    .saturating_sub(self.viewport_height as usize);
-                if self.vertical_scroll == max_scroll {
-                    self.flags.is_following = true;
-                }
-                None
-            }
-            Some(Action::ScrollUp) => {
-                if self.vertical_scroll == 0 {
-                    return Some(MessagePaneAction::LoadHistory);
-                }
-                self.scroll_up();
-                None
-            }
+            Some(Action::ScrollDown) => self.scroll_down_or_load_newer(data),
+            Some(Action::ScrollUp) => self.scroll_up_or_load_history(),
             Some(Action::SelectFirst) => {
                 self.select_first();
                 Some(MessagePaneAction::LoadHistory)
@@ -1280,6 +1935,43 @@ impl MessagePaneState {
                 self.show_spoilers = !self.show_spoilers;
                 None
             }
+            Some(Action::NextReaction) => {
+                if let Some(message) = self.get_selected_message(data) {
+                    self.select_next_reaction(message);
+                }
+                None
+            }
+            Some(Action::ToggleReaction) => {
+                let message = self.get_selected_message(data)?;
+                let reaction = &message.reactions()[self.focused_reaction(message)?];
+                Some(MessagePaneAction::ToggleReaction {
+                    message_id: message.id(),
+                    emoji: reaction.emoji.clone(),
+                    add: !reaction.me,
+                })
+            }
+            Some(Action::AddReaction) => self
+                .get_selected_message_id(data)
+                .map(MessagePaneAction::PickReaction),
+            Some(Action::TogglePin) => {
+                self.get_selected_message(data)
+                    .map(|m| MessagePaneAction::TogglePin {
+                        message_id: m.id(),
+                        pinned: !m.is_pinned(),
+                    })
+            }
+            Some(Action::OpenThread) => self
+                .get_selected_message(data)
+                .and_then(Message::thread_id)
+                .map(MessagePaneAction::OpenThread),
+            Some(Action::StartThread) => self
+                .get_selected_message(data)
+                .filter(|m| m.thread_id().is_none())
+                .map(|m| MessagePaneAction::StartThread(m.id())),
+            Some(Action::ToggleThreadMembership) => Some(MessagePaneAction::ToggleThreadMembership),
+            Some(Action::OpenProfile) => self
+                .get_selected_message(data)
+                .map(|m| MessagePaneAction::OpenProfile(profile_user_ids(m))),
 
             _ => None,
         }
@@ -1315,6 +2007,8 @@ pub struct MessagePaneStyle {
     pub edited_style: Style,
     pub selected_style: Style,
     pub mention_style: Style,
+    /// Messages matched by a notification rule with the `highlight` action.
+    pub highlight_style: Style,
     pub reply_style: Style,
     pub attachment_style: Style,
     pub system_message_style: Style,
@@ -1324,6 +2018,8 @@ pub struct MessagePaneStyle {
     pub scrollbar_track_style: Style,
     pub scrollbar_thumb_style: Style,
     pub blocked_style: Style,
+    pub reaction_style: Style,
+    pub reaction_self_style: Style,
 }
 
 impl MessagePaneStyle {
@@ -1336,6 +2032,9 @@ impl MessagePaneStyle {
         blocked_hsl.l = 0.35;
         blocked_hsl.s = (blocked_hsl.s * 0.4).clamp(0.0, 1.0);
         let blocked_fg = ColorConverter::to_ratatui(blocked_hsl);
+        let mut highlight_hsl = accent_hsl;
+        highlight_hsl.l = 0.18;
+        let highlight_bg = ColorConverter::to_ratatui(highlight_hsl);
 
         Self {
             border_style: theme.border_style,
@@ -1350,6 +2049,7 @@ impl MessagePaneStyle {
             content_style: theme.base_style,
             selected_style: theme.selection_style,
             mention_style: theme.mention_style,
+            highlight_style: Style::default().bg(highlight_bg),
             reply_style: theme.dimmed_style.add_modifier(Modifier::ITALIC),
             timestamp_style: theme.timestamp_style,
             loading_style: theme.info_style,
@@ -1360,6 +2060,10 @@ impl MessagePaneStyle {
                 .add_modifier(Modifier::ITALIC),
             scrollbar_track_style: theme.dimmed_style,
             scrollbar_thumb_style: theme.border_style,
+            reaction_style: theme.dimmed_style,
+            reaction_self_style: Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
             ..Self::default()
         }
     }
@@ -1387,6 +2091,7 @@ impl Default for MessagePaneStyle {
                 .add_modifier(Modifier::ITALIC),
             selected_style: Style::default().bg(Color::DarkGray),
             mention_style: Style::default().bg(Color::Rgb(50, 50, 20)),
+            highlight_style: Style::default().bg(Color::Rgb(20, 45, 60)),
             reply_style: Style::default()
                 .fg(Color::DarkGray)
                 .add_modifier(Modifier::ITALIC),
@@ -1402,6 +2107,10 @@ impl Default for MessagePaneStyle {
             blocked_style: Style::default()
                 .fg(Color::DarkGray)
                 .add_modifier(Modifier::ITALIC),
+            reaction_style: Style::default().fg(Color::Gray),
+            reaction_self_style: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
@@ -1417,8 +2126,12 @@ pub struct MessagePane<'a> {
     markdown_service: &'a MarkdownRenderer,
     /// Optional reference to relationship state for blocked user filtering.
     relationship_state: Option<&'a RelationshipState>,
+    /// Optional presence store for status dots next to author names.
+    presences: Option<&'a PresenceStore>,
     /// If true, completely hide blocked messages; if false, show placeholder.
     hide_blocked_completely: bool,
+    /// Key bound to jumping back to the present, shown while viewing older history.
+    jump_to_present_key: Option<&'a str>,
 }
 
 impl<'a> MessagePane<'a> {
@@ -1433,7 +2146,9 @@ impl<'a> MessagePane<'a> {
             current_user_id: None,
             markdown_service,
             relationship_state: None,
+            presences: None,
             hide_blocked_completely: false,
+            jump_to_present_key: None,
         }
     }
 
@@ -1474,6 +2189,13 @@ impl<'a> MessagePane<'a> {
         self
     }
 
+    /// Sets the presence store used for author status dots.
+    #[must_use]
+    pub const fn with_presences(mut self, presences: &'a PresenceStore) -> Self {
+        self.presences = Some(presences);
+        self
+    }
+
     /// Sets whether to completely hide blocked messages (true) or show placeholder (false).
     #[must_use]
     pub const fn with_hide_blocked_completely(mut self, hide: bool) -> Self {
@@ -1481,6 +2203,13 @@ impl<'a> MessagePane<'a> {
         self
     }
 
+    /// Sets the key named in the notice shown while viewing older history.
+    #[must_use]
+    pub const fn with_jump_to_present_key(mut self, key: Option<&'a str>) -> Self {
+        self.jump_to_present_key = key;
+        self
+    }
+
     #[must_use]
     pub fn calculate_message_height(
         &self,
@@ -1496,7 +2225,12 @@ impl<'a> MessagePane<'a> {
 
         let authors = &self.data.authors;
         let channels = &self.data.channels;
-        let resolver = HashMapResolver { authors, channels };
+        let roles = &self.data.roles;
+        let resolver = HashMapResolver {
+            authors,
+            channels,
+            roles,
+        };
         let text = markdown_service.render_markdown(message.content(), Some(&resolver), false, content_style);
 
         let mut content_lines = 0;
@@ -1541,6 +2275,10 @@ impl<'a> MessagePane<'a> {
                 .height;
         }
 
+        if !message.reactions().is_empty() {
+            height += 1;
+        }
+
         height
     }
 
@@ -1556,7 +2294,11 @@ impl<'a> MessagePane<'a> {
             .border_style(border_style);
 
         if let Some(title) = self.data.formatted_channel_title() {
-            block = block.title(Line::from(Span::styled(title, self.style.title_style)));
+            let mut spans = vec![Span::styled(title, self.style.title_style)];
+            if matches!(state.view_mode, ViewMode::Messages) {
+                spans.push(Span::styled(PINS_MARKER, self.style.topic_style));
+            }
+            block = block.title(Line::from(spans));
         }
 
         if let Some(topic) = self.data.channel_topic() {
@@ -1570,6 +2312,17 @@ impl<'a> MessagePane<'a> {
             );
         }
 
+        if self.data.is_detached() && matches!(state.view_mode, ViewMode::Messages) {
+            let notice = self.jump_to_present_key.map_or_else(
+                || " Viewing older messages ".to_string(),
+                |key| format!(" Viewing older messages - {key} to jump to present "),
+            );
+            block = block.title_bottom(
+                Line::from(Span::styled(notice, self.style.topic_style))
+                    .alignment(Alignment::Right),
+            );
+        }
+
         if let Some(typing) = self.data.typing_indicator() {
             let clean_typing = clean_text(typing);
             block = block.title_bottom(
@@ -1586,12 +2339,32 @@ impl<'a> MessagePane<'a> {
         block
     }
 
+    /// Where `build_block` draws the pins marker, right after the channel title.
+    fn pins_marker_area(&self, area: Rect, state: &MessagePaneState) -> Option<Rect> {
+        if self.data.channel_id().is_none() || !matches!(state.view_mode, ViewMode::Messages) {
+            return None;
+        }
+        let title = self.data.formatted_channel_title()?;
+        let x = area
+            .x
+            .saturating_add(1)
+            .saturating_add(u16::try_from(title.width()).unwrap_or(u16::MAX));
+        let width = u16::try_from(PINS_MARKER.width())
+            .unwrap_or(u16::MAX)
+            .min(area.right().saturating_sub(1).saturating_sub(x));
+        (width > 0).then(|| Rect::new(x, area.y, width, 1))
+    }
+
     #[allow(clippy::too_many_lines)]
     fn render_messages(&mut self, area: Rect, buf: &mut Buffer, state: &mut MessagePaneState) {
         let block = self.build_block(state);
         let inner_area = block.inner(area);
         block.render(area, buf);
 
+        if let Some(pins) = self.pins_marker_area(area, state) {
+            state.hit_areas.push((pins, MessageHit::Pins));
+        }
+
         let MessagePane {
             data,
             style,
@@ -1601,7 +2374,9 @@ impl<'a> MessagePane<'a> {
             current_user_id,
             markdown_service,
             relationship_state,
+            presences,
             hide_blocked_completely,
+            jump_to_present_key: _,
         } = self;
 
         match data.loading_state() {
@@ -1727,6 +2502,14 @@ impl<'a> MessagePane<'a> {
             if current_y_usize + h > offset && current_y_usize < offset + inner_area.height as usize
             {
                 let render_y = current_y - i32::try_from(offset).unwrap_or(0);
+                let first_idx = MessagePaneState::message_index_for_render_item(&item);
+                if let Some(rows) = visible_rows(
+                    inner_area,
+                    render_y,
+                    render_y + i32::try_from(h).unwrap_or(0),
+                ) {
+                    state.hit_areas.push((rows, MessageHit::Message(first_idx)));
+                }
 
                 match item {
                     RenderItem::BlockedRun { count, start_idx } => {
@@ -1737,6 +2520,7 @@ impl<'a> MessagePane<'a> {
                     }
                     RenderItem::Message { idx } => {
                         let ui_msg = &mut data.messages[idx];
+                        let highlighted = ui_msg.highlighted;
                         render_ui_message(
                             ui_msg,
                             style,
@@ -1752,6 +2536,8 @@ impl<'a> MessagePane<'a> {
                             *image_preview,
                             timestamp_format,
                             current_user_id.as_deref(),
+                            *presences,
+                            highlighted,
                         );
                     }
                 }
@@ -1782,15 +2568,27 @@ impl<'a> MessagePane<'a> {
     fn render_forum(&self, area: Rect, buf: &mut Buffer, state: &mut MessagePaneState) {
         let focused = state.is_focused();
         let block = self.build_block(state);
-        let inner_area = block.inner(area);
+        let block_inner = block.inner(area);
         block.render(area, buf);
 
+        let status_area = Rect {
+            height: block_inner.height.min(1),
+            ..block_inner
+        };
+        let inner_area = Rect {
+            y: block_inner.y + status_area.height,
+            height: block_inner.height - status_area.height,
+            ..block_inner
+        };
+
         state.update_dimensions(0, inner_area.height);
 
         let ViewMode::Forum(forum_state) = &mut state.view_mode else {
             return;
         };
 
+        self.render_forum_status(status_area, buf, forum_state);
+
         if forum_state.threads.is_empty() {
             let empty = Paragraph::new("No threads found").style(self.style.empty_style);
             empty.render(inner_area, buf);
@@ -1838,10 +2636,14 @@ impl<'a> MessagePane<'a> {
             }
 
             let card_area = Rect::new(inner_area.x, current_y, inner_area.width - 1, height);
+            state
+                .hit_areas
+                .push((card_area, MessageHit::Thread(thread.id)));
             self.render_thread_card(
                 card_area,
                 buf,
                 thread,
+                forum_state,
                 i == forum_state.selected_idx,
                 focused,
             );
@@ -1868,11 +2670,31 @@ impl<'a> MessagePane<'a> {
         scrollbar.render(scrollbar_area, buf);
     }
 
+    fn render_forum_status(&self, area: Rect, buf: &mut Buffer, forum_state: &ForumState) {
+        if area.height == 0 {
+            return;
+        }
+
+        let tag = forum_state
+            .filter
+            .tag_id
+            .map_or_else(|| "All".to_string(), |id| forum_state.tag_label(id));
+
+        let line = Line::from(vec![
+            Span::styled("Sort: ", self.style.timestamp_style),
+            Span::styled(forum_state.filter.sort.label(), self.style.content_style),
+            Span::styled("  Tag: ", self.style.timestamp_style),
+            Span::styled(tag, Style::default().fg(Color::Blue)),
+        ]);
+        buf.set_line(area.x + 1, area.y, &line, area.width.saturating_sub(1));
+    }
+
     fn render_thread_card(
         &self,
         area: Rect,
         buf: &mut Buffer,
         thread: &ForumThread,
+        forum_state: &ForumState,
         selected: bool,
         focused: bool,
     ) {
@@ -1948,7 +2770,7 @@ impl<'a> MessagePane<'a> {
 
         for tag_id in &thread.applied_tags {
             meta_spans.push(Span::styled(
-                format!("[{tag_id}] "),
+                format!("[{}] ", forum_state.tag_label(*tag_id)),
                 Style::default().fg(Color::Blue),
             ));
         }
@@ -1994,6 +2816,7 @@ impl StatefulWidget for MessagePane<'_> {
     type State = MessagePaneState;
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.hit_areas.clear();
         match &mut state.view_mode {
             ViewMode::Messages => self.render_messages(area, buf, state),
             ViewMode::Forum(_) => {
@@ -2201,7 +3024,8 @@ fn render_blocked_run(
 #[allow(
     clippy::too_many_lines,
     clippy::items_after_statements,
-    clippy::too_many_arguments
+    clippy::too_many_arguments,
+    clippy::fn_params_excessive_bools
 )]
 fn render_ui_message(
     ui_msg: &mut UiMessage,
@@ -2218,6 +3042,8 @@ fn render_ui_message(
     image_preview: bool,
     timestamp_format: &str,
     current_user_id: Option<&str>,
+    presences: Option<&PresenceStore>,
+    highlighted: bool,
 ) {
     let message = &ui_msg.message;
     let is_selected = state.selected_index == Some(index);
@@ -2232,6 +3058,8 @@ fn render_ui_message(
         style.selected_style
     } else if is_mentioned {
         style.mention_style
+    } else if highlighted {
+        style.highlight_style
     } else {
         Style::default()
     };
@@ -2241,7 +3069,7 @@ fn render_ui_message(
             && current_msg_y < i32::from(area.height)
             && let Some(preview) = &ui_msg.reply_preview
         {
-            let render_line = if is_selected || is_mentioned {
+            let render_line = if is_selected || is_mentioned || highlighted {
                 let mut spans = preview.clone().spans;
                 if spans.len() == 4 {
                     spans[1].style = Style::default()
@@ -2283,7 +3111,7 @@ fn render_ui_message(
 
     if ui_msg.group == MessageGroup::Start {
         if current_msg_y >= 0 && current_msg_y < i32::from(area.height) {
-            let (timestamp_style, edited_style) = if is_selected || is_mentioned {
+            let (timestamp_style, edited_style) = if is_selected || is_mentioned || highlighted {
                 (
                     Style::default().fg(Color::White),
                     Style::default()
@@ -2315,6 +3143,14 @@ fn render_ui_message(
                 ),
             ];
 
+            if let Some(status) = presences.and_then(|p| p.status_str(message.author().id())) {
+                header_spans.push(Span::raw(" "));
+                header_spans.push(Span::styled(
+                    status.display_indicator(),
+                    Style::default().fg(presence_color(status)),
+                ));
+            }
+
             if message.author().is_bot() {
                 header_spans.push(Span::raw(" "));
                 header_spans.push(Span::styled("[BOT]", style.bot_badge_style));
@@ -2325,6 +3161,11 @@ fn render_ui_message(
                 header_spans.push(Span::styled("(edited)", edited_style));
             }
 
+            if message.thread_id().is_some() {
+                header_spans.push(Span::raw(" "));
+                header_spans.push(Span::styled("(thread)", edited_style));
+            }
+
             let header_line = Line::from(header_spans);
             let header_para = Paragraph::new(header_line).style(base_style);
 
@@ -2393,6 +3234,50 @@ fn render_ui_message(
             para.render(para_area, buf);
         }
     }
+
+    let content_x = area.x.saturating_add(indent_width);
+    for line in 0..usize::try_from(content_height).unwrap_or(0) {
+        let line_y = content_start_y + i32::try_from(line).unwrap_or(i32::MAX);
+        if let Some(rows) = visible_rows(area, line_y, line_y + 1) {
+            let rows = Rect {
+                x: content_x,
+                width: area.width.saturating_sub(indent_width),
+                ..rows
+            };
+            state
+                .hit_areas
+                .push((rows, MessageHit::ContentLine { index, line }));
+        }
+    }
+
+    for slot in &ui_msg.emoji_slots {
+        let slot_y = content_start_y + i32::from(slot.line);
+        let slot_x = indent_width.saturating_add(slot.column);
+        if slot_y < 0 || slot_y >= i32::from(area.height) || slot_x + 2 > area.width {
+            continue;
+        }
+        let slot_area = Rect::new(
+            area.x + slot_x,
+            area.y + u16::try_from(slot_y).unwrap_or(0),
+            2,
+            1,
+        );
+
+        match ui_msg
+            .emoji_images
+            .get_mut(slot.image)
+            .and_then(|img| img.protocol.as_mut())
+        {
+            Some(protocol) => {
+                use ratatui_image::{Resize, StatefulImage};
+                let image_widget = StatefulImage::default().resize(Resize::Fit(None));
+                ratatui::widgets::StatefulWidget::render(image_widget, slot_area, buf, protocol);
+            }
+            None => {
+                buf.set_string(slot_area.x, slot_area.y, "\u{25c6}", style.attachment_style);
+            }
+        }
+    }
     current_msg_y += content_height;
 
     for attachment in message.attachments() {
@@ -2415,10 +3300,14 @@ fn render_ui_message(
                 1,
             );
             attachment_para.render(att_area, buf);
+            state
+                .hit_areas
+                .push((att_area, MessageHit::Link(attachment.url.clone())));
         }
         current_msg_y += 1;
     }
 
+    let images_start_y = current_msg_y;
     for img_attachment in &mut ui_msg.image_attachments {
         if !image_preview {
             if current_msg_y >= 0 && current_msg_y < i32::from(area.height) {
@@ -2546,10 +3435,64 @@ fn render_ui_message(
         }
     }
 
+    if let Some(rows) = visible_rows(area, images_start_y, current_msg_y) {
+        state
+            .hit_areas
+            .push((rows, MessageHit::Images(message.id())));
+    }
+
     for embed in &ui_msg.rendered_embeds {
         let height = render_embed(embed, current_msg_y, area, buf, style);
         current_msg_y += height;
-    }}
+    }
+
+    if !message.reactions().is_empty()
+        && current_msg_y >= 0
+        && current_msg_y < i32::from(area.height)
+    {
+        let focused = if is_selected {
+            state.focused_reaction(message)
+        } else {
+            None
+        };
+        let reaction_line = build_reaction_line(message.reactions(), focused, style);
+        let reaction_para = Paragraph::new(reaction_line).style(base_style);
+        let reaction_area = Rect::new(
+            area.x,
+            area.y
+                .saturating_add(u16::try_from(current_msg_y).unwrap_or(0)),
+            area.width,
+            1,
+        );
+        reaction_para.render(reaction_area, buf);
+    }
+}
+
+fn build_reaction_line(
+    reactions: &[Reaction],
+    focused: Option<usize>,
+    style: &MessagePaneStyle,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ".repeat(CONTENT_INDENT))];
+    for (idx, reaction) in reactions.iter().enumerate() {
+        let mut reaction_style = if reaction.me {
+            style.reaction_self_style
+        } else {
+            style.reaction_style
+        };
+        if focused == Some(idx) {
+            reaction_style = reaction_style.add_modifier(Modifier::REVERSED);
+        }
+        if idx > 0 {
+            spans.push(Span::raw(" "));
+        }
+        spans.push(Span::styled(
+            format!("[{} {}]", reaction.emoji.display(), reaction.count),
+            reaction_style,
+        ));
+    }
+    Line::from(spans)
+}
 
 fn truncate_string(s: &str, max_len: usize) -> String {
     if UnicodeWidthStr::width(s) <= max_len {
@@ -2668,6 +3611,20 @@ fn wrap_text(text: &str, width: usize) -> Vec<String> {
     lines
 }
 
+/// Rows `[start, end)` relative to the top of `area`, clipped to it.
+fn visible_rows(area: Rect, start: i32, end: i32) -> Option<Rect> {
+    let top = start.max(0);
+    let bottom = end.min(i32::from(area.height));
+    (bottom > top).then(|| {
+        Rect::new(
+            area.x,
+            area.y + u16::try_from(top).unwrap_or(0),
+            area.width,
+            u16::try_from(bottom - top).unwrap_or(0),
+        )
+    })
+}
+
 fn wrap_styled_text(text: Text<'static>, width: u16) -> Text<'static> {
     if width == 0 {
         return text;
@@ -2809,6 +3766,169 @@ mod tests {
         assert_eq!(data.loading_state(), LoadingState::Loaded);
     }
 
+    #[test]
+    fn test_has_author() {
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(1, "Hello")]);
+
+        assert!(data.has_author("1"));
+        assert!(!data.has_author("2"));
+    }
+
+    #[test]
+    fn test_highlights_are_evaluated_when_messages_change() {
+        use crate::infrastructure::config::{NotificationRuleAction, NotificationRuleConfig};
+
+        let rules = NotificationRules::new(&[NotificationRuleConfig {
+            keywords: vec!["deploy".to_string()],
+            actions: vec![NotificationRuleAction::Highlight],
+            ..NotificationRuleConfig::default()
+        }]);
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(1, "deploy done")]);
+        assert!(!data.messages()[0].highlighted);
+
+        data.set_highlight_rules(&rules, MessageScope::default());
+        assert!(data.messages()[0].highlighted);
+
+        data.add_message(create_test_message(2, "lunch?"));
+        assert!(!data.messages()[1].highlighted);
+
+        data.update_message(create_test_message(2, "deploy after lunch?"));
+        assert!(data.messages()[1].highlighted);
+    }
+
+    #[test]
+    fn test_set_pinned() {
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(1, "Hello")]);
+
+        data.set_pinned(MessageId(1), true);
+        assert!(data.get_message(0).is_some_and(Message::is_pinned));
+
+        data.set_pinned(MessageId(1), false);
+        assert!(!data.get_message(0).is_some_and(Message::is_pinned));
+    }
+
+    #[test]
+    fn test_detached_history_pages_forward_to_present() {
+        use crossterm::event::KeyCode;
+
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_detached_messages(vec![
+            create_test_message(1, "old"),
+            create_test_message(2, "older context"),
+        ]);
+        assert!(data.is_detached());
+
+        data.add_message(create_test_message(9, "live"));
+        assert_eq!(
+            data.message_count(),
+            2,
+            "live messages wait for the present"
+        );
+
+        let registry = CommandRegistry::default();
+        let mut state = MessagePaneState::new();
+        state.jump_to_index(1);
+        assert!(matches!(
+            state.handle_key(KeyEvent::from(KeyCode::Down), &data, &registry, None, false),
+            Some(MessagePaneAction::LoadNewer)
+        ));
+
+        data.set_loading_newer(true);
+        assert!(
+            state
+                .handle_key(KeyEvent::from(KeyCode::Down), &data, &registry, None, false)
+                .is_none(),
+            "no second request while one is pending"
+        );
+
+        let newer = vec![create_test_message(2, "dup"), create_test_message(3, "new")];
+        assert_eq!(data.append_messages(newer, true), 1);
+        assert!(!data.is_detached());
+        assert!(!data.is_loading_newer());
+        data.add_message(create_test_message(9, "live"));
+        assert_eq!(data.message_count(), 4);
+    }
+
+    #[test]
+    fn test_refresh_relative_timestamps_invalidates_only_relative() {
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![
+            create_test_message(1, "due <t:1700000000:R>"),
+            create_test_message(2, "at <t:1700000000:t>"),
+        ]);
+        let markdown = MarkdownRenderer::new();
+        data.update_layout(100, &markdown, Style::default(), false, true);
+
+        data.refresh_relative_timestamps();
+
+        let messages = data.ui_messages();
+        assert!(messages[0].rendered_content.is_none());
+        assert!(messages[1].rendered_content.is_some());
+    }
+
+    #[test]
+    fn test_wrapped_links_resolve_by_position() {
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(
+            1,
+            "see https://a.com/x and https://a.com/xy",
+        )]);
+        let markdown = MarkdownRenderer::new();
+
+        data.update_layout(100, &markdown, Style::default(), false, true);
+        assert_eq!(data.link_at(0, 0, 10).as_deref(), Some("https://a.com/x"));
+        assert_eq!(data.link_at(0, 0, 20), None);
+        assert_eq!(data.link_at(0, 0, 30).as_deref(), Some("https://a.com/xy"));
+
+        // Wrapped four columns wide, both links have an "a.co" line.
+        data.update_layout(12, &markdown, Style::default(), false, true);
+        assert_eq!(data.link_at(0, 3, 1).as_deref(), Some("https://a.com/x"));
+        assert_eq!(data.link_at(0, 8, 1).as_deref(), Some("https://a.com/xy"));
+    }
+
+    #[test]
+    fn test_emoji_images_are_placed_only_when_enabled() {
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(1, "hi <:blob:7> and <:blob:7>")]);
+        let markdown = MarkdownRenderer::new();
+
+        data.update_layout(100, &markdown, Style::default(), false, true);
+        let message = &data.ui_messages()[0];
+        assert_eq!(message.emoji_images.len(), 1);
+        assert!(message.emoji_slots.is_empty());
+        assert!(message.collect_image_loads().is_empty());
+
+        data.set_inline_emoji_images(true);
+        data.update_layout(100, &markdown, Style::default(), false, true);
+        let message = &data.ui_messages()[0];
+        assert_eq!(
+            message.emoji_slots,
+            [
+                EmojiSlot {
+                    image: 0,
+                    line: 0,
+                    column: 3
+                },
+                EmojiSlot {
+                    image: 0,
+                    line: 0,
+                    column: 10
+                },
+            ]
+        );
+        assert_eq!(message.collect_image_loads()[0].1, emoji_url("7"));
+    }
+
     #[test]
     fn test_message_pane_state_navigation() {
         let mut state = MessagePaneState::new();
@@ -2975,6 +4095,97 @@ mod tests {
         pane.render(area, &mut buf, &mut state);
     }
 
+    #[test]
+    fn test_mouse_click_selects_message_and_opens_links() {
+        use crossterm::event::KeyModifiers;
+        use ratatui::widgets::StatefulWidget;
+
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![
+            create_test_message(1, "first"),
+            create_test_message(2, "see https://example.com/a%20b"),
+        ]);
+        let markdown = MarkdownRenderer::new();
+        let mut state = MessagePaneState::new();
+        let area = Rect::new(0, 0, 80, 12);
+        let mut buf = Buffer::empty(area);
+        MessagePane::new(&mut data, &markdown).render(area, &mut buf, &mut state);
+
+        let find = |needle: &str| {
+            (0..area.height).find_map(|y| {
+                let row: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+                row.find(needle).map(|x| (u16::try_from(x).unwrap(), y))
+            })
+        };
+        let click = |(column, row): (u16, u16)| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        let first = find("first").unwrap();
+        assert!(matches!(
+            state.handle_mouse(click(first), &data),
+            Some(MessagePaneAction::SelectMessage(id)) if id == MessageId::from(1)
+        ));
+        assert_eq!(state.selected_index(), Some(0));
+
+        let link = find("example.com").unwrap();
+        assert!(matches!(
+            state.handle_mouse(click(link), &data),
+            Some(MessagePaneAction::OpenLink(url)) if url == "https://example.com/a%20b"
+        ));
+        assert_eq!(state.selected_index(), Some(1));
+
+        let scroll_up = MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            ..click(link)
+        };
+        assert!(matches!(
+            state.handle_mouse(scroll_up, &data),
+            Some(MessagePaneAction::LoadHistory)
+        ));
+    }
+
+    #[test]
+    fn test_only_the_pins_marker_opens_pins() {
+        use crossterm::event::KeyModifiers;
+        use ratatui::widgets::StatefulWidget;
+
+        let mut data = MessagePaneData::new(true);
+        data.set_channel(ChannelId(100), "general".to_string());
+        data.set_messages(vec![create_test_message(1, "first")]);
+        let markdown = MarkdownRenderer::new();
+        let mut state = MessagePaneState::new();
+        let area = Rect::new(0, 0, 80, 12);
+        let mut buf = Buffer::empty(area);
+        MessagePane::new(&mut data, &markdown).render(area, &mut buf, &mut state);
+
+        let title_width = data.formatted_channel_title().unwrap().width();
+        let marker = u16::try_from(title_width + 2).unwrap();
+        let click = |column: u16| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert!(matches!(
+            state.handle_mouse(click(marker), &data),
+            Some(MessagePaneAction::OpenPins)
+        ));
+        assert!(!matches!(
+            state.handle_mouse(click(2), &data),
+            Some(MessagePaneAction::OpenPins)
+        ));
+        assert!(!matches!(
+            state.handle_mouse(click(70), &data),
+            Some(MessagePaneAction::OpenPins)
+        ));
+    }
+
     #[test]
     fn test_embed_height_calculation_padding() {
         use crate::domain::entities::Embed;
@@ -3058,4 +4269,54 @@ mod tests {
 
         assert_eq!(data.message_count(), expected_count);
     }
+
+    #[test]
+    fn test_forum_keys_cycle_sort_and_tag_filter() {
+        use crate::domain::entities::{ForumSortOrder, ForumTag};
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let data = MessagePaneData::new(true);
+        let registry = CommandRegistry::default();
+        let mut state = MessagePaneState::new();
+        state.view_mode = ViewMode::Forum(ForumState {
+            available_tags: vec![ForumTag::new(10, "bug"), ForumTag::new(11, "question")],
+            ..ForumState::default()
+        });
+
+        let mut press = |code: KeyCode, modifiers: KeyModifiers| {
+            state.handle_key(
+                KeyEvent::new(code, modifiers),
+                &data,
+                &registry,
+                None,
+                false,
+            )
+        };
+
+        let tags: Vec<Option<u64>> = (0..3)
+            .map(|_| match press(KeyCode::Char('f'), KeyModifiers::NONE) {
+                Some(MessagePaneAction::SetForumFilter(filter)) => filter.tag_id,
+                other => panic!("expected a filter change, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(tags, vec![Some(10), Some(11), None]);
+
+        assert!(matches!(
+            press(KeyCode::Char('S'), KeyModifiers::SHIFT),
+            Some(MessagePaneAction::SetForumFilter(ForumFilter {
+                sort: ForumSortOrder::CreationDate,
+                tag_id: None,
+            }))
+        ));
+        assert!(matches!(
+            press(KeyCode::Char('n'), KeyModifiers::NONE),
+            Some(MessagePaneAction::NewForumPost)
+        ));
+
+        let ViewMode::Forum(forum_state) = &state.view_mode else {
+            panic!("expected forum view");
+        };
+        assert_eq!(forum_state.tag_label(11), "question");
+        assert_eq!(forum_state.tag_label(12), "12");
+    }
 }
@@ -6,6 +6,7 @@ mod guilds_tree;
 mod header_bar;
 mod image_state;
 mod input;
+mod member_list;
 mod mention_popup;
 mod message_input;
 mod message_pane;
@@ -14,7 +15,7 @@ mod status_bar;
 pub use channel_header::{ChannelHeader, ChannelHeaderStyle};
 pub use confirmation_modal::ConfirmationModal;
 pub use file_explorer::{FileExplorerAction, FileExplorerComponent};
-pub use footer_bar::{FocusContext, FooterBar, FooterBarStyle};
+pub use footer_bar::{FocusContext, FooterBar, FooterBarStyle, KeyHints};
 pub use guilds_tree::{
     GuildsTree, GuildsTreeAction, GuildsTreeData, GuildsTreeState, GuildsTreeStyle,
     SortedGuildChannels, TreeNodeId,
@@ -22,6 +23,7 @@ pub use guilds_tree::{
 pub use header_bar::{HeaderBar, HeaderBarStyle};
 pub use image_state::{ImageAttachment, ImageManager, LOAD_BUFFER, MAX_IMAGE_HEIGHT};
 pub use input::TextInput;
+pub use member_list::{MemberList, MemberListAction, MemberListState, MemberListStyle};
 pub use mention_popup::MentionPopup;
 pub use message_input::{
     MessageInput, MessageInputAction, MessageInputMode, MessageInputState, MessageInputStyle,